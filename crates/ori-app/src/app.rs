@@ -6,13 +6,14 @@ use ori_core::{
     command::{CommandProxy, CommandReceiver},
     context::{BaseCx, BuildCx, Contexts, DrawCx, EventCx, LayoutCx, RebuildCx},
     event::{
-        CloseRequested, Code, Event, Key, KeyPressed, KeyReleased, Modifiers, PointerButton,
-        PointerId, PointerLeft, PointerMoved, PointerPressed, PointerReleased, PointerScrolled,
-        WindowMaximized, WindowResized, WindowScaled,
+        CloseRequested, Code, Event, ImeCommit, ImePreedit, Key, KeyPressed, KeyReleased,
+        Modifiers, PointerButton, PointerId, PointerLeft, PointerMoved, PointerPinched,
+        PointerPressed, PointerReleased, PointerScrolled, WindowMaximized, WindowResized,
+        WindowScaled,
     },
     layout::{Point, Size, Space, Vector},
     style::Styles,
-    view::{any, AnyState, BoxedView, View, ViewState},
+    view::{any, AnyState, BoxedView, LayoutDebugger, View, ViewState},
     views::opaque,
     window::{Cursor, Window, WindowId, WindowSizing, WindowSnapshot, WindowUpdate},
 };
@@ -107,6 +108,7 @@ impl<T> WindowState<T> {
 
         cx.insert_context(self.window.clone());
         self.view.draw(&mut self.state, &mut cx, data);
+        LayoutDebugger::draw_overlay(&mut cx);
         self.window = cx.remove_context().expect("Window context missing");
     }
 
@@ -130,6 +132,7 @@ pub struct App<T> {
     pub(crate) style: Styles,
     pub(crate) requests: Vec<AppRequest<T>>,
     pub(crate) contexts: Contexts,
+    pub(crate) layout_debugger_shortcut: bool,
 }
 
 impl<T> App<T> {
@@ -220,6 +223,16 @@ impl<T> App<T> {
         };
 
         let delta = window_state.window.move_pointer(pointer_id, position);
+
+        // the layout debugger shows the hovered view, so it must follow the pointer
+        if self
+            .contexts
+            .get::<LayoutDebugger>()
+            .is_some_and(|d| d.is_enabled())
+        {
+            window_state.view_state.request_draw();
+        }
+
         self.update_hovered(window_id);
 
         let event = Event::PointerMoved(PointerMoved {
@@ -273,6 +286,28 @@ impl<T> App<T> {
         self.window_event(data, window_id, &event);
     }
 
+    /// A pointer made a pinch gesture, `scale` is the change since the last one.
+    pub fn pointer_pinched(
+        &mut self,
+        data: &mut T,
+        window_id: WindowId,
+        pointer_id: PointerId,
+        scale: f32,
+    ) {
+        let position = self
+            .pointer_position(window_id, pointer_id)
+            .unwrap_or(Point::ZERO);
+
+        let event = Event::PointerPinched(PointerPinched {
+            id: pointer_id,
+            modifiers: self.modifiers,
+            position,
+            scale,
+        });
+
+        self.window_event(data, window_id, &event);
+    }
+
     /// A pointer button was pressed or released.
     pub fn pointer_button(
         &mut self,
@@ -326,6 +361,18 @@ impl<T> App<T> {
         text: Option<String>,
         pressed: bool,
     ) {
+        let modifiers = self.modifiers;
+        let shortcut = key == Key::F12 && modifiers.ctrl && modifiers.shift;
+
+        // the release of the shortcut is swallowed too, so views never see half of it
+        if shortcut && self.layout_debugger_shortcut {
+            if pressed {
+                self.toggle_layout_debugger();
+            }
+
+            return;
+        }
+
         if pressed {
             let event = Event::KeyPressed(KeyPressed {
                 key,
@@ -346,6 +393,26 @@ impl<T> App<T> {
         }
     }
 
+    /// The input method updated the text being composed.
+    ///
+    /// `cursor` is the byte range of the cursor in `text`, see [`ImePreedit::cursor`].
+    pub fn ime_preedit(
+        &mut self,
+        data: &mut T,
+        window_id: WindowId,
+        text: String,
+        cursor: Option<(usize, usize)>,
+    ) {
+        let event = Event::ImePreedit(ImePreedit { text, cursor });
+        self.window_event(data, window_id, &event);
+    }
+
+    /// The input method committed text.
+    pub fn ime_commit(&mut self, data: &mut T, window_id: WindowId, text: String) {
+        let event = Event::ImeCommit(ImeCommit { text });
+        self.window_event(data, window_id, &event);
+    }
+
     /// The modifiers changed.
     pub fn modifiers_changed(&mut self, modifiers: Modifiers) {
         self.modifiers = modifiers;
@@ -424,12 +491,25 @@ impl<T> App<T> {
             AppCommand::DragWindow(window_id) => {
                 self.requests.push(AppRequest::DragWindow(window_id));
             }
+            AppCommand::ToggleLayoutDebugger => {
+                self.toggle_layout_debugger();
+            }
             AppCommand::Quit => {
                 self.requests.push(AppRequest::Quit);
             }
         }
     }
 
+    /// Toggle the [`LayoutDebugger`] overlay in all windows.
+    pub fn toggle_layout_debugger(&mut self) {
+        self.contexts.get_or_default::<LayoutDebugger>().toggle();
+
+        for window_state in self.windows.values_mut() {
+            window_state.view_state.request_draw();
+            (self.requests).push(AppRequest::RequestRedraw(window_state.window.id()));
+        }
+    }
+
     /// Handle all pending commands.
     pub fn handle_commands(&mut self, data: &mut T) {
         while let Some(command) = self.receiver.try_recv() {
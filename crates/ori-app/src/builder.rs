@@ -16,6 +16,7 @@ pub struct AppBuilder<T> {
     requests: Vec<AppRequest<T>>,
     style: Styles,
     fonts: Fonts,
+    layout_debugger_shortcut: bool,
 }
 
 impl<T> Default for AppBuilder<T> {
@@ -32,6 +33,7 @@ impl<T> AppBuilder<T> {
             requests: Vec::new(),
             style: Styles::new(),
             fonts: Fonts::new(),
+            layout_debugger_shortcut: cfg!(debug_assertions),
         }
     }
 
@@ -62,6 +64,18 @@ impl<T> AppBuilder<T> {
         self
     }
 
+    /// Set whether `Ctrl+Shift+F12` toggles the [`LayoutDebugger`], this is only enabled in
+    /// debug builds by default.
+    ///
+    /// The layout debugger can always be toggled with [`AppCommand::ToggleLayoutDebugger`].
+    ///
+    /// [`LayoutDebugger`]: ori_core::view::LayoutDebugger
+    /// [`AppCommand::ToggleLayoutDebugger`]: crate::AppCommand::ToggleLayoutDebugger
+    pub fn layout_debugger_shortcut(mut self, enabled: bool) -> Self {
+        self.layout_debugger_shortcut = enabled;
+        self
+    }
+
     /// Add a window to the application.
     pub fn window<V, P>(
         mut self,
@@ -89,6 +103,7 @@ impl<T> AppBuilder<T> {
             style: self.style,
             requests: self.requests,
             contexts,
+            layout_debugger_shortcut: self.layout_debugger_shortcut,
         }
     }
 }
@@ -27,6 +27,12 @@ pub enum AppCommand {
     /// Drag a window.
     DragWindow(WindowId),
 
+    /// Toggle the [`LayoutDebugger`](ori_core::view::LayoutDebugger) overlay in all windows.
+    ///
+    /// This can also be done by pressing `Ctrl+Shift+F12`, see
+    /// [`AppBuilder::layout_debugger_shortcut`](crate::AppBuilder::layout_debugger_shortcut).
+    ToggleLayoutDebugger,
+
     /// Quit the application.
     Quit,
 }
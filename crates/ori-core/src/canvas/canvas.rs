@@ -316,21 +316,25 @@ impl Canvas {
     }
 
     /// Draw an overlay.
+    ///
+    /// Overlays drawn inside of the overlay are placed relative to `index`, so they stay
+    /// above it.
     pub fn overlay<T>(&mut self, index: i32, f: impl FnOnce(&mut Self) -> T) -> T {
         let mut overlay = Canvas::new();
 
         let result = f(&mut overlay);
 
+        let other = mem::take(Arc::make_mut(&mut overlay.primitives));
+        let primitives = Arc::make_mut(self.overlays.entry(index).or_default());
+        primitives.extend(other);
+
         for (i, mut others) in overlay.overlays {
             let others = mem::take(Arc::make_mut(&mut others));
+            let i = index.saturating_add(i);
             let primitives = Arc::make_mut(self.overlays.entry(i).or_default());
             primitives.extend(others);
         }
 
-        let other = mem::take(Arc::make_mut(&mut overlay.primitives));
-        let primitives = Arc::make_mut(self.overlays.entry(index).or_default());
-        primitives.extend(other);
-
         result
     }
 
@@ -583,3 +587,36 @@ impl CanvasDiff {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::layout::{Point, Rect, Size};
+
+    use super::{Canvas, Color, Primitive, Shader};
+
+    fn shaders(canvas: &Canvas) -> Vec<Shader> {
+        let shaders = canvas.primitives().filter_map(|primitive| match primitive {
+            Primitive::Fill { paint, .. } => Some(paint.shader.clone()),
+            _ => None,
+        });
+
+        shaders.collect()
+    }
+
+    #[test]
+    fn nested_overlays() {
+        let rect = Rect::min_size(Point::ZERO, Size::all(10.0));
+        let mut canvas = Canvas::new();
+
+        canvas.overlay(10, |canvas| {
+            canvas.rect(rect, Color::RED.into());
+            canvas.overlay(0, |canvas| canvas.rect(rect, Color::GREEN.into()));
+        });
+
+        canvas.overlay(5, |canvas| canvas.rect(rect, Color::BLUE.into()));
+
+        // the nested overlay is drawn above the overlay it's in, and above lower overlays
+        let expected = [Color::BLUE, Color::RED, Color::GREEN].map(Shader::Solid);
+        assert_eq!(shaders(&canvas), expected);
+    }
+}
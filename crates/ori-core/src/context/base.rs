@@ -6,7 +6,10 @@ use std::{
 use crate::{
     clipboard::Clipboard,
     command::{Command, CommandProxy},
+    layout::Direction,
     text::Fonts,
+    views::{Overlay, OverlayId, Overlays},
+    window::Window,
 };
 
 use super::Contexts;
@@ -53,6 +56,20 @@ impl<'a> BaseCx<'a> {
         self.proxy.cmd_async(future);
     }
 
+    /// Open an [`Overlay`] above the current window, see [`Overlays`].
+    ///
+    /// The overlay is only shown if the content of the window is wrapped in an
+    /// [`OverlayLayer`](crate::views::OverlayLayer), otherwise a warning is logged.
+    pub fn open_overlay<T: 'static>(&mut self, overlay: Overlay<T>) -> OverlayId {
+        let window = self.get_context::<Window>().map(Window::id);
+        self.context_or_default::<Overlays>().open(window, overlay)
+    }
+
+    /// Close the overlay with `id`, see [`Overlays`].
+    pub fn close_overlay(&mut self, id: OverlayId) {
+        self.context_or_default::<Overlays>().close(id);
+    }
+
     /// Get a reference to the [`Contexts`].
     pub fn contexts(&self) -> &Contexts {
         self.contexts
@@ -63,6 +80,11 @@ impl<'a> BaseCx<'a> {
         self.contexts
     }
 
+    /// Get the layout [`Direction`], this is [`Direction::Ltr`] unless set by a parent.
+    pub fn direction(&self) -> Direction {
+        self.get_context().copied().unwrap_or_default()
+    }
+
     /// Insert a context.
     pub fn insert_context<T: Any>(&mut self, context: T) -> Option<T> {
         self.contexts.insert(context)
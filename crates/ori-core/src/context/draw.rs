@@ -67,6 +67,13 @@ impl<'a, 'b> DrawCx<'a, 'b> {
         self.visible.intersects(rect)
     }
 
+    /// Get the visible area in local space.
+    ///
+    /// Views that draw a lot of content, can skip what isn't in this area.
+    pub fn visible(&self) -> Rect {
+        self.visible
+    }
+
     /// Get the transform of the view.
     pub fn transform(&self) -> Affine {
         self.transform
@@ -82,6 +89,14 @@ impl<'a, 'b> DrawCx<'a, 'b> {
         Rect::min_size(Point::ZERO, self.size())
     }
 
+    /// Request an animation frame.
+    ///
+    /// Requesting frames while drawing means they stop when the view is no longer drawn,
+    /// for example when it's scrolled out of view.
+    pub fn animate(&mut self) {
+        self.view_state.request_animate();
+    }
+
     /// Get the canvas.
     pub fn canvas(&mut self) -> &mut Canvas {
         self.canvas
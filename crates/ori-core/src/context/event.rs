@@ -107,6 +107,21 @@ impl<'a, 'b> EventCx<'a, 'b> {
         self.view_state.request_animate();
     }
 
+    /// Mark the event as handled.
+    ///
+    /// Views can use this to tell their ancestors not to act on the event themselves, for
+    /// example an open popup closed by `Escape` keeps an [`OverlayLayer`] from closing too.
+    ///
+    /// [`OverlayLayer`]: crate::views::OverlayLayer
+    pub fn set_handled(&mut self) {
+        self.view_state.set_handled();
+    }
+
+    /// Get whether the event was handled by the view or one of its children.
+    pub fn is_handled(&self) -> bool {
+        self.view_state.is_handled()
+    }
+
     /// Set the cursor of the view.
     pub fn set_cursor(&mut self, cursor: Option<Cursor>) {
         self.view_state.set_cursor(cursor);
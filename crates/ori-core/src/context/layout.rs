@@ -2,7 +2,7 @@ use std::ops::{Deref, DerefMut};
 
 use crate::view::ViewState;
 
-use super::BaseCx;
+use super::{BaseCx, BuildCx};
 
 /// A context for laying out the view tree.
 pub struct LayoutCx<'a, 'b> {
@@ -37,4 +37,28 @@ impl<'a, 'b> LayoutCx<'a, 'b> {
             view_state: self.view_state,
         }
     }
+
+    /// Get a build context.
+    pub fn as_build_cx(&mut self) -> BuildCx<'_, 'b> {
+        BuildCx::new(self.base, self.view_state)
+    }
+
+    /// Cache the layout of the view, even though some of its children can't be cached, see
+    /// [`ViewState::cache_uncached_children`].
+    pub fn cache_uncached_children(&mut self) {
+        self.view_state.cache_uncached_children();
+    }
+
+    /// Set the first baseline of the view, in local coordinates.
+    ///
+    /// Text-like views should set this, and containers should forward the baseline of their
+    /// content, so that [`Align::Baseline`](crate::layout::Align::Baseline) can align them.
+    pub fn set_baseline(&mut self, baseline: Option<f32>) {
+        self.view_state.set_baseline(baseline);
+    }
+
+    /// Get the first baseline of the view, in local coordinates.
+    pub fn baseline(&self) -> Option<f32> {
+        self.view_state.baseline()
+    }
 }
@@ -76,6 +76,23 @@ impl_context! {BuildCx<'_, '_>, RebuildCx<'_, '_>, EventCx<'_, '_>, LayoutCx<'_,
         self.view_state.has_active()
     }
 
+    /// Set whether the layout of the view can be cached, see [`ViewState::set_cache_layout`].
+    ///
+    /// [`ViewState::set_cache_layout`]: crate::view::ViewState::set_cache_layout
+    pub fn set_cache_layout(&mut self, cache: bool) {
+        self.view_state.set_cache_layout(cache);
+    }
+
+    /// Get whether the view is focusable.
+    pub fn is_focusable(&self) -> bool {
+        self.view_state.is_focusable()
+    }
+
+    /// Set whether the view is focusable.
+    pub fn set_focusable(&mut self, focusable: bool) {
+        self.view_state.set_focusable(focusable);
+    }
+
     /// Check if the view has the property `T`.
     pub fn contains_property<T: 'static>(&self) -> bool {
         self.view_state.contains_property::<T>()
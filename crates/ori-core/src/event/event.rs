@@ -1,10 +1,11 @@
 use std::any::Any;
 
-use crate::command::Command;
+use crate::{command::Command, view::ViewId};
 
 use super::{
-    CloseRequested, IsKey, KeyPressed, KeyReleased, PointerLeft, PointerMoved, PointerPressed,
-    PointerReleased, PointerScrolled, WindowMaximized, WindowResized, WindowScaled,
+    CloseRequested, ImeCommit, ImePreedit, IsKey, KeyPressed, KeyReleased, PointerLeft,
+    PointerMoved, PointerPinched, PointerPressed, PointerReleased, PointerScrolled,
+    WindowMaximized, WindowResized, WindowScaled,
 };
 
 /// An event that can be sent to a view.
@@ -38,12 +39,21 @@ pub enum Event {
     /// A pointer was scrolled.
     PointerScrolled(PointerScrolled),
 
+    /// A pointer made a pinch gesture.
+    PointerPinched(PointerPinched),
+
     /// A keyboard key was pressed.
     KeyPressed(KeyPressed),
 
     /// A keyboard key was released.
     KeyReleased(KeyReleased),
 
+    /// The input method updated the text being composed.
+    ImePreedit(ImePreedit),
+
+    /// The input method committed text.
+    ImeCommit(ImeCommit),
+
     /// An animation frame has passed.
     Animate(f32),
 
@@ -52,6 +62,15 @@ pub enum Event {
 
     /// View state needs to be updated.
     Update,
+
+    /// The focusable views are added to the [`FocusOrder`] context, see [`FocusOrder::add`].
+    ///
+    /// [`FocusOrder`]: super::FocusOrder
+    /// [`FocusOrder::add`]: super::FocusOrder::add
+    CollectFocus,
+
+    /// The view with the id is focused, and every other focusable or focused view isn't.
+    GiveFocus(Option<ViewId>),
 }
 
 impl Event {
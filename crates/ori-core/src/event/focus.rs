@@ -0,0 +1,61 @@
+use crate::view::{ViewId, ViewState};
+
+/// The focusable views in a part of the view tree, collected by [`Event::CollectFocus`].
+///
+/// The views are in the order they receive events, which is the order focus moves in.
+///
+/// [`Event::CollectFocus`]: super::Event::CollectFocus
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FocusOrder {
+    /// The focusable views.
+    pub views: Vec<ViewId>,
+
+    /// The focused view, if any.
+    pub focused: Option<ViewId>,
+}
+
+impl FocusOrder {
+    /// Create a new empty [`FocusOrder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a view, if it's focusable or focused.
+    pub fn add(&mut self, view_state: &ViewState) {
+        if view_state.is_focusable() {
+            self.views.push(view_state.id());
+        }
+
+        if view_state.is_focused() {
+            self.focused = Some(view_state.id());
+        }
+    }
+
+    /// Get the first focusable view.
+    pub fn first(&self) -> Option<ViewId> {
+        self.views.first().copied()
+    }
+
+    /// Get the view after the focused one, wrapping around to the first.
+    pub fn next(&self) -> Option<ViewId> {
+        match self.focused_index() {
+            Some(index) => self.views.get((index + 1) % self.views.len()).copied(),
+            None => self.views.first().copied(),
+        }
+    }
+
+    /// Get the view before the focused one, wrapping around to the last.
+    pub fn prev(&self) -> Option<ViewId> {
+        let len = self.views.len();
+
+        match self.focused_index() {
+            Some(index) => self.views.get((index + len - 1) % len).copied(),
+            None => self.views.last().copied(),
+        }
+    }
+
+    fn focused_index(&self) -> Option<usize> {
+        let focused = self.focused?;
+        self.views.iter().position(|&view| view == focused)
+    }
+}
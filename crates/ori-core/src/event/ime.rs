@@ -0,0 +1,31 @@
+/// An event fired when the input method updates the text being composed.
+///
+/// The preedit text isn't part of the input yet, and is replaced by the next
+/// [`ImePreedit`], or removed when the composition is committed with [`ImeCommit`].
+/// An empty `text` means that the composition was cleared.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImePreedit {
+    /// The text being composed.
+    pub text: String,
+
+    /// The byte range of the cursor in `text`.
+    ///
+    /// When the start and end are the same, this is the position of the cursor, otherwise the
+    /// range is the part of the text currently being converted. `None` means the cursor should
+    /// be hidden.
+    pub cursor: Option<(usize, usize)>,
+}
+
+impl ImePreedit {
+    /// Check if the composition was cleared.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+}
+
+/// An event fired when the input method commits text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImeCommit {
+    /// The committed text.
+    pub text: String,
+}
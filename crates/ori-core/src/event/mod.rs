@@ -1,12 +1,16 @@
 //! Events and event handling.
 
 mod event;
+mod focus;
+mod ime;
 mod keyboard;
 mod modifiers;
 mod pointer;
 mod window;
 
 pub use event::*;
+pub use focus::*;
+pub use ime::*;
 pub use keyboard::*;
 pub use modifiers::*;
 pub use pointer::*;
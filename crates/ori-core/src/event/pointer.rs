@@ -141,3 +141,19 @@ pub struct PointerScrolled {
     /// The modifiers of the pointer.
     pub modifiers: Modifiers,
 }
+
+/// A pointer made a pinch gesture, usually on a touchpad.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointerPinched {
+    /// The unique id of the pointer.
+    pub id: PointerId,
+
+    /// The position of the pointer.
+    pub position: Point,
+
+    /// The change in scale since the last event, as a factor.
+    pub scale: f32,
+
+    /// The modifiers of the pointer.
+    pub modifiers: Modifiers,
+}
@@ -0,0 +1,34 @@
+use super::{Size, Vector};
+
+/// The direction in which content flows horizontally.
+///
+/// This is available as a context, see [`BaseCx::direction`](crate::context::BaseCx::direction),
+/// and is set with the [`direction`](crate::views::direction) view.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Content flows from left to right.
+    #[default]
+    Ltr,
+
+    /// Content flows from right to left.
+    Rtl,
+}
+
+impl Direction {
+    /// Get whether the direction is right to left.
+    pub fn is_rtl(self) -> bool {
+        self == Self::Rtl
+    }
+
+    /// Mirror the `offset` of a child of `size` horizontally within a `parent`, if the
+    /// direction is right to left.
+    ///
+    /// Layouts are computed left to right, and mirrored with this when placing the children.
+    pub fn mirror(self, offset: Vector, size: Size, parent: Size) -> Vector {
+        match self {
+            Self::Ltr => offset,
+            Self::Rtl => Vector::new(parent.width - offset.x - size.width, offset.y),
+        }
+    }
+}
@@ -18,6 +18,12 @@ pub enum Align {
 
     /// Items are stretched to fill the available space.
     Fill,
+
+    /// Items are aligned so that their first baselines line up.
+    ///
+    /// Items without a baseline are aligned by their bottom edge. This only applies to
+    /// horizontal containers, vertical containers treat it as [`Align::Start`].
+    Baseline,
 }
 
 impl Align {
@@ -29,6 +35,7 @@ impl Align {
             Self::Center => (available - size) / 2.0,
             Self::Stretch => 0.0,
             Self::Fill => 0.0,
+            Self::Baseline => 0.0,
         }
     }
 }
@@ -3,6 +3,7 @@
 mod affine;
 mod alignment;
 mod axis;
+mod direction;
 mod justify;
 mod matrix;
 mod padding;
@@ -15,6 +16,7 @@ mod vector;
 pub use affine::*;
 pub use alignment::*;
 pub use axis::*;
+pub use direction::*;
 pub use justify::*;
 pub use matrix::*;
 pub use padding::*;
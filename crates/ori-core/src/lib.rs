@@ -10,6 +10,7 @@ pub mod context;
 pub mod event;
 pub mod image;
 pub mod layout;
+pub mod locale;
 pub mod rebuild;
 pub mod style;
 pub mod text;
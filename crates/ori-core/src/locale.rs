@@ -0,0 +1,78 @@
+//! Locales.
+
+use std::fmt::Display;
+
+/// A locale, like `en_US` or `de-DE.UTF-8`, reduced to its language and region.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Locale {
+    /// The lowercase language, like `en`.
+    pub language: String,
+
+    /// The uppercase region, like `US`, if any.
+    pub region: Option<String>,
+}
+
+impl Locale {
+    /// Parse a locale, like `en_US`, `de-DE.UTF-8` or `sr_RS@latin`.
+    ///
+    /// The encoding and modifier are ignored, returns `None` for an empty locale and for the
+    /// `C` and `POSIX` locales, which don't name a language.
+    pub fn parse(locale: &str) -> Option<Self> {
+        let locale = locale.split(['.', '@']).next().unwrap_or_default();
+
+        if locale.is_empty() || locale == "C" || locale == "POSIX" {
+            return None;
+        }
+
+        let mut parts = locale.split(['_', '-']);
+        let language = parts.next().unwrap_or_default().to_lowercase();
+        let region = parts.next().map(str::to_uppercase);
+
+        if language.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            language,
+            region: region.filter(|region| !region.is_empty()),
+        })
+    }
+
+    /// Get the locale of the system for a `category`, like `LC_TIME` or `LC_NUMERIC`.
+    ///
+    /// The `LC_ALL`, `category` and `LANG` environment variables are checked in that order,
+    /// and the first one naming a language is used.
+    pub fn system(category: &str) -> Option<Self> {
+        ["LC_ALL", category, "LANG"]
+            .into_iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find_map(|locale| Self::parse(&locale))
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.region {
+            Some(ref region) => write!(f, "{}_{}", self.language, region),
+            None => write!(f, "{}", self.language),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Locale;
+
+    #[test]
+    fn parse() {
+        let locale = Locale::parse("de-de.UTF-8").unwrap();
+        assert_eq!(locale.language, "de");
+        assert_eq!(locale.region.as_deref(), Some("DE"));
+        assert_eq!(locale.to_string(), "de_DE");
+
+        assert_eq!(Locale::parse("sr@latin").unwrap().to_string(), "sr");
+        assert_eq!(Locale::parse("C.UTF-8"), None);
+        assert_eq!(Locale::parse("POSIX"), None);
+        assert_eq!(Locale::parse(""), None);
+    }
+}
@@ -164,14 +164,14 @@ impl FontStyle {
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TextAlign {
-    /// Align text at the start.
+    /// Align text at the start, following the direction of the paragraph.
     #[default]
     Start,
 
     /// Align text in the center.
     Center,
 
-    /// Align text at the end.
+    /// Align text at the end, following the direction of the paragraph.
     End,
 }
 
@@ -191,6 +191,18 @@ impl TextAlign {
             TextAlign::End => cosmic_text::Align::Right,
         }
     }
+
+    /// Convert the text align to a [`cosmic_text::Align`] following the direction of each
+    /// paragraph, as detected by cosmic-text.
+    ///
+    /// [`TextAlign::Start`] is `None`, which lets cosmic-text choose.
+    pub fn to_cosmic_text_directional(self) -> Option<cosmic_text::Align> {
+        match self {
+            TextAlign::Start => None,
+            TextAlign::Center => Some(cosmic_text::Align::Center),
+            TextAlign::End => Some(cosmic_text::Align::End),
+        }
+    }
 }
 
 /// Wrapping of a section of text.
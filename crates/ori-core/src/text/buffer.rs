@@ -1,4 +1,4 @@
-use cosmic_text::{Buffer, Shaping};
+use cosmic_text::{Attrs, Buffer, Shaping};
 
 use crate::layout::{Point, Rect, Size};
 
@@ -53,7 +53,7 @@ impl TextBuffer {
     /// Set the align of the text buffer.
     pub fn set_align(&mut self, align: TextAlign) {
         for line in &mut self.buffer.lines {
-            line.set_align(Some(align.to_cosmic_text()));
+            line.set_align(align.to_cosmic_text_directional());
         }
     }
 
@@ -67,6 +67,18 @@ impl TextBuffer {
         Rect::min_size(Point::ZERO, self.size())
     }
 
+    /// Get whether the first paragraph of the text buffer is right to left.
+    ///
+    /// This is detected from the text, and is only known after the text has been laid out.
+    pub fn is_rtl(&self) -> bool {
+        self.buffer.layout_runs().next().is_some_and(|run| run.rtl)
+    }
+
+    /// Get the first baseline of the text buffer, if it has any lines.
+    pub fn baseline(&self) -> Option<f32> {
+        self.buffer.layout_runs().next().map(|run| run.line_y)
+    }
+
     /// Get the bounds of the text buffer.
     pub fn bounds(&self) -> Size {
         let (width, height) = self.buffer.size();
@@ -99,6 +111,23 @@ impl TextBuffer {
         );
     }
 
+    /// Set the text of the text buffer from spans of text, each with their own attributes.
+    ///
+    /// Parts of the text not covered by `spans` use `attrs`.
+    pub fn set_rich_text<'a>(
+        &mut self,
+        fonts: &mut Fonts,
+        spans: impl IntoIterator<Item = (&'a str, Attrs<'a>)>,
+        attrs: TextAttributes,
+    ) {
+        self.buffer.set_rich_text(
+            &mut fonts.font_system,
+            spans,
+            attrs.to_cosmic_text(),
+            Shaping::Advanced,
+        );
+    }
+
     /// Set the wrapping mode of the text buffer.
     pub fn set_wrap(&mut self, fonts: &mut Fonts, wrap: TextWrap) {
         (self.buffer).set_wrap(&mut fonts.font_system, wrap.to_cosmic_text());
@@ -5,7 +5,7 @@ use cosmic_text::{Buffer, CacheKey, Command, FontSystem, SwashCache};
 const EMBEDDED_FONTS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/fonts.bin"));
 
 use crate::{
-    canvas::{AntiAlias, Canvas, Curve, FillRule, Paint},
+    canvas::{AntiAlias, Canvas, Color, Curve, FillRule, Paint, Shader},
     layout::{Affine, Point, Size, Vector},
 };
 
@@ -26,6 +26,8 @@ pub struct Fonts {
 
     /// The glyph cache.
     pub glyph_cache: HashMap<CacheKey, Arc<Curve>, BuildHasherDefault<seahash::SeaHasher>>,
+
+    revision: u64,
 }
 
 impl Default for Fonts {
@@ -53,14 +55,23 @@ impl Fonts {
             swash_cache,
             font_system,
             glyph_cache: HashMap::default(),
+            revision: 0,
         }
     }
 
+    /// Get a number that changes whenever fonts are loaded, text laid out before that may have
+    /// a different size now.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
     /// Loads a font from a [`FontSource`].
     ///
     /// This will usually either be a path to a font file or the font data itself, but can also
     /// be a [`Vec<FontSource>`] to load multiple fonts at once.
     pub fn load_font(&mut self, source: impl Into<FontSource>) -> Result<(), io::Error> {
+        self.revision += 1;
+
         match source.into() {
             FontSource::Data(data) => {
                 self.font_system.db_mut().load_font_data(data);
@@ -83,6 +94,7 @@ impl Fonts {
     /// This is a platform-specific operation, for more information see the
     /// documentation for [`fontdb::Database::load_system_fonts`](cosmic_text::fontdb::Database::load_system_fonts).
     pub fn load_system_fonts(&mut self) {
+        self.revision += 1;
         self.font_system.db_mut().load_system_fonts();
     }
 
@@ -97,7 +109,7 @@ impl Fonts {
 
         for run in buffer.layout_runs() {
             width = f32::max(width, run.line_w);
-            height += buffer.metrics().line_height;
+            height += run.line_height;
         }
 
         Size::new(width, height).ceil()
@@ -172,8 +184,22 @@ impl Fonts {
                     glyph.y + run.line_y + glyph.y_offset,
                 ) + offset;
 
+                // glyphs with a color of their own, see `TextBuffer::set_rich_text`
+                let paint = match glyph.color_opt {
+                    Some(color) => Paint {
+                        shader: Shader::Solid(Color::rgba8(
+                            color.r(),
+                            color.g(),
+                            color.b(),
+                            color.a(),
+                        )),
+                        ..paint.clone()
+                    },
+                    None => paint.clone(),
+                };
+
                 canvas.transform(Affine::translate(offset), |canvas| {
-                    canvas.fill(curve.clone(), FillRule::NonZero, paint.clone());
+                    canvas.fill(curve.clone(), FillRule::NonZero, paint);
                 });
             }
         }
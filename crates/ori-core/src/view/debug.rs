@@ -0,0 +1,293 @@
+use std::collections::HashSet;
+
+use crate::{
+    canvas::{Color, Curve, FillRule},
+    context::{BaseCx, DrawCx},
+    layout::{Affine, Padding, Point, Rect, Size, Space, Vector},
+    text::{FontFamily, TextAttributes, TextBuffer, TextWrap},
+    views::Flex,
+};
+
+use super::ViewId;
+
+/// A debug overlay showing the layout of every [`Pod`](super::Pod).
+///
+/// When inserted as a context and enabled, the bounds, padding and flex allocation of every pod
+/// are drawn on top of the window. Views that were laid out this frame are outlined in orange,
+/// and views that were drawn this frame are outlined in blue. Hovering a view shows its type
+/// name, [`ViewId`], [`Space`] and [`Size`] in a floating panel.
+///
+/// While enabled, every view is drawn each frame, so the overlay is not representative of
+/// drawing performance.
+#[derive(Debug, Default)]
+pub struct LayoutDebugger {
+    enabled: bool,
+    records: Vec<DebugRecord>,
+    laid_out: HashSet<ViewId>,
+}
+
+#[derive(Debug)]
+struct DebugRecord {
+    id: ViewId,
+    type_name: &'static str,
+    transform: Affine,
+    size: Size,
+    space: Option<Space>,
+    padding: Option<Padding>,
+    flex: Option<Flex>,
+    drawn: bool,
+}
+
+impl LayoutDebugger {
+    const BOUNDS: Color = Color::rgba(0.5, 0.5, 0.5, 0.6);
+    const LAID_OUT: Color = Color::rgba(1.0, 0.55, 0.0, 0.9);
+    const DRAWN: Color = Color::rgba(0.2, 0.5, 1.0, 0.9);
+    const PADDING: Color = Color::rgba(0.4, 0.8, 0.3, 0.25);
+    const FLEX: Color = Color::rgba(0.7, 0.3, 0.9, 0.12);
+    const HOVERED: Color = Color::rgba(0.2, 0.6, 1.0, 0.2);
+    const PANEL: Color = Color::rgba(0.08, 0.08, 0.1, 0.92);
+
+    /// Create a new disabled [`LayoutDebugger`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get whether the debugger is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Set whether the debugger is enabled.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.records.clear();
+        self.laid_out.clear();
+    }
+
+    /// Toggle the debugger.
+    pub fn toggle(&mut self) {
+        self.set_enabled(!self.enabled);
+    }
+
+    /// Check whether an enabled debugger is present in the contexts of `cx`.
+    pub fn is_active(cx: &BaseCx) -> bool {
+        cx.get_context::<Self>().is_some_and(Self::is_enabled)
+    }
+
+    pub(crate) fn record_layout(cx: &mut BaseCx, id: ViewId) {
+        if let Some(debugger) = cx.get_context_mut::<Self>() {
+            if debugger.enabled {
+                debugger.laid_out.insert(id);
+            }
+        }
+    }
+
+    pub(crate) fn record_draw(cx: &mut DrawCx, drawn: bool) {
+        if !Self::is_active(cx) {
+            return;
+        }
+
+        let record = DebugRecord {
+            id: cx.view_state.id(),
+            type_name: cx.view_state.type_name(),
+            transform: cx.transform(),
+            size: cx.view_state.size(),
+            space: cx.view_state.space,
+            padding: None,
+            flex: cx.view_state.get_property().copied(),
+            drawn,
+        };
+
+        cx.context_or_default::<Self>().records.push(record);
+    }
+
+    // record the padding of the pod currently being drawn
+    pub(crate) fn record_padding(cx: &mut DrawCx, padding: Padding) {
+        if !Self::is_active(cx) {
+            return;
+        }
+
+        let id = cx.view_state.id();
+        let debugger = cx.context_or_default::<Self>();
+
+        if let Some(record) = debugger
+            .records
+            .iter_mut()
+            .rev()
+            .find(|record| record.id == id)
+        {
+            record.padding = Some(padding);
+        }
+    }
+
+    /// Draw the overlay of the debugger in the contexts of `cx`, if it is enabled.
+    ///
+    /// This should be called after the view tree of a window has been drawn, and clears
+    /// everything recorded since.
+    pub fn draw_overlay(cx: &mut DrawCx) {
+        let Some(mut debugger) = cx.remove_context::<Self>() else {
+            return;
+        };
+
+        if debugger.enabled {
+            cx.overlay(i32::MAX, |cx| debugger.draw(cx));
+        }
+
+        debugger.records.clear();
+        debugger.laid_out.clear();
+        cx.insert_context(debugger);
+    }
+
+    fn draw(&self, cx: &mut DrawCx) {
+        for record in &self.records {
+            cx.layer(record.transform, |cx| self.draw_record(cx, record));
+        }
+
+        let pointer = cx
+            .window()
+            .pointers()
+            .first()
+            .map(|pointer| pointer.position);
+
+        // records are stored in draw order, so the last hit is the innermost view
+        let hovered = pointer.and_then(|point| {
+            let hovered = self.records.iter().rev().find(|record| {
+                let local = record.transform.inverse() * point;
+                Rect::min_size(Point::ZERO, record.size).contains(local)
+            })?;
+
+            Some((point, hovered))
+        });
+
+        if let Some((point, record)) = hovered {
+            cx.layer(record.transform, |cx| {
+                cx.fill_rect(Rect::min_size(Point::ZERO, record.size), Self::HOVERED);
+            });
+
+            self.draw_panel(cx, point, record);
+        }
+    }
+
+    fn draw_record(&self, cx: &mut DrawCx, record: &DebugRecord) {
+        let rect = Rect::min_size(Point::ZERO, record.size);
+
+        if record.flex.is_some_and(|flex| flex.amount > 0.0) {
+            cx.fill_rect(rect, Self::FLEX);
+        }
+
+        if let Some(padding) = record.padding {
+            let inner = Rect::new(
+                rect.min + Vector::new(padding.left, padding.top),
+                rect.max - Vector::new(padding.right, padding.bottom),
+            );
+
+            let mut curve = Curve::rect(rect);
+            curve.push_rect(inner);
+            cx.fill(curve, FillRule::EvenOdd, Self::PADDING);
+        }
+
+        let color = if self.laid_out.contains(&record.id) {
+            Self::LAID_OUT
+        } else if record.drawn {
+            Self::DRAWN
+        } else {
+            Self::BOUNDS
+        };
+
+        cx.stroke(Curve::rect(rect.shrink(0.5)), 1.0, color);
+    }
+
+    fn draw_panel(&self, cx: &mut DrawCx, point: Point, record: &DebugRecord) {
+        let mut lines = vec![
+            short_type_name(record.type_name),
+            format!("{:?}", record.id),
+            match record.space {
+                Some(space) => format!(
+                    "space: {} x {} .. {} x {}",
+                    space.min.width, space.min.height, space.max.width, space.max.height,
+                ),
+                None => String::from("space: none"),
+            },
+            format!("size: {} x {}", record.size.width, record.size.height),
+        ];
+
+        if let Some(padding) = record.padding {
+            lines.push(format!(
+                "padding: {} {} {} {}",
+                padding.top, padding.right, padding.bottom, padding.left,
+            ));
+        }
+
+        if let Some(flex) = record.flex {
+            let kind = if flex.is_tight { "expand" } else { "flex" };
+            lines.push(format!("{}: {}", kind, flex.amount));
+        }
+
+        let window_size = cx.window().size;
+
+        let mut buffer = TextBuffer::new(cx.fonts(), 12.0, 1.3);
+        buffer.set_wrap(cx.fonts(), TextWrap::None);
+        buffer.set_bounds(cx.fonts(), window_size);
+        buffer.set_text(
+            cx.fonts(),
+            &lines.join("\n"),
+            TextAttributes {
+                family: FontFamily::Monospace,
+                ..Default::default()
+            },
+        );
+
+        // place the panel below the pointer, keeping it inside the window
+        let padding = Padding::all(6.0);
+        let size = buffer.size() + padding.size();
+        let mut position = point + Vector::new(16.0, 16.0);
+        position.x = f32::min(position.x, window_size.width - size.width).max(0.0);
+        position.y = f32::min(position.y, window_size.height - size.height).max(0.0);
+
+        cx.translate(Vector::from(position), |cx| {
+            cx.quad(
+                Rect::min_size(Point::ZERO, size),
+                Self::PANEL,
+                4.0,
+                1.0,
+                Self::BOUNDS,
+            );
+
+            cx.text(&buffer, Color::WHITE, padding.offset());
+        });
+    }
+}
+
+// strip the module paths from a type name, `a::B<c::D>` becomes `B<D>`
+fn short_type_name(name: &str) -> String {
+    let mut short = String::with_capacity(name.len());
+    let mut segment = String::new();
+
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            segment.push(c);
+            continue;
+        }
+
+        short.push_str(segment.rsplit("::").next().unwrap_or_default());
+        short.push(c);
+        segment.clear();
+    }
+
+    short.push_str(segment.rsplit("::").next().unwrap_or_default());
+    short
+}
+
+#[cfg(test)]
+mod tests {
+    use super::short_type_name;
+
+    #[test]
+    fn short_type_names() {
+        assert_eq!(short_type_name("ori_core::views::Text"), "Text");
+        assert_eq!(
+            short_type_name("ori_core::views::pad::Pad<(ori_core::views::Text, f32)>"),
+            "Pad<(Text, f32)>",
+        );
+    }
+}
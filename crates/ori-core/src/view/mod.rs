@@ -3,12 +3,14 @@
 //! This module contains the [`View`] trait and related types.
 
 mod any;
+mod debug;
 mod pod;
 mod sequence;
 mod state;
 mod view;
 
 pub use any::*;
+pub use debug::*;
 pub use pod::*;
 pub use sequence::*;
 pub use state::*;
@@ -3,11 +3,11 @@ use std::ops::{Deref, DerefMut};
 use crate::{
     canvas::Canvas,
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
-    event::Event,
+    event::{Event, FocusOrder},
     layout::{Rect, Size, Space},
 };
 
-use super::{View, ViewState};
+use super::{LayoutDebugger, LayoutKey, View, ViewState};
 
 /// The state of a [`Pod`].
 pub struct State<T, V: View<T> + ?Sized> {
@@ -17,6 +17,16 @@ pub struct State<T, V: View<T> + ?Sized> {
     prev_visible: Rect,
 }
 
+impl<T, V: View<T> + ?Sized> State<T, V> {
+    pub(crate) fn content(&self) -> &V::State {
+        &self.content
+    }
+
+    pub(crate) fn content_mut(&mut self) -> &mut V::State {
+        &mut self.content
+    }
+}
+
 impl<T, V: View<T> + ?Sized> Deref for State<T, V> {
     type Target = ViewState;
 
@@ -84,7 +94,10 @@ impl<V> Pod<V> {
         cx: &mut BuildCx,
         f: impl FnOnce(&mut BuildCx) -> T,
     ) -> (T, ViewState) {
-        let mut view_state = ViewState::default();
+        let mut view_state = ViewState {
+            type_name: std::any::type_name::<V>(),
+            ..Default::default()
+        };
 
         let mut new_cx = cx.child();
         new_cx.view_state = &mut view_state;
@@ -134,6 +147,24 @@ impl<V> Pod<V> {
             view_state.request_layout();
         }
 
+        // focus is moved by the pods, so views don't have to handle it themselves
+        match event {
+            Event::CollectFocus => {
+                if let Some(order) = cx.get_context_mut::<FocusOrder>() {
+                    order.add(view_state);
+                }
+            }
+            Event::GiveFocus(id) if view_state.is_focusable() || view_state.is_focused() => {
+                let focused = *id == Some(view_state.id());
+
+                if view_state.is_focused() != focused {
+                    view_state.set_focused(focused);
+                    view_state.request_draw();
+                }
+            }
+            _ => {}
+        }
+
         view_state.set_hot(cx.window().is_hovered(view_state.id()));
         view_state.prepare();
 
@@ -149,17 +180,50 @@ impl<V> Pod<V> {
     }
 
     /// Call a closure with the [`LayoutCx`] provided by a pod.
+    ///
+    /// If the view doesn't need to be laid out, and neither `space`, the direction nor the fonts
+    /// have changed since the last layout, the closure isn't called and the previous size is
+    /// returned.
     pub(crate) fn layout_with(
         view_state: &mut ViewState,
         cx: &mut LayoutCx,
+        space: Space,
         f: impl FnOnce(&mut LayoutCx) -> Size,
     ) -> Size {
+        let key = LayoutKey {
+            space,
+            direction: cx.direction(),
+            fonts: cx.fonts().revision(),
+        };
+
+        if view_state.is_layout_cached(key) {
+            return view_state.size;
+        }
+
+        // a view may draw differently in another direction or with other fonts, even when its
+        // size doesn't change
+        if let Some(prev) = view_state.layout_key {
+            if prev.direction != key.direction || prev.fonts != key.fonts {
+                view_state.request_draw();
+            }
+        }
+
+        LayoutDebugger::record_layout(cx, view_state.id());
+
         view_state.mark_layed_out();
+        view_state.baseline = None;
+        view_state.has_uncached = false;
 
         let mut new_cx = cx.child();
         new_cx.view_state = view_state;
 
         view_state.size = f(&mut new_cx);
+        view_state.space = Some(space);
+        view_state.layout_key = Some(key);
+
+        // a view that can't be cached can only be reached by laying out its ancestors
+        cx.view_state.has_uncached |= !view_state.caches_layout();
+
         view_state.size
     }
 
@@ -169,6 +233,7 @@ impl<V> Pod<V> {
         cx: &mut DrawCx,
         f: impl FnOnce(&mut DrawCx),
     ) {
+        let drawn = view_state.needs_draw();
         view_state.mark_drawn();
 
         // create the draw context
@@ -177,8 +242,14 @@ impl<V> Pod<V> {
 
         // draw the content
         new_cx.layer(new_cx.view_state.transform, |cx| {
+            LayoutDebugger::record_draw(cx, drawn);
             f(cx);
         });
+
+        // animation frames can be requested while drawing, and they must reach the window
+        if view_state.needs_animate() {
+            cx.view_state.request_animate();
+        }
     }
 }
 
@@ -235,7 +306,7 @@ impl<T, V: View<T>> View<T> for Pod<V> {
         data: &mut T,
         space: Space,
     ) -> Size {
-        Self::layout_with(&mut state.view_state, cx, |cx| {
+        Self::layout_with(&mut state.view_state, cx, space, |cx| {
             (self.view).layout(&mut state.content, cx, data, space)
         })
     }
@@ -243,7 +314,9 @@ impl<T, V: View<T>> View<T> for Pod<V> {
     fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
         // we need to check if the view needs to be drawn here
         // since the flag gets cleared in draw function
-        let needs_draw = state.view_state.needs_draw();
+        //
+        // the layout debugger needs every view to be drawn to record it
+        let needs_draw = state.view_state.needs_draw() || LayoutDebugger::is_active(cx);
 
         Self::draw_with(&mut state.view_state, cx, |cx| {
             if !cx.is_visible(cx.rect()) {
@@ -267,3 +340,135 @@ impl<T, V: View<T>> View<T> for Pod<V> {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+        event::Event,
+        layout::{Direction, Size, Space},
+        views::{direction, pad, testing::ViewTester, vstack},
+    };
+
+    use super::{pod, View};
+
+    // counts the number of times it is laid out
+    struct Counter {
+        cache: bool,
+    }
+
+    impl View<usize> for Counter {
+        type State = ();
+
+        fn build(&mut self, cx: &mut BuildCx, _data: &mut usize) -> Self::State {
+            cx.set_cache_layout(self.cache);
+        }
+
+        fn rebuild(&mut self, _: &mut (), _: &mut RebuildCx, _: &mut usize, _: &Self) {}
+
+        fn event(&mut self, _: &mut (), _: &mut EventCx, _: &mut usize, _: &Event) {}
+
+        fn layout(&mut self, _: &mut (), _: &mut LayoutCx, data: &mut usize, _: Space) -> Size {
+            *data += 1;
+            Size::ZERO
+        }
+
+        fn draw(&mut self, _: &mut (), _: &mut DrawCx, _: &mut usize) {}
+    }
+
+    // takes its size from a field and the direction, and requests layout when rebuilt
+    struct Sized(f32);
+
+    impl<T> View<T> for Sized {
+        type State = ();
+
+        fn build(&mut self, _: &mut BuildCx, _: &mut T) -> Self::State {}
+
+        fn rebuild(&mut self, _: &mut (), cx: &mut RebuildCx, _: &mut T, old: &Self) {
+            if self.0 != old.0 {
+                cx.layout();
+            }
+        }
+
+        fn event(&mut self, _: &mut (), _: &mut EventCx, _: &mut T, _: &Event) {}
+
+        fn layout(&mut self, _: &mut (), cx: &mut LayoutCx, _: &mut T, _: Space) -> Size {
+            match cx.direction() {
+                Direction::Ltr => Size::all(self.0),
+                Direction::Rtl => Size::all(self.0 * 2.0),
+            }
+        }
+
+        fn draw(&mut self, _: &mut (), _: &mut DrawCx, _: &mut T) {}
+    }
+
+    fn layout_count(cache: bool, spaces: &[Space]) -> usize {
+        let mut view = pad(1.0, pod(Counter { cache }));
+        let mut count = 0;
+
+        let mut tester = ViewTester::new(&mut view, &mut count);
+
+        for &space in spaces {
+            tester.layout(&mut view, &mut count, space);
+        }
+
+        count
+    }
+
+    #[test]
+    fn layout_cache() {
+        let small = Space::new(Size::ZERO, Size::new(10.0, 10.0));
+        let large = Space::new(Size::ZERO, Size::new(20.0, 20.0));
+
+        assert_eq!(layout_count(true, &[small, small, small]), 1);
+        assert_eq!(layout_count(true, &[small, large, large, small]), 3);
+        assert_eq!(layout_count(false, &[small, small, small]), 3);
+    }
+
+    #[test]
+    fn layout_cache_invalidated() {
+        let space = Space::new(Size::ZERO, Size::all(100.0));
+        let sized = |size| direction(Direction::Ltr, pad(0.0, pod(Sized(size))));
+
+        let mut view = sized(10.0);
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        assert_eq!(tester.layout(&mut view, &mut (), space), Size::all(10.0));
+
+        // a child requesting layout when rebuilt is laid out again, even though the space is
+        // the same
+        let old = view;
+        let mut view = sized(20.0);
+        tester.rebuild(&mut view, &mut (), &old);
+        assert_eq!(tester.layout(&mut view, &mut (), space), Size::all(20.0));
+
+        // so is a child whose direction changed
+        view.direction = Direction::Rtl;
+        assert_eq!(tester.layout(&mut view, &mut (), space), Size::all(40.0));
+    }
+
+    #[test]
+    fn layout_cache_rebuilt() {
+        let space = Space::new(Size::ZERO, Size::all(100.0));
+        let siblings = |size| vstack((pod(Counter { cache: true }), pod(Sized(size))));
+
+        let mut view = siblings(10.0);
+        let mut count = 0;
+        let mut tester = ViewTester::new(&mut view, &mut count);
+        tester.layout(&mut view, &mut count, space);
+
+        // rebuilding an unchanged tree doesn't lay anything out again
+        let old = view;
+        let mut view = siblings(10.0);
+        tester.rebuild(&mut view, &mut count, &old);
+        tester.layout(&mut view, &mut count, space);
+
+        // and neither does rebuilding a sibling that requests layout
+        let old = view;
+        let mut view = siblings(20.0);
+        tester.rebuild(&mut view, &mut count, &old);
+        let size = tester.layout(&mut view, &mut count, space);
+
+        assert_eq!(size.height, 20.0);
+        assert_eq!(count, 1);
+    }
+}
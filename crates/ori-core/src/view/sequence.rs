@@ -447,7 +447,7 @@ impl<V> PodSeq<V> {
     where
         V: ViewSeq<T>,
     {
-        Pod::<V>::layout_with(&mut state.view_state[n], cx, |cx| {
+        Pod::<V>::layout_with(&mut state.view_state[n], cx, space, |cx| {
             (self.views).layout_nth(n, &mut state.content, cx, data, space)
         })
     }
@@ -6,7 +6,7 @@ use std::{
 };
 
 use crate::{
-    layout::{Affine, Point, Rect, Size, Vector},
+    layout::{Affine, Direction, Point, Rect, Size, Space, Vector},
     window::Cursor,
 };
 
@@ -102,22 +102,37 @@ impl Display for ViewId {
     }
 }
 
+// everything the last layout of a view depended on, other than the view itself
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct LayoutKey {
+    pub space: Space,
+    pub direction: Direction,
+    pub fonts: u64,
+}
+
 /// State associated with a [`View`](super::View).
 #[derive(Debug)]
 pub struct ViewState {
     pub(crate) id: ViewId,
+    pub(crate) type_name: &'static str,
 
     /* flags */
     pub(crate) prev_flags: ViewFlags,
     pub(crate) flags: ViewFlags,
     pub(crate) update: Update,
+    pub(crate) handled: bool,
 
     /* properties */
     pub(crate) properties: Properties,
 
     /* layout */
     pub(crate) size: Size,
+    pub(crate) baseline: Option<f32>,
     pub(crate) transform: Affine,
+    pub(crate) space: Option<Space>,
+    pub(crate) layout_key: Option<LayoutKey>,
+    pub(crate) cache_layout: bool,
+    pub(crate) has_uncached: bool,
 
     /* cursor */
     pub(crate) cursor: Option<Cursor>,
@@ -135,18 +150,25 @@ impl ViewState {
     pub fn new(id: ViewId) -> Self {
         Self {
             id,
+            type_name: "",
 
             /* flags */
             prev_flags: ViewFlags::default(),
             flags: ViewFlags::default(),
             update: Update::LAYOUT | Update::DRAW,
+            handled: false,
 
             /* properties */
             properties: Properties::new(),
 
             /* layout */
             size: Size::ZERO,
+            baseline: None,
             transform: Affine::IDENTITY,
+            space: None,
+            layout_key: None,
+            cache_layout: true,
+            has_uncached: false,
 
             /* cursor */
             cursor: None,
@@ -158,6 +180,7 @@ impl ViewState {
     pub fn prepare(&mut self) {
         self.flags.remove(ViewFlags::HAS);
         self.flags |= self.flags.has();
+        self.handled = false;
 
         self.inherited_cursor = self.cursor;
     }
@@ -165,6 +188,7 @@ impl ViewState {
     /// Propagate the state of a child view.
     pub fn propagate(&mut self, child: &mut Self) {
         self.update |= child.update;
+        self.handled |= child.handled;
         self.flags |= child.flags.has();
         self.inherited_cursor = self.cursor().or(child.cursor());
     }
@@ -174,6 +198,21 @@ impl ViewState {
         self.id
     }
 
+    /// Get the type name of the view, or an empty string if it isn't known.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Get whether the view, or one of its children, handled the current event.
+    pub fn is_handled(&self) -> bool {
+        self.handled
+    }
+
+    /// Mark the current event as handled by the view.
+    pub fn set_handled(&mut self) {
+        self.handled = true;
+    }
+
     /// Get whether the view is hot.
     pub fn is_hot(&self) -> bool {
         self.flags.contains(ViewFlags::HOT)
@@ -282,6 +321,60 @@ impl ViewState {
         self.size
     }
 
+    /// Set the first baseline of the view, in local coordinates.
+    ///
+    /// This is reset before every layout, so views that have a baseline must set it each time
+    /// they are laid out.
+    pub fn set_baseline(&mut self, baseline: Option<f32>) {
+        self.baseline = baseline;
+    }
+
+    /// Get the first baseline of the view, in local coordinates.
+    pub fn baseline(&self) -> Option<f32> {
+        self.baseline
+    }
+
+    /// Get the first baseline of the view, in the coordinates of the parent.
+    ///
+    /// This should be called after the transform of the view has been set.
+    pub fn parent_baseline(&self) -> Option<f32> {
+        let baseline = self.baseline?;
+        Some((self.transform * Point::new(0.0, baseline)).y)
+    }
+
+    /// Set whether the layout of the view can be cached, this is `true` by default.
+    ///
+    /// A view is only laid out again when it requests layout, or when the [`Space`] it is
+    /// given, the layout [`Direction`] or the loaded fonts change. Views whose
+    /// layout depends on anything else, like another context or the window, or that have side
+    /// effects when laid out, should disable this. This also disables the cache of every
+    /// ancestor of the view.
+    pub fn set_cache_layout(&mut self, cache: bool) {
+        self.cache_layout = cache;
+    }
+
+    /// Get whether the layout of the view can be cached.
+    pub fn caches_layout(&self) -> bool {
+        self.cache_layout && !self.has_uncached
+    }
+
+    /// Cache the layout of the view, even though some of its children can't be cached.
+    ///
+    /// This is for views that lay out children depending on state that only changes when the
+    /// view itself is laid out again, and must be called after the children are laid out.
+    pub fn cache_uncached_children(&mut self) {
+        self.has_uncached = false;
+    }
+
+    /// Discard the cached layout, so the view is laid out the next time its parent is.
+    pub fn invalidate_layout_cache(&mut self) {
+        self.layout_key = None;
+    }
+
+    pub(crate) fn is_layout_cached(&self, key: LayoutKey) -> bool {
+        !self.needs_layout() && self.caches_layout() && self.layout_key == Some(key)
+    }
+
     /// Get the rect of the view in local coordinates.
     pub fn rect(&self) -> Rect {
         Rect::min_size(Point::ZERO, self.size)
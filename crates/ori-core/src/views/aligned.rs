@@ -111,7 +111,8 @@ impl<T, V: View<T>> View<T> for Aligned<V> {
             .max(space.max.finite_or_zero());
 
         let align = self.alignment.align(content_size, size);
-        state.translate(align);
+        state.translate(cx.direction().mirror(align, content_size, size));
+        cx.set_baseline(state.parent_baseline());
 
         size
     }
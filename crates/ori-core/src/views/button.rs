@@ -206,10 +206,13 @@ impl<T, V: View<T>> View<T> for Button<V> {
     ) -> Size {
         let content_space = space.shrink(self.padding.size());
         let content_size = self.content.layout(content, cx, data, content_space);
+        let size = space.fit(content_size + self.padding.size());
 
-        content.translate(self.padding.offset());
+        let offset = self.padding.offset();
+        content.translate(cx.direction().mirror(offset, content_size, size));
+        cx.set_baseline(content.parent_baseline());
 
-        space.fit(content_size + self.padding.size())
+        size
     }
 
     fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
@@ -0,0 +1,974 @@
+use ori_macro::Build;
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color, Curve},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, KeyPressed, PointerButton},
+    layout::{Padding, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    text::{FontFamily, Fonts, TextAttributes, TextBuffer, TextWrap},
+    view::View,
+    window::Cursor,
+};
+
+use super::{Date, DateLocale, TextStyle, Weekday};
+
+/// Create a new [`Calendar`].
+pub fn calendar<T>() -> Calendar<T> {
+    Calendar::new()
+}
+
+/// The dates selected in a [`Calendar`] or [`DatePicker`](super::DatePicker).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DateSelection {
+    /// Nothing is selected.
+    #[default]
+    None,
+
+    /// A single date is selected.
+    Single(Date),
+
+    /// The dates from the first to the second are selected, inclusive.
+    Range(Date, Date),
+}
+
+impl DateSelection {
+    /// Get the first selected date.
+    pub fn start(self) -> Option<Date> {
+        match self {
+            DateSelection::None => None,
+            DateSelection::Single(date) => Some(date),
+            DateSelection::Range(start, _) => Some(start),
+        }
+    }
+
+    /// Get the last selected date.
+    pub fn end(self) -> Option<Date> {
+        match self {
+            DateSelection::None => None,
+            DateSelection::Single(date) => Some(date),
+            DateSelection::Range(_, end) => Some(end),
+        }
+    }
+
+    /// Get whether `date` is selected.
+    pub fn contains(self, date: Date) -> bool {
+        match (self.start(), self.end()) {
+            (Some(start), Some(end)) => start <= date && date <= end,
+            _ => false,
+        }
+    }
+}
+
+impl From<Date> for DateSelection {
+    fn from(date: Date) -> Self {
+        DateSelection::Single(date)
+    }
+}
+
+impl From<Option<Date>> for DateSelection {
+    fn from(date: Option<Date>) -> Self {
+        match date {
+            Some(date) => DateSelection::Single(date),
+            None => DateSelection::None,
+        }
+    }
+}
+
+impl From<(Date, Date)> for DateSelection {
+    fn from((a, b): (Date, Date)) -> Self {
+        DateSelection::Range(Date::min(a, b), Date::max(a, b))
+    }
+}
+
+/// The style of a calendar.
+#[derive(Clone, Debug)]
+pub struct CalendarStyle {
+    /// The locale of the month and weekday names.
+    pub locale: DateLocale,
+
+    /// The font size of the text.
+    pub font_size: f32,
+
+    /// The font family of the text.
+    pub font_family: FontFamily,
+
+    /// The color of the text.
+    pub color: Color,
+
+    /// The color of the weekdays, and of the days outside the month.
+    pub muted_color: Color,
+
+    /// The color of the text of selected days.
+    pub selected_color: Color,
+
+    /// The color of the arrows.
+    pub icon_color: Color,
+
+    /// The size of each day.
+    pub cell_size: f32,
+
+    /// The padding of the calendar.
+    pub padding: Padding,
+
+    /// The background color.
+    pub background: Color,
+
+    /// The background color of a hovered day.
+    pub hover: Color,
+
+    /// The background color of a selected day.
+    pub highlight: Color,
+
+    /// The background color of the days in a selected range.
+    pub range: Color,
+
+    /// The color of the ring around today.
+    pub today: Color,
+
+    /// The border radius.
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    pub border_color: Color,
+
+    /// The border color when focused, and the color of the ring around the focused day.
+    pub focus_color: Color,
+}
+
+impl Style for CalendarStyle {
+    fn styled(style: &Styles) -> Self {
+        let text_style = style.get::<TextStyle>();
+        let palette = style.palette();
+
+        Self {
+            locale: DateLocale::system(),
+            font_size: 14.0,
+            font_family: text_style.font_family.clone(),
+            color: palette.contrast,
+            muted_color: palette.contrast_low,
+            selected_color: palette.background,
+            icon_color: palette.contrast_low,
+            cell_size: 32.0,
+            padding: Padding::all(8.0),
+            background: palette.surface_high,
+            hover: palette.surface_highest,
+            highlight: palette.primary,
+            range: palette.primary.fade(0.25),
+            today: palette.primary,
+            border_radius: BorderRadius::all(4.0),
+            border_width: BorderWidth::all(1.0),
+            border_color: palette.outline,
+            focus_color: palette.primary,
+        }
+    }
+}
+
+/// A month grid for choosing a date, or a range of dates.
+///
+/// Clicking a day selects it, and when [`Calendar::range`] is set the next click completes
+/// the range. When focused, the arrow keys move between days, `PageUp` and `PageDown` between
+/// months, or years while holding `Shift`, `Home` and `End` to the start and end of the week,
+/// and `Enter` or `Space` selects the focused day.
+///
+/// Days before [`Calendar::min`], after [`Calendar::max`] or [`Calendar::disabled`] can't be
+/// selected. Month and weekday names are taken from the [`DateLocale`].
+///
+/// Can be styled using the [`CalendarStyle`].
+#[derive(Build, Rebuild)]
+pub struct Calendar<T> {
+    /// The selected dates.
+    #[rebuild(draw)]
+    pub selection: DateSelection,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_select: Option<Box<dyn FnMut(&mut EventCx, &mut T, DateSelection)>>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    disabled: Option<Box<dyn FnMut(&mut T, Date) -> bool>>,
+
+    /// Whether a range of dates is selected.
+    pub range: bool,
+
+    /// The earliest date that can be selected.
+    #[rebuild(draw)]
+    pub min: Option<Date>,
+
+    /// The latest date that can be selected.
+    #[rebuild(draw)]
+    pub max: Option<Date>,
+
+    /// The first day of the week, `None` uses the one of the locale.
+    #[rebuild(draw)]
+    pub week_start: Option<Weekday>,
+
+    /// The locale of the month and weekday names.
+    #[rebuild(layout)]
+    pub locale: DateLocale,
+
+    /// The font size of the text.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the text.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The color of the text.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of the weekdays, and of the days outside the month.
+    #[rebuild(draw)]
+    pub muted_color: Color,
+
+    /// The color of the text of selected days.
+    #[rebuild(draw)]
+    pub selected_color: Color,
+
+    /// The color of the arrows.
+    #[rebuild(draw)]
+    pub icon_color: Color,
+
+    /// The size of each day.
+    #[rebuild(layout)]
+    pub cell_size: f32,
+
+    /// The padding of the calendar.
+    #[rebuild(layout)]
+    pub padding: Padding,
+
+    /// The background color.
+    #[rebuild(draw)]
+    pub background: Color,
+
+    /// The background color of a hovered day.
+    #[rebuild(draw)]
+    pub hover: Color,
+
+    /// The background color of a selected day.
+    #[rebuild(draw)]
+    pub highlight: Color,
+
+    /// The background color of the days in a selected range.
+    #[rebuild(draw)]
+    pub range_color: Color,
+
+    /// The color of the ring around today.
+    #[rebuild(draw)]
+    pub today: Color,
+
+    /// The border radius.
+    #[rebuild(draw)]
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    #[rebuild(draw)]
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    #[rebuild(draw)]
+    pub border_color: Color,
+
+    /// The border color when focused, and the color of the ring around the focused day.
+    #[rebuild(draw)]
+    pub focus_color: Color,
+}
+
+impl<T> Default for Calendar<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Calendar<T> {
+    /// Create a new [`Calendar`].
+    pub fn new() -> Self {
+        Self::styled(style())
+    }
+
+    /// Create a new [`Calendar`] with a style.
+    pub fn styled(style: CalendarStyle) -> Self {
+        Self {
+            selection: DateSelection::None,
+            on_select: None,
+            disabled: None,
+            range: false,
+            min: None,
+            max: None,
+            week_start: None,
+            locale: style.locale,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            color: style.color,
+            muted_color: style.muted_color,
+            selected_color: style.selected_color,
+            icon_color: style.icon_color,
+            cell_size: style.cell_size,
+            padding: style.padding,
+            background: style.background,
+            hover: style.hover,
+            highlight: style.highlight,
+            range_color: style.range,
+            today: style.today,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+            focus_color: style.focus_color,
+        }
+    }
+
+    /// Set the callback that is called when the selection changes.
+    ///
+    /// While selecting a range, this is called with a range of one day after the first click.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_select(
+        mut self,
+        on_select: impl FnMut(&mut EventCx, &mut T, DateSelection) + 'static,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Set the callback that decides whether a date is disabled.
+    pub fn disabled(mut self, disabled: impl FnMut(&mut T, Date) -> bool + 'static) -> Self {
+        self.disabled = Some(Box::new(disabled));
+        self
+    }
+
+    fn grid_style(&self) -> GridStyle {
+        GridStyle {
+            locale: self.locale,
+            week_start: self.week_start.unwrap_or(self.locale.week_start),
+            font_size: self.font_size,
+            font_family: self.font_family.clone(),
+            color: self.color,
+            muted_color: self.muted_color,
+            selected_color: self.selected_color,
+            icon_color: self.icon_color,
+            cell_size: self.cell_size,
+            padding: self.padding,
+            hover: self.hover,
+            highlight: self.highlight,
+            range: self.range_color,
+            today: self.today,
+            focus_color: self.focus_color,
+            border_radius: self.border_radius,
+        }
+    }
+
+    fn select(&mut self, cx: &mut EventCx, data: &mut T, selection: DateSelection) {
+        self.selection = selection;
+
+        if let Some(ref mut on_select) = self.on_select {
+            on_select(cx, data, selection);
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct CalendarState {
+    grid: MonthGrid,
+}
+
+impl<T> View<T> for Calendar<T> {
+    type State = CalendarState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.set_focusable(true);
+
+        let focused = self.selection.start().unwrap_or_else(Date::today);
+        let focused = focused.clamp_to(self.min, self.max);
+
+        CalendarState {
+            grid: MonthGrid::new(cx.fonts(), &self.grid_style(), focused),
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        let style = self.grid_style();
+
+        if self.locale != old.locale
+            || self.week_start != old.week_start
+            || self.font_size != old.font_size
+            || self.font_family != old.font_family
+        {
+            state.grid.set_style(cx.fonts(), &style);
+        }
+
+        // show the month of a selection made by someone else
+        if self.selection != old.selection {
+            if let Some(start) = self.selection.start() {
+                state
+                    .grid
+                    .focus(cx.fonts(), &style, start, self.min, self.max);
+            }
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        let style = self.grid_style();
+        let rtl = cx.direction().is_rtl();
+
+        match event {
+            Event::PointerMoved(e) => {
+                let local = cx.local(e.position);
+
+                let hovered = match cx.is_hot() {
+                    true => state.grid.hit(&style, local, rtl),
+                    false => None,
+                };
+
+                if state.grid.hovered != hovered {
+                    state.grid.hovered = hovered;
+                    cx.draw();
+                }
+
+                cx.set_cursor(hovered.map(|_| Cursor::Pointer));
+            }
+            Event::PointerPressed(e) if e.button == PointerButton::Primary => {
+                if !cx.is_hot() {
+                    if cx.is_focused() {
+                        cx.set_focused(false);
+                        cx.draw();
+                    }
+
+                    return;
+                }
+
+                cx.set_focused(true);
+                cx.draw();
+
+                let local = cx.local(e.position);
+
+                match state.grid.hit(&style, local, rtl) {
+                    Some(Hit::Previous) => {
+                        let month = state.grid.month.add_months(-1);
+                        state.grid.show_month(cx.fonts(), &style, month);
+                    }
+                    Some(Hit::Next) => {
+                        let month = state.grid.month.add_months(1);
+                        state.grid.show_month(cx.fonts(), &style, month);
+                    }
+                    Some(Hit::Day(date))
+                        if date_enabled(date, self.min, self.max, &mut self.disabled, data) =>
+                    {
+                        state
+                            .grid
+                            .focus(cx.fonts(), &style, date, self.min, self.max);
+
+                        let (selection, _) = state.grid.choose(date, self.range);
+                        self.select(cx, data, selection);
+                    }
+                    Some(Hit::Day(_)) | None => {}
+                }
+            }
+            Event::KeyPressed(e) if cx.is_focused() => {
+                if e.is_key(Key::Enter) || e.is_key(' ') {
+                    let date = state.grid.focused;
+
+                    if date_enabled(date, self.min, self.max, &mut self.disabled, data) {
+                        let (selection, _) = state.grid.choose(date, self.range);
+                        self.select(cx, data, selection);
+                        cx.draw();
+                    }
+                } else if e.is_key(Key::Escape) && state.grid.anchor.is_some() {
+                    state.grid.anchor = None;
+                    cx.set_handled();
+                    cx.draw();
+                } else if let Some(date) = state.grid.key_target(&style, e, rtl) {
+                    state
+                        .grid
+                        .focus(cx.fonts(), &style, date, self.min, self.max);
+                    cx.draw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _state: &mut Self::State,
+        _cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        space.fit(MonthGrid::size(&self.grid_style()))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        let border_color = match cx.is_focused() {
+            true => self.focus_color,
+            false => self.border_color,
+        };
+
+        cx.hoverable(|cx| {
+            cx.quad(
+                cx.rect(),
+                self.background,
+                self.border_radius,
+                self.border_width,
+                border_color,
+            );
+        });
+
+        let style = self.grid_style();
+        let (min, max, disabled) = (self.min, self.max, &mut self.disabled);
+        let focused = cx.is_focused();
+
+        state
+            .grid
+            .draw(cx, &style, self.selection, focused, &mut |date| {
+                date_enabled(date, min, max, disabled, data)
+            });
+    }
+}
+
+// whether `date` can be selected
+#[allow(clippy::type_complexity)]
+pub(super) fn date_enabled<T>(
+    date: Date,
+    min: Option<Date>,
+    max: Option<Date>,
+    disabled: &mut Option<Box<dyn FnMut(&mut T, Date) -> bool>>,
+    data: &mut T,
+) -> bool {
+    if date.clamp_to(min, max) != date {
+        return false;
+    }
+
+    match disabled {
+        Some(disabled) => !disabled(data, date),
+        None => true,
+    }
+}
+
+const LINE_HEIGHT: f32 = 1.2;
+
+// the height of the row of weekday names, relative to the cell size
+const WEEKDAY_HEIGHT: f32 = 0.75;
+
+// the style of a `MonthGrid`, taken from the view that owns it
+pub(super) struct GridStyle {
+    pub(super) locale: DateLocale,
+    pub(super) week_start: Weekday,
+    pub(super) font_size: f32,
+    pub(super) font_family: FontFamily,
+    pub(super) color: Color,
+    pub(super) muted_color: Color,
+    pub(super) selected_color: Color,
+    pub(super) icon_color: Color,
+    pub(super) cell_size: f32,
+    pub(super) padding: Padding,
+    pub(super) hover: Color,
+    pub(super) highlight: Color,
+    pub(super) range: Color,
+    pub(super) today: Color,
+    pub(super) focus_color: Color,
+    pub(super) border_radius: BorderRadius,
+}
+
+impl GridStyle {
+    fn attributes(&self) -> TextAttributes {
+        TextAttributes {
+            family: self.font_family.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn buffer(&self, fonts: &mut Fonts, text: &str) -> TextBuffer {
+        let mut buffer = TextBuffer::new(fonts, self.font_size, LINE_HEIGHT);
+        buffer.set_wrap(fonts, TextWrap::None);
+        buffer.set_text(fonts, text, self.attributes());
+        buffer
+    }
+}
+
+// the part of a `MonthGrid` under the pointer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Hit {
+    Previous,
+    Next,
+    Day(Date),
+}
+
+// six weeks of days around a month, with a header for changing the month
+pub(super) struct MonthGrid {
+    // the first day of the shown month
+    pub(super) month: Date,
+    pub(super) focused: Date,
+    pub(super) hovered: Option<Hit>,
+    // the first date of a range being selected
+    pub(super) anchor: Option<Date>,
+    title: TextBuffer,
+    weekdays: Vec<TextBuffer>,
+    days: Vec<TextBuffer>,
+}
+
+impl MonthGrid {
+    pub(super) fn new(fonts: &mut Fonts, style: &GridStyle, focused: Date) -> Self {
+        let mut grid = Self {
+            month: focused.first_of_month(),
+            focused,
+            hovered: None,
+            anchor: None,
+            title: style.buffer(fonts, ""),
+            weekdays: Vec::new(),
+            days: Vec::new(),
+        };
+
+        grid.set_style(fonts, style);
+        grid
+    }
+
+    pub(super) fn set_style(&mut self, fonts: &mut Fonts, style: &GridStyle) {
+        self.weekdays = (0..7)
+            .map(|i| Weekday::from_index(style.week_start.index() + i))
+            .map(|weekday| style.buffer(fonts, style.locale.weekday_name(weekday)))
+            .collect();
+
+        self.days = (1..=31)
+            .map(|day| style.buffer(fonts, &day.to_string()))
+            .collect();
+
+        self.title = style.buffer(fonts, &style.locale.format_month(self.month));
+    }
+
+    pub(super) fn show_month(&mut self, fonts: &mut Fonts, style: &GridStyle, date: Date) {
+        let month = date.first_of_month();
+
+        if self.month != month {
+            self.month = month;
+
+            let title = style.locale.format_month(month);
+            self.title.set_text(fonts, &title, style.attributes());
+        }
+    }
+
+    // move the focus to `date`, showing its month
+    pub(super) fn focus(
+        &mut self,
+        fonts: &mut Fonts,
+        style: &GridStyle,
+        date: Date,
+        min: Option<Date>,
+        max: Option<Date>,
+    ) {
+        self.focused = date.clamp_to(min, max);
+        self.show_month(fonts, style, self.focused);
+    }
+
+    pub(super) fn size(style: &GridStyle) -> Size {
+        let cell = style.cell_size;
+        Size::new(cell * 7.0, cell * (7.0 + WEEKDAY_HEIGHT)) + style.padding.size()
+    }
+
+    // the first day shown, which is usually in the previous month
+    fn first_day(&self, style: &GridStyle) -> Date {
+        let offset = self.month.weekday().days_since(style.week_start);
+        self.month.add_days(-(offset as i64))
+    }
+
+    fn column_x(style: &GridStyle, column: usize, rtl: bool) -> f32 {
+        let column = if rtl { 6 - column } else { column };
+        style.padding.left + column as f32 * style.cell_size
+    }
+
+    fn arrow_rect(style: &GridStyle, next: bool, rtl: bool) -> Rect {
+        let x = Self::column_x(style, if next { 6 } else { 0 }, rtl);
+        Rect::min_size(Point::new(x, style.padding.top), Size::all(style.cell_size))
+    }
+
+    fn day_rect(style: &GridStyle, index: usize, rtl: bool) -> Rect {
+        let cell = style.cell_size;
+        let x = Self::column_x(style, index % 7, rtl);
+        let y = style.padding.top + cell * (1.0 + WEEKDAY_HEIGHT + (index / 7) as f32);
+
+        Rect::min_size(Point::new(x, y), Size::all(cell))
+    }
+
+    pub(super) fn hit(&self, style: &GridStyle, local: Point, rtl: bool) -> Option<Hit> {
+        if Self::arrow_rect(style, false, rtl).contains(local) {
+            return Some(Hit::Previous);
+        }
+
+        if Self::arrow_rect(style, true, rtl).contains(local) {
+            return Some(Hit::Next);
+        }
+
+        let first = self.first_day(style);
+        let index = (0..42).find(|&i| Self::day_rect(style, i, rtl).contains(local))?;
+
+        Some(Hit::Day(first.add_days(index as i64)))
+    }
+
+    // the date the focus moves to when `e` is pressed
+    pub(super) fn key_target(&self, style: &GridStyle, e: &KeyPressed, rtl: bool) -> Option<Date> {
+        let date = self.focused;
+        let since_start = date.weekday().days_since(style.week_start) as i64;
+        let forward = if rtl { -1 } else { 1 };
+
+        let target = match e.key {
+            Key::Left => date.add_days(-forward),
+            Key::Right => date.add_days(forward),
+            Key::Up => date.add_days(-7),
+            Key::Down => date.add_days(7),
+            Key::PageUp if e.modifiers.shift => date.add_months(-12),
+            Key::PageDown if e.modifiers.shift => date.add_months(12),
+            Key::PageUp => date.add_months(-1),
+            Key::PageDown => date.add_months(1),
+            Key::Home => date.add_days(-since_start),
+            Key::End => date.add_days(6 - since_start),
+            _ => return None,
+        };
+
+        Some(target)
+    }
+
+    // choose `date`, returns the selection and whether it's complete
+    pub(super) fn choose(&mut self, date: Date, range: bool) -> (DateSelection, bool) {
+        if !range {
+            self.anchor = None;
+            return (DateSelection::Single(date), true);
+        }
+
+        match self.anchor.take() {
+            Some(anchor) => (DateSelection::from((anchor, date)), true),
+            None => {
+                self.anchor = Some(date);
+                (DateSelection::Range(date, date), false)
+            }
+        }
+    }
+
+    pub(super) fn draw(
+        &self,
+        cx: &mut DrawCx,
+        style: &GridStyle,
+        selection: DateSelection,
+        focused: bool,
+        enabled: &mut dyn FnMut(Date) -> bool,
+    ) {
+        let rtl = cx.direction().is_rtl();
+        let cell = style.cell_size;
+
+        // the title and the arrows
+        let title = self.title.size();
+        let x = style.padding.left + (cell * 7.0 - title.width) / 2.0;
+        let y = style.padding.top + (cell - title.height) / 2.0;
+        cx.text(&self.title, style.color, Vector::new(x, y));
+
+        for (next, hit) in [(false, Hit::Previous), (true, Hit::Next)] {
+            let rect = Self::arrow_rect(style, next, rtl);
+
+            if self.hovered == Some(hit) {
+                cx.quad(
+                    rect,
+                    style.hover,
+                    style.border_radius,
+                    0.0,
+                    Color::TRANSPARENT,
+                );
+            }
+
+            draw_chevron(cx, rect, next != rtl, style.icon_color);
+        }
+
+        // the weekday names
+        for (column, buffer) in self.weekdays.iter().enumerate() {
+            let x = Self::column_x(style, column, rtl) + (cell - buffer.size().width) / 2.0;
+            let y = style.padding.top + cell + (cell * WEEKDAY_HEIGHT - buffer.size().height) / 2.0;
+
+            cx.text(buffer, style.muted_color, Vector::new(x, y));
+        }
+
+        // while selecting a range, it's previewed up to the hovered day
+        let selection = match (self.anchor, self.hovered) {
+            (Some(anchor), Some(Hit::Day(date))) => DateSelection::from((anchor, date)),
+            (Some(anchor), _) => DateSelection::Single(anchor),
+            (None, _) => selection,
+        };
+
+        let first = self.first_day(style);
+        let today = Date::today();
+
+        for index in 0..42 {
+            let date = first.add_days(index as i64);
+            let rect = Self::day_rect(style, index, rtl);
+            let inner = rect.shrink(2.0);
+
+            let enabled = enabled(date);
+            let is_end = selection.start() == Some(date) || selection.end() == Some(date);
+
+            if selection.contains(date) && !is_end {
+                let band = Rect::min_size(
+                    Point::new(rect.min.x, inner.min.y),
+                    Size::new(cell, inner.height()),
+                );
+                cx.fill_rect(band, style.range);
+            } else if is_end && selection.start() != selection.end() {
+                // join the ends of a range to the days between them
+                let half = Size::new(cell / 2.0, inner.height());
+                let toward_end = (selection.start() == Some(date)) != rtl;
+
+                let x = match toward_end {
+                    true => rect.center().x,
+                    false => rect.min.x,
+                };
+
+                let band = Rect::min_size(Point::new(x, inner.min.y), half);
+                cx.fill_rect(band, style.range);
+            }
+
+            if is_end {
+                cx.quad(
+                    inner,
+                    style.highlight,
+                    style.border_radius,
+                    0.0,
+                    Color::TRANSPARENT,
+                );
+            } else if enabled && self.hovered == Some(Hit::Day(date)) {
+                cx.quad(
+                    inner,
+                    style.hover,
+                    style.border_radius,
+                    0.0,
+                    Color::TRANSPARENT,
+                );
+            }
+
+            if date == today {
+                cx.quad(
+                    inner,
+                    Color::TRANSPARENT,
+                    style.border_radius,
+                    1.0,
+                    style.today,
+                );
+            }
+
+            if focused && date == self.focused {
+                cx.quad(
+                    inner,
+                    Color::TRANSPARENT,
+                    style.border_radius,
+                    2.0,
+                    style.focus_color,
+                );
+            }
+
+            let color = if is_end {
+                style.selected_color
+            } else if !enabled {
+                style.muted_color.fade(0.5)
+            } else if date.month() != self.month.month() {
+                style.muted_color
+            } else {
+                style.color
+            };
+
+            let buffer = &self.days[date.day() as usize - 1];
+            let x = rect.min.x + (cell - buffer.size().width) / 2.0;
+            let y = rect.min.y + (cell - buffer.size().height) / 2.0;
+            cx.text(buffer, color, Vector::new(x, y));
+        }
+    }
+}
+
+// draw a chevron in the center of `rect`, pointing right when `right`
+fn draw_chevron(cx: &mut DrawCx, rect: Rect, right: bool, color: Color) {
+    let center = rect.center();
+    let size = rect.height() / 5.0;
+    let flip = if right { 1.0 } else { -1.0 };
+
+    let mut curve = Curve::new();
+    curve.move_to(center + Vector::new(-size * 0.5 * flip, -size));
+    curve.line_to(center + Vector::new(size * 0.5 * flip, 0.0));
+    curve.line_to(center + Vector::new(-size * 0.5 * flip, size));
+
+    cx.stroke(curve, 1.5, color);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Key, KeyPressed, Modifiers},
+        layout::Space,
+        views::{testing::ViewTester, Date, Weekday},
+    };
+
+    use super::{calendar, DateSelection};
+
+    fn key(key: Key) -> Event {
+        Event::KeyPressed(KeyPressed {
+            key,
+            code: None,
+            text: None,
+            modifiers: Modifiers::default(),
+        })
+    }
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn keyboard_range_selection() {
+        let mut selection = DateSelection::None;
+
+        // the 2nd of march 2024 is a saturday
+        let mut view = calendar()
+            .selection(date(2024, 2, 28))
+            .max(date(2024, 3, 31))
+            .week_start(Weekday::Monday)
+            .range(true)
+            .disabled(|_, date: Date| date.weekday() == Weekday::Saturday)
+            .on_select(|_, selection: &mut DateSelection, new| *selection = new);
+
+        let mut tester = ViewTester::new(&mut view, &mut selection);
+        tester.layout(&mut view, &mut selection, Space::UNBOUNDED);
+        tester.view_state.set_focused(true);
+
+        tester.event(&mut view, &mut selection, &key(Key::Enter));
+        assert_eq!(
+            selection,
+            DateSelection::Range(date(2024, 2, 28), date(2024, 2, 28))
+        );
+
+        // the focus moves to the next month
+        tester.event(&mut view, &mut selection, &key(Key::Down));
+        tester.event(&mut view, &mut selection, &key(Key::End));
+        assert_eq!(tester.state.grid.focused, date(2024, 3, 10));
+        assert_eq!(tester.state.grid.month, date(2024, 3, 1));
+
+        // saturdays are disabled
+        tester.event(&mut view, &mut selection, &key(Key::Left));
+        tester.event(&mut view, &mut selection, &key(Key::Enter));
+        assert_eq!(
+            selection,
+            DateSelection::Range(date(2024, 2, 28), date(2024, 2, 28))
+        );
+
+        tester.event(&mut view, &mut selection, &key(Key::Left));
+        tester.event(&mut view, &mut selection, &key(Key::Enter));
+        assert_eq!(
+            selection,
+            DateSelection::Range(date(2024, 2, 28), date(2024, 3, 8))
+        );
+
+        // the focus can't move past the latest date
+        tester.event(&mut view, &mut selection, &key(Key::PageDown));
+        assert_eq!(tester.state.grid.focused, date(2024, 3, 31));
+    }
+}
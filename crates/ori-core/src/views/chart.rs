@@ -0,0 +1,1221 @@
+use std::{borrow::Cow, collections::HashSet, f32::consts::PI};
+
+use ori_macro::{example, Build};
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color, Curve, FillRule, Stroke, StrokeCap, StrokeJoin},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{pt, Padding, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    text::{FontFamily, Fonts, TextAttributes, TextBuffer},
+    transition::Transition,
+    view::View,
+};
+
+pub use self::{series::*, ticks::*};
+
+mod series;
+mod ticks;
+
+use super::TextStyle;
+
+/// Create a new line [`Chart`].
+pub fn line_chart(series: impl IntoIterator<Item = Series>) -> Chart {
+    Chart::new(ChartKind::Line, series)
+}
+
+/// Create a new area [`Chart`].
+pub fn area_chart(series: impl IntoIterator<Item = Series>) -> Chart {
+    Chart::new(ChartKind::Area, series)
+}
+
+/// Create a new bar [`Chart`].
+pub fn bar_chart(series: impl IntoIterator<Item = Series>) -> Chart {
+    Chart::new(ChartKind::Bar, series)
+}
+
+/// Create a new scatter [`Chart`].
+pub fn scatter_chart(series: impl IntoIterator<Item = Series>) -> Chart {
+    Chart::new(ChartKind::Scatter, series)
+}
+
+/// Create a new pie [`Chart`] from the name and value of each slice.
+pub fn pie_chart<S: Into<String>>(slices: impl IntoIterator<Item = (S, f32)>) -> Chart {
+    Chart::new(ChartKind::Pie, slices.into_iter().map(slice))
+}
+
+/// Create a new donut [`Chart`] from the name and value of each slice.
+pub fn donut_chart<S: Into<String>>(slices: impl IntoIterator<Item = (S, f32)>) -> Chart {
+    Chart::new(ChartKind::Donut, slices.into_iter().map(slice))
+}
+
+// every slice of a pie is a series with a single value
+fn slice<S: Into<String>>((name, value): (S, f32)) -> Series {
+    Series::values(name, [value])
+}
+
+/// The kind of a [`Chart`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ChartKind {
+    /// The points of each series are connected by a line.
+    #[default]
+    Line,
+
+    /// The area between the line and zero is filled.
+    Area,
+
+    /// Each point is a bar, the series are grouped by index.
+    Bar,
+
+    /// Each point is a dot.
+    Scatter,
+
+    /// Each series is a slice of a pie, sized by its first value.
+    Pie,
+
+    /// A pie with a hole in the middle.
+    Donut,
+}
+
+impl ChartKind {
+    /// Check if the chart has axes, which is everything but pies and donuts.
+    pub fn has_axes(self) -> bool {
+        !matches!(self, Self::Pie | Self::Donut)
+    }
+}
+
+/// The style of a [`Chart`].
+#[derive(Clone, Debug)]
+pub struct ChartStyle {
+    /// The transition of the data when it changes.
+    pub transition: Transition,
+
+    /// The colors of the series, repeated when there are more series.
+    pub colors: Vec<Color>,
+
+    /// The font size of the labels.
+    pub font_size: f32,
+
+    /// The font family of the labels.
+    pub font_family: FontFamily,
+
+    /// The color of the labels.
+    pub color: Color,
+
+    /// The color of the axes and the crosshair.
+    pub axis_color: Color,
+
+    /// The color of the grid lines.
+    pub grid_color: Color,
+
+    /// The width of the lines.
+    pub line_width: f32,
+
+    /// The radius of the points.
+    pub point_radius: f32,
+
+    /// The fraction of each category left empty between groups of bars.
+    pub bar_gap: f32,
+
+    /// The opacity of the filled area.
+    pub area_opacity: f32,
+
+    /// The size of the hole in a donut, as a fraction of the radius.
+    pub donut_hole: f32,
+
+    /// The padding of the tooltip.
+    pub tooltip_padding: Padding,
+
+    /// The background color of the tooltip.
+    pub tooltip_background: Color,
+
+    /// The border radius of the tooltip.
+    pub tooltip_border_radius: BorderRadius,
+
+    /// The border width of the tooltip.
+    pub tooltip_border_width: BorderWidth,
+
+    /// The border color of the tooltip.
+    pub tooltip_border_color: Color,
+}
+
+impl Style for ChartStyle {
+    fn styled(style: &Styles) -> Self {
+        let text_style = style.get::<TextStyle>();
+        let palette = style.palette();
+
+        Self {
+            transition: Transition::ease(0.4),
+            colors: vec![
+                palette.primary,
+                palette.secondary,
+                palette.accent,
+                palette.success,
+                palette.warning,
+                palette.info,
+                palette.danger,
+            ],
+            font_size: pt(10.0),
+            font_family: text_style.font_family,
+            color: text_style.color,
+            axis_color: palette.outline,
+            grid_color: palette.outline_low,
+            line_width: 2.0,
+            point_radius: 3.0,
+            bar_gap: 0.2,
+            area_opacity: 0.3,
+            donut_hole: 0.6,
+            tooltip_padding: Padding::from([8.0, 4.0]),
+            tooltip_background: palette.surface_higher,
+            tooltip_border_radius: BorderRadius::all(4.0),
+            tooltip_border_width: BorderWidth::all(1.0),
+            tooltip_border_color: palette.outline,
+        }
+    }
+}
+
+// the space between the labels and the plot
+const LABEL_GAP: f32 = 6.0;
+
+// the size of the chart when the space is unbounded
+const DEFAULT_SIZE: Size = Size::new(400.0, 240.0);
+
+/// A chart of one or more [`Series`].
+///
+/// Line, area, bar and scatter charts have axes with labels at round numbers, or at the
+/// categories when they're set. Pie and donut charts use the first value of each series as a
+/// slice. Hovering shows the values under the pointer in a tooltip, changes to the data are
+/// animated, and series with more points than there are pixels are decimated before drawing.
+///
+/// Can be styled using the [`ChartStyle`].
+#[example(name = "chart", width = 400, height = 300)]
+#[derive(Build, Rebuild)]
+pub struct Chart {
+    /// The kind of the chart.
+    #[rebuild(layout)]
+    pub kind: ChartKind,
+
+    /// The series of the chart.
+    #[build(ignore)]
+    pub series: Vec<Series>,
+
+    /// The labels of the x axis, the `x` of each point is the index of its category.
+    #[build(ignore)]
+    #[rebuild(layout)]
+    pub categories: Vec<String>,
+
+    /// The number of ticks the x axis aims for.
+    #[rebuild(layout)]
+    pub x_ticks: usize,
+
+    /// The number of ticks the y axis aims for.
+    #[rebuild(layout)]
+    pub y_ticks: usize,
+
+    /// Whether to show the legend.
+    #[rebuild(layout)]
+    pub legend: bool,
+
+    /// Whether to show the grid lines.
+    #[rebuild(draw)]
+    pub grid: bool,
+
+    /// Whether to show the crosshair and the tooltip when hovered.
+    #[rebuild(draw)]
+    pub crosshair: bool,
+
+    /// Whether to decimate series with more points than the plot is wide.
+    #[rebuild(draw)]
+    pub decimate: bool,
+
+    /// The transition of the data when it changes.
+    pub transition: Transition,
+
+    /// The colors of the series, repeated when there are more series.
+    #[rebuild(draw)]
+    pub colors: Vec<Color>,
+
+    /// The font size of the labels.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the labels.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The color of the labels.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of the axes and the crosshair.
+    #[rebuild(draw)]
+    pub axis_color: Color,
+
+    /// The color of the grid lines.
+    #[rebuild(draw)]
+    pub grid_color: Color,
+
+    /// The width of the lines.
+    #[rebuild(draw)]
+    pub line_width: f32,
+
+    /// The radius of the points.
+    #[rebuild(draw)]
+    pub point_radius: f32,
+
+    /// The fraction of each category left empty between groups of bars.
+    #[rebuild(draw)]
+    pub bar_gap: f32,
+
+    /// The opacity of the filled area.
+    #[rebuild(draw)]
+    pub area_opacity: f32,
+
+    /// The size of the hole in a donut, as a fraction of the radius.
+    #[rebuild(draw)]
+    pub donut_hole: f32,
+
+    /// The padding of the tooltip.
+    #[rebuild(draw)]
+    pub tooltip_padding: Padding,
+
+    /// The background color of the tooltip.
+    #[rebuild(draw)]
+    pub tooltip_background: Color,
+
+    /// The border radius of the tooltip.
+    #[rebuild(draw)]
+    pub tooltip_border_radius: BorderRadius,
+
+    /// The border width of the tooltip.
+    #[rebuild(draw)]
+    pub tooltip_border_width: BorderWidth,
+
+    /// The border color of the tooltip.
+    #[rebuild(draw)]
+    pub tooltip_border_color: Color,
+}
+
+impl Chart {
+    /// Create a new [`Chart`].
+    pub fn new(kind: ChartKind, series: impl IntoIterator<Item = Series>) -> Self {
+        Self::styled(kind, series, style())
+    }
+
+    /// Create a new [`Chart`] with a style.
+    pub fn styled(
+        kind: ChartKind,
+        series: impl IntoIterator<Item = Series>,
+        style: ChartStyle,
+    ) -> Self {
+        Self {
+            kind,
+            series: series.into_iter().collect(),
+            categories: Vec::new(),
+            x_ticks: 6,
+            y_ticks: 5,
+            legend: true,
+            grid: true,
+            crosshair: true,
+            decimate: true,
+            transition: style.transition,
+            colors: style.colors,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            color: style.color,
+            axis_color: style.axis_color,
+            grid_color: style.grid_color,
+            line_width: style.line_width,
+            point_radius: style.point_radius,
+            bar_gap: style.bar_gap,
+            area_opacity: style.area_opacity,
+            donut_hole: style.donut_hole,
+            tooltip_padding: style.tooltip_padding,
+            tooltip_background: style.tooltip_background,
+            tooltip_border_radius: style.tooltip_border_radius,
+            tooltip_border_width: style.tooltip_border_width,
+            tooltip_border_color: style.tooltip_border_color,
+        }
+    }
+
+    /// Add a series to the chart.
+    pub fn series(mut self, series: Series) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Set the labels of the x axis.
+    pub fn categories(mut self, categories: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.categories = categories.into_iter().map(Into::into).collect();
+        self
+    }
+
+    fn attributes(&self) -> TextAttributes {
+        TextAttributes {
+            family: self.font_family.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn series_color(&self, index: usize) -> Color {
+        match self.series[index].color {
+            Some(color) => color,
+            None if self.colors.is_empty() => self.axis_color,
+            None => self.colors[index % self.colors.len()],
+        }
+    }
+
+    // the number of groups of bars
+    fn groups(&self) -> usize {
+        let points = self.series.iter().map(|series| series.points.len());
+        usize::max(points.max().unwrap_or(0), self.categories.len())
+    }
+
+    // the ranges of the axes, with room for every point
+    fn ranges(&self) -> (Ticks, Ticks) {
+        let points = self.series.iter().flat_map(|series| &series.points);
+
+        let mut min = Point::all(f32::INFINITY);
+        let mut max = Point::all(f32::NEG_INFINITY);
+
+        for point in points {
+            min = min.min(*point);
+            max = max.max(*point);
+        }
+
+        // bars and areas start at zero
+        if matches!(self.kind, ChartKind::Bar | ChartKind::Area) {
+            min.y = f32::min(min.y, 0.0);
+            max.y = f32::max(max.y, 0.0);
+        }
+
+        let x = match self.categories.is_empty() {
+            true => Ticks::new(min.x, max.x, self.x_ticks),
+            false => Ticks {
+                min: 0.0,
+                max: usize::max(self.categories.len(), 2) as f32 - 1.0,
+                step: 1.0,
+            },
+        };
+
+        (x, Ticks::new(min.y, max.y, self.y_ticks))
+    }
+
+    fn map_x(&self, state: &ChartState, x: f32) -> f32 {
+        let plot = state.plot;
+        plot.min.x + (x - state.x.min) / state.x.range() * plot.width()
+    }
+
+    fn map_y(&self, state: &ChartState, y: f32) -> f32 {
+        let plot = state.plot;
+        plot.max.y - (y - state.y.min) / state.y.range() * plot.height()
+    }
+
+    fn map(&self, state: &ChartState, point: Point) -> Point {
+        Point::new(self.map_x(state, point.x), self.map_y(state, point.y))
+    }
+
+    // the width of each group of bars
+    fn slot(&self, state: &ChartState) -> f32 {
+        state.plot.width() / usize::max(self.groups(), 1) as f32
+    }
+
+    // the value new points grow from
+    fn baseline(&self, state: &ChartState) -> f32 {
+        f32::clamp(0.0, state.y.min, state.y.max)
+    }
+
+    // the points of a series part way through the transition
+    fn displayed<'a>(&'a self, state: &ChartState, index: usize) -> Cow<'a, [Point]> {
+        let points = &self.series[index].points;
+
+        if state.t >= 1.0 {
+            return Cow::Borrowed(points);
+        }
+
+        let t = self.transition.get(state.t);
+        let from = state.from.get(index).map_or(&[][..], Vec::as_slice);
+        let baseline = self.baseline(state);
+
+        let points = points.iter().enumerate().map(|(i, point)| {
+            let from = match from.get(i) {
+                Some(from) => *from,
+                None => Point::new(point.x, baseline),
+            };
+
+            from.lerp(*point, t)
+        });
+
+        Cow::Owned(points.collect())
+    }
+
+    // the start angle and sweep of every slice, clockwise from the top
+    fn slices(&self, state: &ChartState) -> Vec<(f32, f32)> {
+        let values: Vec<f32> = (0..self.series.len())
+            .map(|i| {
+                self.displayed(state, i)
+                    .first()
+                    .map_or(0.0, |p| p.y.max(0.0))
+            })
+            .collect();
+
+        let total: f32 = values.iter().sum();
+        let mut start = -PI / 2.0;
+
+        let slices = values.into_iter().map(|value| {
+            let sweep = match total > 0.0 {
+                true => value / total * PI * 2.0,
+                false => 0.0,
+            };
+
+            start += sweep;
+            (start - sweep, sweep)
+        });
+
+        slices.collect()
+    }
+
+    fn pie_radius(&self, state: &ChartState) -> (f32, f32) {
+        let radius = f32::min(state.plot.width(), state.plot.height()) / 2.0;
+
+        match self.kind {
+            ChartKind::Donut => (radius, radius * self.donut_hole),
+            _ => (radius, 0.0),
+        }
+    }
+
+    fn set_labels(
+        &self,
+        fonts: &mut Fonts,
+        labels: &mut Vec<(f32, TextBuffer)>,
+        values: Vec<(f32, String)>,
+    ) {
+        labels.truncate(values.len());
+
+        for (i, (value, text)) in values.into_iter().enumerate() {
+            if i == labels.len() {
+                let buffer = TextBuffer::new(fonts, self.font_size, 1.0);
+                labels.push((value, buffer));
+            }
+
+            labels[i].0 = value;
+            labels[i].1.set_text(fonts, &text, self.attributes());
+        }
+    }
+
+    fn x_label(&self, x: f32) -> String {
+        match self.categories.is_empty() {
+            true => format_value(x),
+            false => {
+                let index = x.round().max(0.0) as usize;
+                self.categories.get(index).cloned().unwrap_or_default()
+            }
+        }
+    }
+
+    fn find_hover(&self, state: &ChartState, local: Point) -> Option<Hover> {
+        match self.kind {
+            _ if !self.kind.has_axes() => {
+                let (radius, hole) = self.pie_radius(state);
+                let delta = local - state.plot.center();
+                let distance = delta.length();
+
+                if distance > radius || distance < hole {
+                    return None;
+                }
+
+                // the angle clockwise from the top
+                let angle = f32::rem_euclid(delta.y.atan2(delta.x) + PI / 2.0, PI * 2.0);
+
+                let slices = self.slices(state).into_iter().enumerate();
+                slices
+                    .filter(|(_, (_, sweep))| *sweep > 0.0)
+                    .find(|(_, (start, sweep))| {
+                        let start = start + PI / 2.0;
+                        angle >= start && angle < start + sweep
+                    })
+                    .map(|(i, _)| Hover::Slice(i))
+            }
+            _ if !state.plot.contains(local) => None,
+            ChartKind::Bar => {
+                let index = ((local.x - state.plot.min.x) / self.slot(state)) as usize;
+                (index < self.groups()).then_some(Hover::Group(index))
+            }
+            ChartKind::Scatter => {
+                let mut nearest = None;
+                let mut distance = self.point_radius + 8.0;
+
+                for (i, series) in self.series.iter().enumerate() {
+                    for (j, point) in series.points.iter().enumerate() {
+                        let d = (self.map(state, *point) - local).length();
+
+                        if d <= distance {
+                            nearest = Some(Hover::Point(i, j));
+                            distance = d;
+                        }
+                    }
+                }
+
+                nearest
+            }
+            _ => {
+                let x = state.x.min
+                    + (local.x - state.plot.min.x) / state.plot.width() * state.x.range();
+
+                let nearest = self.series.iter().filter_map(|series| {
+                    let index = nearest(&series.points, x)?;
+                    Some(series.points[index].x)
+                });
+
+                let nearest = nearest.min_by(|a, b| f32::total_cmp(&(a - x).abs(), &(b - x).abs()));
+                nearest.map(Hover::X)
+            }
+        }
+    }
+
+    fn tooltip_text(&self, hover: Hover) -> String {
+        let mut text = String::new();
+
+        match hover {
+            Hover::X(x) => {
+                text.push_str(&self.x_label(x));
+
+                for series in &self.series {
+                    if let Some(index) = nearest(&series.points, x) {
+                        let value = format_value(series.points[index].y);
+                        text.push_str(&format!("\n{}: {}", series.name, value));
+                    }
+                }
+            }
+            Hover::Group(index) => {
+                match self.categories.get(index) {
+                    Some(category) => text.push_str(category),
+                    None => text.push_str(&index.to_string()),
+                }
+
+                for series in &self.series {
+                    if let Some(point) = series.points.get(index) {
+                        let value = format_value(point.y);
+                        text.push_str(&format!("\n{}: {}", series.name, value));
+                    }
+                }
+            }
+            Hover::Point(series, index) => {
+                let series = &self.series[series];
+                let point = series.points[index];
+
+                let x = self.x_label(point.x);
+                text = format!("{}\n{}, {}", series.name, x, format_value(point.y));
+            }
+            Hover::Slice(index) => {
+                let value = |series: &Series| series.points.first().map_or(0.0, |p| p.y.max(0.0));
+                let total: f32 = self.series.iter().map(value).sum();
+
+                let series = &self.series[index];
+                let percent = value(series) / total * 100.0;
+
+                text = format!(
+                    "{}: {} ({:.0}%)",
+                    series.name,
+                    format_value(value(series)),
+                    percent,
+                );
+            }
+        }
+
+        text
+    }
+
+    fn draw_legend(&self, state: &ChartState, cx: &mut DrawCx) {
+        let swatch = self.font_size * 0.8;
+        let mut x = 0.0;
+
+        for (i, buffer) in state.legend.iter().enumerate() {
+            let height = buffer.size().height;
+            let rect = Rect::min_size(Point::new(x, (height - swatch) / 2.0), Size::all(swatch));
+
+            let color = self.series_color(i);
+            cx.quad(rect, color, swatch / 4.0, 0.0, Color::TRANSPARENT);
+            x += swatch + LABEL_GAP;
+
+            cx.text(buffer, self.color, Vector::new(x, 0.0));
+            x += buffer.size().width + LABEL_GAP * 2.0;
+        }
+    }
+
+    fn draw_axes(&self, state: &ChartState, cx: &mut DrawCx) {
+        let plot = state.plot;
+
+        for (value, buffer) in &state.y_labels {
+            let y = self.map_y(state, *value);
+
+            if self.grid {
+                let line = Rect::new(Point::new(plot.min.x, y), Point::new(plot.max.x, y + 1.0));
+                cx.fill_rect(line, self.grid_color);
+            }
+
+            let size = buffer.size();
+            let offset = Vector::new(plot.min.x - LABEL_GAP - size.width, y - size.height / 2.0);
+            cx.text(buffer, self.color, offset);
+        }
+
+        // labels that would overlap the previous one are skipped
+        let mut right = f32::NEG_INFINITY;
+
+        for (value, buffer) in &state.x_labels {
+            let x = match self.kind {
+                ChartKind::Bar => plot.min.x + self.slot(state) * (value + 0.5),
+                _ => self.map_x(state, *value),
+            };
+
+            if self.grid && self.kind != ChartKind::Bar {
+                let line = Rect::new(Point::new(x, plot.min.y), Point::new(x + 1.0, plot.max.y));
+                cx.fill_rect(line, self.grid_color);
+            }
+
+            let size = buffer.size();
+            let offset = Vector::new(x - size.width / 2.0, plot.max.y + LABEL_GAP);
+
+            if offset.x >= right {
+                cx.text(buffer, self.color, offset);
+                right = offset.x + size.width + LABEL_GAP;
+            }
+        }
+
+        let y_axis = Rect::new(plot.min, Point::new(plot.min.x + 1.0, plot.max.y));
+        let x_axis = Rect::new(
+            Point::new(plot.min.x, plot.max.y),
+            plot.max + Vector::new(0.0, 1.0),
+        );
+
+        cx.fill_rect(y_axis, self.axis_color);
+        cx.fill_rect(x_axis, self.axis_color);
+    }
+
+    fn draw_series(&self, state: &ChartState, cx: &mut DrawCx, index: usize) {
+        let color = self.series_color(index);
+        let points = self.displayed(state, index);
+
+        let stroke = Stroke {
+            width: self.line_width,
+            cap: StrokeCap::Round,
+            join: StrokeJoin::Round,
+            ..Default::default()
+        };
+
+        match self.kind {
+            ChartKind::Line | ChartKind::Area => {
+                let points = match self.decimate {
+                    true => Cow::Owned(decimate(&points, state.plot.width() as usize)),
+                    false => points,
+                };
+
+                let (Some(first), Some(last)) = (points.first(), points.last()) else {
+                    return;
+                };
+
+                let mut curve = Curve::new();
+                curve.move_to(self.map(state, *first));
+
+                for point in &points[1..] {
+                    curve.line_to(self.map(state, *point));
+                }
+
+                if self.kind == ChartKind::Area {
+                    let baseline = self.map_y(state, self.baseline(state));
+
+                    let mut area = curve.clone();
+                    area.line_to(Point::new(self.map_x(state, last.x), baseline));
+                    area.line_to(Point::new(self.map_x(state, first.x), baseline));
+                    area.close();
+
+                    cx.fill(area, FillRule::NonZero, color.fade(self.area_opacity));
+                }
+
+                cx.stroke(curve, stroke, color);
+            }
+            ChartKind::Bar => {
+                let slot = self.slot(state);
+                let group = slot * (1.0 - self.bar_gap);
+                let width = group / self.series.len() as f32;
+                let baseline = self.map_y(state, self.baseline(state));
+
+                for (i, point) in points.iter().enumerate() {
+                    let x = state.plot.min.x + slot * i as f32 + (slot - group) / 2.0;
+                    let x = x + width * index as f32;
+                    let y = self.map_y(state, point.y);
+
+                    let rect = Rect::new(
+                        Point::new(x, f32::min(y, baseline)),
+                        Point::new(x + width, f32::max(y, baseline)),
+                    );
+
+                    let radius = f32::min(2.0, width / 2.0);
+                    cx.quad(rect, color, radius, 0.0, Color::TRANSPARENT);
+                }
+            }
+            ChartKind::Scatter => {
+                // points drawn to the same pixel are only drawn once
+                let mut pixels = HashSet::new();
+                let mut curve = Curve::new();
+
+                for point in points.iter() {
+                    let point = self.map(state, *point);
+                    let pixel = (point.x as i32, point.y as i32);
+
+                    if !self.decimate || pixels.insert(pixel) {
+                        let radius = Vector::all(self.point_radius);
+                        curve.push_oval(Rect::new(point - radius, point + radius));
+                    }
+                }
+
+                cx.fill(curve, FillRule::NonZero, color);
+            }
+            ChartKind::Pie | ChartKind::Donut => {}
+        }
+    }
+
+    fn draw_pie(&self, state: &ChartState, cx: &mut DrawCx) {
+        let (radius, hole) = self.pie_radius(state);
+
+        for (i, (start, sweep)) in self.slices(state).into_iter().enumerate() {
+            if sweep <= 0.0 {
+                continue;
+            }
+
+            // the hovered slice moves out a little
+            let mut center = state.plot.center();
+            if state
+                .hover
+                .is_some_and(|(_, hover)| hover == Hover::Slice(i))
+            {
+                center += direction(start + sweep / 2.0) * 4.0;
+            }
+
+            let mut curve = Curve::new();
+
+            match hole > 0.0 {
+                true => {
+                    curve.move_to(center + direction(start) * radius);
+                    arc(&mut curve, center, radius, start, sweep);
+                    curve.line_to(center + direction(start + sweep) * hole);
+                    arc(&mut curve, center, hole, start + sweep, -sweep);
+                }
+                false => {
+                    curve.move_to(center);
+                    curve.line_to(center + direction(start) * radius);
+                    arc(&mut curve, center, radius, start, sweep);
+                }
+            }
+
+            curve.close();
+            cx.fill(curve, FillRule::NonZero, self.series_color(i));
+        }
+    }
+
+    fn draw_crosshair(&self, state: &ChartState, cx: &mut DrawCx, hover: Hover) {
+        let plot = state.plot;
+        let radius = self.point_radius + 1.0;
+
+        match hover {
+            Hover::X(x) => {
+                let px = self.map_x(state, x);
+                let line = Rect::new(Point::new(px, plot.min.y), Point::new(px + 1.0, plot.max.y));
+                cx.fill_rect(line, self.axis_color);
+
+                for (i, series) in self.series.iter().enumerate() {
+                    if let Some(index) = nearest(&series.points, x) {
+                        let point = self.map(state, series.points[index]);
+                        let curve = Curve::circle(point, radius);
+                        cx.fill(curve, FillRule::NonZero, self.series_color(i));
+                    }
+                }
+            }
+            Hover::Group(index) => {
+                let slot = self.slot(state);
+                let x = plot.min.x + slot * index as f32;
+                let rect = Rect::new(Point::new(x, plot.min.y), Point::new(x + slot, plot.max.y));
+                cx.fill_rect(rect, self.grid_color.fade(0.5));
+            }
+            Hover::Point(series, index) => {
+                let point = self.map(state, self.series[series].points[index]);
+                let curve = Curve::circle(point, radius + 1.0);
+                cx.stroke(curve, 2.0, self.axis_color);
+            }
+            Hover::Slice(_) => {}
+        }
+    }
+
+    fn draw_tooltip(&self, state: &ChartState, cx: &mut DrawCx, position: Point) {
+        let size = state.tooltip.size() + self.tooltip_padding.size();
+
+        // the tooltip follows the pointer, moved to stay inside the window
+        let window = Rect::min_size(Point::ZERO, cx.window().size);
+        let position = cx.transform() * position + Vector::all(12.0);
+        let mut rect = Rect::min_size(position, size);
+
+        if rect.max.x > window.max.x {
+            rect = Rect::max_size(Point::new(position.x - 24.0, rect.max.y), size);
+        }
+
+        if rect.max.y > window.max.y {
+            rect = Rect::min_size(
+                Point::new(rect.min.x, position.y - 24.0 - size.height),
+                size,
+            );
+        }
+
+        cx.overlay(0, |cx| {
+            cx.translate(rect.offset(), |cx| {
+                cx.quad(
+                    Rect::min_size(Point::ZERO, size),
+                    self.tooltip_background,
+                    self.tooltip_border_radius,
+                    self.tooltip_border_width,
+                    self.tooltip_border_color,
+                );
+
+                cx.text(&state.tooltip, self.color, self.tooltip_padding.offset());
+            });
+        });
+    }
+}
+
+// the index of the point closest to `x`, in points sorted by `x`
+fn nearest(points: &[Point], x: f32) -> Option<usize> {
+    let index = points.partition_point(|point| point.x < x);
+
+    let before = index.checked_sub(1);
+    let after = (index < points.len()).then_some(index);
+
+    match (before, after) {
+        (Some(before), Some(after)) => match x - points[before].x < points[after].x - x {
+            true => Some(before),
+            false => Some(after),
+        },
+        (before, after) => before.or(after),
+    }
+}
+
+fn direction(angle: f32) -> Vector {
+    Vector::new(angle.cos(), angle.sin())
+}
+
+// draw an arc from `start` sweeping `sweep` radians, with conics of at most a quarter turn
+fn arc(curve: &mut Curve, center: Point, radius: f32, start: f32, sweep: f32) {
+    let segments = f32::ceil(sweep.abs() / (PI / 2.0)).max(1.0) as usize;
+    let step = sweep / segments as f32;
+    let weight = f32::cos(step / 2.0);
+
+    for i in 0..segments {
+        let angle = start + step * i as f32;
+        let control = center + direction(angle + step / 2.0) * (radius / weight);
+        let end = center + direction(angle + step) * radius;
+
+        curve.conic_to(control, end, weight);
+    }
+}
+
+// format a value without trailing zeros
+fn format_value(value: f32) -> String {
+    let text = format!("{:.2}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+
+    match text {
+        "-0" => String::from("0"),
+        text => String::from(text),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Hover {
+    X(f32),
+    Group(usize),
+    Point(usize, usize),
+    Slice(usize),
+}
+
+#[doc(hidden)]
+pub struct ChartState {
+    // the points animated from, and how far the animation has come
+    from: Vec<Vec<Point>>,
+    t: f32,
+
+    x: Ticks,
+    y: Ticks,
+    plot: Rect,
+    x_labels: Vec<(f32, TextBuffer)>,
+    y_labels: Vec<(f32, TextBuffer)>,
+    legend: Vec<TextBuffer>,
+    tooltip: TextBuffer,
+    hover: Option<(Point, Hover)>,
+}
+
+impl<T> View<T> for Chart {
+    type State = ChartState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        let (x, y) = self.ranges();
+
+        // the first data grows from the baseline
+        ChartState {
+            from: Vec::new(),
+            t: 0.0,
+            x,
+            y,
+            plot: Rect::ZERO,
+            x_labels: Vec::new(),
+            y_labels: Vec::new(),
+            legend: Vec::new(),
+            tooltip: TextBuffer::new(cx.fonts(), self.font_size, 1.0),
+            hover: None,
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        if self.series != old.series {
+            // the transition continues from where the old data was drawn
+            state.from = (0..old.series.len())
+                .map(|i| old.displayed(state, i).into_owned())
+                .collect();
+
+            state.t = 0.0;
+            state.hover = None;
+
+            cx.layout();
+        }
+
+        if self.font_size != old.font_size {
+            (state.tooltip).set_metrics(cx.fonts(), self.font_size, 1.0);
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, _data: &mut T, event: &Event) {
+        match event {
+            Event::Animate(dt) if self.transition.step(&mut state.t, true, *dt) => {
+                cx.draw();
+            }
+            Event::PointerMoved(e) if self.crosshair => {
+                let local = cx.local(e.position);
+
+                let hover = match cx.is_hot() {
+                    true => self.find_hover(state, local),
+                    false => None,
+                };
+
+                if hover != state.hover.map(|(_, hover)| hover) {
+                    if let Some(hover) = hover {
+                        let text = self.tooltip_text(hover);
+                        (state.tooltip).set_text(cx.fonts(), &text, self.attributes());
+                    }
+                }
+
+                if hover.is_some() || state.hover.is_some() {
+                    state.hover = hover.map(|hover| (local, hover));
+                    cx.draw();
+                }
+            }
+            Event::PointerLeft(_) if state.hover.is_some() => {
+                state.hover = None;
+                cx.draw();
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        let size = space.fit(Size::new(
+            match space.max.width.is_finite() {
+                true => space.max.width,
+                false => DEFAULT_SIZE.width,
+            },
+            match space.max.height.is_finite() {
+                true => space.max.height,
+                false => DEFAULT_SIZE.height,
+            },
+        ));
+
+        // the legend is a single row above the plot
+        let names: Vec<&str> = match self.legend {
+            true => self
+                .series
+                .iter()
+                .map(|series| series.name.as_str())
+                .collect(),
+            false => Vec::new(),
+        };
+
+        state.legend.truncate(names.len());
+
+        for (i, name) in names.iter().enumerate() {
+            if i == state.legend.len() {
+                let buffer = TextBuffer::new(cx.fonts(), self.font_size, 1.0);
+                state.legend.push(buffer);
+            }
+
+            state.legend[i].set_text(cx.fonts(), name, self.attributes());
+        }
+
+        let legend = state.legend.iter().map(|buffer| buffer.size().height);
+        let legend = legend.fold(0.0, f32::max);
+        let top = match legend > 0.0 {
+            true => legend + LABEL_GAP * 2.0,
+            false => 0.0,
+        };
+
+        if !self.kind.has_axes() {
+            state.x_labels.clear();
+            state.y_labels.clear();
+
+            let plot = Rect::new(Point::new(0.0, top), size.to_point());
+            state.plot = plot.shrink(4.0);
+
+            return size;
+        }
+
+        let (x, y) = self.ranges();
+        state.x = x;
+        state.y = y;
+
+        let y_labels = y.values().map(|value| (value, y.label(value))).collect();
+        self.set_labels(cx.fonts(), &mut state.y_labels, y_labels);
+
+        let x_labels = match self.kind {
+            ChartKind::Bar => (0..self.groups())
+                .map(|i| match self.categories.get(i) {
+                    Some(category) => (i as f32, category.clone()),
+                    None => {
+                        let point = self.series.iter().find_map(|s| s.points.get(i));
+                        let x = point.map_or(i as f32, |point| point.x);
+                        (i as f32, format_value(x))
+                    }
+                })
+                .collect(),
+            _ if !self.categories.is_empty() => (self.categories.iter().enumerate())
+                .map(|(i, category)| (i as f32, category.clone()))
+                .collect(),
+            _ => x.values().map(|value| (value, x.label(value))).collect(),
+        };
+
+        self.set_labels(cx.fonts(), &mut state.x_labels, x_labels);
+
+        let size_of = |labels: &[(f32, TextBuffer)]| {
+            let sizes = labels.iter().map(|(_, buffer)| buffer.size());
+            sizes.fold(Size::ZERO, Size::max)
+        };
+
+        let y_size = size_of(&state.y_labels);
+        let x_size = size_of(&state.x_labels);
+
+        // the outer labels hang half outside the plot
+        let min = Point::new(y_size.width + LABEL_GAP, top + y_size.height / 2.0);
+        let max = Point::new(
+            size.width - x_size.width / 2.0,
+            size.height - x_size.height - LABEL_GAP,
+        );
+
+        state.plot = Rect::new(min, max.max(min + Vector::all(1.0)));
+
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        cx.trigger(cx.rect());
+
+        if state.t < 1.0 {
+            cx.animate();
+        }
+
+        self.draw_legend(state, cx);
+
+        if self.kind.has_axes() {
+            if let Some((_, hover @ Hover::Group(_))) = state.hover {
+                self.draw_crosshair(state, cx, hover);
+            }
+
+            self.draw_axes(state, cx);
+
+            for i in 0..self.series.len() {
+                self.draw_series(state, cx, i);
+            }
+        } else {
+            self.draw_pie(state, cx);
+        }
+
+        if let Some((position, hover)) = state.hover {
+            if !matches!(hover, Hover::Group(_)) {
+                self.draw_crosshair(state, cx, hover);
+            }
+
+            self.draw_tooltip(state, cx, position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::Event,
+        layout::{Point, Size, Space},
+        views::testing::ViewTester,
+    };
+
+    use super::{bar_chart, pie_chart, Hover, Series};
+
+    #[test]
+    fn data_transition() {
+        let mut view = bar_chart([Series::values("a", [1.0, 2.0])]);
+
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(&mut view, &mut (), Space::new(Size::ZERO, Size::all(200.0)));
+
+        // the bars grow from zero, and stop at the data
+        assert_eq!(view.displayed(&tester.state, 0)[1].y, 0.0);
+        tester.event(&mut view, &mut (), &Event::Animate(1.0));
+        assert_eq!(view.displayed(&tester.state, 0)[1].y, 2.0);
+
+        // new data starts from the old
+        let old = view;
+        let mut view = bar_chart([Series::values("a", [3.0, 2.0, 1.0])]);
+        tester.rebuild(&mut view, &mut (), &old);
+
+        let displayed = view.displayed(&tester.state, 0);
+        assert_eq!(
+            displayed[..],
+            [(0.0, 1.0), (1.0, 2.0), (2.0, 0.0)].map(Point::from)
+        );
+    }
+
+    #[test]
+    fn hover_pie_slice() {
+        let mut view = pie_chart([("a", 1.0), ("b", 3.0)]).legend(false);
+
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(&mut view, &mut (), Space::new(Size::ZERO, Size::all(108.0)));
+        tester.event(&mut view, &mut (), &Event::Animate(1.0));
+
+        // the first slice is the quarter clockwise from the top
+        let state = &tester.state;
+        assert_eq!(
+            view.find_hover(state, Point::new(70.0, 40.0)),
+            Some(Hover::Slice(0))
+        );
+        assert_eq!(
+            view.find_hover(state, Point::new(40.0, 70.0)),
+            Some(Hover::Slice(1))
+        );
+        assert_eq!(view.find_hover(state, Point::new(0.0, 0.0)), None);
+
+        assert_eq!(view.tooltip_text(Hover::Slice(1)), "b: 3 (75%)");
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{canvas::Color, layout::Point};
+
+/// A series of data points in a [`Chart`](super::Chart).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+    /// The name of the series, shown in the legend and the tooltip.
+    pub name: String,
+
+    /// The points of the series.
+    ///
+    /// The points of line and area charts are expected to be sorted by `x`.
+    pub points: Vec<Point>,
+
+    /// The color of the series, when `None` a color of the chart is used.
+    pub color: Option<Color>,
+}
+
+impl Series {
+    /// Create a new [`Series`] from `points`.
+    pub fn new(
+        name: impl Into<String>,
+        points: impl IntoIterator<Item = impl Into<Point>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            points: points.into_iter().map(Into::into).collect(),
+            color: None,
+        }
+    }
+
+    /// Create a new [`Series`] from `values`, the `x` of each point is its index.
+    pub fn values(name: impl Into<String>, values: impl IntoIterator<Item = f32>) -> Self {
+        let points = values.into_iter().enumerate();
+        Self::new(name, points.map(|(i, y)| Point::new(i as f32, y)))
+    }
+
+    /// Set the color of the series.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// Reduce `points` sorted by `x` into `buckets` evenly sized ranges of `x`, keeping the first,
+/// last, lowest and highest point of each, which preserves the shape of the line.
+pub(super) fn decimate(points: &[Point], buckets: usize) -> Vec<Point> {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return Vec::new();
+    };
+
+    let width = last.x - first.x;
+
+    if buckets == 0 || points.len() <= buckets * 4 || width <= 0.0 {
+        return points.to_vec();
+    }
+
+    let bucket = |point: &Point| {
+        let bucket = (point.x - first.x) / width * buckets as f32;
+        usize::min(bucket as usize, buckets - 1)
+    };
+
+    let mut decimated = Vec::with_capacity(buckets * 4);
+    let mut start = 0;
+
+    while start < points.len() {
+        let current = bucket(&points[start]);
+
+        let end = start + points[start..].partition_point(|point| bucket(point) == current);
+        let slice = &points[start..end];
+
+        let (mut low, mut high) = (0, 0);
+
+        for (i, point) in slice.iter().enumerate() {
+            if point.y < slice[low].y {
+                low = i;
+            }
+
+            if point.y > slice[high].y {
+                high = i;
+            }
+        }
+
+        // the points are kept in their original order
+        let mut keep = [0, low, high, slice.len() - 1];
+        keep.sort_unstable();
+
+        let mut previous = None;
+        for i in keep {
+            if previous != Some(i) {
+                decimated.push(slice[i]);
+                previous = Some(i);
+            }
+        }
+
+        start = end;
+    }
+
+    decimated
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layout::Point;
+
+    use super::decimate;
+
+    #[test]
+    fn decimate_keeps_extremes() {
+        let points: Vec<Point> = (0..10_000)
+            .map(|i| Point::new(i as f32, f32::sin(i as f32 * 0.01)))
+            .collect();
+
+        let decimated = decimate(&points, 100);
+        assert!(decimated.len() <= 400);
+
+        // the ends and the peaks survive
+        assert_eq!(decimated.first(), points.first());
+        assert_eq!(decimated.last(), points.last());
+
+        let max = |points: &[Point]| points.iter().map(|p| p.y).fold(f32::MIN, f32::max);
+        assert_eq!(max(&decimated), max(&points));
+
+        // small series are left alone
+        assert_eq!(decimate(&points[..100], 100), &points[..100]);
+    }
+}
@@ -0,0 +1,115 @@
+/// The ticks of a chart axis, at evenly spaced round numbers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ticks {
+    /// The first tick, at or below the smallest value.
+    pub min: f32,
+
+    /// The last tick, at or above the largest value.
+    pub max: f32,
+
+    /// The distance between two ticks.
+    pub step: f32,
+}
+
+impl Ticks {
+    /// Create about `count` ticks covering the range from `min` to `max`.
+    pub fn new(min: f32, max: f32, count: usize) -> Self {
+        let (mut min, mut max) = (f32::min(min, max), f32::max(min, max));
+
+        if !min.is_finite() || !max.is_finite() {
+            return Self {
+                min: 0.0,
+                max: 1.0,
+                step: 1.0,
+            };
+        }
+
+        // a single value gets a range around it
+        if min == max {
+            let pad = match min == 0.0 {
+                true => 1.0,
+                false => min.abs() / 2.0,
+            };
+
+            min -= pad;
+            max += pad;
+        }
+
+        let range = nice(max - min, false);
+        let step = nice(range / count.max(2).saturating_sub(1) as f32, true);
+
+        Self {
+            min: f32::floor(min / step) * step,
+            max: f32::ceil(max / step) * step,
+            step,
+        }
+    }
+
+    /// Get the length of the range covered by the ticks.
+    pub fn range(&self) -> f32 {
+        self.max - self.min
+    }
+
+    /// Get the number of ticks, there is always at least one.
+    pub fn count(&self) -> usize {
+        f32::round(self.range() / self.step) as usize + 1
+    }
+
+    /// Iterate over the values of the ticks.
+    pub fn values(&self) -> impl Iterator<Item = f32> {
+        let Self { min, step, .. } = *self;
+        (0..self.count()).map(move |i| min + step * i as f32)
+    }
+
+    /// Format a `value` with as many decimals as the step needs.
+    pub fn label(&self, value: f32) -> String {
+        let decimals = f32::max(-f32::floor(self.step.log10()), 0.0) as usize;
+
+        // rounding errors shouldn't show up as `-0`
+        let value = match value.abs() < self.step / 2.0 {
+            true => 0.0,
+            false => value,
+        };
+
+        format!("{:.*}", decimals, value)
+    }
+}
+
+// round `range` to 1, 2 or 5 times a power of ten
+fn nice(range: f32, round: bool) -> f32 {
+    let exponent = f32::floor(range.log10());
+    let fraction = range / f32::powf(10.0, exponent);
+
+    let nice = match round {
+        true if fraction < 1.5 => 1.0,
+        true if fraction < 3.0 => 2.0,
+        true if fraction < 7.0 => 5.0,
+        false if fraction <= 1.0 => 1.0,
+        false if fraction <= 2.0 => 2.0,
+        false if fraction <= 5.0 => 5.0,
+        _ => 10.0,
+    };
+
+    nice * f32::powf(10.0, exponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ticks;
+
+    #[test]
+    fn nice_ticks() {
+        let ticks = Ticks::new(0.0, 97.0, 5);
+        assert_eq!((ticks.min, ticks.max, ticks.step), (0.0, 100.0, 20.0));
+        assert_eq!(ticks.count(), 6);
+
+        let ticks = Ticks::new(-0.3, 0.42, 5);
+        assert_eq!((ticks.min, ticks.max), (-0.4, 0.6));
+        assert_eq!(ticks.label(0.2), "0.2");
+        assert_eq!(ticks.label(-1e-8), "0.0");
+
+        // a single value still gets a range
+        let ticks = Ticks::new(10.0, 10.0, 5);
+        assert!(ticks.min < 10.0 && ticks.max > 10.0);
+    }
+}
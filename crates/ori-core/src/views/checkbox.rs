@@ -174,10 +174,16 @@ impl<T> View<T> for Checkbox {
             );
 
             if self.checked {
+                // the check mark is mirrored when right to left
+                let x = |x: f32| match cx.direction().is_rtl() {
+                    true => 1.0 - x,
+                    false => x,
+                };
+
                 let mut curve = Curve::new();
-                curve.move_to(Point::new(0.2, 0.5) * cx.size());
-                curve.line_to(Point::new(0.4, 0.7) * cx.size());
-                curve.line_to(Point::new(0.8, 0.3) * cx.size());
+                curve.move_to(Point::new(x(0.2), 0.5) * cx.size());
+                curve.line_to(Point::new(x(0.4), 0.7) * cx.size());
+                curve.line_to(Point::new(x(0.8), 0.3) * cx.size());
 
                 cx.stroke(curve, self.stroke, self.color);
             }
@@ -154,7 +154,9 @@ where
         data: &mut T,
         space: Space,
     ) -> Size {
-        self.content.layout(content, cx, data, space)
+        let size = self.content.layout(content, cx, data, space);
+        cx.set_baseline(content.parent_baseline());
+        size
     }
 
     fn draw(&mut self, content: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
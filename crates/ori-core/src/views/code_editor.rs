@@ -0,0 +1,1306 @@
+use std::fmt::Write;
+
+use cosmic_text::{
+    Action, AttrsList, Buffer, BufferRef, Change, Edit, Editor, FontSystem, LayoutRun, Metrics,
+    Motion, Selection, Shaping, Wrap,
+};
+use ori_macro::Build;
+
+use crate::{
+    canvas::Color,
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, KeyPressed},
+    layout::{Point, Rect, Size, Space, Vector},
+    style::{style, Style, Styles},
+    text::{
+        FontFamily, FontStretch, FontStyle, FontWeight, Fonts, TextAlign, TextAttributes,
+        TextBuffer,
+    },
+    view::View,
+    window::Cursor,
+};
+
+pub use self::highlight::*;
+
+mod highlight;
+
+/// Create a new [`CodeEditor`].
+pub fn code_editor<T>() -> CodeEditor<T> {
+    CodeEditor::new()
+}
+
+/// The style of a code editor.
+#[derive(Clone, Debug)]
+pub struct CodeEditorStyle {
+    /// The font size of the text.
+    pub font_size: f32,
+
+    /// The font family of the text.
+    pub font_family: FontFamily,
+
+    /// The line height of the text.
+    pub line_height: f32,
+
+    /// The color of the text.
+    pub color: Color,
+
+    /// The color of the background.
+    pub background: Color,
+
+    /// The color of the line numbers.
+    pub gutter_color: Color,
+
+    /// The color of the line number of the line with the cursor.
+    pub gutter_active_color: Color,
+
+    /// The color of the background of the line numbers.
+    pub gutter_background: Color,
+
+    /// The horizontal padding around the line numbers.
+    pub gutter_padding: f32,
+
+    /// The color of the background of the line with the cursor.
+    pub current_line_color: Color,
+
+    /// The color of the selection.
+    pub selection_color: Color,
+
+    /// The color of the background of the bracket matching the one at the cursor.
+    pub bracket_color: Color,
+
+    /// The color of the cursor.
+    pub cursor_color: Color,
+}
+
+impl Style for CodeEditorStyle {
+    fn styled(style: &Styles) -> Self {
+        let palette = style.palette();
+
+        Self {
+            font_size: 14.0,
+            font_family: FontFamily::Monospace,
+            line_height: 1.4,
+            color: palette.contrast,
+            background: palette.surface_lower,
+            gutter_color: palette.contrast_low,
+            gutter_active_color: palette.contrast,
+            gutter_background: palette.surface_lower,
+            gutter_padding: 12.0,
+            current_line_color: palette.surface,
+            selection_color: palette.primary.fade(0.3),
+            bracket_color: palette.outline,
+            cursor_color: palette.contrast,
+        }
+    }
+}
+
+/// A code editor, with line numbers and syntax highlighting.
+///
+/// Only the lines that are visible are laid out, and after an edit only the lines whose
+/// highlighting changed are highlighted again, which keeps large files responsive. The editor
+/// scrolls by itself, and should therefore be given a bounded height.
+///
+/// Can be styled using the [`CodeEditorStyle`].
+#[derive(Build)]
+pub struct CodeEditor<T> {
+    /// The text.
+    #[build(ignore)]
+    pub text: Option<String>,
+
+    /// A callback that is called when the text is edited.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub on_input: Option<Box<dyn FnMut(&mut EventCx, &mut T, String)>>,
+
+    /// The highlighter of the text.
+    #[build(ignore)]
+    pub highlighter: Option<Box<dyn AnyHighlighter>>,
+
+    /// The number of spaces in an indentation.
+    pub tab_width: u16,
+
+    /// The font size of the text.
+    pub font_size: f32,
+
+    /// The font family of the text.
+    pub font_family: FontFamily,
+
+    /// The line height of the text.
+    pub line_height: f32,
+
+    /// The color of the text.
+    pub color: Color,
+
+    /// The color of the background.
+    pub background: Color,
+
+    /// The color of the line numbers.
+    pub gutter_color: Color,
+
+    /// The color of the line number of the line with the cursor.
+    pub gutter_active_color: Color,
+
+    /// The color of the background of the line numbers.
+    pub gutter_background: Color,
+
+    /// The horizontal padding around the line numbers.
+    pub gutter_padding: f32,
+
+    /// The color of the background of the line with the cursor.
+    pub current_line_color: Color,
+
+    /// The color of the selection.
+    pub selection_color: Color,
+
+    /// The color of the background of the bracket matching the one at the cursor.
+    pub bracket_color: Color,
+
+    /// The color of the cursor.
+    pub cursor_color: Color,
+}
+
+impl<T> Default for CodeEditor<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CodeEditor<T> {
+    /// Create a new code editor.
+    pub fn new() -> Self {
+        Self::styled(style())
+    }
+
+    /// Create a new code editor with a style.
+    pub fn styled(style: CodeEditorStyle) -> Self {
+        Self {
+            text: None,
+            on_input: None,
+            highlighter: None,
+            tab_width: 4,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            line_height: style.line_height,
+            color: style.color,
+            background: style.background,
+            gutter_color: style.gutter_color,
+            gutter_active_color: style.gutter_active_color,
+            gutter_background: style.gutter_background,
+            gutter_padding: style.gutter_padding,
+            current_line_color: style.current_line_color,
+            selection_color: style.selection_color,
+            bracket_color: style.bracket_color,
+            cursor_color: style.cursor_color,
+        }
+    }
+
+    /// Set the text of the editor.
+    pub fn text(mut self, text: impl AsRef<str>) -> Self {
+        self.text = Some(text.as_ref().to_string());
+        self
+    }
+
+    /// Set the callback that is called when the text is edited.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_input(
+        mut self,
+        on_input: impl FnMut(&mut EventCx, &mut T, String) + 'static,
+    ) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Set the highlighter of the text.
+    pub fn highlighter(mut self, highlighter: impl Highlighter) -> Self {
+        self.highlighter = Some(Box::new(highlighter));
+        self
+    }
+
+    /// Highlight the text with the built-in highlighter of a `language`.
+    pub fn language(self, language: Language) -> Self {
+        self.highlighter(SyntaxHighlighter::new(language))
+    }
+
+    fn attributes(&self) -> TextAttributes {
+        TextAttributes {
+            family: self.font_family.clone(),
+            stretch: FontStretch::Normal,
+            weight: FontWeight::NORMAL,
+            style: FontStyle::Normal,
+        }
+    }
+
+    fn metrics(&self) -> Metrics {
+        Metrics {
+            font_size: self.font_size,
+            line_height: self.line_height * self.font_size,
+        }
+    }
+
+    fn gutter_width(&self, state: &CodeEditorState) -> f32 {
+        state.digits_width + self.gutter_padding * 2.0
+    }
+
+    // the horizontal position of the text, relative to the editor
+    fn text_x(&self, state: &CodeEditorState) -> f32 {
+        self.gutter_width(state) + self.gutter_padding - state.scroll_x
+    }
+
+    // replace the text, keeping the cursor where it is as far as possible
+    fn set_text(&self, fonts: &mut Fonts, state: &mut CodeEditorState, text: &str) {
+        let cursor = state.editor.cursor();
+
+        state.buffer_mut().set_text(
+            &mut fonts.font_system,
+            text,
+            self.attributes().to_cosmic_text(),
+            Shaping::Advanced,
+        );
+
+        let lines = &state.buffer().lines;
+        let line = usize::min(cursor.line, lines.len() - 1);
+        let text = lines[line].text();
+
+        let mut index = usize::min(cursor.index, text.len());
+        while !text.is_char_boundary(index) {
+            index -= 1;
+        }
+
+        state.editor.set_selection(Selection::None);
+        (state.editor).set_cursor(cosmic_text::Cursor::new(line, index));
+
+        self.highlight_all(state);
+    }
+
+    fn highlight_all(&self, state: &mut CodeEditorState) {
+        let lines = state.buffer().lines.len();
+        state.line_states = vec![u32::MAX; lines];
+
+        match self.highlighter {
+            Some(_) => self.highlight(state, 0, lines),
+            None => {
+                let attrs_list = AttrsList::new(self.attributes().to_cosmic_text());
+
+                for line in state.buffer_mut().lines.iter_mut() {
+                    line.set_attrs_list(attrs_list.clone());
+                }
+            }
+        }
+    }
+
+    // highlight the lines from `start`, stopping after `end` when the state of a line is
+    // the same as it was before
+    fn highlight(&self, state: &mut CodeEditorState, start: usize, end: usize) {
+        let highlighter = match self.highlighter {
+            Some(ref highlighter) => highlighter,
+            None => return,
+        };
+
+        let attrs = self.attributes();
+        let mut highlights = std::mem::take(&mut state.highlights);
+
+        let mut line_state = match start {
+            0 => 0,
+            _ => state.line_states[start - 1],
+        };
+
+        for i in start..state.line_states.len() {
+            let line = &mut state.buffer_mut().lines[i];
+
+            highlights.clear();
+            highlighter.dyn_highlight(line.text(), &mut line_state, &mut highlights);
+
+            let mut attrs_list = AttrsList::new(attrs.to_cosmic_text());
+
+            for highlight in &highlights {
+                let attrs = TextAttributes {
+                    weight: highlight.font_weight.unwrap_or(attrs.weight),
+                    style: highlight.font_style.unwrap_or(attrs.style),
+                    ..attrs.clone()
+                };
+
+                let [r, g, b, a] = highlight.color.to_rgba8();
+                let color = cosmic_text::Color::rgba(r, g, b, a);
+                attrs_list.add_span(highlight.range.clone(), attrs.to_cosmic_text().color(color));
+            }
+
+            // this only invalidates the shaping of the line when the attributes changed
+            line.set_attrs_list(attrs_list);
+
+            let old = std::mem::replace(&mut state.line_states[i], line_state);
+
+            if i >= end && old == line_state {
+                break;
+            }
+        }
+
+        state.highlights = highlights;
+    }
+
+    // perform an edit, highlighting the lines that changed, returns whether anything changed
+    fn edit(
+        &self,
+        fonts: &mut Fonts,
+        state: &mut CodeEditorState,
+        edit: impl FnOnce(&mut Editor<'static>, &mut FontSystem),
+    ) -> bool {
+        state.editor.start_change();
+        edit(&mut state.editor, &mut fonts.font_system);
+
+        match state.editor.finish_change() {
+            Some(change) if !change.items.is_empty() => {
+                self.apply_change(state, &change);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn apply_change(&self, state: &mut CodeEditorState, change: &Change) {
+        let mut dirty: Option<(usize, usize)> = None;
+
+        for item in &change.items {
+            let lines = item.end.line - item.start.line;
+            let after = item.start.line + 1;
+
+            let (start, mut end) = dirty.unwrap_or((item.start.line, item.start.line));
+
+            if item.insert {
+                let inserted = vec![u32::MAX; lines];
+                state.line_states.splice(after..after, inserted);
+
+                if end > item.start.line {
+                    end += lines;
+                }
+
+                end = usize::max(end, item.end.line);
+            } else {
+                state.line_states.drain(after..after + lines);
+
+                if end > item.start.line {
+                    end = usize::max(end.saturating_sub(lines), item.start.line);
+                }
+            }
+
+            dirty = Some((usize::min(start, item.start.line), end));
+        }
+
+        if let Some((start, end)) = dirty {
+            self.highlight(state, start, end);
+        }
+    }
+
+    fn key_pressed(
+        &self,
+        fonts: &mut Fonts,
+        state: &mut CodeEditorState,
+        e: &KeyPressed,
+    ) -> Edited {
+        let tab_width = self.tab_width as usize;
+
+        if e.is_key(Key::Tab) {
+            let unindent = e.modifiers.shift;
+            let changed = self.edit(fonts, state, |editor, font_system| {
+                indent(editor, font_system, unindent, tab_width);
+            });
+
+            return Edited::from(changed);
+        }
+
+        if e.is_key(Key::Enter) {
+            let changed = self.edit(fonts, state, |editor, _| {
+                enter(editor, tab_width);
+            });
+
+            return Edited::from(changed);
+        }
+
+        if e.is_key(Key::Backspace) {
+            let changed = self.edit(fonts, state, |editor, font_system| {
+                backspace(editor, font_system, tab_width);
+            });
+
+            return Edited::from(changed);
+        }
+
+        if e.is_key(Key::Delete) {
+            let changed = self.edit(fonts, state, |editor, font_system| {
+                editor.action(font_system, Action::Delete);
+            });
+
+            return Edited::from(changed);
+        }
+
+        if e.is_key(Key::Escape) {
+            (state.editor).action(&mut fonts.font_system, Action::Escape);
+            return Edited::Moved;
+        }
+
+        if e.is_key('a') && e.modifiers.ctrl {
+            let lines = &state.buffer().lines;
+            let end =
+                cosmic_text::Cursor::new(lines.len() - 1, lines[lines.len() - 1].text().len());
+
+            let start = cosmic_text::Cursor::new(0, 0);
+            state.editor.set_selection(Selection::Normal(start));
+            state.editor.set_cursor(end);
+
+            return Edited::Moved;
+        }
+
+        if let Some(motion) = move_key(e) {
+            let selection = state.editor.selection();
+
+            if e.modifiers.shift && selection == Selection::None {
+                let cursor = state.editor.cursor();
+                state.editor.set_selection(Selection::Normal(cursor));
+            } else if !e.modifiers.shift && selection != Selection::None {
+                state.editor.set_selection(Selection::None);
+            }
+
+            (state.editor).action(&mut fonts.font_system, Action::Motion(motion));
+            return Edited::Moved;
+        }
+
+        if e.modifiers.ctrl || e.modifiers.alt || e.modifiers.meta {
+            return Edited::None;
+        }
+
+        match e.text {
+            Some(ref text) => {
+                let changed = self.edit(fonts, state, |editor, _| {
+                    editor.insert_string(text, None);
+                });
+
+                Edited::from(changed)
+            }
+            None => Edited::None,
+        }
+    }
+}
+
+// what a key press did to the editor
+enum Edited {
+    None,
+    Moved,
+    Changed,
+}
+
+impl From<bool> for Edited {
+    fn from(changed: bool) -> Self {
+        match changed {
+            true => Edited::Changed,
+            false => Edited::Moved,
+        }
+    }
+}
+
+// indent or unindent the selected lines, or insert spaces up to the next tab stop
+fn indent(editor: &mut Editor, font_system: &mut FontSystem, unindent: bool, tab_width: usize) {
+    let multiline =
+        matches!(editor.selection_bounds(), Some((start, end)) if start.line != end.line);
+
+    if unindent {
+        editor.action(font_system, Action::Unindent);
+    } else if multiline {
+        editor.action(font_system, Action::Indent);
+    } else {
+        editor.delete_selection();
+
+        let cursor = editor.cursor();
+        let column = editor.with_buffer(|buffer| {
+            let line = buffer.lines[cursor.line].text();
+            line[..cursor.index].chars().count()
+        });
+
+        let spaces = tab_width - column % tab_width;
+        editor.insert_string(&" ".repeat(spaces), None);
+    }
+}
+
+// insert a new line, keeping the indentation of the current line and indenting after an
+// opening bracket
+fn enter(editor: &mut Editor, tab_width: usize) {
+    editor.delete_selection();
+
+    let cursor = editor.cursor();
+    let (indentation, opens, closes) = editor.with_buffer(|buffer| {
+        let line = buffer.lines[cursor.line].text();
+        let (before, after) = line.split_at(cursor.index);
+
+        let indentation = line.len() - line.trim_start_matches([' ', '\t']).len();
+        let indentation = String::from(&line[..indentation.min(cursor.index)]);
+
+        let open = before
+            .trim_end()
+            .chars()
+            .next_back()
+            .filter(|&c| closing(c).is_some());
+        let close = after.trim_start().chars().next();
+
+        let opens = open.is_some();
+        let closes = open.is_some() && open.and_then(closing) == close;
+
+        (indentation, opens, closes)
+    });
+
+    let mut text = format!("\n{}", indentation);
+
+    if opens {
+        text.push_str(&" ".repeat(tab_width));
+    }
+
+    editor.insert_string(&text, None);
+
+    // move the closing bracket to a line of its own
+    if closes {
+        let cursor = editor.cursor();
+        editor.insert_string(&format!("\n{}", indentation), None);
+        editor.set_cursor(cursor);
+    }
+}
+
+// delete a character, or an indentation when the cursor is in the leading spaces of a line
+fn backspace(editor: &mut Editor, font_system: &mut FontSystem, tab_width: usize) {
+    let cursor = editor.cursor();
+
+    let spaces = editor.with_buffer(|buffer| {
+        let before = &buffer.lines[cursor.line].text()[..cursor.index];
+        let spaces = !before.is_empty() && before.bytes().all(|c| c == b' ');
+        spaces.then_some(before.len())
+    });
+
+    match spaces {
+        Some(spaces) if editor.selection_bounds().is_none() => {
+            let remove = match spaces % tab_width {
+                0 => tab_width,
+                remainder => remainder,
+            };
+
+            let start = cosmic_text::Cursor::new(cursor.line, cursor.index - remove);
+            editor.delete_range(start, cursor);
+            editor.set_cursor(start);
+        }
+        _ => editor.action(font_system, Action::Backspace),
+    }
+}
+
+fn closing(c: char) -> Option<char> {
+    match c {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        _ => None,
+    }
+}
+
+fn opening(c: char) -> Option<char> {
+    match c {
+        ')' => Some('('),
+        ']' => Some('['),
+        '}' => Some('{'),
+        _ => None,
+    }
+}
+
+// how far to look for a matching bracket, in lines
+const BRACKET_SEARCH_LINES: usize = 1000;
+
+// find the bracket at or before the cursor and the bracket matching it, as (line, index)
+fn matching_brackets(buffer: &Buffer, cursor: cosmic_text::Cursor) -> Option<[(usize, usize); 2]> {
+    let line = buffer.lines[cursor.line].text();
+
+    let at = line[cursor.index..].chars().next();
+    let before = line[..cursor.index].chars().next_back();
+
+    let (index, bracket) = match (at, before) {
+        (Some(c), _) if closing(c).is_some() || opening(c).is_some() => (cursor.index, c),
+        (_, Some(c)) if closing(c).is_some() || opening(c).is_some() => {
+            (cursor.index - c.len_utf8(), c)
+        }
+        _ => return None,
+    };
+
+    let mut depth = 0;
+
+    if let Some(close) = closing(bracket) {
+        let lines = buffer.lines.iter().enumerate().skip(cursor.line);
+
+        for (i, line) in lines.take(BRACKET_SEARCH_LINES) {
+            let start = if i == cursor.line { index } else { 0 };
+
+            for (j, c) in line.text()[start..].char_indices() {
+                if c == bracket {
+                    depth += 1;
+                } else if c == close {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        return Some([(cursor.line, index), (i, start + j)]);
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(open) = opening(bracket) {
+        let lines = buffer.lines[..=cursor.line].iter().enumerate().rev();
+
+        for (i, line) in lines.take(BRACKET_SEARCH_LINES) {
+            let end = if i == cursor.line {
+                index + 1
+            } else {
+                line.text().len()
+            };
+
+            for (j, c) in line.text()[..end].char_indices().rev() {
+                if c == bracket {
+                    depth += 1;
+                } else if c == open {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        return Some([(cursor.line, index), (i, j)]);
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// the horizontal position of the character at `index` in a run
+fn index_x(run: &LayoutRun, index: usize) -> f32 {
+    let glyph = run.glyphs.iter().find(|glyph| glyph.end > index);
+    glyph.map_or(run.line_w, |glyph| glyph.x)
+}
+
+fn move_key(e: &KeyPressed) -> Option<Motion> {
+    match e.key {
+        Key::Left if e.modifiers.ctrl => Some(Motion::LeftWord),
+        Key::Right if e.modifiers.ctrl => Some(Motion::RightWord),
+        Key::Home if e.modifiers.ctrl => Some(Motion::BufferStart),
+        Key::End if e.modifiers.ctrl => Some(Motion::BufferEnd),
+        Key::Left => Some(Motion::Left),
+        Key::Right => Some(Motion::Right),
+        Key::Up => Some(Motion::Up),
+        Key::Down => Some(Motion::Down),
+        Key::Home => Some(Motion::SoftHome),
+        Key::End => Some(Motion::End),
+        Key::PageUp => Some(Motion::PageUp),
+        Key::PageDown => Some(Motion::PageDown),
+        _ => None,
+    }
+}
+
+#[doc(hidden)]
+pub struct CodeEditorState {
+    editor: Editor<'static>,
+    gutter: TextBuffer,
+    // the first and last visible line, and the line of the cursor, when the gutter was last set
+    gutter_lines: Option<[usize; 3]>,
+    digits: usize,
+    digits_width: f32,
+    // the state of the highlighter at the end of each line
+    line_states: Vec<u32>,
+    highlights: Vec<Highlight>,
+    scroll_x: f32,
+    follow_cursor: bool,
+    dragging: bool,
+    blink: f32,
+    // whether the text was edited and handed to `on_input` since the last rebuild
+    edited: bool,
+}
+
+impl CodeEditorState {
+    fn buffer(&self) -> &Buffer {
+        match self.editor.buffer_ref() {
+            BufferRef::Owned(buffer) => buffer,
+            _ => unreachable!(),
+        }
+    }
+
+    fn buffer_mut(&mut self) -> &mut Buffer {
+        match self.editor.buffer_ref_mut() {
+            BufferRef::Owned(buffer) => buffer,
+            _ => unreachable!(),
+        }
+    }
+
+    fn text(&self) -> String {
+        let mut text = String::new();
+
+        for (i, line) in self.buffer().lines.iter().enumerate() {
+            if i > 0 {
+                text.push('\n');
+            }
+
+            text.push_str(line.text());
+        }
+
+        text
+    }
+}
+
+impl<T> View<T> for CodeEditor<T> {
+    type State = CodeEditorState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.set_focusable(true);
+
+        let font_system = &mut cx.fonts().font_system;
+
+        let mut buffer = Buffer::new(font_system, self.metrics());
+        buffer.set_wrap(font_system, Wrap::None);
+
+        let mut editor = Editor::new(buffer);
+        editor.set_tab_width(font_system, self.tab_width);
+
+        let mut gutter = TextBuffer::new(cx.fonts(), self.font_size, self.line_height);
+        gutter.set_align(TextAlign::End);
+
+        let mut state = CodeEditorState {
+            editor,
+            gutter,
+            gutter_lines: None,
+            digits: 0,
+            digits_width: 0.0,
+            line_states: Vec::new(),
+            highlights: Vec::new(),
+            scroll_x: 0.0,
+            follow_cursor: false,
+            dragging: false,
+            blink: 0.0,
+            edited: false,
+        };
+
+        let text = self.text.clone().unwrap_or_default();
+        self.set_text(cx.fonts(), &mut state, &text);
+        state.editor.set_cursor(cosmic_text::Cursor::default());
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        if self.font_size != old.font_size || self.line_height != old.line_height {
+            let metrics = self.metrics();
+            (state.buffer_mut()).set_metrics(&mut cx.fonts().font_system, metrics);
+            (state.gutter).set_metrics(cx.fonts(), self.font_size, self.line_height);
+
+            state.digits = 0;
+            state.gutter_lines = None;
+
+            cx.layout();
+        }
+
+        if self.tab_width != old.tab_width {
+            (state.editor).set_tab_width(&mut cx.fonts().font_system, self.tab_width);
+            cx.layout();
+        }
+
+        // the text handed to `on_input` is assumed to be the new bound text, otherwise the text
+        // is only compared when the bound text changed
+        let edited = std::mem::take(&mut state.edited);
+
+        if let Some(ref text) = self.text {
+            if !edited && self.text != old.text && *text != state.text() {
+                self.set_text(cx.fonts(), state, text);
+                cx.layout();
+                return;
+            }
+        }
+
+        let highlighter_changed = match (&self.highlighter, &old.highlighter) {
+            (Some(highlighter), Some(old)) => !highlighter.dyn_eq(old.as_ref()),
+            (None, None) => false,
+            _ => true,
+        };
+
+        if self.font_family != old.font_family {
+            state.digits = 0;
+            state.gutter_lines = None;
+        }
+
+        if highlighter_changed || self.font_family != old.font_family {
+            self.highlight_all(state);
+            cx.layout();
+        }
+
+        if self.gutter_color != old.gutter_color
+            || self.gutter_active_color != old.gutter_active_color
+        {
+            state.gutter_lines = None;
+            cx.draw();
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        if cx.is_hot() {
+            cx.set_cursor(Some(Cursor::Text));
+        } else {
+            cx.set_cursor(None);
+        }
+
+        match event {
+            Event::KeyPressed(e) => {
+                if !cx.is_focused() {
+                    return;
+                }
+
+                // tab indents, instead of moving the focus
+                if e.is_key(Key::Tab) {
+                    cx.set_handled();
+                }
+
+                let edited = self.key_pressed(cx.fonts(), state, e);
+
+                if matches!(edited, Edited::None) {
+                    return;
+                }
+
+                state.blink = 0.0;
+                state.follow_cursor = true;
+
+                if matches!(edited, Edited::Moved) {
+                    cx.draw();
+                    return;
+                }
+
+                cx.layout();
+
+                if let Some(ref mut on_input) = self.on_input {
+                    state.edited = true;
+                    on_input(cx, data, state.text());
+                }
+            }
+            Event::PointerPressed(e) => {
+                if !cx.is_hot() {
+                    if cx.is_focused() {
+                        (state.editor).action(&mut cx.fonts().font_system, Action::Escape);
+                        cx.set_focused(false);
+                        cx.draw();
+                    }
+
+                    return;
+                }
+
+                cx.set_focused(true);
+                cx.animate();
+
+                state.blink = 0.0;
+                state.dragging = true;
+                state.follow_cursor = true;
+
+                let local = cx.local(e.position);
+                let x = (local.x - self.text_x(state)) as i32;
+                let y = local.y as i32;
+
+                if e.modifiers.shift {
+                    if state.editor.selection() == Selection::None {
+                        let cursor = state.editor.cursor();
+                        state.editor.set_selection(Selection::Normal(cursor));
+                    }
+
+                    (state.editor).action(&mut cx.fonts().font_system, Action::Drag { x, y });
+                } else {
+                    (state.editor).action(&mut cx.fonts().font_system, Action::Click { x, y });
+                }
+
+                cx.draw();
+            }
+            Event::PointerReleased(_) => {
+                state.dragging = false;
+            }
+            Event::PointerMoved(e) if state.dragging => {
+                let local = cx.local(e.position);
+                let x = (local.x - self.text_x(state)) as i32;
+                let y = local.y as i32;
+
+                (state.editor).action(&mut cx.fonts().font_system, Action::Drag { x, y });
+                state.follow_cursor = true;
+
+                cx.draw();
+            }
+            Event::PointerScrolled(e) => {
+                if !cx.is_hot() {
+                    return;
+                }
+
+                let delta = match e.modifiers.shift {
+                    true => Vector::new(e.delta.y, 0.0),
+                    false => e.delta,
+                };
+
+                // scroll three lines at a time
+                let distance = self.font_size * self.line_height * 3.0;
+
+                let buffer = state.buffer_mut();
+                let mut scroll = buffer.scroll();
+                scroll.vertical -= delta.y * distance;
+                buffer.set_scroll(scroll);
+
+                let width = buffer
+                    .layout_runs()
+                    .map(|run| run.line_w)
+                    .fold(0.0, f32::max);
+                state.scroll_x = (state.scroll_x - delta.x * distance).clamp(0.0, width);
+
+                cx.draw();
+            }
+            Event::Animate(dt) if cx.is_focused() => {
+                cx.animate();
+                cx.draw();
+
+                state.blink += *dt * 10.0;
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        let lines = state.buffer().lines.len();
+        let digits = usize::max(lines.to_string().len(), 2);
+
+        if digits != state.digits {
+            let mut buffer = TextBuffer::new(cx.fonts(), self.font_size, self.line_height);
+            buffer.set_text(cx.fonts(), &"0".repeat(digits), self.attributes());
+
+            state.digits = digits;
+            state.digits_width = buffer.size().width;
+            state.gutter_lines = None;
+        }
+
+        // every line has the same height, since lines don't wrap
+        let content_height = lines as f32 * self.font_size * self.line_height;
+        let height = f32::min(content_height, space.max.height);
+
+        let font_system = &mut cx.fonts().font_system;
+        state.buffer_mut().set_size(font_system, None, Some(height));
+        state.editor.shape_as_needed(font_system, true);
+
+        let runs = state.buffer().layout_runs();
+        let width = runs.map(|run| run.line_w).fold(0.0, f32::max);
+        let width = self.gutter_width(state) + self.gutter_padding * 2.0 + width;
+
+        let size = space.fit(Size::new(width, content_height));
+
+        if size.height != height {
+            let font_system = &mut cx.fonts().font_system;
+            state
+                .buffer_mut()
+                .set_size(font_system, None, Some(size.height));
+            state.editor.shape_as_needed(font_system, true);
+        }
+
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        cx.hoverable(|cx| {
+            cx.trigger(cx.rect());
+
+            (state.editor).shape_as_needed(&mut cx.fonts().font_system, true);
+
+            let rect = cx.rect();
+            let line_height = self.font_size * self.line_height;
+            let gutter_width = self.gutter_width(state);
+            let cursor = state.editor.cursor();
+
+            // keep the cursor in view horizontally
+            if state.follow_cursor {
+                state.follow_cursor = false;
+
+                let run = state
+                    .buffer()
+                    .layout_runs()
+                    .find(|run| run.line_i == cursor.line);
+
+                if let Some(run) = run {
+                    let x = index_x(&run, cursor.index);
+                    let visible = rect.width() - gutter_width - self.gutter_padding * 2.0;
+
+                    if x < state.scroll_x {
+                        state.scroll_x = x;
+                    } else if x > state.scroll_x + visible {
+                        state.scroll_x = x - visible;
+                    }
+                }
+            }
+
+            cx.fill_rect(rect, self.background);
+
+            let gutter = Rect::min_size(rect.min, Size::new(gutter_width, rect.height()));
+            cx.fill_rect(gutter, self.gutter_background);
+
+            let text_rect = Rect::new(Point::new(rect.min.x + gutter_width, rect.min.y), rect.max);
+            let text_x = rect.min.x + self.text_x(state);
+
+            let brackets = match cx.is_focused() {
+                true => matching_brackets(state.buffer(), cursor),
+                false => None,
+            };
+
+            cx.mask(text_rect, |cx| {
+                for run in state.buffer().layout_runs() {
+                    let top = rect.min.y + run.line_top;
+
+                    if run.line_i == cursor.line {
+                        let min = Point::new(text_rect.min.x, top);
+                        let size = Size::new(text_rect.width(), line_height);
+                        cx.fill_rect(Rect::min_size(min, size), self.current_line_color);
+                    }
+
+                    if let Some((start, end)) = state.editor.selection_bounds() {
+                        if let Some((x, width)) = run.highlight(start, end) {
+                            // show the selection of empty lines
+                            let width = f32::max(width, self.font_size * 0.25);
+
+                            let min = Point::new(text_x + x, top);
+                            let size = Size::new(width, line_height);
+                            cx.fill_rect(Rect::min_size(min, size), self.selection_color);
+                        }
+                    }
+
+                    for &(line, index) in brackets.iter().flatten() {
+                        if line != run.line_i {
+                            continue;
+                        }
+
+                        if let Some(glyph) = run.glyphs.iter().find(|g| g.start == index) {
+                            let min = Point::new(text_x + glyph.x, top);
+                            let size = Size::new(glyph.w, line_height);
+                            cx.fill_rect(Rect::min_size(min, size), self.bracket_color);
+                        }
+                    }
+                }
+
+                cx.text_raw(state.buffer(), self.color, Vector::new(text_x, rect.min.y));
+
+                if !cx.is_focused() {
+                    return;
+                }
+
+                let run = state
+                    .buffer()
+                    .layout_runs()
+                    .find(|run| run.line_i == cursor.line);
+
+                if let Some(run) = run {
+                    let min = Point::new(
+                        text_x + index_x(&run, cursor.index),
+                        rect.min.y + run.line_top,
+                    );
+                    let cursor = Rect::min_size(min.round(), Size::new(1.0, line_height));
+
+                    let blink = state.blink.cos() * 0.5 + 0.5;
+                    cx.fill_rect(cursor, self.cursor_color.fade(blink));
+                }
+            });
+
+            /* draw the line numbers */
+            let mut runs = state.buffer().layout_runs();
+            let first = runs.next().map(|run| (run.line_i, run.line_top));
+            let last = runs
+                .last()
+                .map_or(first.map(|(line, _)| line), |run| Some(run.line_i));
+
+            if let (Some((first, top)), Some(last)) = (first, last) {
+                let lines = [first, last, cursor.line];
+
+                if state.gutter_lines != Some(lines) {
+                    state.gutter_lines = Some(lines);
+                    self.set_gutter(cx.fonts(), state, first, last, cursor.line);
+                }
+
+                let offset = Vector::new(rect.min.x + self.gutter_padding, rect.min.y + top);
+                cx.text(&state.gutter, self.gutter_color, offset);
+            }
+        });
+    }
+}
+
+impl<T> CodeEditor<T> {
+    fn set_gutter(
+        &self,
+        fonts: &mut Fonts,
+        state: &mut CodeEditorState,
+        first: usize,
+        last: usize,
+        active: usize,
+    ) {
+        let mut text = String::new();
+        let mut active_range = 0..0;
+
+        for line in first..=last {
+            if line > first {
+                text.push('\n');
+            }
+
+            let start = text.len();
+            let _ = write!(text, "{}", line + 1);
+
+            if line == active {
+                active_range = start..text.len();
+            }
+        }
+
+        let attributes = self.attributes();
+
+        let [r, g, b, a] = self.gutter_active_color.to_rgba8();
+        let active_attrs =
+            (attributes.to_cosmic_text()).color(cosmic_text::Color::rgba(r, g, b, a));
+
+        let spans = [
+            (&text[..active_range.start], attributes.to_cosmic_text()),
+            (&text[active_range.clone()], active_attrs),
+            (&text[active_range.end..], attributes.to_cosmic_text()),
+        ];
+
+        state.gutter.set_rich_text(fonts, spans, attributes.clone());
+        state.gutter.set_align(TextAlign::End);
+
+        let bounds = Size::new(state.digits_width, f32::INFINITY);
+        state.gutter.set_bounds(fonts, bounds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmic_text::Edit;
+
+    use crate::{
+        event::{Event, Key, KeyPressed, Modifiers},
+        layout::{Size, Space},
+        views::testing::ViewTester,
+    };
+
+    use super::{code_editor, CodeEditor, Language};
+
+    fn key(key: Key, text: Option<&str>, shift: bool) -> Event {
+        Event::KeyPressed(KeyPressed {
+            key,
+            code: None,
+            text: text.map(String::from),
+            modifiers: Modifiers {
+                shift,
+                ..Default::default()
+            },
+        })
+    }
+
+    fn editor(text: &str) -> CodeEditor<String> {
+        code_editor()
+            .text(text)
+            .language(Language::Rust)
+            .on_input(|_, data: &mut String, text| *data = text)
+    }
+
+    #[test]
+    fn rebuild_with_bound_text() {
+        let mut data = String::from("let a = 1;");
+        let mut view = editor(&data);
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        tester.view_state.set_focused(true);
+
+        tester.event(&mut view, &mut data, &key(Key::End, None, false));
+        tester.event(&mut view, &mut data, &key(Key::Enter, Some("\r"), false));
+        assert!(tester.state.edited);
+
+        // the text produced by the editor isn't compared again
+        let old = view;
+        let mut view = editor(&data);
+        tester.rebuild(&mut view, &mut data, &old);
+        assert!(!tester.state.edited);
+        assert_eq!(tester.state.editor.cursor().line, 1);
+
+        // but text changed by something else replaces it
+        let old = view;
+        let mut view = editor("let b = 2;");
+        tester.rebuild(&mut view, &mut data, &old);
+        assert_eq!(tester.state.text(), "let b = 2;");
+    }
+
+    #[test]
+    fn indent_between_brackets() {
+        let mut data = String::new();
+        let mut view = editor("fn main() {}");
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(
+            &mut view,
+            &mut data,
+            Space::new(Size::ZERO, Size::all(400.0)),
+        );
+        tester.view_state.set_focused(true);
+
+        // move the cursor between the brackets
+        tester.event(&mut view, &mut data, &key(Key::End, None, false));
+        tester.event(&mut view, &mut data, &key(Key::Left, None, false));
+
+        tester.event(&mut view, &mut data, &key(Key::Enter, Some("\r"), false));
+        assert_eq!(data, "fn main() {\n    \n}");
+
+        tester.event(&mut view, &mut data, &key(Key::Tab, Some("\t"), false));
+        assert_eq!(data, "fn main() {\n        \n}");
+
+        tester.event(&mut view, &mut data, &key(Key::Backspace, None, false));
+        tester.event(&mut view, &mut data, &key(Key::Tab, None, true));
+        assert_eq!(data, "fn main() {\n\n}");
+
+        tester.draw(&mut view, &mut data);
+    }
+
+    #[test]
+    fn highlight_only_changed_lines() {
+        let mut data = String::new();
+        let mut view = editor("let a = 1;\nlet b = 2;\nlet c = 3;");
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(
+            &mut view,
+            &mut data,
+            Space::new(Size::ZERO, Size::all(400.0)),
+        );
+        tester.view_state.set_focused(true);
+        assert_eq!(tester.state.line_states, [0, 0, 0]);
+
+        // opening a block comment changes the state of every line after it
+        tester.event(
+            &mut view,
+            &mut data,
+            &key(Key::Character('/'), Some("/"), false),
+        );
+        tester.event(
+            &mut view,
+            &mut data,
+            &key(Key::Character('*'), Some("*"), false),
+        );
+        assert_eq!(tester.state.line_states, [257, 257, 257]);
+
+        // closing it on the next line only changes the lines up to the next unchanged state
+        tester.event(&mut view, &mut data, &key(Key::Down, None, false));
+        tester.event(&mut view, &mut data, &key(Key::Home, None, false));
+        tester.event(
+            &mut view,
+            &mut data,
+            &key(Key::Character('*'), Some("*"), false),
+        );
+        tester.event(
+            &mut view,
+            &mut data,
+            &key(Key::Character('/'), Some("/"), false),
+        );
+        assert_eq!(data, "/*let a = 1;\n*/let b = 2;\nlet c = 3;");
+        assert_eq!(tester.state.line_states, [257, 0, 0]);
+    }
+}
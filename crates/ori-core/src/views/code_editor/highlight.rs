@@ -0,0 +1,573 @@
+use std::{any::Any, ops::Range};
+
+use crate::{
+    canvas::Color,
+    style::{style, Style, Styles},
+    text::{FontStyle, FontWeight},
+};
+
+/// A highlighted range of a line, see [`Highlighter`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Highlight {
+    /// The byte range of the line that is highlighted.
+    pub range: Range<usize>,
+
+    /// The color of the text.
+    pub color: Color,
+
+    /// The font weight of the text, if it differs from the rest of the text.
+    pub font_weight: Option<FontWeight>,
+
+    /// The font style of the text, if it differs from the rest of the text.
+    pub font_style: Option<FontStyle>,
+}
+
+impl Highlight {
+    /// Create a new highlight.
+    pub fn new(range: Range<usize>, color: Color) -> Self {
+        Self {
+            range,
+            color,
+            font_weight: None,
+            font_style: None,
+        }
+    }
+}
+
+/// A syntax highlighter for a [`CodeEditor`](super::CodeEditor).
+///
+/// Lines are highlighted one at a time, starting at the top of the text. The `state` carries
+/// what the highlighter needs to know about the lines before, like being inside a block
+/// comment, and is `0` before the first line. When a line is edited, the lines after it are
+/// only highlighted again until their state is the same as before the edit.
+pub trait Highlighter: PartialEq + 'static {
+    /// Highlight a `line`, pushing the highlighted ranges to `highlights`.
+    fn highlight(&self, line: &str, state: &mut u32, highlights: &mut Vec<Highlight>);
+}
+
+/// A [`Highlighter`] that supports dynamic dispatch.
+pub trait AnyHighlighter {
+    /// Get a reference to the underlying [`Any`] object.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Highlight a line, see [`Highlighter::highlight`].
+    fn dyn_highlight(&self, line: &str, state: &mut u32, highlights: &mut Vec<Highlight>);
+
+    /// Check whether two highlighters are equal.
+    fn dyn_eq(&self, other: &dyn AnyHighlighter) -> bool;
+}
+
+impl<H: Highlighter> AnyHighlighter for H {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_highlight(&self, line: &str, state: &mut u32, highlights: &mut Vec<Highlight>) {
+        self.highlight(line, state, highlights);
+    }
+
+    fn dyn_eq(&self, other: &dyn AnyHighlighter) -> bool {
+        other.as_any().downcast_ref::<H>() == Some(self)
+    }
+}
+
+/// A language with a built-in [`SyntaxHighlighter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// The Rust programming language.
+    Rust,
+
+    /// JSON.
+    Json,
+}
+
+/// The style of a [`SyntaxHighlighter`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyntaxStyle {
+    /// The color of keywords and lifetimes.
+    pub keyword_color: Color,
+
+    /// The color of types.
+    pub type_color: Color,
+
+    /// The color of functions and macros.
+    pub function_color: Color,
+
+    /// The color of strings and characters.
+    pub string_color: Color,
+
+    /// The color of numbers.
+    pub number_color: Color,
+
+    /// The color of constants, like `true`, `null` and `MAX`.
+    pub constant_color: Color,
+
+    /// The color of comments.
+    pub comment_color: Color,
+}
+
+impl Style for SyntaxStyle {
+    fn styled(style: &Styles) -> Self {
+        let palette = style.palette();
+
+        Self {
+            keyword_color: palette.primary,
+            type_color: palette.accent,
+            function_color: palette.info,
+            string_color: palette.success,
+            number_color: palette.warning,
+            constant_color: palette.warning,
+            comment_color: palette.contrast_low,
+        }
+    }
+}
+
+/// A [`Highlighter`] for one of the built-in [`Language`]s.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyntaxHighlighter {
+    /// The language that is highlighted.
+    pub language: Language,
+
+    /// The style of the highlighted text.
+    pub style: SyntaxStyle,
+}
+
+impl SyntaxHighlighter {
+    /// Create a new syntax highlighter.
+    pub fn new(language: Language) -> Self {
+        Self::styled(language, style())
+    }
+
+    /// Create a new syntax highlighter with a style.
+    pub fn styled(language: Language, style: SyntaxStyle) -> Self {
+        Self { language, style }
+    }
+
+    fn color(&self, token: Token) -> Color {
+        match token {
+            Token::Keyword => self.style.keyword_color,
+            Token::Type => self.style.type_color,
+            Token::Function => self.style.function_color,
+            Token::String => self.style.string_color,
+            Token::Number => self.style.number_color,
+            Token::Constant => self.style.constant_color,
+            Token::Comment => self.style.comment_color,
+        }
+    }
+}
+
+impl Highlighter for SyntaxHighlighter {
+    fn highlight(&self, line: &str, state: &mut u32, highlights: &mut Vec<Highlight>) {
+        let mut push = |range: Range<usize>, token| {
+            if !range.is_empty() {
+                highlights.push(Highlight::new(range, self.color(token)));
+            }
+        };
+
+        match self.language {
+            Language::Rust => rust(line, state, &mut push),
+            Language::Json => json(line, &mut push),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token {
+    Keyword,
+    Type,
+    Function,
+    String,
+    Number,
+    Constant,
+    Comment,
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "type", "union", "unsafe",
+    "use", "where", "while", "yield",
+];
+
+const RUST_PRIMITIVES: &[&str] = &[
+    "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+    "i128", "isize", "f32", "f64",
+];
+
+// the state of the rust lexer, the kind is stored in the low byte and its payload above it
+const RUST_CODE: u32 = 0;
+const RUST_BLOCK_COMMENT: u32 = 1; // payload is the depth of nesting
+const RUST_STRING: u32 = 2;
+const RUST_RAW_STRING: u32 = 3; // payload is the number of hashes
+
+fn rust(line: &str, state: &mut u32, push: &mut impl FnMut(Range<usize>, Token)) {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+
+        match *state & 0xff {
+            RUST_BLOCK_COMMENT => {
+                i = block_comment(bytes, i, state);
+                push(start..i, Token::Comment);
+                continue;
+            }
+            RUST_STRING => {
+                match end_of_string(bytes, i) {
+                    Some(end) => {
+                        i = end;
+                        *state = RUST_CODE;
+                    }
+                    None => i = bytes.len(),
+                }
+
+                push(start..i, Token::String);
+                continue;
+            }
+            RUST_RAW_STRING => {
+                let hashes = (*state >> 8) as usize;
+
+                match end_of_raw_string(bytes, i, hashes) {
+                    Some(end) => {
+                        i = end;
+                        *state = RUST_CODE;
+                    }
+                    None => i = bytes.len(),
+                }
+
+                push(start..i, Token::String);
+                continue;
+            }
+            _ => {}
+        }
+
+        let c = bytes[i];
+
+        if bytes[i..].starts_with(b"//") {
+            push(i..bytes.len(), Token::Comment);
+            return;
+        }
+
+        if bytes[i..].starts_with(b"/*") {
+            *state = RUST_BLOCK_COMMENT | 1 << 8;
+            i = block_comment(bytes, i + 2, state);
+            push(start..i, Token::Comment);
+            continue;
+        }
+
+        // raw strings, like r"..", r#".."# and br".."
+        let raw = match bytes[i..] {
+            [b'r', b'"' | b'#', ..] => Some(i + 1),
+            [b'b', b'r', b'"' | b'#', ..] => Some(i + 2),
+            _ => None,
+        };
+
+        if let Some(mut j) = raw.filter(|_| !is_ident_continue(previous(bytes, i))) {
+            let hashes = bytes[j..].iter().take_while(|&&c| c == b'#').count();
+            j += hashes;
+
+            if bytes.get(j) == Some(&b'"') {
+                *state = RUST_RAW_STRING | (hashes as u32) << 8;
+                i = j + 1;
+
+                match end_of_raw_string(bytes, i, hashes) {
+                    Some(end) => {
+                        i = end;
+                        *state = RUST_CODE;
+                    }
+                    None => i = bytes.len(),
+                }
+
+                push(start..i, Token::String);
+                continue;
+            }
+        }
+
+        if c == b'"' || (c == b'b' && bytes.get(i + 1) == Some(&b'"')) {
+            i += if c == b'b' { 2 } else { 1 };
+
+            match end_of_string(bytes, i) {
+                Some(end) => i = end,
+                None => {
+                    i = bytes.len();
+                    *state = RUST_STRING;
+                }
+            }
+
+            push(start..i, Token::String);
+            continue;
+        }
+
+        if c == b'\'' || (c == b'b' && bytes.get(i + 1) == Some(&b'\'')) {
+            let j = if c == b'b' { i + 2 } else { i + 1 };
+
+            // a character like 'a' or '\n', otherwise a lifetime like 'a
+            if let Some(end) = end_of_char(line, j) {
+                push(start..end, Token::String);
+                i = end;
+                continue;
+            }
+
+            if c == b'\'' {
+                let end = j + ident_len(&bytes[j..]);
+                push(start..end, Token::Keyword);
+                i = end.max(i + 1);
+                continue;
+            }
+        }
+
+        if c.is_ascii_digit() {
+            i += number_len(bytes, i);
+            push(start..i, Token::Number);
+            continue;
+        }
+
+        if is_ident_start(c) {
+            i += ident_len(&bytes[i..]);
+            let ident = &line[start..i];
+
+            let token = if RUST_KEYWORDS.contains(&ident) {
+                Some(Token::Keyword)
+            } else if ident == "true" || ident == "false" {
+                Some(Token::Constant)
+            } else if RUST_PRIMITIVES.contains(&ident) {
+                Some(Token::Type)
+            } else if is_constant(ident) {
+                Some(Token::Constant)
+            } else if ident.starts_with(|c: char| c.is_ascii_uppercase()) {
+                Some(Token::Type)
+            } else if matches!(bytes.get(i), Some(b'(' | b'!')) {
+                Some(Token::Function)
+            } else {
+                None
+            };
+
+            if let Some(token) = token {
+                push(start..i, token);
+            }
+
+            continue;
+        }
+
+        // skip the whole character, it might not be ascii
+        i += line[i..].chars().next().map_or(1, char::len_utf8);
+    }
+}
+
+fn json(line: &str, push: &mut impl FnMut(Range<usize>, Token)) {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+        let c = bytes[i];
+
+        if c == b'"' {
+            i = end_of_string(bytes, i + 1).unwrap_or(bytes.len());
+
+            // strings followed by a colon are keys
+            let rest = line[i..].trim_start();
+            let token = match rest.starts_with(':') {
+                true => Token::Keyword,
+                false => Token::String,
+            };
+
+            push(start..i, token);
+            continue;
+        }
+
+        if c == b'-' || c.is_ascii_digit() {
+            i += 1 + number_len(bytes, i + 1);
+            push(start..i, Token::Number);
+            continue;
+        }
+
+        if c.is_ascii_alphabetic() {
+            i += ident_len(&bytes[i..]);
+
+            if matches!(&line[start..i], "true" | "false" | "null") {
+                push(start..i, Token::Constant);
+            }
+
+            continue;
+        }
+
+        i += line[i..].chars().next().map_or(1, char::len_utf8);
+    }
+}
+
+// find the end of a block comment, updating the depth of nesting stored in `state`
+fn block_comment(bytes: &[u8], mut i: usize, state: &mut u32) -> usize {
+    let mut depth = *state >> 8;
+
+    while i < bytes.len() && depth > 0 {
+        if bytes[i..].starts_with(b"/*") {
+            depth += 1;
+            i += 2;
+        } else if bytes[i..].starts_with(b"*/") {
+            depth -= 1;
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+
+    *state = match depth {
+        0 => RUST_CODE,
+        _ => RUST_BLOCK_COMMENT | depth << 8,
+    };
+
+    i
+}
+
+// find the end of a string starting at `i`, just after the opening quote
+fn end_of_string(bytes: &[u8], mut i: usize) -> Option<usize> {
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+
+    None
+}
+
+fn end_of_raw_string(bytes: &[u8], mut i: usize, hashes: usize) -> Option<usize> {
+    while i < bytes.len() {
+        let closes = bytes[i] == b'"'
+            && bytes.len() >= i + 1 + hashes
+            && bytes[i + 1..i + 1 + hashes].iter().all(|&c| c == b'#');
+
+        if closes {
+            return Some(i + 1 + hashes);
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+// find the end of a character literal starting at `i`, just after the opening quote
+fn end_of_char(line: &str, i: usize) -> Option<usize> {
+    let rest = line.get(i..)?;
+    let mut chars = rest.char_indices();
+
+    let len = match chars.next()? {
+        (_, '\\') => {
+            chars.next()?;
+            let (j, _) = chars.find(|&(_, c)| c == '\'')?;
+            j
+        }
+        (_, '\'') => return None,
+        (_, c) => c.len_utf8(),
+    };
+
+    match rest[len..].starts_with('\'') {
+        true => Some(i + len + 1),
+        false => None,
+    }
+}
+
+fn number_len(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+
+        // a decimal point, but not a range like `0..10` or a method like `1.max(2)`
+        let point = c == b'.' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+
+        // an exponent, like `1e-3`
+        let sign = matches!(c, b'+' | b'-') && matches!(previous(bytes, i), b'e' | b'E');
+
+        if c.is_ascii_alphanumeric() || c == b'_' || point || sign {
+            i += 1;
+        } else {
+            break;
+        }
+    }
+
+    i - start
+}
+
+fn ident_len(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|&&c| is_ident_continue(c)).count()
+}
+
+fn is_ident_start(c: u8) -> bool {
+    c.is_ascii_alphabetic() || c == b'_'
+}
+
+fn is_ident_continue(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+fn is_constant(ident: &str) -> bool {
+    ident.len() > 1
+        && ident.starts_with(|c: char| c.is_ascii_uppercase())
+        && ident
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn previous(bytes: &[u8], i: usize) -> u8 {
+    match i {
+        0 => 0,
+        _ => bytes[i - 1],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rust, Token};
+
+    fn tokens<'a>(lines: &[&'a str]) -> Vec<(&'a str, Token)> {
+        let mut state = 0;
+        let mut tokens = Vec::new();
+
+        for line in lines {
+            rust(line, &mut state, &mut |range, token| {
+                tokens.push((&line[range], token));
+            });
+        }
+
+        tokens
+    }
+
+    #[test]
+    fn rust_tokens() {
+        let tokens = tokens(&[
+            "fn main(x: &'a str) -> Option<u8> { /* a /* nested */",
+            "comment */ let c = '\\n'; MAX.max(0..10); println!(r#\"raw",
+            "string\"#, 1.5e-3) // done",
+        ]);
+
+        assert_eq!(
+            tokens,
+            [
+                ("fn", Token::Keyword),
+                ("main", Token::Function),
+                ("'a", Token::Keyword),
+                ("str", Token::Type),
+                ("Option", Token::Type),
+                ("u8", Token::Type),
+                ("/* a /* nested */", Token::Comment),
+                ("comment */", Token::Comment),
+                ("let", Token::Keyword),
+                ("'\\n'", Token::String),
+                ("MAX", Token::Constant),
+                ("max", Token::Function),
+                ("0", Token::Number),
+                ("10", Token::Number),
+                ("println", Token::Function),
+                ("r#\"raw", Token::String),
+                ("string\"#", Token::String),
+                ("1.5e-3", Token::Number),
+                ("// done", Token::Comment),
+            ]
+        );
+    }
+}
@@ -268,7 +268,7 @@ impl<T, H: View<T>, V: View<T>> View<T> for Collapsing<T, H, V> {
     }
 }
 
-fn icon() -> Curve {
+pub(super) fn icon() -> Curve {
     let mut curve = Curve::new();
 
     let d = 0.25;
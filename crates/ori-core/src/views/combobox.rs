@@ -0,0 +1,601 @@
+use std::fmt::Display;
+
+use ori_macro::Build;
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, KeyPressed},
+    layout::{Padding, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    text::{FontFamily, Fonts},
+    view::{Pod, State, View},
+    window::Cursor,
+};
+
+use super::{
+    dropdown::{draw_arrow, ListStyle, PopupList},
+    TextInput, TextStyle,
+};
+
+/// Create a new [`Combobox`].
+pub fn combobox<T, I>(items: impl IntoIterator<Item = I>) -> Combobox<T, I> {
+    Combobox::new(items)
+}
+
+/// The style of a combobox.
+#[derive(Clone, Debug)]
+pub struct ComboboxStyle {
+    /// The font size of the items in the list.
+    pub font_size: f32,
+
+    /// The font family of the items in the list.
+    pub font_family: FontFamily,
+
+    /// The color of the items in the list.
+    pub color: Color,
+
+    /// The color of the arrow.
+    pub icon_color: Color,
+
+    /// The padding of the combobox and of each item in the list.
+    pub padding: Padding,
+
+    /// The background color.
+    pub background: Color,
+
+    /// The border radius.
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    pub border_color: Color,
+
+    /// The border color when focused.
+    pub focus_color: Color,
+
+    /// The background color of the list.
+    pub list_background: Color,
+
+    /// The background color of the highlighted item in the list.
+    pub highlight: Color,
+
+    /// The maximum height of the list.
+    pub max_height: f32,
+}
+
+impl Style for ComboboxStyle {
+    fn styled(style: &Styles) -> Self {
+        let text_style = style.get::<TextStyle>();
+        let palette = style.palette();
+
+        Self {
+            font_size: 14.0,
+            font_family: text_style.font_family.clone(),
+            color: palette.contrast,
+            icon_color: palette.contrast_low,
+            padding: Padding::from([8.0, 6.0]),
+            background: palette.surface_higher,
+            border_radius: BorderRadius::all(4.0),
+            border_width: BorderWidth::all(1.0),
+            border_color: palette.outline,
+            focus_color: palette.primary,
+            list_background: palette.surface_high,
+            highlight: palette.primary.fade(0.4),
+            max_height: 240.0,
+        }
+    }
+}
+
+/// A [`TextInput`] with a list of items, filtered by the text typed.
+///
+/// While typing, the list shows the items matching the text, see [`Combobox::filter`]. The
+/// arrow keys move through the list, `Enter` chooses the highlighted item, replacing the text
+/// with it, and `Escape` closes the list.
+///
+/// Items are displayed with their [`Display`] implementation.
+///
+/// Can be styled using the [`ComboboxStyle`].
+#[derive(Build, Rebuild)]
+pub struct Combobox<T, I> {
+    /// The items.
+    #[build(ignore)]
+    pub items: Vec<I>,
+
+    /// The text input.
+    #[build(ignore)]
+    pub input: Pod<TextInput<T>>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    filter: Option<Box<dyn Fn(&I, &str) -> bool>>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_select: Option<Box<dyn FnMut(&mut EventCx, &mut T, &I)>>,
+
+    /// The font size of the items in the list.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the items in the list.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The color of the items in the list.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of the arrow.
+    #[rebuild(draw)]
+    pub icon_color: Color,
+
+    /// The padding of the combobox and of each item in the list.
+    #[rebuild(layout)]
+    pub padding: Padding,
+
+    /// The background color.
+    #[rebuild(draw)]
+    pub background: Color,
+
+    /// The border radius.
+    #[rebuild(draw)]
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    #[rebuild(draw)]
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    #[rebuild(draw)]
+    pub border_color: Color,
+
+    /// The border color when focused.
+    #[rebuild(draw)]
+    pub focus_color: Color,
+
+    /// The background color of the list.
+    #[rebuild(draw)]
+    pub list_background: Color,
+
+    /// The background color of the highlighted item in the list.
+    #[rebuild(draw)]
+    pub highlight: Color,
+
+    /// The maximum height of the list.
+    #[rebuild(draw)]
+    pub max_height: f32,
+}
+
+impl<T, I> Combobox<T, I> {
+    /// Create a new [`Combobox`].
+    pub fn new(items: impl IntoIterator<Item = I>) -> Self {
+        Self::styled(items, style())
+    }
+
+    /// Create a new [`Combobox`] with a style.
+    pub fn styled(items: impl IntoIterator<Item = I>, style: ComboboxStyle) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+            input: Pod::new(TextInput::new()),
+            filter: None,
+            on_select: None,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            color: style.color,
+            icon_color: style.icon_color,
+            padding: style.padding,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+            focus_color: style.focus_color,
+            list_background: style.list_background,
+            highlight: style.highlight,
+            max_height: style.max_height,
+        }
+    }
+
+    /// Set the text of the input.
+    pub fn text(mut self, text: impl AsRef<str>) -> Self {
+        self.input.text = Some(text.as_ref().to_string());
+        self
+    }
+
+    /// Set the placeholder text of the input.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.input.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the callback that is called when the text changes, see [`TextInput::on_input`].
+    ///
+    /// This is also called when an item is chosen.
+    pub fn on_input(
+        mut self,
+        on_input: impl FnMut(&mut EventCx, &mut T, String) + 'static,
+    ) -> Self {
+        self.input.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Set the function deciding whether an item matches the text typed.
+    ///
+    /// By default items match when their text contains the typed text, ignoring case.
+    pub fn filter(mut self, filter: impl Fn(&I, &str) -> bool + 'static) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Set the callback that is called when an item is chosen.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_select(mut self, on_select: impl FnMut(&mut EventCx, &mut T, &I) + 'static) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    fn list_style(&self) -> ListStyle {
+        ListStyle {
+            font_size: self.font_size,
+            font_family: self.font_family.clone(),
+            color: self.color,
+            padding: self.padding,
+            background: self.list_background,
+            highlight: self.highlight,
+            border_radius: self.border_radius,
+            border_color: self.border_color,
+            max_height: self.max_height,
+        }
+    }
+
+    fn arrow_width(&self) -> f32 {
+        self.font_size * 1.2
+    }
+}
+
+#[doc(hidden)]
+pub struct ComboboxState<T> {
+    input: State<T, TextInput<T>>,
+    labels: Vec<String>,
+    filtered: Vec<usize>,
+    list: PopupList,
+}
+
+impl<T, I: Display> Combobox<T, I> {
+    fn labels(&self) -> Vec<String> {
+        self.items.iter().map(ToString::to_string).collect()
+    }
+
+    // filter the items by the text of the input
+    fn update_filter(&self, fonts: &mut Fonts, state: &mut ComboboxState<T>) {
+        let text = state.input.content().text();
+        let lowercase = text.to_lowercase();
+
+        state.filtered.clear();
+
+        for (i, item) in self.items.iter().enumerate() {
+            let matches = match self.filter {
+                Some(ref filter) => filter(item, &text),
+                None => state.labels[i].to_lowercase().contains(&lowercase),
+            };
+
+            if matches {
+                state.filtered.push(i);
+            }
+        }
+
+        let labels = state.filtered.iter().map(|&i| &state.labels[i]);
+        state.list.set_items(fonts, &self.list_style(), labels);
+    }
+
+    fn choose(
+        &mut self,
+        state: &mut ComboboxState<T>,
+        cx: &mut EventCx,
+        data: &mut T,
+        index: usize,
+    ) {
+        let item = state.filtered[index];
+        let label = state.labels[item].clone();
+
+        (self.input).set_text(cx.fonts(), state.input.content_mut(), &label);
+        state.input.request_layout();
+        state.list.open = false;
+        cx.layout();
+
+        if let Some(ref mut on_input) = self.input.on_input {
+            on_input(cx, data, label);
+        }
+
+        if let Some(ref mut on_select) = self.on_select {
+            on_select(cx, data, &self.items[item]);
+        }
+
+        self.update_filter(cx.fonts(), state);
+    }
+
+    // returns true if the key was handled by the list
+    fn key_pressed(
+        &mut self,
+        state: &mut ComboboxState<T>,
+        cx: &mut EventCx,
+        data: &mut T,
+        e: &KeyPressed,
+    ) -> bool {
+        let style = self.list_style();
+
+        if !state.list.open {
+            if e.is_key(Key::Down) {
+                self.update_filter(cx.fonts(), state);
+                state.list.show(&style, None);
+                cx.draw();
+                return true;
+            }
+
+            return false;
+        }
+
+        match e.key {
+            Key::Down => state.list.move_highlight(&style, 1),
+            Key::Up => state.list.move_highlight(&style, -1),
+            Key::Escape => {
+                state.list.open = false;
+                cx.set_handled();
+            }
+            Key::Enter => match state.list.highlighted {
+                Some(index) => self.choose(state, cx, data, index),
+                None => return false,
+            },
+            _ => return false,
+        }
+
+        cx.draw();
+        true
+    }
+}
+
+impl<T, I: Display> View<T> for Combobox<T, I> {
+    type State = ComboboxState<T>;
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let mut state = ComboboxState {
+            input: self.input.build(cx, data),
+            labels: self.labels(),
+            filtered: Vec::new(),
+            list: PopupList::new(),
+        };
+
+        self.update_filter(cx.fonts(), &mut state);
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        let text = state.input.content().text();
+        View::rebuild(&mut self.input, &mut state.input, cx, data, &old.input);
+
+        let labels = self.labels();
+
+        if labels != state.labels
+            || text != state.input.content().text()
+            || self.font_size != old.font_size
+            || self.font_family != old.font_family
+        {
+            state.labels = labels;
+            self.update_filter(cx.fonts(), state);
+            cx.layout();
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        let style = self.list_style();
+
+        match event {
+            Event::PointerPressed(e) => {
+                let local = cx.local(e.position);
+
+                if state.list.open {
+                    if let Some(index) = state.list.index_at(&style, cx.size(), local) {
+                        self.choose(state, cx, data, index);
+                        return;
+                    }
+                }
+
+                // a press on the frame or the arrow toggles the list and focuses the input
+                if cx.is_hot() && !state.input.is_hot() && cx.rect().contains(local) {
+                    if state.list.open {
+                        state.list.open = false;
+                    } else {
+                        self.update_filter(cx.fonts(), state);
+                        state.list.show(&style, None);
+                    }
+
+                    state.input.set_focused(true);
+                    state.input.request_animate();
+                    cx.animate();
+                    cx.draw();
+                    return;
+                }
+            }
+            Event::PointerMoved(e) => {
+                if state.list.open {
+                    let local = cx.local(e.position);
+
+                    if let Some(index) = state.list.index_at(&style, cx.size(), local) {
+                        if state.list.highlighted != Some(index) {
+                            state.list.highlighted = Some(index);
+                            cx.draw();
+                        }
+                    }
+                }
+
+                if cx.is_hot() && !state.input.is_hot() {
+                    cx.set_cursor(Some(Cursor::Pointer));
+                } else {
+                    cx.set_cursor(None);
+                }
+            }
+            Event::PointerScrolled(e) => {
+                let local = cx.local(e.position);
+
+                if state.list.open && state.list.index_at(&style, cx.size(), local).is_some() {
+                    state
+                        .list
+                        .scroll_by(&style, -e.delta.y * style.row_height());
+                    cx.draw();
+                    return;
+                }
+            }
+            Event::KeyPressed(e)
+                if state.input.is_focused() && self.key_pressed(state, cx, data, e) =>
+            {
+                return;
+            }
+            _ => {}
+        }
+
+        let text = state.input.content().text();
+        let focused = state.input.is_focused();
+
+        self.input.event(&mut state.input, cx, data, event);
+
+        if text != state.input.content().text() {
+            self.update_filter(cx.fonts(), state);
+
+            if state.input.is_focused() && state.list.len() > 0 {
+                state.list.show(&style, Some(0));
+            } else {
+                state.list.open = false;
+            }
+
+            cx.draw();
+        }
+
+        if !state.input.is_focused() && state.list.open {
+            state.list.open = false;
+            cx.draw();
+        }
+
+        if focused != state.input.is_focused() {
+            cx.draw();
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        // the input is at least as wide as the widest item, if there is room for it
+        let mut widest: f32 = 0.0;
+
+        for buffer in &state.list.buffers {
+            widest = widest.max(buffer.size().width);
+        }
+
+        let chrome = self.padding.size() + Size::new(self.arrow_width(), 0.0);
+        let inner = space.shrink(chrome);
+
+        let min_width = f32::max(inner.min.width, widest.min(inner.max.width));
+        let input_space = Space::new(Size::new(min_width, inner.min.height), inner.max);
+        let input_size = self.input.layout(&mut state.input, cx, data, input_space);
+
+        let size = space.fit(input_size + chrome);
+
+        let offset = match cx.direction().is_rtl() {
+            true => Vector::new(self.padding.right + self.arrow_width(), self.padding.top),
+            false => self.padding.offset(),
+        };
+
+        state.input.translate(offset);
+        cx.set_baseline(state.input.parent_baseline());
+
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        let border_color = match state.input.is_focused() {
+            true => self.focus_color,
+            false => self.border_color,
+        };
+
+        cx.hoverable(|cx| {
+            cx.quad(
+                cx.rect(),
+                self.background,
+                self.border_radius,
+                self.border_width,
+                border_color,
+            );
+        });
+
+        self.input.draw(&mut state.input, cx, data);
+
+        let arrow = self.arrow_width();
+        let x = match cx.direction().is_rtl() {
+            true => self.padding.left,
+            false => cx.size().width - self.padding.right - arrow,
+        };
+
+        let rect = Rect::min_size(Point::new(x, self.padding.top), Size::all(arrow));
+        draw_arrow(cx, rect, state.list.open, self.icon_color);
+
+        if state.list.open {
+            let style = self.list_style();
+            state.list.draw(cx, &style, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Key, KeyPressed, Modifiers},
+        layout::Space,
+        views::testing::ViewTester,
+    };
+
+    use super::combobox;
+
+    fn key(key: Key, text: Option<&str>) -> Event {
+        Event::KeyPressed(KeyPressed {
+            key,
+            code: None,
+            text: text.map(String::from),
+            modifiers: Modifiers::default(),
+        })
+    }
+
+    #[test]
+    fn filter_and_choose() {
+        let mut chosen = None::<&str>;
+
+        let mut view = combobox(["apple", "banana", "apricot"])
+            .on_select(|_, chosen: &mut Option<&str>, item| *chosen = Some(*item));
+
+        let mut tester = ViewTester::new(&mut view, &mut chosen);
+        tester.layout(&mut view, &mut chosen, Space::UNBOUNDED);
+
+        tester.state.input.set_focused(true);
+        tester.event(&mut view, &mut chosen, &key(Key::Character('a'), Some("a")));
+        tester.event(&mut view, &mut chosen, &key(Key::Character('p'), Some("p")));
+
+        assert_eq!(tester.state.filtered, [0, 2]);
+        assert!(tester.state.list.open);
+
+        tester.event(&mut view, &mut chosen, &key(Key::Down, None));
+        tester.event(&mut view, &mut chosen, &key(Key::Enter, None));
+
+        assert_eq!(chosen, Some("apricot"));
+        assert_eq!(tester.state.input.content().text(), "apricot");
+        assert!(!tester.state.list.open);
+    }
+}
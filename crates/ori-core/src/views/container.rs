@@ -151,7 +151,9 @@ impl<T, V: View<T>> View<T> for Container<V> {
         data: &mut T,
         space: Space,
     ) -> Size {
-        self.content.layout(state, cx, data, space)
+        let size = self.content.layout(state, cx, data, space);
+        cx.set_baseline(state.parent_baseline());
+        size
     }
 
     fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
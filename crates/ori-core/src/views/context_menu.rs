@@ -0,0 +1,321 @@
+use ori_macro::Build;
+
+use crate::{
+    canvas::{BorderRadius, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, PointerButton},
+    layout::{Padding, Point, Size, Space},
+    rebuild::Rebuild,
+    style::style,
+    text::FontFamily,
+    view::{Pod, State, View},
+};
+
+use super::{handle_accelerator, handle_action, Menu, MenuAction, MenuPopup, MenuStyle};
+
+/// Create a new [`ContextMenu`], that shows `menu` when `content` is right-clicked.
+pub fn context_menu<V>(menu: Menu, content: V) -> ContextMenu<V> {
+    ContextMenu::new(menu, content)
+}
+
+/// A view that shows a menu when the content is right-clicked.
+///
+/// The menu also opens with `Shift+F10` while the content is focused, and the accelerators
+/// of the items are handled while the content is focused.
+///
+/// Can be styled using the [`MenuStyle`].
+#[derive(Build, Rebuild)]
+pub struct ContextMenu<V> {
+    /// The content.
+    #[build(ignore)]
+    pub content: Pod<V>,
+
+    /// The menu.
+    #[build(ignore)]
+    pub menu: Menu,
+
+    /// The font size of the labels.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the labels.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The color of the labels.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of disabled items.
+    #[rebuild(draw)]
+    pub disabled_color: Color,
+
+    /// The color of the accelerators.
+    #[rebuild(draw)]
+    pub accelerator_color: Color,
+
+    /// The padding of each item.
+    #[rebuild(draw)]
+    pub padding: Padding,
+
+    /// The minimum width of a popup menu.
+    #[rebuild(draw)]
+    pub min_width: f32,
+
+    /// The background color of a popup menu.
+    #[rebuild(draw)]
+    pub background: Color,
+
+    /// The background color of the highlighted item.
+    #[rebuild(draw)]
+    pub highlight: Color,
+
+    /// The color of the separators.
+    #[rebuild(draw)]
+    pub separator: Color,
+
+    /// The border radius of a popup menu.
+    #[rebuild(draw)]
+    pub border_radius: BorderRadius,
+
+    /// The border color of a popup menu.
+    #[rebuild(draw)]
+    pub border_color: Color,
+}
+
+impl<V> ContextMenu<V> {
+    /// Create a new [`ContextMenu`].
+    pub fn new(menu: Menu, content: V) -> Self {
+        Self::styled(menu, content, style())
+    }
+
+    /// Create a new [`ContextMenu`] with a style.
+    pub fn styled(menu: Menu, content: V, style: MenuStyle) -> Self {
+        Self {
+            content: Pod::new(content),
+            menu,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            color: style.color,
+            disabled_color: style.disabled_color,
+            accelerator_color: style.accelerator_color,
+            padding: style.padding,
+            min_width: style.min_width,
+            background: style.background,
+            highlight: style.highlight,
+            separator: style.separator,
+            border_radius: style.border_radius,
+            border_color: style.border_color,
+        }
+    }
+
+    fn menu_style(&self) -> MenuStyle {
+        MenuStyle {
+            font_size: self.font_size,
+            font_family: self.font_family.clone(),
+            color: self.color,
+            disabled_color: self.disabled_color,
+            accelerator_color: self.accelerator_color,
+            padding: self.padding,
+            min_width: self.min_width,
+            background: self.background,
+            highlight: self.highlight,
+            separator: self.separator,
+            border_radius: self.border_radius,
+            border_color: self.border_color,
+            // the menu bar isn't used by popup menus
+            bar_padding: Padding::all(0.0),
+            bar_background: Color::TRANSPARENT,
+        }
+    }
+
+    fn open(&self, cx: &mut EventCx, popup: &mut MenuPopup, position: Point) {
+        let style = self.menu_style();
+        popup.open(cx.fonts(), &style, &self.menu, position, false);
+
+        cx.set_active(true);
+        cx.draw();
+    }
+}
+
+#[doc(hidden)]
+pub struct ContextMenuState {
+    popup: MenuPopup,
+}
+
+impl<T, V: View<T>> View<T> for ContextMenu<V> {
+    type State = (ContextMenuState, State<T, V>);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let state = ContextMenuState {
+            popup: MenuPopup::new(),
+        };
+
+        (state, self.content.build(cx, data))
+    }
+
+    fn rebuild(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        Rebuild::rebuild(self, cx, old);
+
+        if self.menu != old.menu && state.popup.is_open() {
+            state.popup.close();
+            cx.set_active(false);
+            cx.draw();
+        }
+
+        (self.content).rebuild(content, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) {
+        self.content.event(content, cx, data, event);
+
+        let style = self.menu_style();
+
+        match event {
+            Event::PointerMoved(e) if state.popup.is_open() => {
+                let local = cx.local(e.position);
+
+                if state
+                    .popup
+                    .pointer_moved(cx.fonts(), &style, &self.menu, local)
+                {
+                    cx.draw();
+                }
+            }
+            Event::PointerPressed(e) => {
+                let local = cx.local(e.position);
+
+                if state.popup.is_open() {
+                    let action = match e.button {
+                        PointerButton::Primary => {
+                            (state.popup).press(cx.fonts(), &style, &self.menu, local)
+                        }
+                        _ if state.popup.contains(local) => MenuAction::Handled,
+                        _ => MenuAction::Close,
+                    };
+
+                    if handle_action(&mut state.popup, cx, &self.menu, &action) {
+                        cx.set_active(false);
+                    }
+                }
+
+                if e.button == PointerButton::Secondary && content.has_hot() {
+                    self.open(cx, &mut state.popup, local);
+                }
+            }
+            Event::KeyPressed(e) if state.popup.is_open() => {
+                let action = match state.popup.key(cx.fonts(), &style, &self.menu, e) {
+                    MenuAction::Previous | MenuAction::Next => MenuAction::Handled,
+                    action => action,
+                };
+
+                if handle_action(&mut state.popup, cx, &self.menu, &action) {
+                    cx.set_active(false);
+                }
+            }
+            Event::KeyPressed(e) if content.has_focused() || content.is_focused() => {
+                if e.key == Key::F10 && e.modifiers.shift {
+                    self.open(cx, &mut state.popup, Point::new(0.0, content.size().height));
+                    return;
+                }
+
+                handle_accelerator(cx, &self.menu, e);
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        (_, content): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        let size = self.content.layout(content, cx, data, space);
+        cx.set_baseline(content.parent_baseline());
+        size
+    }
+
+    fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.content.draw(content, cx, data);
+        state.popup.draw(cx, &self.menu_style());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Modifiers, PointerButton, PointerId, PointerPressed},
+        layout::{Point, Size, Space},
+        views::{size, testing::ViewTester, Menu, MenuItem},
+        window::{Pointer, Window},
+    };
+
+    use super::context_menu;
+
+    #[test]
+    fn open_and_activate() {
+        let menu = Menu::default()
+            .item(MenuItem::new("Cut").command("cut"))
+            .item(MenuItem::new("Copy").command("copy"));
+
+        let mut view = context_menu(menu, size(Size::all(100.0), ()));
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(&mut view, &mut (), Space::UNBOUNDED);
+
+        let press = |x, y, button| {
+            Event::PointerPressed(PointerPressed {
+                id: PointerId::from_hash(&0),
+                position: Point::new(x, y),
+                button,
+                modifiers: Modifiers::default(),
+            })
+        };
+
+        // the content isn't hot, so the menu doesn't open
+        tester.event(
+            &mut view,
+            &mut (),
+            &press(10.0, 10.0, PointerButton::Secondary),
+        );
+        assert!(!tester.state.0.popup.is_open());
+
+        // hover the content
+        let mut pointer = Pointer::new(PointerId::from_hash(&0), Point::new(10.0, 10.0));
+        pointer.hovering = Some(tester.state.1.id());
+
+        let window = tester.contexts.get_mut::<Window>().unwrap();
+        window.pointers_mut().push(pointer);
+
+        tester.event(
+            &mut view,
+            &mut (),
+            &press(10.0, 10.0, PointerButton::Secondary),
+        );
+        assert!(tester.state.0.popup.is_open());
+
+        // the middle of the second row, below the inset of the popup and the first row
+        let style = view.menu_style();
+        let row_height = style.font_size * 1.2 + style.padding.size().height;
+        let y = 10.0 + 4.0 + row_height * 1.5;
+
+        tester.event(&mut view, &mut (), &press(20.0, y, PointerButton::Primary));
+        assert!(!tester.state.0.popup.is_open());
+
+        let command = tester.command_rx.try_recv().unwrap();
+        assert_eq!(command.get::<&str>(), Some(&"copy"));
+    }
+}
@@ -0,0 +1,603 @@
+use std::{
+    fmt::{self, Display},
+    sync::OnceLock,
+};
+
+use chrono::{Datelike, Local, Timelike};
+
+use crate::locale::Locale;
+
+/// A day of the week.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    /// Monday.
+    Monday,
+
+    /// Tuesday.
+    Tuesday,
+
+    /// Wednesday.
+    Wednesday,
+
+    /// Thursday.
+    Thursday,
+
+    /// Friday.
+    Friday,
+
+    /// Saturday.
+    Saturday,
+
+    /// Sunday.
+    Sunday,
+}
+
+impl Weekday {
+    /// All the weekdays, starting with Monday.
+    pub const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// Get the weekday `index` days after Monday, wrapping around.
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index % 7]
+    }
+
+    /// Get the number of days since Monday.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Get the number of days from `start` to this weekday.
+    pub fn days_since(self, start: Weekday) -> usize {
+        (self.index() + 7 - start.index()) % 7
+    }
+}
+
+/// A date in the proleptic Gregorian calendar.
+///
+/// Dates are ordered chronologically.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Create a new [`Date`], returns `None` if the date doesn't exist.
+    ///
+    /// The `month` and `day` start at 1.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        let valid = (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month);
+        valid.then_some(Self { year, month, day })
+    }
+
+    /// Get the current date in the local time zone.
+    pub fn today() -> Self {
+        let today = Local::now().date_naive();
+
+        Self {
+            year: today.year(),
+            month: today.month() as u8,
+            day: today.day() as u8,
+        }
+    }
+
+    /// Get the year.
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Get the month, starting at 1.
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Get the day of the month, starting at 1.
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Get the day of the week.
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::from_index((self.days() + 3).rem_euclid(7) as usize)
+    }
+
+    /// Get the number of days in the month.
+    pub fn days_in_month(self) -> u8 {
+        days_in_month(self.year, self.month)
+    }
+
+    /// Get the first day of the month.
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    /// Add a number of days, which may be negative.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.days() + days)
+    }
+
+    /// Add a number of months, which may be negative.
+    ///
+    /// The day is clamped to the length of the new month.
+    pub fn add_months(self, months: i32) -> Self {
+        let month = self.year as i64 * 12 + self.month as i64 - 1 + months as i64;
+        let year = month.div_euclid(12) as i32;
+        let month = month.rem_euclid(12) as u8 + 1;
+
+        Self {
+            year,
+            month,
+            day: u8::min(self.day, days_in_month(year, month)),
+        }
+    }
+
+    /// Clamp the date between `min` and `max`.
+    pub fn clamp_to(self, min: Option<Date>, max: Option<Date>) -> Self {
+        let date = match min {
+            Some(min) if self < min => min,
+            _ => self,
+        };
+
+        match max {
+            Some(max) if date > max => max,
+            _ => date,
+        }
+    }
+
+    // the number of days since 1970-01-01
+    fn days(self) -> i64 {
+        let year = self.year as i64 - (self.month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+
+        let month = self.month as i64;
+        let month = if month > 2 { month - 3 } else { month + 9 };
+
+        let day_of_year = (153 * month + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146097 + day_of_era - 719468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let month = if month < 10 { month + 3 } else { month - 9 };
+        let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Get whether `year` is a leap year.
+pub fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// A time of day, with minute precision.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Time {
+    /// Midnight, `00:00`.
+    pub const MIDNIGHT: Self = Self { hour: 0, minute: 0 };
+
+    /// Create a new [`Time`], returns `None` if the time doesn't exist.
+    pub fn new(hour: u8, minute: u8) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    /// Get the current time in the local time zone.
+    pub fn now() -> Self {
+        let now = Local::now();
+
+        Self {
+            hour: now.hour() as u8,
+            minute: now.minute() as u8,
+        }
+    }
+
+    /// Get the hour, from 0 to 23.
+    pub fn hour(self) -> u8 {
+        self.hour
+    }
+
+    /// Get the minute, from 0 to 59.
+    pub fn minute(self) -> u8 {
+        self.minute
+    }
+
+    /// Add a number of minutes, which may be negative, wrapping around midnight.
+    pub fn add_minutes(self, minutes: i64) -> Self {
+        Self::from_minutes(self.hour as i64 * 60 + self.minute as i64 + minutes)
+    }
+
+    /// Clamp the time between `min` and `max`.
+    pub fn clamp_to(self, min: Option<Time>, max: Option<Time>) -> Self {
+        let time = match min {
+            Some(min) if self < min => min,
+            _ => self,
+        };
+
+        match max {
+            Some(max) if time > max => max,
+            _ => time,
+        }
+    }
+
+    fn from_minutes(minutes: i64) -> Self {
+        let minutes = minutes.rem_euclid(24 * 60);
+
+        Self {
+            hour: (minutes / 60) as u8,
+            minute: (minutes % 60) as u8,
+        }
+    }
+}
+
+impl Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// How dates and times are written, which depends on the locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateLocale {
+    /// The names of the months, starting with January.
+    pub months: [&'static str; 12],
+
+    /// The short names of the weekdays, starting with Monday.
+    pub weekdays: [&'static str; 7],
+
+    /// The first day of the week.
+    pub week_start: Weekday,
+
+    /// Whether the day is written before the month.
+    pub day_first: bool,
+
+    /// Whether times are written with a 12-hour clock.
+    pub hour12: bool,
+}
+
+impl Default for DateLocale {
+    fn default() -> Self {
+        Self::system()
+    }
+}
+
+impl DateLocale {
+    /// The locale of American English.
+    pub const ENGLISH: Self = Self {
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        weekdays: ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
+        week_start: Weekday::Sunday,
+        day_first: false,
+        hour12: true,
+    };
+
+    /// Get the locale of `locale`, like `en_US` or `de-DE.UTF-8`.
+    ///
+    /// Month and weekday names are only known for English, German, French, Spanish, Italian,
+    /// Dutch, Portuguese and Swedish. Every other language silently falls back to the English
+    /// names, while the first day of the week and the clock still follow the region. Use
+    /// [`DateLocale::names`] to provide the names of other languages.
+    pub fn locale(locale: &str) -> Self {
+        let locale = Locale::parse(locale);
+        let language = locale
+            .as_ref()
+            .map_or("", |locale| locale.language.as_str());
+        let region = (locale.as_ref())
+            .and_then(|locale| locale.region.as_deref())
+            .unwrap_or_default();
+
+        let (months, weekdays) = match language {
+            "de" => (
+                [
+                    "Januar",
+                    "Februar",
+                    "März",
+                    "April",
+                    "Mai",
+                    "Juni",
+                    "Juli",
+                    "August",
+                    "September",
+                    "Oktober",
+                    "November",
+                    "Dezember",
+                ],
+                ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+            ),
+            "fr" => (
+                [
+                    "janvier",
+                    "février",
+                    "mars",
+                    "avril",
+                    "mai",
+                    "juin",
+                    "juillet",
+                    "août",
+                    "septembre",
+                    "octobre",
+                    "novembre",
+                    "décembre",
+                ],
+                ["lu", "ma", "me", "je", "ve", "sa", "di"],
+            ),
+            "es" => (
+                [
+                    "enero",
+                    "febrero",
+                    "marzo",
+                    "abril",
+                    "mayo",
+                    "junio",
+                    "julio",
+                    "agosto",
+                    "septiembre",
+                    "octubre",
+                    "noviembre",
+                    "diciembre",
+                ],
+                ["lu", "ma", "mi", "ju", "vi", "sá", "do"],
+            ),
+            "it" => (
+                [
+                    "gennaio",
+                    "febbraio",
+                    "marzo",
+                    "aprile",
+                    "maggio",
+                    "giugno",
+                    "luglio",
+                    "agosto",
+                    "settembre",
+                    "ottobre",
+                    "novembre",
+                    "dicembre",
+                ],
+                ["lu", "ma", "me", "gi", "ve", "sa", "do"],
+            ),
+            "nl" => (
+                [
+                    "januari",
+                    "februari",
+                    "maart",
+                    "april",
+                    "mei",
+                    "juni",
+                    "juli",
+                    "augustus",
+                    "september",
+                    "oktober",
+                    "november",
+                    "december",
+                ],
+                ["ma", "di", "wo", "do", "vr", "za", "zo"],
+            ),
+            "pt" => (
+                [
+                    "janeiro",
+                    "fevereiro",
+                    "março",
+                    "abril",
+                    "maio",
+                    "junho",
+                    "julho",
+                    "agosto",
+                    "setembro",
+                    "outubro",
+                    "novembro",
+                    "dezembro",
+                ],
+                ["seg", "ter", "qua", "qui", "sex", "sáb", "dom"],
+            ),
+            "sv" => (
+                [
+                    "januari",
+                    "februari",
+                    "mars",
+                    "april",
+                    "maj",
+                    "juni",
+                    "juli",
+                    "augusti",
+                    "september",
+                    "oktober",
+                    "november",
+                    "december",
+                ],
+                ["må", "ti", "on", "to", "fr", "lö", "sö"],
+            ),
+            _ => (Self::ENGLISH.months, Self::ENGLISH.weekdays),
+        };
+
+        // unknown languages are written in english
+        let english = months == Self::ENGLISH.months;
+
+        // most of the world starts the week on monday, and uses a 24-hour clock
+        let sunday = ["US", "CA", "BR", "MX", "JP", "IL", "PH"].contains(&region);
+        let week_start = match sunday || (english && region.is_empty()) {
+            true => Weekday::Sunday,
+            false => Weekday::Monday,
+        };
+
+        let hour12 = ["US", "CA", "AU", "PH", "IN"].contains(&region);
+
+        Self {
+            months,
+            weekdays,
+            week_start,
+            day_first: !(english && (region.is_empty() || region == "US")),
+            hour12: hour12 || (english && region.is_empty()),
+        }
+    }
+
+    /// Set the names of the months, starting with January, and the short names of the
+    /// weekdays, starting with Monday.
+    pub const fn names(mut self, months: [&'static str; 12], weekdays: [&'static str; 7]) -> Self {
+        self.months = months;
+        self.weekdays = weekdays;
+        self
+    }
+
+    /// Get the locale of the system, see [`Locale::system`] with `LC_TIME`.
+    pub fn system() -> Self {
+        static SYSTEM: OnceLock<DateLocale> = OnceLock::new();
+
+        *SYSTEM.get_or_init(|| match Locale::system("LC_TIME") {
+            Some(locale) => Self::locale(&locale.to_string()),
+            None => Self::ENGLISH,
+        })
+    }
+
+    /// Get the name of the month of a `date`.
+    pub fn month_name(&self, date: Date) -> &'static str {
+        self.months[date.month() as usize - 1]
+    }
+
+    /// Get the short name of a `weekday`.
+    pub fn weekday_name(&self, weekday: Weekday) -> &'static str {
+        self.weekdays[weekday.index()]
+    }
+
+    /// Format the month and year of a `date`, like `March 2024`.
+    pub fn format_month(&self, date: Date) -> String {
+        format!("{} {}", self.month_name(date), date.year())
+    }
+
+    /// Format a `date`, like `March 5, 2024` or `5 March 2024`.
+    pub fn format_date(&self, date: Date) -> String {
+        let month = self.month_name(date);
+
+        match self.day_first {
+            true => format!("{} {} {}", date.day(), month, date.year()),
+            false => format!("{} {}, {}", month, date.day(), date.year()),
+        }
+    }
+
+    /// Format the hour of a `time`, like `2 PM` or `14`.
+    pub fn format_hour(&self, time: Time) -> String {
+        match self.hour12 {
+            true => format!("{} {}", hour12(time), meridiem(time)),
+            false => format!("{:02}", time.hour()),
+        }
+    }
+
+    /// Format a `time`, like `2:30 PM` or `14:30`.
+    pub fn format_time(&self, time: Time) -> String {
+        match self.hour12 {
+            true => format!("{}:{:02} {}", hour12(time), time.minute(), meridiem(time)),
+            false => time.to_string(),
+        }
+    }
+}
+
+fn hour12(time: Time) -> u8 {
+    match time.hour() % 12 {
+        0 => 12,
+        hour => hour,
+    }
+}
+
+fn meridiem(time: Time) -> &'static str {
+    match time.hour() < 12 {
+        true => "AM",
+        false => "PM",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Date, DateLocale, Time, Weekday};
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn date_arithmetic() {
+        assert_eq!(Date::new(2023, 2, 29), None);
+        assert_eq!(date(2024, 2, 29).weekday(), Weekday::Thursday);
+        assert_eq!(date(1970, 1, 1).add_days(-1), date(1969, 12, 31));
+        assert_eq!(date(2024, 12, 30).add_days(400), date(2026, 2, 3));
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 15).add_months(-13), date(2022, 12, 15));
+        assert_eq!(
+            Time::new(23, 50).unwrap().add_minutes(15).to_string(),
+            "00:05"
+        );
+    }
+
+    #[test]
+    fn locales() {
+        let us = DateLocale::locale("en_US.UTF-8");
+        let gb = DateLocale::locale("en_GB");
+        let de = DateLocale::locale("de-DE");
+
+        assert_eq!(us.week_start, Weekday::Sunday);
+        assert_eq!(gb.week_start, Weekday::Monday);
+        assert_eq!(us.format_date(date(2024, 3, 5)), "March 5, 2024");
+        assert_eq!(gb.format_date(date(2024, 3, 5)), "5 March 2024");
+        assert_eq!(de.format_month(date(2024, 3, 5)), "März 2024");
+
+        let time = Time::new(14, 5).unwrap();
+        assert_eq!(us.format_time(time), "2:05 PM");
+        assert_eq!(de.format_time(time), "14:05");
+    }
+}
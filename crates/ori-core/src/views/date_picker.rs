@@ -0,0 +1,689 @@
+use ori_macro::Build;
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, PointerButton},
+    layout::{Padding, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    text::{FontFamily, Fonts, TextAttributes, TextBuffer, TextWrap},
+    view::View,
+    window::Cursor,
+};
+
+use super::{
+    calendar::{date_enabled, GridStyle, Hit, MonthGrid},
+    dropdown::draw_arrow,
+    CalendarStyle, Date, DateLocale, DateSelection, Weekday,
+};
+
+/// Create a new [`DatePicker`].
+pub fn date_picker<T>() -> DatePicker<T> {
+    DatePicker::new()
+}
+
+/// The style of a date picker.
+#[derive(Clone, Debug)]
+pub struct DatePickerStyle {
+    /// The locale of the dates.
+    pub locale: DateLocale,
+
+    /// The font size of the text.
+    pub font_size: f32,
+
+    /// The font family of the text.
+    pub font_family: FontFamily,
+
+    /// The color of the text.
+    pub color: Color,
+
+    /// The color of the placeholder text.
+    pub placeholder_color: Color,
+
+    /// The color of the weekdays, and of the days outside the month.
+    pub muted_color: Color,
+
+    /// The color of the text of selected days.
+    pub selected_color: Color,
+
+    /// The color of the arrows.
+    pub icon_color: Color,
+
+    /// The padding of the picker.
+    pub padding: Padding,
+
+    /// The size of each day in the calendar.
+    pub cell_size: f32,
+
+    /// The padding of the calendar.
+    pub calendar_padding: Padding,
+
+    /// The background color.
+    pub background: Color,
+
+    /// The background color when hovered.
+    pub hover: Color,
+
+    /// The background color of the calendar.
+    pub calendar_background: Color,
+
+    /// The background color of a selected day.
+    pub highlight: Color,
+
+    /// The background color of the days in a selected range.
+    pub range: Color,
+
+    /// The color of the ring around today.
+    pub today: Color,
+
+    /// The border radius.
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    pub border_color: Color,
+
+    /// The border color when focused.
+    pub focus_color: Color,
+}
+
+impl Style for DatePickerStyle {
+    fn styled(style: &Styles) -> Self {
+        let calendar = style.get::<CalendarStyle>();
+        let palette = style.palette();
+
+        Self {
+            locale: calendar.locale,
+            font_size: calendar.font_size,
+            font_family: calendar.font_family,
+            color: calendar.color,
+            placeholder_color: palette.contrast_low,
+            muted_color: calendar.muted_color,
+            selected_color: calendar.selected_color,
+            icon_color: calendar.icon_color,
+            padding: Padding::from([8.0, 6.0]),
+            cell_size: calendar.cell_size,
+            calendar_padding: calendar.padding,
+            background: palette.surface_higher,
+            hover: palette.surface_highest,
+            calendar_background: calendar.background,
+            highlight: calendar.highlight,
+            range: calendar.range,
+            today: calendar.today,
+            border_radius: calendar.border_radius,
+            border_width: calendar.border_width,
+            border_color: calendar.border_color,
+            focus_color: calendar.focus_color,
+        }
+    }
+}
+
+/// A view that shows a date, or a range of dates, and lets the user choose another from a
+/// [`Calendar`](super::Calendar) in a popover.
+///
+/// When focused, the arrow keys, `Enter` and `Space` open the calendar, which is then navigated
+/// like a focused [`Calendar`](super::Calendar), and `Escape` closes it. The calendar closes
+/// when a date, or the end of a range, is chosen.
+///
+/// Can be styled using the [`DatePickerStyle`].
+#[derive(Build, Rebuild)]
+pub struct DatePicker<T> {
+    /// The selected dates.
+    #[rebuild(layout)]
+    pub selection: DateSelection,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_select: Option<Box<dyn FnMut(&mut EventCx, &mut T, DateSelection)>>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    disabled: Option<Box<dyn FnMut(&mut T, Date) -> bool>>,
+
+    /// Whether a range of dates is selected.
+    pub range: bool,
+
+    /// The earliest date that can be selected.
+    #[rebuild(draw)]
+    pub min: Option<Date>,
+
+    /// The latest date that can be selected.
+    #[rebuild(draw)]
+    pub max: Option<Date>,
+
+    /// The first day of the week, `None` uses the one of the locale.
+    #[rebuild(draw)]
+    pub week_start: Option<Weekday>,
+
+    /// The text displayed when nothing is selected.
+    #[rebuild(layout)]
+    pub placeholder: String,
+
+    /// The locale of the dates.
+    #[rebuild(layout)]
+    pub locale: DateLocale,
+
+    /// The font size of the text.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the text.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The color of the text.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of the placeholder text.
+    #[rebuild(draw)]
+    pub placeholder_color: Color,
+
+    /// The color of the weekdays, and of the days outside the month.
+    #[rebuild(draw)]
+    pub muted_color: Color,
+
+    /// The color of the text of selected days.
+    #[rebuild(draw)]
+    pub selected_color: Color,
+
+    /// The color of the arrows.
+    #[rebuild(draw)]
+    pub icon_color: Color,
+
+    /// The padding of the picker.
+    #[rebuild(layout)]
+    pub padding: Padding,
+
+    /// The size of each day in the calendar.
+    #[rebuild(draw)]
+    pub cell_size: f32,
+
+    /// The padding of the calendar.
+    #[rebuild(draw)]
+    pub calendar_padding: Padding,
+
+    /// The background color.
+    #[rebuild(draw)]
+    pub background: Color,
+
+    /// The background color when hovered.
+    #[rebuild(draw)]
+    pub hover: Color,
+
+    /// The background color of the calendar.
+    #[rebuild(draw)]
+    pub calendar_background: Color,
+
+    /// The background color of a selected day.
+    #[rebuild(draw)]
+    pub highlight: Color,
+
+    /// The background color of the days in a selected range.
+    #[rebuild(draw)]
+    pub range_color: Color,
+
+    /// The color of the ring around today.
+    #[rebuild(draw)]
+    pub today: Color,
+
+    /// The border radius.
+    #[rebuild(draw)]
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    #[rebuild(draw)]
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    #[rebuild(draw)]
+    pub border_color: Color,
+
+    /// The border color when focused.
+    #[rebuild(draw)]
+    pub focus_color: Color,
+}
+
+impl<T> Default for DatePicker<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> DatePicker<T> {
+    /// Create a new [`DatePicker`].
+    pub fn new() -> Self {
+        Self::styled(style())
+    }
+
+    /// Create a new [`DatePicker`] with a style.
+    pub fn styled(style: DatePickerStyle) -> Self {
+        Self {
+            selection: DateSelection::None,
+            on_select: None,
+            disabled: None,
+            range: false,
+            min: None,
+            max: None,
+            week_start: None,
+            placeholder: String::from("Select date..."),
+            locale: style.locale,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            color: style.color,
+            placeholder_color: style.placeholder_color,
+            muted_color: style.muted_color,
+            selected_color: style.selected_color,
+            icon_color: style.icon_color,
+            padding: style.padding,
+            cell_size: style.cell_size,
+            calendar_padding: style.calendar_padding,
+            background: style.background,
+            hover: style.hover,
+            calendar_background: style.calendar_background,
+            highlight: style.highlight,
+            range_color: style.range,
+            today: style.today,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+            focus_color: style.focus_color,
+        }
+    }
+
+    /// Set the callback that is called when the selection changes.
+    ///
+    /// While selecting a range, this is called with a range of one day after the first date is
+    /// chosen.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_select(
+        mut self,
+        on_select: impl FnMut(&mut EventCx, &mut T, DateSelection) + 'static,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Set the callback that decides whether a date is disabled.
+    pub fn disabled(mut self, disabled: impl FnMut(&mut T, Date) -> bool + 'static) -> Self {
+        self.disabled = Some(Box::new(disabled));
+        self
+    }
+
+    fn grid_style(&self) -> GridStyle {
+        GridStyle {
+            locale: self.locale,
+            week_start: self.week_start.unwrap_or(self.locale.week_start),
+            font_size: self.font_size,
+            font_family: self.font_family.clone(),
+            color: self.color,
+            muted_color: self.muted_color,
+            selected_color: self.selected_color,
+            icon_color: self.icon_color,
+            cell_size: self.cell_size,
+            padding: self.calendar_padding,
+            hover: self.hover,
+            highlight: self.highlight,
+            range: self.range_color,
+            today: self.today,
+            focus_color: self.focus_color,
+            border_radius: self.border_radius,
+        }
+    }
+
+    fn set_label(&self, fonts: &mut Fonts, state: &mut DatePickerState) {
+        let label = match self.selection {
+            DateSelection::None => self.placeholder.clone(),
+            DateSelection::Single(date) => self.locale.format_date(date),
+            DateSelection::Range(start, end) => format!(
+                "{} – {}",
+                self.locale.format_date(start),
+                self.locale.format_date(end),
+            ),
+        };
+
+        let attributes = TextAttributes {
+            family: self.font_family.clone(),
+            ..Default::default()
+        };
+
+        state.label.set_metrics(fonts, self.font_size, LINE_HEIGHT);
+        state.label.set_text(fonts, &label, attributes);
+    }
+
+    fn open(&self, fonts: &mut Fonts, state: &mut DatePickerState) {
+        let date = self.selection.start().unwrap_or_else(Date::today);
+
+        state
+            .grid
+            .focus(fonts, &self.grid_style(), date, self.min, self.max);
+        state.grid.anchor = None;
+        state.grid.hovered = None;
+        state.open = true;
+    }
+
+    // the rect of the calendar, local to the picker of size `size`
+    fn popover_rect(&self, state: &DatePickerState, size: Size, rtl: bool) -> Rect {
+        let grid = MonthGrid::size(&self.grid_style());
+
+        let x = match rtl {
+            true => size.width - grid.width,
+            false => 0.0,
+        };
+
+        let y = match state.above {
+            true => -grid.height - GAP,
+            false => size.height + GAP,
+        };
+
+        Rect::min_size(Point::new(x, y), grid)
+    }
+
+    // choose `date` in the calendar, closing it when the selection is complete
+    fn choose(&mut self, state: &mut DatePickerState, cx: &mut EventCx, data: &mut T, date: Date) {
+        if !date_enabled(date, self.min, self.max, &mut self.disabled, data) {
+            return;
+        }
+
+        let style = self.grid_style();
+        state
+            .grid
+            .focus(cx.fonts(), &style, date, self.min, self.max);
+
+        let (selection, complete) = state.grid.choose(date, self.range);
+        state.open = !complete;
+
+        self.selection = selection;
+        self.set_label(cx.fonts(), state);
+        cx.layout();
+
+        if let Some(ref mut on_select) = self.on_select {
+            on_select(cx, data, selection);
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct DatePickerState {
+    label: TextBuffer,
+    grid: MonthGrid,
+    open: bool,
+    above: bool,
+}
+
+impl<T> View<T> for DatePicker<T> {
+    type State = DatePickerState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.set_focusable(true);
+
+        let mut label = TextBuffer::new(cx.fonts(), self.font_size, LINE_HEIGHT);
+        label.set_wrap(cx.fonts(), TextWrap::None);
+
+        let mut state = DatePickerState {
+            label,
+            grid: MonthGrid::new(cx.fonts(), &self.grid_style(), Date::today()),
+            open: false,
+            above: false,
+        };
+
+        self.set_label(cx.fonts(), &mut state);
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        if self.locale != old.locale
+            || self.week_start != old.week_start
+            || self.font_size != old.font_size
+            || self.font_family != old.font_family
+        {
+            state.grid.set_style(cx.fonts(), &self.grid_style());
+        }
+
+        self.set_label(cx.fonts(), state);
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        let style = self.grid_style();
+        let rtl = cx.direction().is_rtl();
+
+        match event {
+            Event::PointerPressed(e) if e.button == PointerButton::Primary => {
+                let local = cx.local(e.position);
+
+                if state.open {
+                    let rect = self.popover_rect(state, cx.size(), rtl);
+
+                    if rect.contains(local) {
+                        match state.grid.hit(&style, local - rect.offset(), rtl) {
+                            Some(Hit::Previous) => {
+                                let month = state.grid.month.add_months(-1);
+                                state.grid.show_month(cx.fonts(), &style, month);
+                            }
+                            Some(Hit::Next) => {
+                                let month = state.grid.month.add_months(1);
+                                state.grid.show_month(cx.fonts(), &style, month);
+                            }
+                            Some(Hit::Day(date)) => self.choose(state, cx, data, date),
+                            None => {}
+                        }
+                    } else {
+                        state.open = false;
+                        cx.set_focused(cx.rect().contains(local));
+                    }
+
+                    cx.draw();
+                } else if cx.is_hot() {
+                    self.open(cx.fonts(), state);
+                    cx.set_focused(true);
+                    cx.draw();
+                } else if cx.is_focused() {
+                    cx.set_focused(false);
+                    cx.draw();
+                }
+            }
+            Event::PointerMoved(e) => {
+                let local = cx.local(e.position);
+                let rect = self.popover_rect(state, cx.size(), rtl);
+
+                let hovered = match state.open && rect.contains(local) {
+                    true => state.grid.hit(&style, local - rect.offset(), rtl),
+                    false => None,
+                };
+
+                if state.grid.hovered != hovered || cx.hot_changed() {
+                    state.grid.hovered = hovered;
+                    cx.draw();
+                }
+
+                cx.set_cursor(cx.is_hot().then_some(Cursor::Pointer));
+            }
+            Event::KeyPressed(e) if cx.is_focused() => {
+                if !state.open {
+                    if e.is_key(Key::Down) || e.is_key(Key::Enter) || e.is_key(' ') {
+                        self.open(cx.fonts(), state);
+                        cx.draw();
+                    }
+
+                    return;
+                }
+
+                if e.is_key(Key::Escape) {
+                    state.open = false;
+                    cx.set_handled();
+                } else if e.is_key(Key::Enter) || e.is_key(' ') {
+                    let date = state.grid.focused;
+                    self.choose(state, cx, data, date);
+                } else if let Some(date) = state.grid.key_target(&style, e, rtl) {
+                    state
+                        .grid
+                        .focus(cx.fonts(), &style, date, self.min, self.max);
+                }
+
+                cx.draw();
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        let height = self.font_size * LINE_HEIGHT;
+        let size = Size::new(state.label.size().width + height, height) + self.padding.size();
+        let size = space.fit(size);
+
+        let baseline = state.label.baseline().map(|b| b + self.padding.top);
+        cx.set_baseline(baseline);
+
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        let background = match cx.is_hot() && !state.open {
+            true => self.hover,
+            false => self.background,
+        };
+
+        let border_color = match cx.is_focused() {
+            true => self.focus_color,
+            false => self.border_color,
+        };
+
+        cx.hoverable(|cx| {
+            cx.quad(
+                cx.rect(),
+                background,
+                self.border_radius,
+                self.border_width,
+                border_color,
+            );
+        });
+
+        let color = match self.selection {
+            DateSelection::None => self.placeholder_color,
+            _ => self.color,
+        };
+
+        let rtl = cx.direction().is_rtl();
+        let arrow = self.font_size * LINE_HEIGHT;
+        let (label_x, arrow_x) = match rtl {
+            true => (
+                cx.size().width - self.padding.right - state.label.size().width,
+                self.padding.left,
+            ),
+            false => (
+                self.padding.left,
+                cx.size().width - self.padding.right - arrow,
+            ),
+        };
+
+        cx.text(&state.label, color, Vector::new(label_x, self.padding.top));
+
+        let arrow = Rect::min_size(Point::new(arrow_x, self.padding.top), Size::all(arrow));
+        draw_arrow(cx, arrow, state.open, self.icon_color);
+
+        if !state.open {
+            return;
+        }
+
+        // open above the picker when the calendar doesn't fit below it, but does above
+        let transform = cx.transform();
+        let window = cx.window().size;
+        let height = MonthGrid::size(&self.grid_style()).height + GAP;
+        let top = (transform * Point::ZERO).y;
+        let bottom = (transform * Point::new(0.0, cx.size().height)).y;
+        state.above = bottom + height > window.height && top - height >= 0.0;
+
+        let rect = self.popover_rect(state, cx.size(), rtl);
+        let style = self.grid_style();
+        let (min, max, disabled) = (self.min, self.max, &mut self.disabled);
+
+        cx.overlay(1, |cx| {
+            cx.layer(transform, |cx| {
+                cx.hoverable(|cx| {
+                    cx.quad(
+                        rect,
+                        self.calendar_background,
+                        self.border_radius,
+                        self.border_width,
+                        self.border_color,
+                    );
+                });
+
+                cx.translate(rect.offset(), |cx| {
+                    state
+                        .grid
+                        .draw(cx, &style, self.selection, true, &mut |date| {
+                            date_enabled(date, min, max, disabled, data)
+                        });
+                });
+            });
+        });
+    }
+}
+
+const LINE_HEIGHT: f32 = 1.2;
+
+// the gap between the picker and the calendar
+const GAP: f32 = 4.0;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Key, KeyPressed, Modifiers},
+        layout::Space,
+        views::{testing::ViewTester, Date, DateSelection},
+    };
+
+    use super::date_picker;
+
+    fn key(key: Key) -> Event {
+        Event::KeyPressed(KeyPressed {
+            key,
+            code: None,
+            text: None,
+            modifiers: Modifiers::default(),
+        })
+    }
+
+    #[test]
+    fn choose_with_keyboard() {
+        let start = Date::new(2024, 5, 10).unwrap();
+        let mut selection = DateSelection::Single(start);
+
+        let mut view = date_picker()
+            .selection(start)
+            .min(start)
+            .on_select(|_, selection: &mut DateSelection, new| *selection = new);
+
+        let mut tester = ViewTester::new(&mut view, &mut selection);
+        tester.layout(&mut view, &mut selection, Space::UNBOUNDED);
+        tester.view_state.set_focused(true);
+
+        tester.event(&mut view, &mut selection, &key(Key::Enter));
+        assert!(tester.state.open);
+
+        // the focus can't move before the earliest date
+        tester.event(&mut view, &mut selection, &key(Key::Up));
+        tester.event(&mut view, &mut selection, &key(Key::Right));
+        tester.event(&mut view, &mut selection, &key(Key::Enter));
+
+        assert!(!tester.state.open);
+        assert_eq!(
+            selection,
+            DateSelection::Single(Date::new(2024, 5, 11).unwrap())
+        );
+    }
+}
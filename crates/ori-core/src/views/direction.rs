@@ -0,0 +1,108 @@
+use std::ops::DerefMut;
+
+use crate::{
+    context::{BaseCx, BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Direction, Size, Space},
+    view::{Pod, State, View},
+};
+
+/// Create a new [`Directed`] view, laying out its content in `direction`.
+pub fn direction<V>(direction: Direction, content: V) -> Directed<V> {
+    Directed::new(direction, content)
+}
+
+/// Create a new [`Directed`] view, laying out its content left to right.
+pub fn ltr<V>(content: V) -> Directed<V> {
+    Directed::new(Direction::Ltr, content)
+}
+
+/// Create a new [`Directed`] view, laying out its content right to left.
+pub fn rtl<V>(content: V) -> Directed<V> {
+    Directed::new(Direction::Rtl, content)
+}
+
+/// A view that sets the layout [`Direction`] of its content.
+///
+/// In a right to left direction, containers like [`Stack`](super::Stack) and
+/// [`Wrap`](super::Wrap) mirror the horizontal positions of their content, which also mirrors
+/// horizontal alignment and padding.
+pub struct Directed<V> {
+    /// The content.
+    pub content: Pod<V>,
+
+    /// The direction of the content.
+    pub direction: Direction,
+}
+
+impl<V> Directed<V> {
+    /// Create a new [`Directed`] view.
+    pub fn new(direction: Direction, content: V) -> Self {
+        Self {
+            content: Pod::new(content),
+            direction,
+        }
+    }
+}
+
+// call `f` with the direction as a context, restoring the direction of any outer view
+fn with_direction<'b, C, R>(cx: &mut C, direction: Direction, f: impl FnOnce(&mut C) -> R) -> R
+where
+    C: DerefMut<Target = BaseCx<'b>>,
+{
+    let outer = cx.insert_context(direction);
+    let result = f(cx);
+
+    match outer {
+        Some(outer) => cx.insert_context(outer),
+        None => cx.remove_context::<Direction>(),
+    };
+
+    result
+}
+
+impl<T, V: View<T>> View<T> for Directed<V> {
+    type State = State<T, V>;
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        with_direction(cx, self.direction, |cx| self.content.build(cx, data))
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        with_direction(cx, self.direction, |cx| {
+            self.content.rebuild(state, cx, data, &old.content);
+        });
+
+        if self.direction != old.direction {
+            cx.layout();
+            cx.draw();
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        with_direction(cx, self.direction, |cx| {
+            self.content.event(state, cx, data, event);
+        });
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        let size = with_direction(cx, self.direction, |cx| {
+            self.content.layout(state, cx, data, space)
+        });
+
+        cx.set_baseline(state.parent_baseline());
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        with_direction(cx, self.direction, |cx| {
+            self.content.draw(state, cx, data);
+        });
+    }
+}
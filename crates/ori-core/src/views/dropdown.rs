@@ -0,0 +1,807 @@
+use std::{fmt::Display, time::Instant};
+
+use ori_macro::Build;
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color, Curve},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, KeyPressed},
+    layout::{Padding, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    text::{FontFamily, Fonts, TextAttributes, TextBuffer, TextWrap},
+    view::View,
+    window::Cursor,
+};
+
+use super::TextStyle;
+
+/// Create a new [`Dropdown`].
+pub fn dropdown<T, I>(items: impl IntoIterator<Item = I>) -> Dropdown<T, I> {
+    Dropdown::new(items)
+}
+
+/// The style of a dropdown.
+#[derive(Clone, Debug)]
+pub struct DropdownStyle {
+    /// The font size of the text.
+    pub font_size: f32,
+
+    /// The font family of the text.
+    pub font_family: FontFamily,
+
+    /// The color of the text.
+    pub color: Color,
+
+    /// The color of the placeholder text.
+    pub placeholder_color: Color,
+
+    /// The color of the arrow.
+    pub icon_color: Color,
+
+    /// The padding of the dropdown and of each item in the list.
+    pub padding: Padding,
+
+    /// The background color.
+    pub background: Color,
+
+    /// The background color when hovered.
+    pub hover: Color,
+
+    /// The border radius.
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    pub border_color: Color,
+
+    /// The border color when focused.
+    pub focus_color: Color,
+
+    /// The background color of the list.
+    pub list_background: Color,
+
+    /// The background color of the highlighted item in the list.
+    pub highlight: Color,
+
+    /// The maximum height of the list.
+    pub max_height: f32,
+}
+
+impl Style for DropdownStyle {
+    fn styled(style: &Styles) -> Self {
+        let text_style = style.get::<TextStyle>();
+        let palette = style.palette();
+
+        Self {
+            font_size: 14.0,
+            font_family: text_style.font_family.clone(),
+            color: palette.contrast,
+            placeholder_color: palette.contrast_low,
+            icon_color: palette.contrast_low,
+            padding: Padding::from([8.0, 6.0]),
+            background: palette.surface_higher,
+            hover: palette.surface_highest,
+            border_radius: BorderRadius::all(4.0),
+            border_width: BorderWidth::all(1.0),
+            border_color: palette.outline,
+            focus_color: palette.primary,
+            list_background: palette.surface_high,
+            highlight: palette.primary.fade(0.4),
+            max_height: 240.0,
+        }
+    }
+}
+
+/// A view that shows the selected item of a list, and lets the user choose another from a popup.
+///
+/// When focused, the arrow keys, `Enter` and `Space` open the list, the arrow keys, `Home` and
+/// `End` move through it, `Enter` chooses the highlighted item and `Escape` closes it. Typing
+/// jumps to the first item starting with the typed text.
+///
+/// Items are displayed with their [`Display`] implementation, and the selected item is compared
+/// by [`PartialEq`].
+///
+/// Can be styled using the [`DropdownStyle`].
+#[derive(Build, Rebuild)]
+pub struct Dropdown<T, I> {
+    /// The items.
+    #[build(ignore)]
+    pub items: Vec<I>,
+
+    /// The selected item.
+    #[build(ignore)]
+    pub selected: Option<I>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_select: Option<Box<dyn FnMut(&mut EventCx, &mut T, &I)>>,
+
+    /// The text displayed when no item is selected.
+    #[rebuild(layout)]
+    pub placeholder: String,
+
+    /// The font size of the text.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the text.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The color of the text.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of the placeholder text.
+    #[rebuild(draw)]
+    pub placeholder_color: Color,
+
+    /// The color of the arrow.
+    #[rebuild(draw)]
+    pub icon_color: Color,
+
+    /// The padding of the dropdown and of each item in the list.
+    #[rebuild(layout)]
+    pub padding: Padding,
+
+    /// The background color.
+    #[rebuild(draw)]
+    pub background: Color,
+
+    /// The background color when hovered.
+    #[rebuild(draw)]
+    pub hover: Color,
+
+    /// The border radius.
+    #[rebuild(draw)]
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    #[rebuild(draw)]
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    #[rebuild(draw)]
+    pub border_color: Color,
+
+    /// The border color when focused.
+    #[rebuild(draw)]
+    pub focus_color: Color,
+
+    /// The background color of the list.
+    #[rebuild(draw)]
+    pub list_background: Color,
+
+    /// The background color of the highlighted item in the list.
+    #[rebuild(draw)]
+    pub highlight: Color,
+
+    /// The maximum height of the list.
+    #[rebuild(draw)]
+    pub max_height: f32,
+}
+
+impl<T, I> Dropdown<T, I> {
+    /// Create a new [`Dropdown`].
+    pub fn new(items: impl IntoIterator<Item = I>) -> Self {
+        Self::styled(items, style())
+    }
+
+    /// Create a new [`Dropdown`] with a style.
+    pub fn styled(items: impl IntoIterator<Item = I>, style: DropdownStyle) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+            selected: None,
+            on_select: None,
+            placeholder: String::from("Select..."),
+            font_size: style.font_size,
+            font_family: style.font_family,
+            color: style.color,
+            placeholder_color: style.placeholder_color,
+            icon_color: style.icon_color,
+            padding: style.padding,
+            background: style.background,
+            hover: style.hover,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+            focus_color: style.focus_color,
+            list_background: style.list_background,
+            highlight: style.highlight,
+            max_height: style.max_height,
+        }
+    }
+
+    /// Set the selected item.
+    pub fn selected(mut self, selected: impl Into<Option<I>>) -> Self {
+        self.selected = selected.into();
+        self
+    }
+
+    /// Set the callback that is called when an item is chosen.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_select(mut self, on_select: impl FnMut(&mut EventCx, &mut T, &I) + 'static) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    fn list_style(&self) -> ListStyle {
+        ListStyle {
+            font_size: self.font_size,
+            font_family: self.font_family.clone(),
+            color: self.color,
+            padding: self.padding,
+            background: self.list_background,
+            highlight: self.highlight,
+            border_radius: self.border_radius,
+            border_color: self.border_color,
+            max_height: self.max_height,
+        }
+    }
+
+    fn attributes(&self) -> TextAttributes {
+        TextAttributes {
+            family: self.font_family.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn select(&mut self, cx: &mut EventCx, data: &mut T, index: usize) {
+        if let Some(ref mut on_select) = self.on_select {
+            on_select(cx, data, &self.items[index]);
+        }
+    }
+}
+
+impl<T, I: Display + PartialEq> Dropdown<T, I> {
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+        self.items.iter().position(|item| item == selected)
+    }
+
+    fn labels(&self) -> Vec<String> {
+        self.items.iter().map(ToString::to_string).collect()
+    }
+
+    fn set_label(&self, fonts: &mut Fonts, state: &mut DropdownState) {
+        let label = match self.selected_index() {
+            Some(index) => &state.labels[index],
+            None => &self.placeholder,
+        };
+
+        state.label.set_metrics(fonts, self.font_size, LINE_HEIGHT);
+        state.label.set_text(fonts, label, self.attributes());
+    }
+}
+
+#[doc(hidden)]
+pub struct DropdownState {
+    labels: Vec<String>,
+    label: TextBuffer,
+    list: PopupList,
+    typeahead: String,
+    last_typed: Option<Instant>,
+}
+
+impl<T, I: Display + PartialEq> View<T> for Dropdown<T, I> {
+    type State = DropdownState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.set_focusable(true);
+
+        let mut label = TextBuffer::new(cx.fonts(), self.font_size, LINE_HEIGHT);
+        label.set_wrap(cx.fonts(), TextWrap::None);
+
+        let mut state = DropdownState {
+            labels: self.labels(),
+            label,
+            list: PopupList::new(),
+            typeahead: String::new(),
+            last_typed: None,
+        };
+
+        let style = self.list_style();
+        (state.list).set_items(cx.fonts(), &style, state.labels.iter());
+        self.set_label(cx.fonts(), &mut state);
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        let labels = self.labels();
+        let style = self.list_style();
+
+        if labels != state.labels || self.font_size != old.font_size {
+            state.labels = labels;
+            (state.list).set_items(cx.fonts(), &style, state.labels.iter());
+            cx.layout();
+        }
+
+        if self.font_family != old.font_family {
+            (state.list).set_items(cx.fonts(), &style, state.labels.iter());
+        }
+
+        self.set_label(cx.fonts(), state);
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        let style = self.list_style();
+
+        match event {
+            Event::PointerPressed(e) => {
+                let local = cx.local(e.position);
+
+                if state.list.open {
+                    if let Some(index) = state.list.index_at(&style, cx.size(), local) {
+                        self.select(cx, data, index);
+                    }
+
+                    state.list.open = false;
+                    cx.set_focused(cx.rect().contains(local));
+                    cx.draw();
+                } else if cx.is_hot() {
+                    state.list.show(&style, self.selected_index());
+                    cx.set_focused(true);
+                    cx.draw();
+                } else if cx.is_focused() {
+                    cx.set_focused(false);
+                    cx.draw();
+                }
+            }
+            Event::PointerMoved(e) => {
+                if state.list.open {
+                    let local = cx.local(e.position);
+
+                    if let Some(index) = state.list.index_at(&style, cx.size(), local) {
+                        if state.list.highlighted != Some(index) {
+                            state.list.highlighted = Some(index);
+                            cx.draw();
+                        }
+                    }
+                }
+
+                if cx.hot_changed() {
+                    cx.draw();
+                }
+
+                cx.set_cursor(cx.is_hot().then_some(Cursor::Pointer));
+            }
+            Event::PointerScrolled(e) => {
+                let local = cx.local(e.position);
+
+                if state.list.open && state.list.index_at(&style, cx.size(), local).is_some() {
+                    state
+                        .list
+                        .scroll_by(&style, -e.delta.y * style.row_height());
+                    cx.draw();
+                }
+            }
+            Event::KeyPressed(e) if cx.is_focused() => {
+                self.key_pressed(state, cx, data, e);
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        // the dropdown is as wide as its widest item, so it doesn't resize when selecting
+        let mut width = state.label.size().width;
+
+        for buffer in &state.list.buffers {
+            width = width.max(buffer.size().width);
+        }
+
+        let height = self.font_size * LINE_HEIGHT;
+        let size = Size::new(width + height, height) + self.padding.size();
+        let size = space.fit(size);
+
+        let baseline = state.label.baseline().map(|b| b + self.padding.top);
+        cx.set_baseline(baseline);
+
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        let background = match cx.is_hot() && !state.list.open {
+            true => self.hover,
+            false => self.background,
+        };
+
+        let border_color = match cx.is_focused() {
+            true => self.focus_color,
+            false => self.border_color,
+        };
+
+        cx.hoverable(|cx| {
+            cx.quad(
+                cx.rect(),
+                background,
+                self.border_radius,
+                self.border_width,
+                border_color,
+            );
+        });
+
+        let color = match self.selected_index() {
+            Some(_) => self.color,
+            None => self.placeholder_color,
+        };
+
+        let arrow = self.font_size * LINE_HEIGHT;
+        let (label_x, arrow_x) = match cx.direction().is_rtl() {
+            true => (
+                cx.size().width - self.padding.right - state.label.size().width,
+                self.padding.left,
+            ),
+            false => (
+                self.padding.left,
+                cx.size().width - self.padding.right - arrow,
+            ),
+        };
+
+        cx.text(&state.label, color, Vector::new(label_x, self.padding.top));
+
+        let arrow = Rect::min_size(Point::new(arrow_x, self.padding.top), Size::all(arrow));
+        draw_arrow(cx, arrow, state.list.open, self.icon_color);
+
+        if state.list.open {
+            let style = self.list_style();
+            state.list.draw(cx, &style, self.selected_index());
+        }
+    }
+}
+
+impl<T, I: Display + PartialEq> Dropdown<T, I> {
+    fn key_pressed(
+        &mut self,
+        state: &mut DropdownState,
+        cx: &mut EventCx,
+        data: &mut T,
+        e: &KeyPressed,
+    ) {
+        let style = self.list_style();
+
+        if !state.list.open {
+            if e.is_key(Key::Down) || e.is_key(Key::Up) || e.is_key(Key::Enter) || e.is_key(' ') {
+                state.list.show(&style, self.selected_index());
+                cx.draw();
+                return;
+            }
+
+            // while closed, typing selects an item directly
+            if let Some(index) = self.typeahead(state, e) {
+                self.select(cx, data, index);
+                cx.draw();
+            }
+
+            return;
+        }
+
+        match e.key {
+            Key::Down => state.list.move_highlight(&style, 1),
+            Key::Up => state.list.move_highlight(&style, -1),
+            Key::Home => state.list.move_highlight(&style, isize::MIN),
+            Key::End => state.list.move_highlight(&style, isize::MAX),
+            Key::Enter => {
+                if let Some(index) = state.list.highlighted {
+                    self.select(cx, data, index);
+                }
+
+                state.list.open = false;
+            }
+            Key::Escape => {
+                state.list.open = false;
+                cx.set_handled();
+            }
+            _ => {
+                if let Some(index) = self.typeahead(state, e) {
+                    state.list.highlighted = Some(index);
+                    state.list.scroll_to(&style, index);
+                }
+            }
+        }
+
+        cx.draw();
+    }
+
+    // find the item starting with the text typed recently
+    fn typeahead(&self, state: &mut DropdownState, e: &KeyPressed) -> Option<usize> {
+        if e.modifiers.ctrl || e.modifiers.alt || e.modifiers.meta {
+            return None;
+        }
+
+        let text = e.text.as_ref()?;
+
+        if text.chars().any(char::is_control) {
+            return None;
+        }
+
+        let now = Instant::now();
+
+        let expired = match state.last_typed {
+            Some(last) => now.duration_since(last).as_secs_f32() > TYPEAHEAD_TIMEOUT,
+            None => true,
+        };
+
+        if expired {
+            state.typeahead.clear();
+        }
+
+        state.typeahead.push_str(&text.to_lowercase());
+        state.last_typed = Some(now);
+
+        let starts_with = |label: &String| label.to_lowercase().starts_with(&state.typeahead);
+        state.labels.iter().position(starts_with)
+    }
+}
+
+const LINE_HEIGHT: f32 = 1.2;
+const TYPEAHEAD_TIMEOUT: f32 = 1.0;
+
+// draw a chevron in the center of `rect`, pointing up when `up`
+pub(super) fn draw_arrow(cx: &mut DrawCx, rect: Rect, up: bool, color: Color) {
+    let center = rect.center();
+    let size = rect.height() / 5.0;
+    let flip = if up { -1.0 } else { 1.0 };
+
+    let mut curve = Curve::new();
+    curve.move_to(center + Vector::new(-size, -size * 0.5 * flip));
+    curve.line_to(center + Vector::new(0.0, size * 0.5 * flip));
+    curve.line_to(center + Vector::new(size, -size * 0.5 * flip));
+
+    cx.stroke(curve, 1.5, color);
+}
+
+// the style of a `PopupList`, taken from the view that owns it
+pub(super) struct ListStyle {
+    pub(super) font_size: f32,
+    pub(super) font_family: FontFamily,
+    pub(super) color: Color,
+    pub(super) padding: Padding,
+    pub(super) background: Color,
+    pub(super) highlight: Color,
+    pub(super) border_radius: BorderRadius,
+    pub(super) border_color: Color,
+    pub(super) max_height: f32,
+}
+
+impl ListStyle {
+    pub(super) fn row_height(&self) -> f32 {
+        self.font_size * LINE_HEIGHT + self.padding.size().height
+    }
+}
+
+// a list of items drawn in an overlay below, or above, the view that owns it
+pub(super) struct PopupList {
+    pub(super) open: bool,
+    pub(super) highlighted: Option<usize>,
+    pub(super) buffers: Vec<TextBuffer>,
+    scroll: f32,
+    above: bool,
+}
+
+impl PopupList {
+    pub(super) fn new() -> Self {
+        Self {
+            open: false,
+            highlighted: None,
+            buffers: Vec::new(),
+            scroll: 0.0,
+            above: false,
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.buffers.len()
+    }
+
+    pub(super) fn set_items(
+        &mut self,
+        fonts: &mut Fonts,
+        style: &ListStyle,
+        labels: impl IntoIterator<Item = impl AsRef<str>>,
+    ) {
+        self.buffers.clear();
+
+        for label in labels {
+            let mut buffer = TextBuffer::new(fonts, style.font_size, LINE_HEIGHT);
+            buffer.set_wrap(fonts, TextWrap::None);
+            buffer.set_text(
+                fonts,
+                label.as_ref(),
+                TextAttributes {
+                    family: style.font_family.clone(),
+                    ..Default::default()
+                },
+            );
+
+            self.buffers.push(buffer);
+        }
+
+        self.highlighted = self
+            .highlighted
+            .map(|i| i.min(self.len().saturating_sub(1)));
+        self.highlighted = self.highlighted.filter(|_| self.len() > 0);
+        self.scroll_by(style, 0.0);
+    }
+
+    pub(super) fn show(&mut self, style: &ListStyle, highlighted: Option<usize>) {
+        self.open = true;
+        self.highlighted = highlighted.or((self.len() > 0).then_some(0));
+        self.scroll = 0.0;
+
+        if let Some(index) = self.highlighted {
+            self.scroll_to(style, index);
+        }
+    }
+
+    fn height(&self, style: &ListStyle) -> f32 {
+        f32::min(self.len() as f32 * style.row_height(), style.max_height)
+    }
+
+    // the rect of the list, local to the view of size `size`
+    pub(super) fn rect(&self, style: &ListStyle, size: Size) -> Rect {
+        let height = self.height(style);
+
+        match self.above {
+            true => Rect::min_size(Point::new(0.0, -height), Size::new(size.width, height)),
+            false => Rect::min_size(Point::new(0.0, size.height), Size::new(size.width, height)),
+        }
+    }
+
+    pub(super) fn index_at(&self, style: &ListStyle, size: Size, local: Point) -> Option<usize> {
+        let rect = self.rect(style, size);
+
+        if !rect.contains(local) {
+            return None;
+        }
+
+        let index = ((local.y - rect.min.y + self.scroll) / style.row_height()) as usize;
+        (index < self.len()).then_some(index)
+    }
+
+    pub(super) fn scroll_by(&mut self, style: &ListStyle, delta: f32) {
+        let overflow = self.len() as f32 * style.row_height() - self.height(style);
+        self.scroll = (self.scroll + delta).clamp(0.0, overflow.max(0.0));
+    }
+
+    // scroll just enough for the item at `index` to be visible
+    pub(super) fn scroll_to(&mut self, style: &ListStyle, index: usize) {
+        let top = index as f32 * style.row_height();
+        let bottom = top + style.row_height();
+        let height = self.height(style);
+
+        if top < self.scroll {
+            self.scroll = top;
+        } else if bottom > self.scroll + height {
+            self.scroll = bottom - height;
+        }
+    }
+
+    pub(super) fn move_highlight(&mut self, style: &ListStyle, delta: isize) {
+        if self.len() == 0 {
+            return;
+        }
+
+        let index = match self.highlighted {
+            Some(index) => index.saturating_add_signed(delta),
+            None if delta < 0 => self.len() - 1,
+            None => 0,
+        };
+
+        let index = index.min(self.len() - 1);
+        self.highlighted = Some(index);
+        self.scroll_to(style, index);
+    }
+
+    pub(super) fn draw(&mut self, cx: &mut DrawCx, style: &ListStyle, selected: Option<usize>) {
+        let transform = cx.transform();
+        let window = cx.window().size;
+        let size = cx.size();
+
+        // open above the view when the list doesn't fit below it, but does above
+        let height = self.height(style);
+        let top = (transform * Point::ZERO).y;
+        let bottom = (transform * Point::new(0.0, size.height)).y;
+        self.above = bottom + height > window.height && top - height >= 0.0;
+
+        let rect = self.rect(style, size);
+        let row_height = style.row_height();
+
+        cx.overlay(1, |cx| {
+            cx.layer(transform, |cx| {
+                cx.hoverable(|cx| {
+                    cx.quad(
+                        rect,
+                        style.background,
+                        style.border_radius,
+                        1.0,
+                        style.border_color,
+                    );
+                });
+
+                cx.mask(rect, |cx| {
+                    let first = (self.scroll / row_height) as usize;
+                    let count = (rect.height() / row_height).ceil() as usize + 1;
+
+                    for index in first..usize::min(first + count, self.len()) {
+                        let y = rect.min.y + index as f32 * row_height - self.scroll;
+                        let row =
+                            Rect::min_size(Point::new(0.0, y), Size::new(size.width, row_height));
+
+                        if self.highlighted == Some(index) {
+                            cx.fill_rect(row, style.highlight);
+                        } else if selected == Some(index) {
+                            cx.fill_rect(row, style.highlight.fade(0.5));
+                        }
+
+                        let buffer = &self.buffers[index];
+                        let x = match cx.direction().is_rtl() {
+                            true => size.width - style.padding.right - buffer.size().width,
+                            false => style.padding.left,
+                        };
+
+                        cx.text(buffer, style.color, Vector::new(x, y + style.padding.top));
+                    }
+                });
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        layout::{Point, Size},
+        text::Fonts,
+    };
+
+    use super::{ListStyle, PopupList};
+
+    fn list_style() -> ListStyle {
+        ListStyle {
+            font_size: 10.0,
+            font_family: Default::default(),
+            color: Default::default(),
+            padding: [0.0, 4.0].into(),
+            background: Default::default(),
+            highlight: Default::default(),
+            border_radius: Default::default(),
+            border_color: Default::default(),
+            max_height: 40.0,
+        }
+    }
+
+    #[test]
+    fn popup_list() {
+        let style = list_style();
+        let mut fonts = Fonts::default();
+
+        let mut list = PopupList::new();
+        list.set_items(&mut fonts, &style, ["a", "b", "c", "d"]);
+        list.show(&style, Some(3));
+
+        // each row is 20 pixels high, and only two fit, so the last item is scrolled to
+        assert_eq!(list.highlighted, Some(3));
+        let size = Size::new(100.0, 20.0);
+        assert_eq!(list.index_at(&style, size, Point::new(5.0, 25.0)), Some(2));
+        assert_eq!(list.index_at(&style, size, Point::new(5.0, 45.0)), Some(3));
+        assert_eq!(list.index_at(&style, size, Point::new(5.0, 65.0)), None);
+
+        list.move_highlight(&style, isize::MIN);
+        assert_eq!(list.highlighted, Some(0));
+        assert_eq!(list.index_at(&style, size, Point::new(5.0, 25.0)), Some(0));
+    }
+}
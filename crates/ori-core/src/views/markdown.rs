@@ -0,0 +1,670 @@
+use ori_macro::Build;
+use smol_str::SmolStr;
+
+use crate::{
+    canvas::{BorderRadius, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    image::Image,
+    layout::{Align, Alignment, Padding, Size, Space, FILL},
+    style::{style, Style, Styles},
+    text::{FontFamily, FontStyle, FontWeight, TextWrap},
+    view::{BoxedView, Pod, State, View},
+};
+
+use super::{
+    align, background, container, expand, height, hscroll, hstack_any, pad, rich_text, span, text,
+    vstack_any, width, RichText, Stack,
+};
+
+use self::parse::{Block, ColumnAlign, Inline};
+
+mod parse;
+
+/// Create a new [`Markdown`].
+pub fn markdown<T>(text: impl Into<String>) -> Markdown<T> {
+    Markdown::new(text)
+}
+
+/// The style of a markdown view.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarkdownStyle {
+    /// The font size of the text.
+    pub font_size: f32,
+
+    /// The font family of the text.
+    pub font_family: FontFamily,
+
+    /// The font family of code.
+    pub code_font_family: FontFamily,
+
+    /// The line height of the text.
+    pub line_height: f32,
+
+    /// The font size of each heading level, relative to the font size of the text.
+    pub heading_scale: [f32; 6],
+
+    /// The font weight of headings.
+    pub heading_weight: FontWeight,
+
+    /// The color of the text.
+    pub color: Color,
+
+    /// The color of the alt text of images that can't be loaded, and of struck out text.
+    pub muted_color: Color,
+
+    /// The color of links.
+    pub link_color: Color,
+
+    /// The color of code.
+    pub code_color: Color,
+
+    /// The background color of code blocks.
+    pub code_background: Color,
+
+    /// The padding of code blocks.
+    pub code_padding: Padding,
+
+    /// The border radius of code blocks.
+    pub code_border_radius: BorderRadius,
+
+    /// The color of the bar beside block quotes.
+    pub quote_color: Color,
+
+    /// The width of the bar beside block quotes.
+    pub quote_width: f32,
+
+    /// The color of thematic breaks.
+    pub rule_color: Color,
+
+    /// The color of the borders of tables.
+    pub table_border_color: Color,
+
+    /// The background color of the header row of tables.
+    pub table_header_background: Color,
+
+    /// The padding of table cells.
+    pub table_padding: Padding,
+
+    /// The gap between blocks.
+    pub block_gap: f32,
+
+    /// The indentation of list items.
+    pub list_indent: f32,
+}
+
+impl Style for MarkdownStyle {
+    fn styled(style: &Styles) -> Self {
+        let palette = style.palette();
+
+        Self {
+            font_size: 16.0,
+            font_family: FontFamily::SansSerif,
+            code_font_family: FontFamily::Monospace,
+            line_height: 1.4,
+            heading_scale: [2.0, 1.5, 1.25, 1.125, 1.0, 0.875],
+            heading_weight: FontWeight::BOLD,
+            color: palette.contrast,
+            muted_color: palette.contrast_low,
+            link_color: palette.primary,
+            code_color: palette.contrast,
+            code_background: palette.surface_high,
+            code_padding: Padding::all(12.0),
+            code_border_radius: BorderRadius::all(6.0),
+            quote_color: palette.outline,
+            quote_width: 4.0,
+            rule_color: palette.outline_low,
+            table_border_color: palette.outline_low,
+            table_header_background: palette.surface_high,
+            table_padding: Padding::from([8.0, 4.0]),
+            block_gap: 12.0,
+            list_indent: 24.0,
+        }
+    }
+}
+
+/// A view that displays [CommonMark](https://commonmark.org) markdown.
+///
+/// Headings, paragraphs with inline emphasis, code spans and blocks, lists, block quotes,
+/// thematic breaks, images, links and tables are supported. Images are loaded from paths
+/// when the `image` feature is enabled, see [`Markdown::load_image`] to load them otherwise.
+///
+/// Can be styled using the [`MarkdownStyle`].
+#[derive(Build)]
+pub struct Markdown<T> {
+    /// The markdown text.
+    pub text: String,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_link: Option<Box<dyn FnMut(&mut EventCx, &mut T, &str)>>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    load_image: Option<Box<dyn FnMut(&str) -> Option<Image>>>,
+
+    /// The font size of the text.
+    pub font_size: f32,
+
+    /// The font family of the text.
+    pub font_family: FontFamily,
+
+    /// The font family of code.
+    pub code_font_family: FontFamily,
+
+    /// The line height of the text.
+    pub line_height: f32,
+
+    /// The font size of each heading level, relative to the font size of the text.
+    pub heading_scale: [f32; 6],
+
+    /// The font weight of headings.
+    pub heading_weight: FontWeight,
+
+    /// The color of the text.
+    pub color: Color,
+
+    /// The color of the alt text of images that can't be loaded, and of struck out text.
+    pub muted_color: Color,
+
+    /// The color of links.
+    pub link_color: Color,
+
+    /// The color of code.
+    pub code_color: Color,
+
+    /// The background color of code blocks.
+    pub code_background: Color,
+
+    /// The padding of code blocks.
+    pub code_padding: Padding,
+
+    /// The border radius of code blocks.
+    pub code_border_radius: BorderRadius,
+
+    /// The color of the bar beside block quotes.
+    pub quote_color: Color,
+
+    /// The width of the bar beside block quotes.
+    pub quote_width: f32,
+
+    /// The color of thematic breaks.
+    pub rule_color: Color,
+
+    /// The color of the borders of tables.
+    pub table_border_color: Color,
+
+    /// The background color of the header row of tables.
+    pub table_header_background: Color,
+
+    /// The padding of table cells.
+    pub table_padding: Padding,
+
+    /// The gap between blocks.
+    pub block_gap: f32,
+
+    /// The indentation of list items.
+    pub list_indent: f32,
+}
+
+impl<T> Markdown<T> {
+    /// Create a new [`Markdown`].
+    pub fn new(text: impl Into<String>) -> Self {
+        Self::styled(text, style())
+    }
+
+    /// Create a new [`Markdown`] with a style.
+    pub fn styled(text: impl Into<String>, style: MarkdownStyle) -> Self {
+        Self {
+            text: text.into(),
+            on_link: None,
+            load_image: None,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            code_font_family: style.code_font_family,
+            line_height: style.line_height,
+            heading_scale: style.heading_scale,
+            heading_weight: style.heading_weight,
+            color: style.color,
+            muted_color: style.muted_color,
+            link_color: style.link_color,
+            code_color: style.code_color,
+            code_background: style.code_background,
+            code_padding: style.code_padding,
+            code_border_radius: style.code_border_radius,
+            quote_color: style.quote_color,
+            quote_width: style.quote_width,
+            rule_color: style.rule_color,
+            table_border_color: style.table_border_color,
+            table_header_background: style.table_header_background,
+            table_padding: style.table_padding,
+            block_gap: style.block_gap,
+            list_indent: style.list_indent,
+        }
+    }
+
+    /// Set the callback that is called when a link is clicked, with the destination of the link.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_link(mut self, on_link: impl FnMut(&mut EventCx, &mut T, &str) + 'static) -> Self {
+        self.on_link = Some(Box::new(on_link));
+        self
+    }
+
+    /// Set the function used to load images, with the source of the image.
+    ///
+    /// Images that can't be loaded are replaced by their alt text.
+    pub fn load_image(mut self, load_image: impl FnMut(&str) -> Option<Image> + 'static) -> Self {
+        self.load_image = Some(Box::new(load_image));
+        self
+    }
+
+    fn style(&self) -> MarkdownStyle {
+        MarkdownStyle {
+            font_size: self.font_size,
+            font_family: self.font_family.clone(),
+            code_font_family: self.code_font_family.clone(),
+            line_height: self.line_height,
+            heading_scale: self.heading_scale,
+            heading_weight: self.heading_weight,
+            color: self.color,
+            muted_color: self.muted_color,
+            link_color: self.link_color,
+            code_color: self.code_color,
+            code_background: self.code_background,
+            code_padding: self.code_padding,
+            code_border_radius: self.code_border_radius,
+            quote_color: self.quote_color,
+            quote_width: self.quote_width,
+            rule_color: self.rule_color,
+            table_border_color: self.table_border_color,
+            table_header_background: self.table_header_background,
+            table_padding: self.table_padding,
+            block_gap: self.block_gap,
+            list_indent: self.list_indent,
+        }
+    }
+
+    fn content(&mut self, style: &MarkdownStyle) -> Pod<BoxedView<Link>> {
+        let blocks = parse::parse(&self.text);
+
+        let mut load_image = |src: &str| match self.load_image {
+            Some(ref mut load_image) => load_image(src),
+            None => default_image(src),
+        };
+
+        let mut content = Content {
+            style,
+            load_image: &mut load_image,
+        };
+
+        Pod::new(Box::new(content.blocks(&blocks, 0)))
+    }
+}
+
+#[cfg(feature = "image")]
+fn default_image(src: &str) -> Option<Image> {
+    Image::try_load(src).ok()
+}
+
+#[cfg(not(feature = "image"))]
+fn default_image(_src: &str) -> Option<Image> {
+    None
+}
+
+// the destination of the link clicked in the content
+type Link = Option<SmolStr>;
+
+// builds the views of the blocks
+struct Content<'a> {
+    style: &'a MarkdownStyle,
+    load_image: &'a mut dyn FnMut(&str) -> Option<Image>,
+}
+
+impl Content<'_> {
+    fn blocks(&mut self, blocks: &[Block], depth: usize) -> Stack<Vec<BoxedView<Link>>> {
+        let mut stack = vstack_any().align(Align::Start).gap(self.style.block_gap);
+
+        for block in blocks {
+            stack.push(self.block(block, depth));
+        }
+
+        stack
+    }
+
+    fn block(&mut self, block: &Block, depth: usize) -> BoxedView<Link> {
+        let style = self.style;
+
+        match block {
+            Block::Heading(level, inlines) => {
+                let font_size = style.font_size * style.heading_scale[*level as usize - 1];
+                self.paragraph(inlines, font_size, style.heading_weight)
+            }
+            Block::Paragraph(inlines) => {
+                self.paragraph(inlines, style.font_size, FontWeight::NORMAL)
+            }
+            Block::Code { code, .. } => {
+                let code = text(code.as_str())
+                    .font_size(style.font_size * 0.875)
+                    .font_family(style.code_font_family.clone())
+                    .line_height(style.line_height)
+                    .color(style.code_color)
+                    .wrap(TextWrap::None);
+
+                let block = container(hscroll(pad(style.code_padding, code)))
+                    .background(style.code_background)
+                    .border_radius(style.code_border_radius);
+
+                Box::new(width(FILL, block))
+            }
+            Block::Quote(blocks) => {
+                let content = pad([style.list_indent / 2.0, 0.0], self.blocks(blocks, depth));
+
+                let quote = container(content)
+                    .background(Color::TRANSPARENT)
+                    .border_color(style.quote_color)
+                    .border_left(style.quote_width);
+
+                Box::new(quote)
+            }
+            Block::List {
+                start,
+                tight,
+                items,
+            } => {
+                let gap = if *tight {
+                    style.block_gap / 3.0
+                } else {
+                    style.block_gap
+                };
+                let mut list = vstack_any().align(Align::Start).gap(gap);
+
+                for (i, item) in items.iter().enumerate() {
+                    let marker = match start {
+                        Some(start) => format!("{}.", start + i as u64),
+                        None => String::from(BULLETS[depth % BULLETS.len()]),
+                    };
+
+                    let marker = text(marker)
+                        .font_size(style.font_size)
+                        .font_family(style.font_family.clone())
+                        .line_height(style.line_height)
+                        .color(style.color);
+
+                    let mut blocks = self.blocks(item, depth + 1);
+                    blocks.gap = gap;
+
+                    let mut item = hstack_any().align(Align::Baseline);
+                    item.push(Box::new(width(style.list_indent, marker)));
+                    item.push(Box::new(expand(blocks)));
+
+                    list.push(Box::new(item));
+                }
+
+                Box::new(list)
+            }
+            Block::Table { align, head, rows } => {
+                let mut table = vstack_any().align(Align::Fill);
+
+                let header = self.row(head, align, true);
+                table.push(Box::new(background(style.table_header_background, header)));
+
+                for row in rows {
+                    table.push(Box::new(self.row(row, align, false)));
+                }
+
+                let table = container(table)
+                    .background(Color::TRANSPARENT)
+                    .border_color(style.table_border_color)
+                    .border_width(1.0);
+
+                Box::new(width(FILL, table))
+            }
+            Block::Rule => {
+                let rule = background(style.rule_color, height(1.0, ()));
+                Box::new(width(FILL, rule))
+            }
+        }
+    }
+
+    fn row(
+        &mut self,
+        cells: &[Vec<Inline>],
+        columns: &[ColumnAlign],
+        header: bool,
+    ) -> Stack<Vec<BoxedView<Link>>> {
+        let style = self.style;
+        let mut row = hstack_any().align(Align::Stretch);
+
+        let weight = match header {
+            true => FontWeight::BOLD,
+            false => FontWeight::NORMAL,
+        };
+
+        for (cell, column) in cells.iter().zip(columns) {
+            let alignment = match column {
+                ColumnAlign::None | ColumnAlign::Left => Alignment::LEFT,
+                ColumnAlign::Center => Alignment::CENTER,
+                ColumnAlign::Right => Alignment::RIGHT,
+            };
+
+            let content = self.paragraph(cell, style.font_size, weight);
+            let cell = container(pad(style.table_padding, align(alignment, content)))
+                .background(Color::TRANSPARENT)
+                .border_color(style.table_border_color)
+                .border_width(0.5);
+
+            row.push(Box::new(expand(cell)));
+        }
+
+        row
+    }
+
+    // a paragraph of text, interrupted by images
+    fn paragraph(
+        &mut self,
+        inlines: &[Inline],
+        font_size: f32,
+        weight: FontWeight,
+    ) -> BoxedView<Link> {
+        let mut pieces: Vec<BoxedView<Link>> = Vec::new();
+        let mut spans = Vec::new();
+
+        for inline in inlines {
+            match inline {
+                Inline::Text(text, inline) => {
+                    let mut span = span(text.as_str());
+
+                    if inline.strong {
+                        span = span.font_weight(FontWeight::BOLD);
+                    }
+
+                    if inline.emphasis {
+                        span = span.font_style(FontStyle::Italic);
+                    }
+
+                    if inline.strike {
+                        span = span.color(self.style.muted_color);
+                    }
+
+                    if inline.code {
+                        span = span
+                            .font_family(self.style.code_font_family.clone())
+                            .color(self.style.code_color);
+                    }
+
+                    if let Some(ref destination) = inline.link {
+                        let destination = SmolStr::from(destination);
+
+                        span = span
+                            .color(self.style.link_color)
+                            .on_click(move |_, link: &mut Link| *link = Some(destination.clone()));
+                    }
+
+                    spans.push(span);
+                }
+                Inline::Image { src, alt } => {
+                    if !spans.is_empty() {
+                        let text = self.rich_text(std::mem::take(&mut spans), font_size, weight);
+                        pieces.push(Box::new(text));
+                    }
+
+                    match (self.load_image)(src) {
+                        Some(image) => pieces.push(Box::new(image)),
+                        None => {
+                            let alt = span(alt.as_str())
+                                .font_style(FontStyle::Italic)
+                                .color(self.style.muted_color);
+
+                            pieces.push(Box::new(self.rich_text(vec![alt], font_size, weight)));
+                        }
+                    }
+                }
+            }
+        }
+
+        if !spans.is_empty() {
+            pieces.push(Box::new(self.rich_text(spans, font_size, weight)));
+        }
+
+        if pieces.len() == 1 {
+            return pieces.pop().unwrap();
+        }
+
+        let mut paragraph = vstack_any().align(Align::Start);
+
+        for piece in pieces {
+            paragraph.push(piece);
+        }
+
+        Box::new(paragraph)
+    }
+
+    fn rich_text(
+        &self,
+        spans: Vec<super::Span<Link>>,
+        font_size: f32,
+        weight: FontWeight,
+    ) -> RichText<Link> {
+        rich_text(spans)
+            .font_size(font_size)
+            .font_family(self.style.font_family.clone())
+            .font_weight(weight)
+            .line_height(self.style.line_height)
+            .color(self.style.color)
+    }
+}
+
+const BULLETS: [&str; 3] = ["•", "◦", "▪"];
+
+#[doc(hidden)]
+pub struct MarkdownState {
+    content: Pod<BoxedView<Link>>,
+    state: State<Link, BoxedView<Link>>,
+    style: MarkdownStyle,
+}
+
+impl<T> View<T> for Markdown<T> {
+    type State = MarkdownState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        let style = self.style();
+        let mut content = self.content(&style);
+        let state = content.build(cx, &mut None);
+
+        MarkdownState {
+            content,
+            state,
+            style,
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        let style = self.style();
+
+        // the content is only built again when the text or the style changes
+        if self.text != old.text || style != state.style {
+            let mut content = self.content(&style);
+            state.state = content.build(&mut cx.as_build_cx(), &mut None);
+            state.content = content;
+            state.style = style;
+
+            cx.layout();
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        let mut link = None;
+        (state.content).event(&mut state.state, cx, &mut link, event);
+
+        if let (Some(link), Some(on_link)) = (link, &mut self.on_link) {
+            on_link(cx, data, &link);
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        (state.content).layout(&mut state.state, cx, &mut None, space)
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        (state.content).draw(&mut state.state, cx, &mut None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Modifiers, PointerButton, PointerId, PointerPressed, PointerReleased},
+        layout::{Point, Space},
+        views::testing::ViewTester,
+        window::{Pointer, Window},
+    };
+
+    use super::markdown;
+
+    #[test]
+    fn click_link() {
+        let mut clicked = Vec::new();
+
+        let mut view = markdown("[docs](https://example.com)")
+            .on_link(|_, clicked: &mut Vec<String>, link| clicked.push(link.to_string()));
+
+        let mut tester = ViewTester::new(&mut view, &mut clicked);
+        let size = tester.layout(&mut view, &mut clicked, Space::UNBOUNDED);
+        let position = Point::new(size.width / 2.0, size.height / 2.0);
+
+        // hover the link, like the app would after drawing
+        let canvas = tester.draw(&mut view, &mut clicked);
+        let mut pointer = Pointer::new(PointerId::from_hash(&0), position);
+        pointer.hovering = canvas.view_at(position);
+
+        let window = tester.contexts.get_mut::<Window>().unwrap();
+        window.pointers_mut().push(pointer);
+
+        let pressed = Event::PointerPressed(PointerPressed {
+            id: PointerId::from_hash(&0),
+            position,
+            button: PointerButton::Primary,
+            modifiers: Modifiers::default(),
+        });
+
+        let released = Event::PointerReleased(PointerReleased {
+            id: PointerId::from_hash(&0),
+            position,
+            button: PointerButton::Primary,
+            modifiers: Modifiers::default(),
+            clicked: true,
+        });
+
+        tester.event(&mut view, &mut clicked, &pressed);
+        tester.event(&mut view, &mut clicked, &released);
+
+        assert_eq!(clicked, ["https://example.com"]);
+    }
+}
@@ -0,0 +1,489 @@
+//! Blocks of markdown, built from the events of [`pulldown_cmark`], with the table and
+//! strikethrough extensions from GitHub Flavored Markdown.
+
+use std::mem;
+
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+
+/// A block of markdown.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Block {
+    /// A heading, with a level from 1 to 6.
+    Heading(u8, Vec<Inline>),
+
+    /// A paragraph.
+    Paragraph(Vec<Inline>),
+
+    /// A fenced or indented code block, with the info string of the fence.
+    Code { info: String, code: String },
+
+    /// A block quote.
+    Quote(Vec<Block>),
+
+    /// A list, `start` is the number of the first item of an ordered list.
+    List {
+        start: Option<u64>,
+        tight: bool,
+        items: Vec<Vec<Block>>,
+    },
+
+    /// A table.
+    Table {
+        align: Vec<ColumnAlign>,
+        head: Vec<Vec<Inline>>,
+        rows: Vec<Vec<Vec<Inline>>>,
+    },
+
+    /// A thematic break.
+    Rule,
+}
+
+/// The alignment of a table column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum ColumnAlign {
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+/// A run of inline content.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Inline {
+    /// Text with a style, hard line breaks are `\n`.
+    Text(String, InlineStyle),
+
+    /// An image.
+    Image { src: String, alt: String },
+}
+
+/// The style of a run of text.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct InlineStyle {
+    pub emphasis: bool,
+    pub strong: bool,
+    pub strike: bool,
+    pub code: bool,
+    pub link: Option<String>,
+}
+
+/// Parse markdown into blocks.
+pub(super) fn parse(text: &str) -> Vec<Block> {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+
+    let mut builder = Builder::default();
+
+    for event in Parser::new_ext(text, options) {
+        builder.event(event);
+    }
+
+    builder.finish()
+}
+
+// a block containing other blocks, that is still being built
+enum Container {
+    Quote,
+    List {
+        start: Option<u64>,
+        tight: bool,
+        items: Vec<Vec<Block>>,
+    },
+    Item,
+}
+
+#[derive(Default)]
+struct Table {
+    align: Vec<ColumnAlign>,
+    head: Vec<Vec<Inline>>,
+    rows: Vec<Vec<Vec<Inline>>>,
+    in_head: bool,
+}
+
+#[derive(Default)]
+struct Builder {
+    // the open containers, with the blocks inside each of them, the root is at the bottom
+    containers: Vec<(Container, Vec<Block>)>,
+    blocks: Vec<Block>,
+
+    inlines: Vec<Inline>,
+    styles: Vec<InlineStyle>,
+
+    // the info string and text of the open code block
+    code: Option<(String, String)>,
+
+    // the source and alt text of the open image
+    image: Option<(String, String)>,
+
+    table: Option<Table>,
+}
+
+impl Builder {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.text(&text),
+            Event::Code(text) => {
+                let mut style = self.style();
+                style.code = true;
+                self.push_text(&text, style);
+            }
+            Event::Html(html) | Event::InlineHtml(html) => self.text(&html),
+            Event::SoftBreak => self.text(" "),
+            Event::HardBreak => self.text("\n"),
+            Event::Rule => self.push_block(Block::Rule),
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => {
+                // paragraphs are only in the items of loose lists
+                if let [.., (Container::List { tight, .. }, _), (Container::Item, _)] =
+                    self.containers.as_mut_slice()
+                {
+                    *tight = false;
+                }
+
+                self.flush();
+            }
+            Tag::Heading { .. } => self.flush(),
+            Tag::BlockQuote(_) => self.open(Container::Quote),
+            Tag::CodeBlock(kind) => {
+                self.flush();
+
+                let info = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+
+                self.code = Some((info, String::new()));
+            }
+            Tag::List(start) => self.open(Container::List {
+                start,
+                tight: true,
+                items: Vec::new(),
+            }),
+            Tag::Item => self.open(Container::Item),
+            Tag::Table(align) => {
+                self.flush();
+
+                let align = align.into_iter().map(|align| match align {
+                    Alignment::None => ColumnAlign::None,
+                    Alignment::Left => ColumnAlign::Left,
+                    Alignment::Center => ColumnAlign::Center,
+                    Alignment::Right => ColumnAlign::Right,
+                });
+
+                self.table = Some(Table {
+                    align: align.collect(),
+                    ..Default::default()
+                });
+            }
+            Tag::TableHead => {
+                if let Some(ref mut table) = self.table {
+                    table.in_head = true;
+                }
+            }
+            Tag::TableRow => {
+                if let Some(ref mut table) = self.table {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::Emphasis => self.push_style(|style| style.emphasis = true),
+            Tag::Strong => self.push_style(|style| style.strong = true),
+            Tag::Strikethrough => self.push_style(|style| style.strike = true),
+            Tag::Link { dest_url, .. } => {
+                self.push_style(|style| style.link = Some(dest_url.to_string()));
+            }
+            Tag::Image { dest_url, .. } => {
+                self.image = Some((dest_url.to_string(), String::new()));
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                let inlines = mem::take(&mut self.inlines);
+                self.push_block(Block::Paragraph(inlines));
+            }
+            TagEnd::Heading(level) => {
+                let inlines = mem::take(&mut self.inlines);
+                self.push_block(Block::Heading(level as u8, inlines));
+            }
+            TagEnd::CodeBlock => {
+                if let Some((info, mut code)) = self.code.take() {
+                    if code.ends_with('\n') {
+                        code.pop();
+                    }
+
+                    self.push_block(Block::Code { info, code });
+                }
+            }
+            TagEnd::BlockQuote(_) | TagEnd::List(_) | TagEnd::Item => self.close(),
+            TagEnd::TableHead => {
+                if let Some(ref mut table) = self.table {
+                    table.in_head = false;
+                }
+            }
+            TagEnd::TableCell => {
+                let cell = mem::take(&mut self.inlines);
+
+                if let Some(ref mut table) = self.table {
+                    if table.in_head {
+                        table.head.push(cell);
+                    } else if let Some(row) = table.rows.last_mut() {
+                        row.push(cell);
+                    }
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.push_block(Block::Table {
+                        align: table.align,
+                        head: table.head,
+                        rows: table.rows,
+                    });
+                }
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Link => {
+                self.styles.pop();
+            }
+            TagEnd::Image => {
+                if let Some((src, alt)) = self.image.take() {
+                    self.inlines.push(Inline::Image { src, alt });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> Vec<Block> {
+        while !self.containers.is_empty() {
+            self.close();
+        }
+
+        self.flush();
+        self.blocks
+    }
+
+    fn text(&mut self, text: &str) {
+        match self.code {
+            Some((_, ref mut code)) => code.push_str(text),
+            None => self.push_text(text, self.style()),
+        }
+    }
+
+    fn style(&self) -> InlineStyle {
+        self.styles.last().cloned().unwrap_or_default()
+    }
+
+    fn push_style(&mut self, f: impl FnOnce(&mut InlineStyle)) {
+        let mut style = self.style();
+        f(&mut style);
+        self.styles.push(style);
+    }
+
+    // push text, merging it with the previous run when the styles match, the text of an image
+    // is its alt text
+    fn push_text(&mut self, text: &str, style: InlineStyle) {
+        if let Some((_, ref mut alt)) = self.image {
+            alt.push_str(text);
+            return;
+        }
+
+        match self.inlines.last_mut() {
+            Some(Inline::Text(last, last_style)) if *last_style == style => last.push_str(text),
+            _ => self.inlines.push(Inline::Text(text.to_string(), style)),
+        }
+    }
+
+    fn push_block(&mut self, block: Block) {
+        self.flush();
+
+        match self.containers.last_mut() {
+            Some((_, blocks)) => blocks.push(block),
+            None => self.blocks.push(block),
+        }
+    }
+
+    // the text of tight list items isn't in a paragraph, so it's wrapped in one here
+    fn flush(&mut self) {
+        if !self.inlines.is_empty() && self.table.is_none() {
+            let inlines = mem::take(&mut self.inlines);
+            self.push_block(Block::Paragraph(inlines));
+        }
+    }
+
+    fn open(&mut self, container: Container) {
+        self.flush();
+        self.containers.push((container, Vec::new()));
+    }
+
+    fn close(&mut self) {
+        self.flush();
+
+        let Some((container, blocks)) = self.containers.pop() else {
+            return;
+        };
+
+        match container {
+            Container::Quote => self.push_block(Block::Quote(blocks)),
+            Container::List {
+                start,
+                tight,
+                items,
+            } => {
+                self.push_block(Block::List {
+                    start,
+                    tight,
+                    items,
+                });
+            }
+            Container::Item => {
+                if let Some((Container::List { items, .. }, _)) = self.containers.last_mut() {
+                    items.push(blocks);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Block, ColumnAlign, Inline, InlineStyle};
+
+    fn text(text: &str) -> Inline {
+        Inline::Text(text.to_string(), InlineStyle::default())
+    }
+
+    fn styled(text: &str, f: impl FnOnce(&mut InlineStyle)) -> Inline {
+        let mut style = InlineStyle::default();
+        f(&mut style);
+        Inline::Text(text.to_string(), style)
+    }
+
+    #[test]
+    fn blocks_and_inlines() {
+        let markdown = "\
+# Release *notes*
+
+Some **bold** and *emphasis*, `code` and a [link][docs].
+Soft broken.
+
+- one
+- two
+  1. nested
+
+> quoted
+lazily
+
+```rust
+fn main() {}
+```
+
+| Name | Value |
+|:-----|------:|
+| a    | 1     |
+
+---
+
+[docs]: https://example.com
+";
+
+        let blocks = parse(markdown);
+
+        let expected = vec![
+            Block::Heading(
+                1,
+                vec![text("Release "), styled("notes", |s| s.emphasis = true)],
+            ),
+            Block::Paragraph(vec![
+                text("Some "),
+                styled("bold", |s| s.strong = true),
+                text(" and "),
+                styled("emphasis", |s| s.emphasis = true),
+                text(", "),
+                styled("code", |s| s.code = true),
+                text(" and a "),
+                styled("link", |s| s.link = Some("https://example.com".into())),
+                text(". Soft broken."),
+            ]),
+            Block::List {
+                start: None,
+                tight: true,
+                items: vec![
+                    vec![Block::Paragraph(vec![text("one")])],
+                    vec![
+                        Block::Paragraph(vec![text("two")]),
+                        Block::List {
+                            start: Some(1),
+                            tight: true,
+                            items: vec![vec![Block::Paragraph(vec![text("nested")])]],
+                        },
+                    ],
+                ],
+            },
+            Block::Quote(vec![Block::Paragraph(vec![text("quoted lazily")])]),
+            Block::Code {
+                info: String::from("rust"),
+                code: String::from("fn main() {}"),
+            },
+            Block::Table {
+                align: vec![ColumnAlign::Left, ColumnAlign::Right],
+                head: vec![vec![text("Name")], vec![text("Value")]],
+                rows: vec![vec![vec![text("a")], vec![text("1")]]],
+            },
+            Block::Rule,
+        ];
+
+        assert_eq!(blocks, expected);
+    }
+
+    #[test]
+    fn emphasis_and_images() {
+        let blocks = parse("***both*** and _a_b_ ~~gone~~ ![alt *text*](img.png)\\\nnext");
+
+        let expected = vec![Block::Paragraph(vec![
+            styled("both", |s| {
+                s.emphasis = true;
+                s.strong = true;
+            }),
+            text(" and "),
+            styled("a_b", |s| s.emphasis = true),
+            text(" "),
+            styled("gone", |s| s.strike = true),
+            text(" "),
+            Inline::Image {
+                src: String::from("img.png"),
+                alt: String::from("alt text"),
+            },
+            text("\nnext"),
+        ])];
+
+        assert_eq!(blocks, expected);
+    }
+
+    #[test]
+    fn loose_lists() {
+        let blocks = parse("1. one\n\n2. two\n");
+
+        let expected = vec![Block::List {
+            start: Some(1),
+            tight: false,
+            items: vec![
+                vec![Block::Paragraph(vec![text("one")])],
+                vec![Block::Paragraph(vec![text("two")])],
+            ],
+        }];
+
+        assert_eq!(blocks, expected);
+    }
+}
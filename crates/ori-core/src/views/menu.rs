@@ -0,0 +1,1505 @@
+use std::{any::Any, fmt::Display, rc::Rc};
+
+use ori_macro::Build;
+use smol_str::SmolStr;
+
+use crate::{
+    canvas::{BorderRadius, Color, Curve},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, KeyPressed, Modifiers, PointerButton},
+    layout::{Padding, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    text::{FontFamily, Fonts, TextAttributes, TextBuffer, TextWrap},
+    view::View,
+    window::Cursor,
+};
+
+use super::TextStyle;
+
+const LINE_HEIGHT: f32 = 1.2;
+
+// the space around the rows of a popup
+const INSET: f32 = 4.0;
+
+/// Create a new [`MenuBar`].
+pub fn menu_bar(menus: impl IntoIterator<Item = Menu>) -> MenuBar {
+    MenuBar::new(menus)
+}
+
+/// A keyboard shortcut, like `Ctrl+S`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Accelerator {
+    /// The modifiers that must be held down.
+    pub modifiers: Modifiers,
+
+    /// The key that must be pressed.
+    pub key: Key,
+}
+
+const NAMED_KEYS: &[(&str, Key)] = &[
+    ("Enter", Key::Enter),
+    ("Tab", Key::Tab),
+    ("Escape", Key::Escape),
+    ("Esc", Key::Escape),
+    ("Space", Key::Character(' ')),
+    ("Backspace", Key::Backspace),
+    ("Delete", Key::Delete),
+    ("Del", Key::Delete),
+    ("Insert", Key::Insert),
+    ("Home", Key::Home),
+    ("End", Key::End),
+    ("PageUp", Key::PageUp),
+    ("PageDown", Key::PageDown),
+    ("Up", Key::Up),
+    ("Down", Key::Down),
+    ("Left", Key::Left),
+    ("Right", Key::Right),
+    ("F1", Key::F1),
+    ("F2", Key::F2),
+    ("F3", Key::F3),
+    ("F4", Key::F4),
+    ("F5", Key::F5),
+    ("F6", Key::F6),
+    ("F7", Key::F7),
+    ("F8", Key::F8),
+    ("F9", Key::F9),
+    ("F10", Key::F10),
+    ("F11", Key::F11),
+    ("F12", Key::F12),
+];
+
+impl Accelerator {
+    /// Create a new [`Accelerator`].
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Parse an accelerator like `Ctrl+Shift+S` or `F5`.
+    ///
+    /// Returns `None` if the accelerator is invalid.
+    pub fn parse(accelerator: &str) -> Option<Self> {
+        let mut modifiers = Modifiers::default();
+        let mut parts = accelerator.split('+').map(str::trim).peekable();
+
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                let key = match NAMED_KEYS
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(part))
+                {
+                    Some((_, key)) => *key,
+                    None => {
+                        let mut chars = part.chars();
+                        let c = chars.next()?;
+
+                        if chars.next().is_some() {
+                            return None;
+                        }
+
+                        Key::Character(c.to_ascii_lowercase())
+                    }
+                };
+
+                return Some(Self::new(modifiers, key));
+            }
+
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => modifiers.ctrl = true,
+                "shift" => modifiers.shift = true,
+                "alt" | "option" => modifiers.alt = true,
+                "meta" | "cmd" | "super" => modifiers.meta = true,
+                _ => return None,
+            }
+        }
+
+        None
+    }
+
+    /// Check whether a key press matches the accelerator.
+    pub fn matches(&self, event: &KeyPressed) -> bool {
+        let key = match (self.key, event.key) {
+            (Key::Character(a), Key::Character(b)) => a.eq_ignore_ascii_case(&b),
+            (a, b) => a == b,
+        };
+
+        key && self.modifiers == event.modifiers
+    }
+}
+
+impl From<&str> for Accelerator {
+    /// Parse an accelerator, see [`Accelerator::parse`].
+    ///
+    /// # Panics
+    /// - If the accelerator is invalid.
+    fn from(accelerator: &str) -> Self {
+        match Self::parse(accelerator) {
+            Some(accelerator) => accelerator,
+            None => panic!("invalid accelerator `{}`", accelerator),
+        }
+    }
+}
+
+impl Display for Accelerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.modifiers.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+
+        if self.modifiers.alt {
+            write!(f, "Alt+")?;
+        }
+
+        if self.modifiers.shift {
+            write!(f, "Shift+")?;
+        }
+
+        if self.modifiers.meta {
+            write!(f, "Meta+")?;
+        }
+
+        match self.key {
+            Key::Character(' ') => write!(f, "Space"),
+            Key::Character(c) => write!(f, "{}", c.to_ascii_uppercase()),
+            key => write!(f, "{:?}", key),
+        }
+    }
+}
+
+/// An item of a [`Menu`].
+///
+/// A `&` in the label marks the next character as the mnemonic of the item, use `&&` for a
+/// literal `&`.
+#[derive(Clone, Build)]
+pub struct MenuItem {
+    /// The label of the item.
+    #[build(ignore)]
+    pub label: SmolStr,
+
+    /// The keyboard shortcut of the item, shown next to the label.
+    #[build(ignore)]
+    pub accelerator: Option<Accelerator>,
+
+    /// Whether the item is checked, `None` if the item can't be checked.
+    #[build(ignore)]
+    pub checked: Option<bool>,
+
+    /// Whether the item can be activated.
+    pub enabled: bool,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    command: Option<Rc<dyn Fn(&mut EventCx)>>,
+}
+
+impl MenuItem {
+    /// Create a new [`MenuItem`].
+    pub fn new(label: impl Into<SmolStr>) -> Self {
+        Self {
+            label: label.into(),
+            accelerator: None,
+            checked: None,
+            enabled: true,
+            command: None,
+        }
+    }
+
+    /// Set the keyboard shortcut of the item.
+    ///
+    /// # Panics
+    /// - If the accelerator is a string that can't be parsed.
+    pub fn accelerator(mut self, accelerator: impl Into<Accelerator>) -> Self {
+        self.accelerator = Some(accelerator.into());
+        self
+    }
+
+    /// Make the item checkable, with a check mark if `checked` is true.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Set the command that is sent when the item is activated.
+    pub fn command<C: Any + Send + Clone>(mut self, command: C) -> Self {
+        self.command = Some(Rc::new(move |cx| cx.cmd(command.clone())));
+        self
+    }
+
+    fn activate(&self, cx: &mut EventCx) {
+        if let (true, Some(command)) = (self.enabled, &self.command) {
+            command(cx);
+        }
+    }
+}
+
+// the commands are closures, and don't affect how the item looks
+impl PartialEq for MenuItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label
+            && self.accelerator == other.accelerator
+            && self.checked == other.checked
+            && self.enabled == other.enabled
+    }
+}
+
+/// An entry of a [`Menu`].
+#[derive(Clone, PartialEq)]
+pub enum MenuEntry {
+    /// An item.
+    Item(MenuItem),
+
+    /// A line separating groups of entries.
+    Separator,
+
+    /// A nested menu.
+    Submenu(Menu),
+}
+
+/// A menu, shown by a [`MenuBar`] or a [`ContextMenu`](super::ContextMenu).
+#[derive(Clone, Default, PartialEq)]
+pub struct Menu {
+    /// The title of the menu, shown in the menu bar or in the parent menu.
+    ///
+    /// Like the labels of items, the title can contain a mnemonic.
+    pub title: SmolStr,
+
+    /// The entries of the menu.
+    pub entries: Vec<MenuEntry>,
+}
+
+impl Menu {
+    /// Create a new empty [`Menu`].
+    pub fn new(title: impl Into<SmolStr>) -> Self {
+        Self {
+            title: title.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add an item to the menu.
+    pub fn item(mut self, item: MenuItem) -> Self {
+        self.entries.push(MenuEntry::Item(item));
+        self
+    }
+
+    /// Add a separator to the menu.
+    pub fn separator(mut self) -> Self {
+        self.entries.push(MenuEntry::Separator);
+        self
+    }
+
+    /// Add a nested menu to the menu.
+    pub fn submenu(mut self, menu: Menu) -> Self {
+        self.entries.push(MenuEntry::Submenu(menu));
+        self
+    }
+
+    // the menu at `path`, where each index is a submenu entry
+    fn menu_at(&self, path: &[usize]) -> Option<&Menu> {
+        let mut menu = self;
+
+        for &index in path {
+            match menu.entries.get(index)? {
+                MenuEntry::Submenu(submenu) => menu = submenu,
+                _ => return None,
+            }
+        }
+
+        Some(menu)
+    }
+
+    fn item_at(&self, path: &[usize]) -> Option<&MenuItem> {
+        let (&last, path) = path.split_last()?;
+
+        match self.menu_at(path)?.entries.get(last)? {
+            MenuEntry::Item(item) => Some(item),
+            _ => None,
+        }
+    }
+
+    // the enabled item with an accelerator matching `event`, including nested menus
+    fn find_accelerator(&self, event: &KeyPressed) -> Option<&MenuItem> {
+        self.entries.iter().find_map(|entry| match entry {
+            MenuEntry::Item(item) => {
+                let matches = item.accelerator.is_some_and(|a| a.matches(event));
+                (matches && item.enabled).then_some(item)
+            }
+            MenuEntry::Separator => None,
+            MenuEntry::Submenu(menu) => menu.find_accelerator(event),
+        })
+    }
+}
+
+// split a label into the text to show and the mnemonic, with the byte index of the mnemonic
+fn parse_mnemonic(label: &str) -> (String, Option<(char, usize)>) {
+    let mut text = String::with_capacity(label.len());
+    let mut mnemonic = None;
+    let mut chars = label.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '&' {
+            text.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('&') => text.push('&'),
+            Some(c) => {
+                if mnemonic.is_none() {
+                    mnemonic = Some((c.to_ascii_lowercase(), text.len()));
+                }
+
+                text.push(c);
+            }
+            None => {}
+        }
+    }
+
+    (text, mnemonic)
+}
+
+// the horizontal span of the glyph starting at byte `index`, and the baseline
+fn glyph_span(buffer: &TextBuffer, index: usize) -> Option<(f32, f32, f32)> {
+    for run in buffer.raw().layout_runs() {
+        for glyph in run.glyphs {
+            if glyph.start == index {
+                return Some((glyph.x, glyph.w, run.line_y));
+            }
+        }
+    }
+
+    None
+}
+
+/// The style of menus.
+#[derive(Clone, Debug)]
+pub struct MenuStyle {
+    /// The font size of the labels.
+    pub font_size: f32,
+
+    /// The font family of the labels.
+    pub font_family: FontFamily,
+
+    /// The color of the labels.
+    pub color: Color,
+
+    /// The color of disabled items.
+    pub disabled_color: Color,
+
+    /// The color of the accelerators.
+    pub accelerator_color: Color,
+
+    /// The padding of each item.
+    pub padding: Padding,
+
+    /// The minimum width of a popup menu.
+    pub min_width: f32,
+
+    /// The background color of a popup menu.
+    pub background: Color,
+
+    /// The background color of the highlighted item.
+    pub highlight: Color,
+
+    /// The color of the separators.
+    pub separator: Color,
+
+    /// The border radius of a popup menu.
+    pub border_radius: BorderRadius,
+
+    /// The border color of a popup menu.
+    pub border_color: Color,
+
+    /// The padding of the titles in the menu bar.
+    pub bar_padding: Padding,
+
+    /// The background color of the menu bar.
+    pub bar_background: Color,
+}
+
+impl Style for MenuStyle {
+    fn styled(style: &Styles) -> Self {
+        let text_style = style.get::<TextStyle>();
+        let palette = style.palette();
+
+        Self {
+            font_size: 14.0,
+            font_family: text_style.font_family.clone(),
+            color: palette.contrast,
+            disabled_color: palette.contrast_low.fade(0.5),
+            accelerator_color: palette.contrast_low,
+            padding: Padding::from([10.0, 6.0]),
+            min_width: 160.0,
+            background: palette.surface_high,
+            highlight: palette.surface_higher,
+            separator: palette.outline_low,
+            border_radius: BorderRadius::all(6.0),
+            border_color: palette.outline,
+            bar_padding: Padding::from([10.0, 6.0]),
+            bar_background: palette.surface,
+        }
+    }
+}
+
+impl MenuStyle {
+    fn item_height(&self) -> f32 {
+        self.font_size * LINE_HEIGHT + self.padding.size().height
+    }
+
+    fn separator_height(&self) -> f32 {
+        self.padding.size().height + 1.0
+    }
+
+    // the width of the column with check marks, and the column with submenu arrows
+    fn column_width(&self) -> f32 {
+        self.font_size + self.padding.left / 2.0
+    }
+
+    fn buffer(&self, fonts: &mut Fonts, text: &str) -> TextBuffer {
+        let mut buffer = TextBuffer::new(fonts, self.font_size, LINE_HEIGHT);
+        buffer.set_wrap(fonts, TextWrap::None);
+        buffer.set_text(
+            fonts,
+            text,
+            TextAttributes {
+                family: self.font_family.clone(),
+                ..Default::default()
+            },
+        );
+
+        buffer
+    }
+}
+
+// what happened after an event was handled by a popup
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum MenuAction {
+    None,
+    Handled,
+    Activate(Vec<usize>),
+    Close,
+    Previous,
+    Next,
+}
+
+struct PopupRow {
+    y: f32,
+    height: f32,
+    label: Option<TextBuffer>,
+    accelerator: Option<TextBuffer>,
+    mnemonic: Option<(char, usize)>,
+    checked: Option<bool>,
+    enabled: bool,
+    submenu: bool,
+}
+
+impl PopupRow {
+    fn is_selectable(&self) -> bool {
+        self.label.is_some() && self.enabled
+    }
+}
+
+struct PopupLevel {
+    path: Vec<usize>,
+    origin: Point,
+    size: Size,
+    rows: Vec<PopupRow>,
+    highlighted: Option<usize>,
+    // whether the level has been moved to fit in the window
+    fitted: bool,
+}
+
+impl PopupLevel {
+    fn new(fonts: &mut Fonts, style: &MenuStyle, menu: &Menu, path: Vec<usize>) -> Self {
+        let mut rows = Vec::with_capacity(menu.entries.len());
+        let mut y = INSET;
+        let mut width = 0.0f32;
+
+        for entry in &menu.entries {
+            let (label, accelerator, checked, enabled, submenu) = match entry {
+                MenuEntry::Item(item) => (
+                    Some(item.label.as_str()),
+                    item.accelerator,
+                    item.checked,
+                    item.enabled,
+                    false,
+                ),
+                MenuEntry::Submenu(menu) => (Some(menu.title.as_str()), None, None, true, true),
+                MenuEntry::Separator => (None, None, None, false, false),
+            };
+
+            let Some(label) = label else {
+                let height = style.separator_height();
+                rows.push(PopupRow {
+                    y,
+                    height,
+                    label: None,
+                    accelerator: None,
+                    mnemonic: None,
+                    checked: None,
+                    enabled: false,
+                    submenu: false,
+                });
+
+                y += height;
+                continue;
+            };
+
+            let (text, mnemonic) = parse_mnemonic(label);
+            let label = style.buffer(fonts, &text);
+            let accelerator = accelerator.map(|a| style.buffer(fonts, &a.to_string()));
+
+            let mut row_width = label.size().width + style.column_width() * 2.0;
+
+            if let Some(ref accelerator) = accelerator {
+                row_width += accelerator.size().width + style.font_size * 2.0;
+            }
+
+            width = width.max(row_width + style.padding.size().width);
+
+            let height = style.item_height();
+            rows.push(PopupRow {
+                y,
+                height,
+                label: Some(label),
+                accelerator,
+                mnemonic,
+                checked,
+                enabled,
+                submenu,
+            });
+
+            y += height;
+        }
+
+        Self {
+            path,
+            origin: Point::ZERO,
+            size: Size::new(width.max(style.min_width), y + INSET),
+            rows,
+            highlighted: None,
+            fitted: false,
+        }
+    }
+
+    fn rect(&self) -> Rect {
+        Rect::min_size(self.origin, self.size)
+    }
+
+    fn row_at(&self, point: Point) -> Option<usize> {
+        if !self.rect().contains(point) {
+            return None;
+        }
+
+        let y = point.y - self.origin.y;
+        (self.rows.iter()).position(|row| y >= row.y && y < row.y + row.height)
+    }
+
+    // the next selectable row in `direction`, wrapping around
+    fn step(&self, direction: isize) -> Option<usize> {
+        let count = self.rows.len() as isize;
+
+        let start = match self.highlighted {
+            Some(index) => index as isize,
+            None if direction > 0 => -1,
+            None => count,
+        };
+
+        (1..=count)
+            .map(|i| (start + i * direction).rem_euclid(count) as usize)
+            .find(|&i| self.rows[i].is_selectable())
+    }
+}
+
+// a stack of popup menus, each level is a submenu of the level before it
+pub(super) struct MenuPopup {
+    levels: Vec<PopupLevel>,
+}
+
+impl MenuPopup {
+    pub(super) fn new() -> Self {
+        Self { levels: Vec::new() }
+    }
+
+    pub(super) fn is_open(&self) -> bool {
+        !self.levels.is_empty()
+    }
+
+    // open `menu` at `origin`, local to the view that owns the popup
+    pub(super) fn open(
+        &mut self,
+        fonts: &mut Fonts,
+        style: &MenuStyle,
+        menu: &Menu,
+        origin: Point,
+        highlight: bool,
+    ) {
+        let mut level = PopupLevel::new(fonts, style, menu, Vec::new());
+        level.origin = origin;
+
+        if highlight {
+            level.highlighted = level.step(1);
+        }
+
+        self.levels = vec![level];
+    }
+
+    pub(super) fn close(&mut self) {
+        self.levels.clear();
+    }
+
+    pub(super) fn contains(&self, point: Point) -> bool {
+        self.levels.iter().any(|level| level.rect().contains(point))
+    }
+
+    fn open_submenu(&mut self, fonts: &mut Fonts, style: &MenuStyle, menu: &Menu, highlight: bool) {
+        let Some(parent) = self.levels.last() else {
+            return;
+        };
+
+        let Some(index) = parent.highlighted else {
+            return;
+        };
+
+        let row = &parent.rows[index];
+
+        if !row.submenu {
+            return;
+        }
+
+        let mut path = parent.path.clone();
+        path.push(index);
+
+        let Some(submenu) = menu.menu_at(&path) else {
+            return;
+        };
+
+        let origin = Point::new(
+            parent.origin.x + parent.size.width - INSET,
+            parent.origin.y + row.y - INSET,
+        );
+
+        let mut level = PopupLevel::new(fonts, style, submenu, path);
+        level.origin = origin;
+
+        if highlight {
+            level.highlighted = level.step(1);
+        }
+
+        self.levels.push(level);
+    }
+
+    // activate the highlighted row of the last level, or open it if it's a submenu
+    fn choose(&mut self, fonts: &mut Fonts, style: &MenuStyle, menu: &Menu) -> MenuAction {
+        let Some(level) = self.levels.last() else {
+            return MenuAction::None;
+        };
+
+        let Some(index) = level.highlighted else {
+            return MenuAction::Handled;
+        };
+
+        if level.rows[index].submenu {
+            self.open_submenu(fonts, style, menu, true);
+            return MenuAction::Handled;
+        }
+
+        if !level.rows[index].is_selectable() {
+            return MenuAction::Handled;
+        }
+
+        let mut path = level.path.clone();
+        path.push(index);
+
+        MenuAction::Activate(path)
+    }
+
+    pub(super) fn pointer_moved(
+        &mut self,
+        fonts: &mut Fonts,
+        style: &MenuStyle,
+        menu: &Menu,
+        point: Point,
+    ) -> bool {
+        // the deepest level under the pointer
+        let Some(index) = self.levels.iter().rposition(|l| l.rect().contains(point)) else {
+            // keep open submenus, but clear the highlight of the last level
+            let level = self.levels.last_mut();
+            let changed = level.as_ref().is_some_and(|l| l.highlighted.is_some());
+
+            if let Some(level) = level {
+                level.highlighted = None;
+            }
+
+            return changed;
+        };
+
+        let last = self.levels.len() == index + 1;
+        let level = &mut self.levels[index];
+        let row = level
+            .row_at(point)
+            .filter(|&i| level.rows[i].is_selectable());
+
+        // the submenu of the highlighted row is already open
+        let submenu = row.is_some_and(|i| level.rows[i].submenu);
+
+        if level.highlighted == row && (last || submenu) {
+            return false;
+        }
+
+        level.highlighted = row;
+        self.levels.truncate(index + 1);
+        self.open_submenu(fonts, style, menu, false);
+
+        true
+    }
+
+    pub(super) fn press(
+        &mut self,
+        fonts: &mut Fonts,
+        style: &MenuStyle,
+        menu: &Menu,
+        point: Point,
+    ) -> MenuAction {
+        let Some(index) = self.levels.iter().rposition(|l| l.rect().contains(point)) else {
+            return MenuAction::Close;
+        };
+
+        let level = &mut self.levels[index];
+        let Some(row) = level.row_at(point) else {
+            return MenuAction::Handled;
+        };
+
+        if !level.rows[row].is_selectable() {
+            return MenuAction::Handled;
+        }
+
+        level.highlighted = Some(row);
+        self.levels.truncate(index + 1);
+        self.choose(fonts, style, menu)
+    }
+
+    pub(super) fn key(
+        &mut self,
+        fonts: &mut Fonts,
+        style: &MenuStyle,
+        menu: &Menu,
+        event: &KeyPressed,
+    ) -> MenuAction {
+        let nested = self.levels.len() > 1;
+
+        let Some(level) = self.levels.last_mut() else {
+            return MenuAction::None;
+        };
+
+        match event.key {
+            Key::Up | Key::Down => {
+                let direction = if event.key == Key::Up { -1 } else { 1 };
+                level.highlighted = level.step(direction).or(level.highlighted);
+                MenuAction::Handled
+            }
+            Key::Home => {
+                level.highlighted = None;
+                level.highlighted = level.step(1);
+                MenuAction::Handled
+            }
+            Key::End => {
+                level.highlighted = None;
+                level.highlighted = level.step(-1);
+                MenuAction::Handled
+            }
+            Key::Right => {
+                let submenu = (level.highlighted).is_some_and(|i| level.rows[i].submenu);
+
+                match submenu {
+                    true => self.choose(fonts, style, menu),
+                    false => MenuAction::Next,
+                }
+            }
+            Key::Left if nested => {
+                self.levels.pop();
+                MenuAction::Handled
+            }
+            Key::Left => MenuAction::Previous,
+            Key::Escape if nested => {
+                self.levels.pop();
+                MenuAction::Handled
+            }
+            Key::Escape => MenuAction::Close,
+            Key::Enter | Key::Character(' ') => self.choose(fonts, style, menu),
+            Key::Character(c) if !event.modifiers.ctrl => {
+                let c = c.to_ascii_lowercase();
+                let row = (level.rows.iter()).position(|row| {
+                    row.is_selectable() && row.mnemonic.is_some_and(|(m, _)| m == c)
+                });
+
+                match row {
+                    Some(row) => {
+                        level.highlighted = Some(row);
+                        self.choose(fonts, style, menu)
+                    }
+                    None => MenuAction::Handled,
+                }
+            }
+            _ => MenuAction::None,
+        }
+    }
+
+    // move the levels that don't fit in the window, and submenus to the other side of their
+    // parent when there isn't room for them
+    fn fit(&mut self, cx: &DrawCx) {
+        let transform = cx.transform();
+        let window = cx.window().size;
+
+        for i in 0..self.levels.len() {
+            if self.levels[i].fitted {
+                continue;
+            }
+
+            let parent = i.checked_sub(1).map(|i| self.levels[i].rect());
+            let level = &mut self.levels[i];
+            level.fitted = true;
+
+            let min = transform * level.origin;
+            let max = min + level.size;
+
+            if max.x > window.width {
+                match parent {
+                    Some(parent) => level.origin.x = parent.min.x - level.size.width + INSET,
+                    None => level.origin.x -= max.x - window.width,
+                }
+            }
+
+            if max.y > window.height {
+                level.origin.y -= f32::min(max.y - window.height, min.y);
+            }
+        }
+    }
+
+    pub(super) fn draw(&mut self, cx: &mut DrawCx, style: &MenuStyle) {
+        if !self.is_open() {
+            return;
+        }
+
+        self.fit(cx);
+
+        let transform = cx.transform();
+
+        cx.overlay(2, |cx| {
+            cx.layer(transform, |cx| {
+                for level in &self.levels {
+                    draw_level(cx, style, level);
+                }
+            });
+        });
+    }
+}
+
+fn draw_level(cx: &mut DrawCx, style: &MenuStyle, level: &PopupLevel) {
+    let rect = level.rect();
+
+    cx.hoverable(|cx| {
+        cx.quad(
+            rect,
+            style.background,
+            style.border_radius,
+            1.0,
+            style.border_color,
+        );
+    });
+
+    let column = style.column_width();
+    let width = rect.width();
+
+    for (i, row) in level.rows.iter().enumerate() {
+        let row_rect = Rect::min_size(
+            level.origin + Vector::new(INSET, row.y),
+            Size::new(width - INSET * 2.0, row.height),
+        );
+
+        let Some(ref label) = row.label else {
+            let y = row_rect.center().y.round();
+            let line = Rect::new(
+                Point::new(row_rect.min.x + style.padding.left, y - 0.5),
+                Point::new(row_rect.max.x - style.padding.right, y + 0.5),
+            );
+
+            cx.fill_rect(line, style.separator);
+            continue;
+        };
+
+        if level.highlighted == Some(i) {
+            cx.quad(
+                row_rect,
+                style.highlight,
+                style.border_radius,
+                0.0,
+                Color::TRANSPARENT,
+            );
+        }
+
+        let color = match row.enabled {
+            true => style.color,
+            false => style.disabled_color,
+        };
+
+        let x = row_rect.min.x + style.padding.left;
+        let y = row_rect.min.y + style.padding.top;
+
+        if row.checked == Some(true) {
+            let check = Rect::min_size(Point::new(x, y), Size::all(style.font_size)).shrink(3.0);
+
+            let mut curve = Curve::new();
+            curve.move_to(Point::new(check.min.x, check.center().y));
+            curve.line_to(Point::new(check.min.x + check.width() * 0.4, check.max.y));
+            curve.line_to(Point::new(check.max.x, check.min.y));
+
+            cx.stroke(curve, 1.5, color);
+        }
+
+        let label_offset = Vector::new(x + column, y);
+        cx.text(label, color, label_offset);
+
+        if let Some((x, w, baseline)) = row.mnemonic.and_then(|(_, i)| glyph_span(label, i)) {
+            let underline = Rect::min_size(
+                Point::new(x, baseline + 2.0) + label_offset,
+                Size::new(w, 1.0),
+            );
+
+            cx.fill_rect(underline, color);
+        }
+
+        let right = row_rect.max.x - style.padding.right - column;
+
+        if let Some(ref accelerator) = row.accelerator {
+            let x = right - accelerator.size().width;
+            let color = match row.enabled {
+                true => style.accelerator_color,
+                false => style.disabled_color,
+            };
+
+            cx.text(accelerator, color, Vector::new(x, y));
+        }
+
+        if row.submenu {
+            let size = style.font_size * 0.25;
+            let center = Point::new(right + column / 2.0, row_rect.center().y);
+
+            let mut curve = Curve::new();
+            curve.move_to(center + Vector::new(-size / 2.0, -size));
+            curve.line_to(center + Vector::new(size / 2.0, 0.0));
+            curve.line_to(center + Vector::new(-size / 2.0, size));
+
+            cx.stroke(curve, 1.5, color);
+        }
+    }
+}
+
+/// Handle an action of a [`MenuPopup`], returns true if the popup was closed.
+pub(super) fn handle_action(
+    popup: &mut MenuPopup,
+    cx: &mut EventCx,
+    menu: &Menu,
+    action: &MenuAction,
+) -> bool {
+    match action {
+        MenuAction::Activate(path) => {
+            popup.close();
+
+            if let Some(item) = menu.item_at(path) {
+                item.activate(cx);
+            }
+        }
+        MenuAction::Close => popup.close(),
+        _ => {}
+    }
+
+    if *action != MenuAction::None {
+        cx.set_handled();
+        cx.draw();
+    }
+
+    !popup.is_open()
+}
+
+/// Activate the item of `menu` matching the accelerator of `event`, returns true if one was found.
+pub(super) fn handle_accelerator(cx: &mut EventCx, menu: &Menu, event: &KeyPressed) -> bool {
+    match menu.find_accelerator(event) {
+        Some(item) => {
+            item.activate(cx);
+            true
+        }
+        None => false,
+    }
+}
+
+/// A menu bar, with menus that open when a title is clicked.
+///
+/// The items of the menus send their commands when activated, either by clicking them, with
+/// the keyboard or with their accelerator. `Alt` and the mnemonic of a title opens a menu, and
+/// the arrow keys move between items and menus.
+///
+/// Can be styled using the [`MenuStyle`].
+#[derive(Build, Rebuild)]
+pub struct MenuBar {
+    /// The menus.
+    #[build(ignore)]
+    pub menus: Vec<Menu>,
+
+    /// The font size of the labels.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the labels.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The color of the labels.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of disabled items.
+    #[rebuild(draw)]
+    pub disabled_color: Color,
+
+    /// The color of the accelerators.
+    #[rebuild(draw)]
+    pub accelerator_color: Color,
+
+    /// The padding of each item.
+    #[rebuild(draw)]
+    pub padding: Padding,
+
+    /// The minimum width of a popup menu.
+    #[rebuild(draw)]
+    pub min_width: f32,
+
+    /// The background color of a popup menu.
+    #[rebuild(draw)]
+    pub background: Color,
+
+    /// The background color of the highlighted item.
+    #[rebuild(draw)]
+    pub highlight: Color,
+
+    /// The color of the separators.
+    #[rebuild(draw)]
+    pub separator: Color,
+
+    /// The border radius of a popup menu.
+    #[rebuild(draw)]
+    pub border_radius: BorderRadius,
+
+    /// The border color of a popup menu.
+    #[rebuild(draw)]
+    pub border_color: Color,
+
+    /// The padding of the titles in the menu bar.
+    #[rebuild(layout)]
+    pub bar_padding: Padding,
+
+    /// The background color of the menu bar.
+    #[rebuild(draw)]
+    pub bar_background: Color,
+}
+
+impl MenuBar {
+    /// Create a new [`MenuBar`].
+    pub fn new(menus: impl IntoIterator<Item = Menu>) -> Self {
+        Self::styled(menus, style())
+    }
+
+    /// Create a new [`MenuBar`] with a style.
+    pub fn styled(menus: impl IntoIterator<Item = Menu>, style: MenuStyle) -> Self {
+        Self {
+            menus: menus.into_iter().collect(),
+            font_size: style.font_size,
+            font_family: style.font_family,
+            color: style.color,
+            disabled_color: style.disabled_color,
+            accelerator_color: style.accelerator_color,
+            padding: style.padding,
+            min_width: style.min_width,
+            background: style.background,
+            highlight: style.highlight,
+            separator: style.separator,
+            border_radius: style.border_radius,
+            border_color: style.border_color,
+            bar_padding: style.bar_padding,
+            bar_background: style.bar_background,
+        }
+    }
+
+    fn menu_style(&self) -> MenuStyle {
+        MenuStyle {
+            font_size: self.font_size,
+            font_family: self.font_family.clone(),
+            color: self.color,
+            disabled_color: self.disabled_color,
+            accelerator_color: self.accelerator_color,
+            padding: self.padding,
+            min_width: self.min_width,
+            background: self.background,
+            highlight: self.highlight,
+            separator: self.separator,
+            border_radius: self.border_radius,
+            border_color: self.border_color,
+            bar_padding: self.bar_padding,
+            bar_background: self.bar_background,
+        }
+    }
+
+    fn set_titles(&self, fonts: &mut Fonts, state: &mut MenuBarState) {
+        let style = self.menu_style();
+        state.titles.clear();
+
+        for menu in &self.menus {
+            let (text, mnemonic) = parse_mnemonic(&menu.title);
+            let buffer = style.buffer(fonts, &text);
+            state.titles.push((buffer, mnemonic));
+        }
+    }
+
+    fn title_rects(&self, state: &MenuBarState) -> Vec<Rect> {
+        let height = self.font_size * LINE_HEIGHT + self.bar_padding.size().height;
+        let mut x = 0.0;
+
+        (state.titles.iter())
+            .map(|(buffer, _)| {
+                let width = buffer.size().width + self.bar_padding.size().width;
+                let rect = Rect::min_size(Point::new(x, 0.0), Size::new(width, height));
+                x += width;
+                rect
+            })
+            .collect()
+    }
+
+    fn open(&self, cx: &mut EventCx, state: &mut MenuBarState, index: usize, highlight: bool) {
+        let rects = self.title_rects(state);
+        let style = self.menu_style();
+
+        state.open = Some(index);
+        (state.popup).open(
+            cx.fonts(),
+            &style,
+            &self.menus[index],
+            rects[index].bottom_left(),
+            highlight,
+        );
+
+        cx.set_active(true);
+        cx.draw();
+    }
+
+    fn close(&self, cx: &mut EventCx, state: &mut MenuBarState) {
+        state.open = None;
+        state.popup.close();
+
+        cx.set_active(false);
+        cx.draw();
+    }
+}
+
+#[doc(hidden)]
+pub struct MenuBarState {
+    titles: Vec<(TextBuffer, Option<(char, usize)>)>,
+    hovered: Option<usize>,
+    open: Option<usize>,
+    popup: MenuPopup,
+}
+
+impl<T> View<T> for MenuBar {
+    type State = MenuBarState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        let mut state = MenuBarState {
+            titles: Vec::new(),
+            hovered: None,
+            open: None,
+            popup: MenuPopup::new(),
+        };
+
+        self.set_titles(cx.fonts(), &mut state);
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        if self.menus != old.menus {
+            self.set_titles(cx.fonts(), state);
+
+            // the open menu may no longer exist
+            state.open = None;
+            state.popup.close();
+            cx.set_active(false);
+
+            cx.layout();
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, _data: &mut T, event: &Event) {
+        let style = self.menu_style();
+
+        match event {
+            Event::PointerMoved(e) => {
+                let local = cx.local(e.position);
+                let rects = self.title_rects(state);
+
+                let hovered = match cx.is_hot() {
+                    true => rects.iter().position(|rect| rect.contains(local)),
+                    false => None,
+                };
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    cx.draw();
+                }
+
+                // moving over another title while a menu is open switches menu
+                if let (Some(open), Some(hovered)) = (state.open, hovered) {
+                    if open != hovered {
+                        self.open(cx, state, hovered, false);
+                    }
+                }
+
+                if let Some(open) = state.open {
+                    let menu = &self.menus[open];
+
+                    if (state.popup).pointer_moved(cx.fonts(), &style, menu, local) {
+                        cx.draw();
+                    }
+                }
+
+                cx.set_cursor(hovered.map(|_| Cursor::Pointer));
+            }
+            Event::PointerPressed(e) if e.button == PointerButton::Primary => {
+                let local = cx.local(e.position);
+                let rects = self.title_rects(state);
+                let title = rects.iter().position(|rect| rect.contains(local));
+
+                if let (true, Some(title)) = (cx.is_hot(), title) {
+                    match state.open == Some(title) {
+                        true => self.close(cx, state),
+                        false => self.open(cx, state, title, false),
+                    }
+
+                    return;
+                }
+
+                let Some(open) = state.open else {
+                    return;
+                };
+
+                let menu = &self.menus[open];
+                let action = (state.popup).press(cx.fonts(), &style, menu, local);
+
+                if handle_action(&mut state.popup, cx, menu, &action) {
+                    self.close(cx, state);
+                }
+            }
+            Event::KeyPressed(e) => {
+                if let Some(open) = state.open {
+                    let menu = &self.menus[open];
+                    let action = (state.popup).key(cx.fonts(), &style, menu, e);
+                    let count = self.menus.len();
+
+                    match action {
+                        MenuAction::Previous => {
+                            self.open(cx, state, (open + count - 1) % count, true)
+                        }
+                        MenuAction::Next => self.open(cx, state, (open + 1) % count, true),
+                        MenuAction::None => {}
+                        _ => {
+                            if handle_action(&mut state.popup, cx, menu, &action) {
+                                self.close(cx, state);
+                            }
+
+                            return;
+                        }
+                    }
+                }
+
+                // alt and a mnemonic opens a menu
+                if let (true, Some(c)) = (e.modifiers.alt, e.key.as_char()) {
+                    let c = c.to_ascii_lowercase();
+                    let menu = (state.titles.iter())
+                        .position(|(_, mnemonic)| mnemonic.is_some_and(|(m, _)| m == c));
+
+                    if let Some(menu) = menu {
+                        self.open(cx, state, menu, true);
+                        return;
+                    }
+                }
+
+                for menu in &self.menus {
+                    if handle_accelerator(cx, menu, e) {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        _cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        let rects = self.title_rects(state);
+        let width = rects.last().map_or(0.0, |rect| rect.max.x);
+        let height = self.font_size * LINE_HEIGHT + self.bar_padding.size().height;
+
+        space.fit(Size::new(width, height))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        cx.hoverable(|cx| {
+            cx.fill_rect(cx.rect(), self.bar_background);
+        });
+
+        let rects = self.title_rects(state);
+
+        for (i, (rect, (buffer, mnemonic))) in rects.iter().zip(&state.titles).enumerate() {
+            if state.open == Some(i) || state.hovered == Some(i) {
+                cx.quad(
+                    rect.shrink(2.0),
+                    self.highlight,
+                    self.border_radius,
+                    0.0,
+                    Color::TRANSPARENT,
+                );
+            }
+
+            let offset = rect.top_left() + self.bar_padding.offset();
+            let offset = offset - Point::ZERO;
+            cx.text(buffer, self.color, offset);
+
+            if let Some((x, w, baseline)) = mnemonic.and_then(|(_, i)| glyph_span(buffer, i)) {
+                let underline =
+                    Rect::min_size(Point::new(x, baseline + 2.0) + offset, Size::new(w, 1.0));
+                cx.fill_rect(underline, self.color);
+            }
+        }
+
+        state.popup.draw(cx, &self.menu_style());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Key, KeyPressed, Modifiers, PointerButton, PointerId, PointerPressed},
+        layout::{Point, Space},
+        views::testing::ViewTester,
+    };
+
+    use super::{menu_bar, parse_mnemonic, Accelerator, Menu, MenuItem};
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Action {
+        Save,
+        Export,
+    }
+
+    fn key(key: Key, modifiers: Modifiers) -> Event {
+        Event::KeyPressed(KeyPressed {
+            key,
+            code: None,
+            text: None,
+            modifiers,
+        })
+    }
+
+    fn menus() -> Vec<Menu> {
+        vec![Menu::new("&File")
+            .item(MenuItem::new("&Open").enabled(false))
+            .separator()
+            .item(
+                MenuItem::new("&Save")
+                    .accelerator("Ctrl+S")
+                    .command(Action::Save),
+            )
+            .submenu(Menu::new("E&xport").item(MenuItem::new("&PDF").command(Action::Export)))]
+    }
+
+    #[test]
+    fn accelerator() {
+        let accelerator = Accelerator::parse("ctrl+shift+s").unwrap();
+        assert_eq!(accelerator.to_string(), "Ctrl+Shift+S");
+        assert_eq!(Accelerator::parse("F5").unwrap().to_string(), "F5");
+        assert_eq!(Accelerator::parse("Ctrl+Hyper+S"), None);
+
+        let ctrl_shift = Modifiers {
+            ctrl: true,
+            shift: true,
+            ..Default::default()
+        };
+
+        let Event::KeyPressed(e) = key(Key::Character('S'), ctrl_shift) else {
+            unreachable!()
+        };
+        assert!(accelerator.matches(&e));
+
+        assert_eq!(
+            parse_mnemonic("Save &As"),
+            (String::from("Save As"), Some(('a', 5)))
+        );
+        assert_eq!(parse_mnemonic("A && B"), (String::from("A & B"), None));
+    }
+
+    #[test]
+    fn keyboard_navigation() {
+        let mut view = menu_bar(menus());
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(&mut view, &mut (), Space::UNBOUNDED);
+
+        // open the file menu with its mnemonic, the disabled item is skipped
+        let alt = Modifiers {
+            alt: true,
+            ..Default::default()
+        };
+
+        tester.event(&mut view, &mut (), &key(Key::Character('f'), alt));
+        assert_eq!(tester.state.open, Some(0));
+        assert_eq!(tester.state.popup.levels[0].highlighted, Some(2));
+
+        // open the submenu, and activate the item in it
+        tester.event(&mut view, &mut (), &key(Key::Down, Modifiers::default()));
+        tester.event(&mut view, &mut (), &key(Key::Right, Modifiers::default()));
+        assert_eq!(tester.state.popup.levels.len(), 2);
+
+        tester.event(&mut view, &mut (), &key(Key::Enter, Modifiers::default()));
+        assert_eq!(tester.state.open, None);
+
+        let command = tester.command_rx.try_recv().unwrap();
+        assert_eq!(command.get::<Action>(), Some(&Action::Export));
+
+        // accelerators work while the menus are closed
+        let ctrl = Modifiers {
+            ctrl: true,
+            ..Default::default()
+        };
+
+        tester.event(&mut view, &mut (), &key(Key::Character('s'), ctrl));
+        let command = tester.command_rx.try_recv().unwrap();
+        assert_eq!(command.get::<Action>(), Some(&Action::Save));
+
+        // pressing outside of an open menu closes it
+        tester.event(&mut view, &mut (), &key(Key::Character('f'), alt));
+
+        let press = Event::PointerPressed(PointerPressed {
+            id: PointerId::from_hash(&0),
+            position: Point::new(500.0, 500.0),
+            button: PointerButton::Primary,
+            modifiers: Modifiers::default(),
+        });
+
+        tester.event(&mut view, &mut (), &press);
+        assert_eq!(tester.state.open, None);
+    }
+}
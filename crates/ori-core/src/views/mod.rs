@@ -4,30 +4,61 @@ mod aligned;
 mod animate;
 mod build_handler;
 mod button;
+#[cfg(feature = "calendar")]
+mod calendar;
+mod chart;
 mod checkbox;
 mod clickable;
+mod code_editor;
 mod collapsing;
 mod color_picker;
+mod combobox;
 mod constrain;
 mod container;
+mod context_menu;
+#[cfg(feature = "calendar")]
+mod date;
+#[cfg(feature = "calendar")]
+mod date_picker;
+mod direction;
 mod draw_handler;
+mod dropdown;
 mod event_handler;
 mod flex;
 mod focus;
 mod image;
+#[cfg(feature = "markdown")]
+mod markdown;
 mod memorize;
+mod menu;
+mod number_input;
 mod opaque;
+mod overlay;
 mod pad;
 mod painter;
+mod progress;
+mod radio_group;
 mod rebuild_handler;
+mod rich_text;
 mod scroll;
+mod segmented;
+mod selectable_text;
 mod slider;
+mod spinner;
+mod split;
 mod stack;
+mod table;
+mod tabs;
 mod text;
 mod text_input;
+#[cfg(feature = "calendar")]
+mod time_picker;
 mod tooltip;
 mod transform;
+mod tree;
 mod trigger;
+mod viewport;
+mod virtual_list;
 mod with_state;
 mod wrap;
 mod zstack;
@@ -36,39 +67,71 @@ pub use aligned::*;
 pub use animate::*;
 pub use build_handler::*;
 pub use button::*;
+#[cfg(feature = "calendar")]
+pub use calendar::*;
+pub use chart::*;
 pub use checkbox::*;
 pub use clickable::*;
+pub use code_editor::*;
 pub use collapsing::*;
 pub use color_picker::*;
+pub use combobox::*;
 pub use constrain::*;
 pub use container::*;
+pub use context_menu::*;
+#[cfg(feature = "calendar")]
+pub use date::*;
+#[cfg(feature = "calendar")]
+pub use date_picker::*;
+pub use direction::*;
 pub use draw_handler::*;
+pub use dropdown::*;
 pub use event_handler::*;
 pub use flex::*;
 pub use focus::*;
+#[cfg(feature = "markdown")]
+pub use markdown::*;
 pub use memorize::*;
+pub use menu::*;
+pub use number_input::*;
 pub use opaque::*;
+pub use overlay::*;
 pub use pad::*;
 pub use painter::*;
+pub use progress::*;
+pub use radio_group::*;
 pub use rebuild_handler::*;
+pub use rich_text::*;
 pub use scroll::*;
+pub use segmented::*;
+pub use selectable_text::*;
 pub use slider::*;
+pub use spinner::*;
+pub use split::*;
 pub use stack::*;
+pub use table::*;
+pub use tabs::*;
 pub use text::*;
 pub use text_input::*;
+#[cfg(feature = "calendar")]
+pub use time_picker::*;
 pub use tooltip::*;
 pub use transform::*;
+pub use tree::*;
 pub use trigger::*;
+pub use viewport::*;
+pub use virtual_list::*;
 pub use with_state::*;
 pub use wrap::*;
 pub use zstack::*;
 
 #[cfg(test)]
 #[allow(dead_code)]
-mod testing {
+pub(crate) mod testing {
     use std::collections::HashMap;
 
     use crate::{
+        canvas::Canvas,
         command::{CommandProxy, CommandReceiver, CommandWaker},
         context::{BaseCx, BuildCx, Contexts, DrawCx, EventCx, LayoutCx, RebuildCx},
         event::Event,
@@ -138,6 +201,16 @@ mod testing {
 
             size
         }
+
+        pub fn draw(&mut self, view: &mut V, data: &mut T) -> Canvas {
+            let mut canvas = Canvas::default();
+
+            let mut base_cx = BaseCx::new(&mut self.contexts, &mut self.command_proxy);
+            let mut draw_cx = DrawCx::new(&mut base_cx, &mut self.view_state, &mut canvas);
+            view.draw(&mut self.state, &mut draw_cx, data);
+
+            canvas
+        }
     }
 
     pub fn test_layout<T>(view: &mut impl View<T>, data: &mut T, space: Space) -> SavedLayouts {
@@ -212,4 +285,57 @@ mod testing {
             self.content.draw(state, cx, data);
         }
     }
+
+    pub fn with_baseline<V>(baseline: f32, content: V) -> WithBaseline<V> {
+        WithBaseline { content, baseline }
+    }
+
+    pub struct WithBaseline<V> {
+        pub content: V,
+        pub baseline: f32,
+    }
+
+    impl<T, V: View<T>> View<T> for WithBaseline<V> {
+        type State = V::State;
+
+        fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+            self.content.build(cx, data)
+        }
+
+        fn rebuild(
+            &mut self,
+            state: &mut Self::State,
+            cx: &mut RebuildCx,
+            data: &mut T,
+            old: &Self,
+        ) {
+            self.content.rebuild(state, cx, data, &old.content);
+        }
+
+        fn event(
+            &mut self,
+            state: &mut Self::State,
+            cx: &mut EventCx,
+            data: &mut T,
+            event: &Event,
+        ) {
+            self.content.event(state, cx, data, event);
+        }
+
+        fn layout(
+            &mut self,
+            state: &mut Self::State,
+            cx: &mut LayoutCx,
+            data: &mut T,
+            space: Space,
+        ) -> Size {
+            let size = self.content.layout(state, cx, data, space);
+            cx.set_baseline(Some(self.baseline));
+            size
+        }
+
+        fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+            self.content.draw(state, cx, data);
+        }
+    }
 }
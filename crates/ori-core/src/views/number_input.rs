@@ -0,0 +1,923 @@
+use std::{fmt::Display, str::FromStr, sync::OnceLock};
+
+use ori_macro::Build;
+use smol_str::SmolStr;
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, PointerButton},
+    layout::{Padding, Point, Rect, Size, Space, Vector},
+    locale::Locale,
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    text::{FontFamily, Fonts, TextAttributes, TextBuffer},
+    view::{Pod, State, View},
+    window::Cursor,
+};
+
+use super::{dropdown::draw_arrow, TextInput, TextStyle};
+
+/// A number that can be edited by a [`NumberInput`].
+pub trait Number: Copy + PartialOrd + Display + FromStr + 'static {
+    /// The smallest value.
+    const MIN: Self;
+
+    /// The largest value.
+    const MAX: Self;
+
+    /// The default step.
+    const ONE: Self;
+
+    /// Whether the number is an integer.
+    const INTEGER: bool;
+
+    /// Convert the number to an [`f64`].
+    fn to_f64(self) -> f64;
+
+    /// Convert an [`f64`] to the number, saturating at the bounds.
+    fn from_f64(value: f64) -> Self;
+
+    /// Add `step` to the number `steps` times, saturating at the bounds.
+    fn add_steps(self, step: Self, steps: i64) -> Self {
+        Self::from_f64(self.to_f64() + step.to_f64() * steps as f64)
+    }
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),*) => {$(
+        impl Number for $ty {
+            const MIN: Self = <$ty>::MIN;
+            const MAX: Self = <$ty>::MAX;
+            const ONE: Self = 1;
+            const INTEGER: bool = true;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value.round() as $ty
+            }
+
+            // integers are stepped exactly, even when they don't fit in an `f64`
+            fn add_steps(self, step: Self, steps: i64) -> Self {
+                let count = <$ty>::try_from(steps.unsigned_abs()).unwrap_or(<$ty>::MAX);
+                let delta = step.saturating_mul(count);
+
+                match steps < 0 {
+                    true => self.saturating_sub(delta),
+                    false => self.saturating_add(delta),
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_float {
+    ($($ty:ty),*) => {$(
+        impl Number for $ty {
+            const MIN: Self = <$ty>::MIN;
+            const MAX: Self = <$ty>::MAX;
+            const ONE: Self = 1.0;
+            const INTEGER: bool = false;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $ty
+            }
+        }
+    )*};
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_float!(f32, f64);
+
+/// How numbers are written, which depends on the locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// The separator between the integer and the fraction.
+    pub decimal_separator: char,
+
+    /// The separator between groups of thousands, if any.
+    pub group_separator: Option<char>,
+
+    /// The number of digits after the decimal separator, `None` uses as few as needed.
+    ///
+    /// This is ignored for integers.
+    pub precision: Option<usize>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::system()
+    }
+}
+
+impl NumberFormat {
+    /// The format used in English, `1,234.5`.
+    pub const ENGLISH: Self = Self::new('.', Some(','));
+
+    /// Create a new [`NumberFormat`].
+    pub const fn new(decimal_separator: char, group_separator: Option<char>) -> Self {
+        Self {
+            decimal_separator,
+            group_separator,
+            precision: None,
+        }
+    }
+
+    /// Set the number of digits after the decimal separator.
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Get the format of a `locale`, like `en_US` or `de-DE.UTF-8`.
+    pub fn locale(locale: &str) -> Self {
+        let locale = Locale::parse(locale);
+        let language = locale
+            .as_ref()
+            .map_or("", |locale| locale.language.as_str());
+
+        match language {
+            "de" | "es" | "it" | "pt" | "nl" | "da" | "tr" | "id" | "ro" | "el" | "hr" | "sl" => {
+                Self::new(',', Some('.'))
+            }
+            "fr" | "ru" | "uk" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "nn" | "no" | "hu"
+            | "bg" | "lt" | "lv" | "et" => Self::new(',', Some('\u{a0}')),
+            _ => Self::ENGLISH,
+        }
+    }
+
+    /// Get the format of the system locale, see [`Locale::system`] with `LC_NUMERIC`.
+    pub fn system() -> Self {
+        static SYSTEM: OnceLock<NumberFormat> = OnceLock::new();
+
+        *SYSTEM.get_or_init(|| match Locale::system("LC_NUMERIC") {
+            Some(locale) => Self::locale(&locale.to_string()),
+            None => Self::ENGLISH,
+        })
+    }
+
+    /// Format a `value`.
+    pub fn format<N: Number>(&self, value: N) -> String {
+        let text = match self.precision {
+            Some(precision) if !N::INTEGER => format!("{:.*}", precision, value),
+            _ => value.to_string(),
+        };
+
+        let (sign, text) = match text.strip_prefix('-') {
+            Some(text) => ("-", text),
+            None => ("", text.as_str()),
+        };
+
+        let (integer, fraction) = match text.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (text, None),
+        };
+
+        let mut formatted = String::from(sign);
+
+        for (i, digit) in integer.chars().enumerate() {
+            let left = integer.len() - i;
+
+            if let Some(group) = self.group_separator {
+                if i > 0 && left % 3 == 0 && digit.is_ascii_digit() {
+                    formatted.push(group);
+                }
+            }
+
+            formatted.push(digit);
+        }
+
+        if let Some(fraction) = fraction {
+            formatted.push(self.decimal_separator);
+            formatted.push_str(fraction);
+        }
+
+        formatted
+    }
+
+    /// Parse a `text`, returns `None` if it isn't a valid number.
+    pub fn parse<N: Number>(&self, text: &str) -> Option<N> {
+        let mut normalized = String::new();
+
+        for c in text.trim().chars() {
+            if c == self.decimal_separator {
+                normalized.push('.');
+            } else if Some(c) == self.group_separator || c == ' ' || c == '\u{a0}' {
+                continue;
+            } else {
+                normalized.push(c);
+            }
+        }
+
+        normalized.parse().ok()
+    }
+}
+
+/// Create a new [`NumberInput`].
+pub fn number_input<T, N: Number>(value: N) -> NumberInput<T, N> {
+    NumberInput::new(value)
+}
+
+/// The style of a number input.
+#[derive(Clone, Debug)]
+pub struct NumberInputStyle {
+    /// The format of the numbers.
+    pub format: NumberFormat,
+
+    /// The font size of the label.
+    pub font_size: f32,
+
+    /// The font family of the label.
+    pub font_family: FontFamily,
+
+    /// The color of the label.
+    pub label_color: Color,
+
+    /// The color of the increment and decrement arrows.
+    pub icon_color: Color,
+
+    /// The padding of the input.
+    pub padding: Padding,
+
+    /// The minimum width of the text.
+    pub min_width: f32,
+
+    /// The distance the pointer is dragged over the label, for each step.
+    pub scrub_distance: f32,
+
+    /// The background color.
+    pub background: Color,
+
+    /// The background color of a hovered button.
+    pub hover: Color,
+
+    /// The border radius.
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    pub border_color: Color,
+
+    /// The border color when focused.
+    pub focus_color: Color,
+
+    /// The border color when the text isn't a valid number.
+    pub invalid_color: Color,
+}
+
+impl Style for NumberInputStyle {
+    fn styled(style: &Styles) -> Self {
+        let text_style = style.get::<TextStyle>();
+        let palette = style.palette();
+
+        Self {
+            format: NumberFormat::system(),
+            font_size: text_style.font_size,
+            font_family: text_style.font_family.clone(),
+            label_color: palette.contrast_low,
+            icon_color: palette.contrast_low,
+            padding: Padding::from([8.0, 6.0]),
+            min_width: 48.0,
+            scrub_distance: 4.0,
+            background: palette.surface_higher,
+            hover: palette.surface_highest,
+            border_radius: BorderRadius::all(4.0),
+            border_width: BorderWidth::all(1.0),
+            border_color: palette.outline,
+            focus_color: palette.primary,
+            invalid_color: palette.danger,
+        }
+    }
+}
+
+/// A [`TextInput`] for numbers, with buttons to increment and decrement it.
+///
+/// The value is stepped by [`NumberInput::step`] with the buttons, the arrow keys while
+/// focused and the mouse wheel while hovered. When a [`NumberInput::label`] is set, dragging
+/// it horizontally scrubs the value.
+///
+/// While typing, the text is kept as it is, and the input shows when it isn't a valid number
+/// in the range. Numbers are written and parsed with the [`NumberFormat`] of the locale.
+///
+/// Can be styled using the [`NumberInputStyle`].
+#[derive(Build, Rebuild)]
+pub struct NumberInput<T, N: Number> {
+    /// The value.
+    #[build(ignore)]
+    #[rebuild(layout)]
+    pub value: N,
+
+    /// The text input.
+    #[build(ignore)]
+    pub input: Pod<TextInput<T>>,
+
+    /// The label.
+    #[build(ignore)]
+    #[rebuild(layout)]
+    pub label: Option<SmolStr>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_change: Option<Box<dyn FnMut(&mut EventCx, &mut T, N)>>,
+
+    /// The smallest value.
+    #[rebuild(layout)]
+    pub min: N,
+
+    /// The largest value.
+    #[rebuild(layout)]
+    pub max: N,
+
+    /// The amount the value is incremented and decremented by.
+    pub step: N,
+
+    /// The format of the numbers.
+    #[rebuild(layout)]
+    pub format: NumberFormat,
+
+    /// The font size of the label.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the label.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The color of the label.
+    #[rebuild(draw)]
+    pub label_color: Color,
+
+    /// The color of the increment and decrement arrows.
+    #[rebuild(draw)]
+    pub icon_color: Color,
+
+    /// The padding of the input.
+    #[rebuild(layout)]
+    pub padding: Padding,
+
+    /// The minimum width of the text.
+    #[rebuild(layout)]
+    pub min_width: f32,
+
+    /// The distance the pointer is dragged over the label, for each step.
+    pub scrub_distance: f32,
+
+    /// The background color.
+    #[rebuild(draw)]
+    pub background: Color,
+
+    /// The background color of a hovered button.
+    #[rebuild(draw)]
+    pub hover: Color,
+
+    /// The border radius.
+    #[rebuild(draw)]
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    #[rebuild(draw)]
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    #[rebuild(draw)]
+    pub border_color: Color,
+
+    /// The border color when focused.
+    #[rebuild(draw)]
+    pub focus_color: Color,
+
+    /// The border color when the text isn't a valid number.
+    #[rebuild(draw)]
+    pub invalid_color: Color,
+}
+
+impl<T, N: Number> NumberInput<T, N> {
+    /// Create a new [`NumberInput`].
+    pub fn new(value: N) -> Self {
+        Self::styled(value, style())
+    }
+
+    /// Create a new [`NumberInput`] with a style.
+    pub fn styled(value: N, style: NumberInputStyle) -> Self {
+        Self {
+            value,
+            input: Pod::new(TextInput::new()),
+            label: None,
+            on_change: None,
+            min: N::MIN,
+            max: N::MAX,
+            step: N::ONE,
+            format: style.format,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            label_color: style.label_color,
+            icon_color: style.icon_color,
+            padding: style.padding,
+            min_width: style.min_width,
+            scrub_distance: style.scrub_distance,
+            background: style.background,
+            hover: style.hover,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+            focus_color: style.focus_color,
+            invalid_color: style.invalid_color,
+        }
+    }
+
+    /// Set the label, which can be dragged to scrub the value.
+    pub fn label(mut self, label: impl Into<SmolStr>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the range of the value.
+    pub fn range(mut self, min: N, max: N) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Set the callback that is called when the value changes.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_change(mut self, on_change: impl FnMut(&mut EventCx, &mut T, N) + 'static) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    fn parse(&self, text: &str) -> Option<N> {
+        let value = self.format.parse::<N>(text)?;
+        (value >= self.min && value <= self.max).then_some(value)
+    }
+
+    // `value` moved by `steps` steps, clamped to the range
+    fn stepped(&self, value: N, steps: i64) -> N {
+        let mut value = value.add_steps(self.step, steps);
+
+        // avoid errors like 0.1 + 0.2 = 0.30000000000000004
+        if !N::INTEGER {
+            let decimals = (self.step.to_string().split_once('.'))
+                .map_or(0, |(_, fraction)| fraction.len()) as i32;
+            let scale = 10f64.powi(decimals);
+            value = N::from_f64((value.to_f64() * scale).round() / scale);
+        }
+
+        match value {
+            _ if value < self.min => self.min,
+            _ if value > self.max => self.max,
+            _ => value,
+        }
+    }
+
+    fn button_width(&self) -> f32 {
+        self.font_size * 1.2
+    }
+
+    fn label_width(&self, state: &NumberInputState<T, N>) -> f32 {
+        match self.label {
+            Some(_) => state.label.size().width + self.padding.left,
+            None => 0.0,
+        }
+    }
+
+    // the part of the input at `local`
+    fn part_at(&self, state: &NumberInputState<T, N>, size: Size, local: Point) -> Part {
+        let button = size.width - self.padding.right - self.button_width();
+
+        if !Rect::min_size(Point::ZERO, size).contains(local) {
+            Part::None
+        } else if local.x >= button {
+            match local.y < size.height / 2.0 {
+                true => Part::Increment,
+                false => Part::Decrement,
+            }
+        } else if self.label.is_some() && local.x < self.padding.left + self.label_width(state) {
+            Part::Label
+        } else {
+            Part::None
+        }
+    }
+
+    fn set_label(&self, fonts: &mut Fonts, state: &mut NumberInputState<T, N>) {
+        let label = self.label.as_deref().unwrap_or_default();
+
+        state.label.set_metrics(fonts, self.font_size, 1.2);
+        state.label.set_text(
+            fonts,
+            label,
+            TextAttributes {
+                family: self.font_family.clone(),
+                ..Default::default()
+            },
+        );
+    }
+
+    // replace the text with the formatted value
+    fn show_value(&self, fonts: &mut Fonts, state: &mut NumberInputState<T, N>, value: N) {
+        let text = self.format.format(value);
+        (self.input).set_text(fonts, state.input.content_mut(), &text);
+        state.input.request_layout();
+        state.invalid = false;
+    }
+
+    fn change(
+        &mut self,
+        state: &mut NumberInputState<T, N>,
+        cx: &mut EventCx,
+        data: &mut T,
+        value: N,
+    ) {
+        self.show_value(cx.fonts(), state, value);
+        cx.layout();
+
+        if value != self.value {
+            self.value = value;
+
+            if let Some(ref mut on_change) = self.on_change {
+                on_change(cx, data, value);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Part {
+    None,
+    Label,
+    Increment,
+    Decrement,
+}
+
+#[doc(hidden)]
+pub struct NumberInputState<T, N> {
+    input: State<T, TextInput<T>>,
+    label: TextBuffer,
+    invalid: bool,
+    hovered: Part,
+    // the start position and value of a scrub
+    scrub: Option<(f32, N)>,
+}
+
+impl<T, N: Number> View<T> for NumberInput<T, N> {
+    type State = NumberInputState<T, N>;
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let mut state = NumberInputState {
+            input: self.input.build(cx, data),
+            label: TextBuffer::new(cx.fonts(), self.font_size, 1.2),
+            invalid: false,
+            hovered: Part::None,
+            scrub: None,
+        };
+
+        self.set_label(cx.fonts(), &mut state);
+        self.show_value(cx.fonts(), &mut state, self.value);
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        View::rebuild(&mut self.input, &mut state.input, cx, data, &old.input);
+
+        if self.label != old.label
+            || self.font_size != old.font_size
+            || self.font_family != old.font_family
+        {
+            self.set_label(cx.fonts(), state);
+        }
+
+        // the text being typed is kept, unless the value was changed by someone else
+        let text = state.input.content().text();
+        let typed = self.parse(&text);
+
+        let changed = match state.input.is_focused() {
+            true => typed.map_or(self.value != old.value, |typed| typed != self.value),
+            false => text != self.format.format(self.value),
+        };
+
+        if changed {
+            self.show_value(cx.fonts(), state, self.value);
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        match event {
+            Event::PointerPressed(e) if e.button == PointerButton::Primary => {
+                let local = cx.local(e.position);
+
+                match self.part_at(state, cx.size(), local) {
+                    Part::Increment => {
+                        let value = self.stepped(self.value, 1);
+                        self.change(state, cx, data, value);
+                        return;
+                    }
+                    Part::Decrement => {
+                        let value = self.stepped(self.value, -1);
+                        self.change(state, cx, data, value);
+                        return;
+                    }
+                    Part::Label => {
+                        state.scrub = Some((local.x, self.value));
+                        cx.set_active(true);
+                        return;
+                    }
+                    Part::None => {}
+                }
+            }
+            Event::PointerReleased(_) if state.scrub.is_some() => {
+                state.scrub = None;
+                cx.set_active(false);
+            }
+            Event::PointerMoved(e) => {
+                let local = cx.local(e.position);
+
+                if let Some((start, value)) = state.scrub {
+                    let steps = ((local.x - start) / self.scrub_distance).trunc() as i64;
+                    let value = self.stepped(value, steps);
+
+                    if value != self.value {
+                        self.change(state, cx, data, value);
+                    }
+                }
+
+                let hovered = match cx.is_hot() || state.scrub.is_some() {
+                    true => self.part_at(state, cx.size(), local),
+                    false => Part::None,
+                };
+
+                if state.hovered != hovered {
+                    state.hovered = hovered;
+                    cx.draw();
+                }
+
+                match (hovered, state.scrub) {
+                    (Part::Label, _) | (_, Some(_)) => cx.set_cursor(Some(Cursor::EwResize)),
+                    (Part::Increment | Part::Decrement, _) => cx.set_cursor(Some(Cursor::Default)),
+                    _ => cx.set_cursor(None),
+                }
+            }
+            Event::PointerScrolled(e) if cx.is_hot() || cx.has_hot() => {
+                // horizontal scrolling doesn't change the value
+                if e.delta.y == 0.0 {
+                    return;
+                }
+
+                let steps = e.delta.y.signum() as i64;
+                let value = self.stepped(self.value, steps);
+                self.change(state, cx, data, value);
+                return;
+            }
+            Event::KeyPressed(e) if state.input.is_focused() => {
+                let steps = match e.key {
+                    Key::Up => 1,
+                    Key::Down => -1,
+                    Key::PageUp => 10,
+                    Key::PageDown => -10,
+                    _ => 0,
+                };
+
+                if steps != 0 {
+                    let value = self.parse(&state.input.content().text());
+                    let value = self.stepped(value.unwrap_or(self.value), steps);
+                    self.change(state, cx, data, value);
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        let text = state.input.content().text();
+        let focused = state.input.is_focused();
+
+        self.input.event(&mut state.input, cx, data, event);
+
+        let new_text = state.input.content().text();
+
+        if text != new_text {
+            match self.parse(&new_text) {
+                Some(value) => {
+                    state.invalid = false;
+
+                    if value != self.value {
+                        self.value = value;
+
+                        if let Some(ref mut on_change) = self.on_change {
+                            on_change(cx, data, value);
+                        }
+                    }
+                }
+                None => state.invalid = true,
+            }
+
+            cx.draw();
+        }
+
+        // when done typing a valid number, it's written in the format
+        if focused && !state.input.is_focused() {
+            if !state.invalid {
+                self.show_value(cx.fonts(), state, self.value);
+                cx.layout();
+            }
+
+            cx.draw();
+        }
+
+        if !focused && state.input.is_focused() {
+            cx.draw();
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        let label = self.label_width(state);
+        let chrome = self.padding.size() + Size::new(label + self.button_width(), 0.0);
+        let inner = space.shrink(chrome);
+
+        let min_width = f32::max(inner.min.width, self.min_width.min(inner.max.width));
+        let input_space = Space::new(Size::new(min_width, inner.min.height), inner.max);
+        let input_size = self.input.layout(&mut state.input, cx, data, input_space);
+
+        let size = space.fit(input_size + chrome);
+
+        let offset = self.padding.offset() + Vector::new(label, 0.0);
+        state.input.translate(offset);
+        cx.set_baseline(state.input.parent_baseline());
+
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        let border_color = match (state.invalid, state.input.is_focused()) {
+            (true, _) => self.invalid_color,
+            (false, true) => self.focus_color,
+            (false, false) => self.border_color,
+        };
+
+        cx.hoverable(|cx| {
+            cx.quad(
+                cx.rect(),
+                self.background,
+                self.border_radius,
+                self.border_width,
+                border_color,
+            );
+        });
+
+        if self.label.is_some() {
+            cx.text(&state.label, self.label_color, self.padding.offset());
+        }
+
+        self.input.draw(&mut state.input, cx, data);
+
+        let size = cx.size();
+        let width = self.button_width();
+        let x = size.width - self.padding.right - width;
+        let half = Size::new(width, size.height / 2.0);
+
+        let increment = Rect::min_size(Point::new(x, 0.0), half);
+        let decrement = Rect::min_size(Point::new(x, size.height / 2.0), half);
+
+        match state.hovered {
+            Part::Increment => cx.fill_rect(increment, self.hover),
+            Part::Decrement => cx.fill_rect(decrement, self.hover),
+            _ => {}
+        }
+
+        let arrow = Size::all(f32::min(width, half.height));
+        let up = Rect::center_size(increment.center(), arrow);
+        let down = Rect::center_size(decrement.center(), arrow);
+
+        draw_arrow(cx, up, true, self.icon_color);
+        draw_arrow(cx, down, false, self.icon_color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Key, KeyPressed, Modifiers, PointerId, PointerScrolled},
+        layout::{Point, Space, Vector},
+        views::testing::ViewTester,
+    };
+
+    use super::{number_input, NumberFormat};
+
+    fn key(key: Key, text: Option<&str>) -> Event {
+        Event::KeyPressed(KeyPressed {
+            key,
+            code: None,
+            text: text.map(String::from),
+            modifiers: Modifiers::default(),
+        })
+    }
+
+    #[test]
+    fn format_and_parse() {
+        let german = NumberFormat::locale("de_DE.UTF-8");
+
+        assert_eq!(german.format(-1234567.5), "-1.234.567,5");
+        assert_eq!(german.parse::<f64>("1.234,5"), Some(1234.5));
+        assert_eq!(german.precision(2).format(3.0f32), "3,00");
+
+        assert_eq!(NumberFormat::ENGLISH.format(1000u32), "1,000");
+        assert_eq!(NumberFormat::ENGLISH.parse::<u32>("1,000"), Some(1000));
+        assert_eq!(NumberFormat::ENGLISH.parse::<u32>("-1"), None);
+    }
+
+    #[test]
+    fn type_and_step() {
+        let mut value = 5;
+
+        let mut view = number_input(value)
+            .range(0, 10)
+            .format(NumberFormat::ENGLISH)
+            .on_change(|_, value: &mut i32, new| *value = new);
+
+        let mut tester = ViewTester::new(&mut view, &mut value);
+        tester.layout(&mut view, &mut value, Space::UNBOUNDED);
+
+        tester.state.input.set_focused(true);
+        tester.event(&mut view, &mut value, &key(Key::Character('1'), Some("1")));
+
+        // 51 is out of range, but the text is kept
+        assert!(tester.state.invalid);
+        assert_eq!(tester.state.input.content().text(), "51");
+        assert_eq!(value, 5);
+
+        tester.event(&mut view, &mut value, &key(Key::Backspace, None));
+        tester.event(&mut view, &mut value, &key(Key::Up, None));
+
+        assert!(!tester.state.invalid);
+        assert_eq!(value, 6);
+
+        for _ in 0..10 {
+            tester.event(&mut view, &mut value, &key(Key::Up, None));
+        }
+
+        assert_eq!(value, 10);
+        assert_eq!(tester.state.input.content().text(), "10");
+    }
+
+    #[test]
+    fn scroll_vertically() {
+        let mut value = 5;
+
+        let mut view = number_input(value).on_change(|_, value: &mut i32, new| *value = new);
+
+        let mut tester = ViewTester::new(&mut view, &mut value);
+        tester.layout(&mut view, &mut value, Space::UNBOUNDED);
+        tester.view_state.set_hot(true);
+
+        let scroll = |x, y| {
+            Event::PointerScrolled(PointerScrolled {
+                id: PointerId::from_u64(0),
+                position: Point::ZERO,
+                delta: Vector::new(x, y),
+                modifiers: Modifiers::default(),
+            })
+        };
+
+        tester.event(&mut view, &mut value, &scroll(1.0, 0.0));
+        assert_eq!(value, 5);
+
+        tester.event(&mut view, &mut value, &scroll(0.0, -1.0));
+        assert_eq!(value, 4);
+    }
+
+    #[test]
+    fn step_large_integers() {
+        let mut value = i64::MAX - 2;
+
+        let mut view = number_input(value)
+            .format(NumberFormat::ENGLISH)
+            .on_change(|_, value: &mut i64, new| *value = new);
+
+        let mut tester = ViewTester::new(&mut view, &mut value);
+        tester.layout(&mut view, &mut value, Space::UNBOUNDED);
+        tester.state.input.set_focused(true);
+
+        // the value isn't rounded through a float, and saturates at the maximum
+        tester.event(&mut view, &mut value, &key(Key::Down, None));
+        assert_eq!(value, i64::MAX - 3);
+
+        tester.event(&mut view, &mut value, &key(Key::PageUp, None));
+        assert_eq!(value, i64::MAX);
+    }
+}
@@ -0,0 +1,938 @@
+use std::{
+    any::Any,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use ori_macro::Build;
+
+use crate::{
+    canvas::Color,
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, FocusOrder, Key},
+    layout::{Alignment, Padding, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    transition::Transition,
+    view::{AnyView, BoxedView, Pod, State, View, ViewId},
+    window::WindowId,
+};
+
+/// The overlay index of the lowest overlay, each overlay above it is placed this far above
+/// the previous one, leaving room for the overlays drawn by the views inside of it.
+const OVERLAY_INDEX: i32 = 1 << 16;
+
+/// A unique identifier for an [`Overlay`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OverlayId {
+    index: u64,
+}
+
+impl Default for OverlayId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OverlayId {
+    /// Create a new [`OverlayId`].
+    pub fn new() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        let index = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        Self { index }
+    }
+}
+
+/// The kind of an [`Overlay`], deciding where it's placed and whether it blocks input.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OverlayKind {
+    /// A dialog centered in the window, above a scrim blocking input to everything beneath it.
+    ///
+    /// The focus is moved into the dialog while it's open, and given back when it's closed.
+    Dialog,
+
+    /// A sheet attached to the bottom of the window, above a scrim blocking input to
+    /// everything beneath it, the focus is moved into it like a [`OverlayKind::Dialog`].
+    Sheet,
+
+    /// A panel placed in the window with an alignment, not blocking input beneath it.
+    Panel(Alignment),
+}
+
+impl OverlayKind {
+    /// Get whether the overlay blocks input to everything beneath it.
+    pub fn is_blocking(self) -> bool {
+        matches!(self, Self::Dialog | Self::Sheet)
+    }
+}
+
+/// Something displayed above the whole window, opened with [`BaseCx::open_overlay`].
+///
+/// The content of the overlay is built from the data like the rest of the window, and is
+/// rebuilt every time the window is. The [`OverlayId`] passed to the builder can be used to
+/// close the overlay from inside, see [`BaseCx::close_overlay`].
+///
+/// [`BaseCx::open_overlay`]: crate::context::BaseCx::open_overlay
+/// [`BaseCx::close_overlay`]: crate::context::BaseCx::close_overlay
+pub struct Overlay<T> {
+    id: OverlayId,
+    kind: OverlayKind,
+    dismissible: bool,
+    #[allow(clippy::type_complexity)]
+    builder: Box<dyn FnMut(&mut T, OverlayId) -> BoxedView<T>>,
+    #[allow(clippy::type_complexity)]
+    on_close: Option<Box<dyn FnMut(&mut EventCx, &mut T)>>,
+}
+
+impl<T> Overlay<T> {
+    /// Create a new [`Overlay`] of `kind`, with content built by `builder`.
+    pub fn new<V>(
+        kind: OverlayKind,
+        mut builder: impl FnMut(&mut T, OverlayId) -> V + 'static,
+    ) -> Self
+    where
+        V: AnyView<T> + 'static,
+    {
+        Self {
+            id: OverlayId::new(),
+            kind,
+            dismissible: true,
+            builder: Box::new(move |data, id| Box::new(builder(data, id))),
+            on_close: None,
+        }
+    }
+
+    /// Create a new dialog [`Overlay`], see [`OverlayKind::Dialog`].
+    pub fn dialog<V>(builder: impl FnMut(&mut T, OverlayId) -> V + 'static) -> Self
+    where
+        V: AnyView<T> + 'static,
+    {
+        Self::new(OverlayKind::Dialog, builder)
+    }
+
+    /// Create a new sheet [`Overlay`], see [`OverlayKind::Sheet`].
+    pub fn sheet<V>(builder: impl FnMut(&mut T, OverlayId) -> V + 'static) -> Self
+    where
+        V: AnyView<T> + 'static,
+    {
+        Self::new(OverlayKind::Sheet, builder)
+    }
+
+    /// Create a new panel [`Overlay`], see [`OverlayKind::Panel`].
+    pub fn panel<V>(
+        alignment: impl Into<Alignment>,
+        builder: impl FnMut(&mut T, OverlayId) -> V + 'static,
+    ) -> Self
+    where
+        V: AnyView<T> + 'static,
+    {
+        Self::new(OverlayKind::Panel(alignment.into()), builder)
+    }
+
+    /// Get the id of the overlay.
+    pub fn id(&self) -> OverlayId {
+        self.id
+    }
+
+    /// Get the kind of the overlay.
+    pub fn kind(&self) -> OverlayKind {
+        self.kind
+    }
+
+    /// Set whether the overlay is closed by `Escape`, and by clicking the scrim.
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+
+    /// Set the callback for when the overlay is closed, for whatever reason.
+    ///
+    /// This is the place to hand a result back to the opener, note that this doesn't trigger
+    /// a rebuild automatically.
+    pub fn on_close(mut self, on_close: impl FnMut(&mut EventCx, &mut T) + 'static) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+}
+
+enum OverlayRequest {
+    Open {
+        window: Option<WindowId>,
+        overlay: Box<dyn Any>,
+    },
+    Close(OverlayId),
+}
+
+/// The overlays requested to be opened or closed, stored as a context.
+///
+/// Requests are made with [`BaseCx::open_overlay`] and [`BaseCx::close_overlay`], and are
+/// handled by the [`OverlayLayer`] of the window they were made in. Requests made outside
+/// of a window, for example in a delegate, are handled by the first window to see them.
+///
+/// [`BaseCx::open_overlay`]: crate::context::BaseCx::open_overlay
+/// [`BaseCx::close_overlay`]: crate::context::BaseCx::close_overlay
+#[derive(Default)]
+pub struct Overlays {
+    requests: Vec<OverlayRequest>,
+    layers: Vec<WindowId>,
+}
+
+impl Overlays {
+    /// Request `overlay` to be opened in `window`.
+    pub fn open<T: 'static>(&mut self, window: Option<WindowId>, overlay: Overlay<T>) -> OverlayId {
+        let id = overlay.id;
+
+        let has_layer = match window {
+            Some(window) => self.layers.contains(&window),
+            None => !self.layers.is_empty(),
+        };
+
+        if !has_layer {
+            tracing::warn!("An overlay was opened without an `OverlayLayer` to show it in");
+        }
+
+        self.requests.push(OverlayRequest::Open {
+            window,
+            overlay: Box::new(overlay),
+        });
+
+        id
+    }
+
+    /// Request the overlay with `id` to be closed.
+    pub fn close(&mut self, id: OverlayId) {
+        self.requests.push(OverlayRequest::Close(id));
+    }
+
+    /// Register the [`OverlayLayer`] of `window`.
+    pub fn add_layer(&mut self, window: WindowId) {
+        if !self.layers.contains(&window) {
+            self.layers.push(window);
+        }
+    }
+
+    /// Get whether there are any pending requests.
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+}
+
+/// Create a new [`OverlayLayer`].
+pub fn overlay_layer<V>(content: V) -> OverlayLayer<V> {
+    OverlayLayer::new(content)
+}
+
+/// The style of an [`OverlayLayer`].
+#[derive(Clone, Debug)]
+pub struct OverlayStyle {
+    /// The transition of the overlays when opened and closed.
+    pub transition: Transition,
+
+    /// The color of the scrim beneath dialogs and sheets.
+    pub scrim: Color,
+
+    /// The padding between the overlays and the edges of the window.
+    pub padding: Padding,
+}
+
+impl Style for OverlayStyle {
+    fn styled(_style: &Styles) -> Self {
+        Self {
+            transition: Transition::ease(0.15),
+            scrim: Color::BLACK.fade(0.4),
+            padding: Padding::all(16.0),
+        }
+    }
+}
+
+/// A view displaying [`Overlay`]s above its content.
+///
+/// Wrap the content of a window in an overlay layer, to open overlays from anywhere inside of
+/// it with [`BaseCx::open_overlay`].
+///
+/// While a dialog or sheet is open, pointer presses, scrolling and keys are only delivered
+/// to the overlays above it. The focus is taken from beneath it, and given to the first
+/// focusable view inside of it, `Tab` and `Shift+Tab` move the focus between the focusable
+/// views inside of it, and when it's closed the focus is given back to the view that had it.
+///
+/// `Escape` closes the top overlay, unless a view inside of it already handled the key, see
+/// [`EventCx::set_handled`]. Clicking the scrim closes the top dialog or sheet. Neither happens
+/// if the overlay isn't [`dismissible`](Overlay::dismissible).
+///
+/// Can be styled using the [`OverlayStyle`].
+///
+/// [`BaseCx::open_overlay`]: crate::context::BaseCx::open_overlay
+/// [`EventCx::set_handled`]: crate::context::EventCx::set_handled
+#[derive(Build, Rebuild)]
+pub struct OverlayLayer<V> {
+    /// The content.
+    #[build(ignore)]
+    pub content: Pod<V>,
+
+    /// The transition of the overlays when opened and closed.
+    pub transition: Transition,
+
+    /// The color of the scrim beneath dialogs and sheets.
+    #[rebuild(draw)]
+    pub scrim: Color,
+
+    /// The padding between the overlays and the edges of the window.
+    #[rebuild(layout)]
+    pub padding: Padding,
+}
+
+impl<V> OverlayLayer<V> {
+    /// Create a new [`OverlayLayer`].
+    pub fn new(content: V) -> Self {
+        Self::styled(content, style())
+    }
+
+    /// Create a new [`OverlayLayer`] with a style.
+    pub fn styled(content: V, style: OverlayStyle) -> Self {
+        Self {
+            content: Pod::new(content),
+            transition: style.transition,
+            scrim: style.scrim,
+            padding: style.padding,
+        }
+    }
+
+    fn offset(&self, kind: OverlayKind, content: Size, size: Size, t: f32) -> Vector {
+        let inner = size - self.padding.size();
+        let slide = (1.0 - t) * 24.0;
+
+        match kind {
+            OverlayKind::Dialog => {
+                let offset = Alignment::CENTER.align(content, inner);
+                self.padding.offset() + offset + Vector::new(0.0, slide)
+            }
+            OverlayKind::Sheet => Vector::new(0.0, size.height - content.height * t),
+            OverlayKind::Panel(alignment) => {
+                let offset = alignment.align(content, inner);
+                let slide = if alignment.y < 0.5 { -slide } else { slide };
+                self.padding.offset() + offset + Vector::new(0.0, slide)
+            }
+        }
+    }
+}
+
+// the focus of a blocking overlay
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FocusTrap {
+    // the focus is moved into the overlay with the next event
+    Pending,
+
+    // the focus is inside the overlay, and is given back to the view beneath when it closes
+    Trapped(Option<ViewId>),
+
+    // the overlay doesn't hold the focus
+    Released,
+}
+
+struct OverlayEntry<T> {
+    overlay: Overlay<T>,
+    view: Pod<BoxedView<T>>,
+    state: State<T, BoxedView<T>>,
+    t: f32,
+    closing: bool,
+    focus: FocusTrap,
+}
+
+impl<T> OverlayEntry<T> {
+    fn is_blocking(&self) -> bool {
+        !self.closing && self.overlay.kind.is_blocking()
+    }
+
+    fn focus_order(&mut self, cx: &mut EventCx, data: &mut T) -> FocusOrder {
+        focus_order(cx, |cx| {
+            (self.view).event(&mut self.state, cx, data, &Event::CollectFocus);
+        })
+    }
+
+    fn give_focus(&mut self, cx: &mut EventCx, data: &mut T, id: Option<ViewId>) {
+        (self.view).event(&mut self.state, cx, data, &Event::GiveFocus(id));
+    }
+}
+
+// collect the focusable views that get `CollectFocus` events in `f`
+fn focus_order(cx: &mut EventCx, f: impl FnOnce(&mut EventCx)) -> FocusOrder {
+    let outer = cx.insert_context(FocusOrder::new());
+    f(cx);
+
+    let order = cx.remove_context::<FocusOrder>().unwrap_or_default();
+
+    if let Some(outer) = outer {
+        cx.insert_context(outer);
+    }
+
+    order
+}
+
+#[doc(hidden)]
+pub struct OverlayLayerState<T> {
+    entries: Vec<OverlayEntry<T>>,
+}
+
+impl<T: 'static> OverlayLayerState<T> {
+    // the index of the top overlay blocking input
+    fn blocking(&self) -> Option<usize> {
+        self.entries.iter().rposition(OverlayEntry::is_blocking)
+    }
+
+    fn close(&mut self, id: OverlayId) -> bool {
+        match self.entries.iter_mut().find(|entry| entry.overlay.id == id) {
+            Some(entry) if !entry.closing => {
+                entry.closing = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // handle the requests for this window, returns true if any overlay was opened or closed
+    fn handle_requests(&mut self, cx: &mut BuildCx, data: &mut T) -> bool {
+        let window = cx.window().id();
+
+        let Some(overlays) = cx.get_context_mut::<Overlays>() else {
+            return false;
+        };
+
+        if overlays.is_empty() {
+            return false;
+        }
+
+        let mut opened: Vec<Overlay<T>> = Vec::new();
+        let mut closed = Vec::new();
+
+        let requests = std::mem::take(&mut overlays.requests);
+        for request in requests {
+            match request {
+                OverlayRequest::Open {
+                    window: Some(other),
+                    overlay,
+                } if other != window => {
+                    overlays.requests.push(OverlayRequest::Open {
+                        window: Some(other),
+                        overlay,
+                    });
+                }
+                OverlayRequest::Open { window, overlay } => match overlay.downcast() {
+                    Ok(overlay) => opened.push(*overlay),
+                    Err(overlay) => {
+                        let request = OverlayRequest::Open { window, overlay };
+                        overlays.requests.push(request);
+                    }
+                },
+                OverlayRequest::Close(id) => closed.push(id),
+            }
+        }
+
+        let mut changed = false;
+
+        for mut overlay in opened {
+            let mut view = Pod::new((overlay.builder)(data, overlay.id));
+            let state = view.build(cx, data);
+
+            let focus = match overlay.kind.is_blocking() {
+                true => FocusTrap::Pending,
+                false => FocusTrap::Released,
+            };
+
+            self.entries.push(OverlayEntry {
+                overlay,
+                view,
+                state,
+                t: 0.0,
+                closing: false,
+                focus,
+            });
+
+            changed = true;
+        }
+
+        for id in closed {
+            if self.entries.iter().any(|entry| entry.overlay.id == id) {
+                changed |= self.close(id);
+            } else if let Some(overlays) = cx.get_context_mut::<Overlays>() {
+                // the overlay might belong to another window
+                overlays.close(id);
+            }
+        }
+
+        changed
+    }
+}
+
+impl<V> OverlayLayer<V> {
+    // move the focus into overlays that were opened, and back out of overlays that were closed
+    fn trap_focus<T>(
+        &mut self,
+        state: &mut OverlayLayerState<T>,
+        content: &mut State<T, V>,
+        cx: &mut EventCx,
+        data: &mut T,
+    ) where
+        V: View<T>,
+    {
+        for i in 0..state.entries.len() {
+            let (below, rest) = state.entries.split_at_mut(i);
+            let (entry, above) = rest.split_first_mut().unwrap();
+
+            // the overlay, or the content, that had the focus before the overlay was opened
+            let mut beneath = below.iter_mut().rfind(|entry| entry.is_blocking());
+
+            match entry.focus {
+                FocusTrap::Pending if entry.closing => {
+                    entry.focus = FocusTrap::Released;
+                }
+                FocusTrap::Pending => {
+                    let restore = match beneath {
+                        Some(ref mut beneath) => {
+                            let restore = beneath.focus_order(cx, data).focused;
+                            beneath.give_focus(cx, data, None);
+                            restore
+                        }
+                        None => {
+                            let order = focus_order(cx, |cx| {
+                                (self.content).event(content, cx, data, &Event::CollectFocus);
+                            });
+
+                            let focus = Event::GiveFocus(None);
+                            self.content.event(content, cx, data, &focus);
+                            order.focused
+                        }
+                    };
+
+                    let first = entry.focus_order(cx, data).first();
+                    entry.give_focus(cx, data, first);
+                    entry.focus = FocusTrap::Trapped(restore);
+                }
+                FocusTrap::Trapped(restore) if entry.closing => {
+                    entry.give_focus(cx, data, None);
+                    entry.focus = FocusTrap::Released;
+
+                    // when an overlay above is still open, it gives the focus back instead
+                    let above = above.iter_mut().find(|entry| entry.is_blocking());
+
+                    if let Some(above) = above {
+                        if let FocusTrap::Trapped(ref mut above) = above.focus {
+                            *above = restore;
+                            continue;
+                        }
+                    }
+
+                    if restore.is_none() {
+                        continue;
+                    }
+
+                    match beneath {
+                        Some(beneath) => beneath.give_focus(cx, data, restore),
+                        None => {
+                            let focus = Event::GiveFocus(restore);
+                            self.content.event(content, cx, data, &focus);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<T: 'static, V: View<T>> View<T> for OverlayLayer<V> {
+    type State = (OverlayLayerState<T>, State<T, V>);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let window = cx.window().id();
+        cx.context_or_default::<Overlays>().add_layer(window);
+
+        let state = OverlayLayerState {
+            entries: Vec::new(),
+        };
+
+        (state, self.content.build(cx, data))
+    }
+
+    fn rebuild(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        Rebuild::rebuild(self, cx, old);
+
+        (self.content).rebuild(content, cx, data, &old.content);
+
+        for entry in &mut state.entries {
+            let mut view = Pod::new((entry.overlay.builder)(data, entry.overlay.id));
+            view.rebuild(&mut entry.state, cx, data, &entry.view);
+            entry.view = view;
+        }
+
+        if state.handle_requests(&mut cx.as_build_cx(), data) {
+            cx.layout();
+            cx.animate();
+        }
+    }
+
+    fn event(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) {
+        // requests made outside of the window, since the last event
+        if state.handle_requests(&mut cx.as_build_cx(), data) {
+            cx.layout();
+            cx.animate();
+        }
+
+        self.trap_focus(state, content, cx, data);
+
+        // pointer movement is still delivered to everything, so hot states stay up to date,
+        // the scrim makes sure nothing beneath it is hot
+        let is_input = matches!(
+            event,
+            Event::PointerPressed(_)
+                | Event::PointerScrolled(_)
+                | Event::PointerPinched(_)
+                | Event::KeyPressed(_)
+                | Event::KeyReleased(_)
+                | Event::ImePreedit(_)
+                | Event::ImeCommit(_)
+                | Event::CollectFocus
+                | Event::GiveFocus(_)
+        );
+
+        let blocking = state.blocking().filter(|_| is_input);
+
+        // whether a view that got the event handled it
+        let mut handled = false;
+
+        // the top overlays get the events first
+        let first = blocking.unwrap_or(0);
+        for entry in state.entries[first..].iter_mut().rev() {
+            if entry.closing && is_input {
+                continue;
+            }
+
+            entry.view.event(&mut entry.state, cx, data, event);
+            handled |= entry.state.is_handled();
+        }
+
+        if blocking.is_none() {
+            self.content.event(content, cx, data, event);
+            handled |= content.is_handled();
+        }
+
+        match event {
+            Event::KeyPressed(e) if e.key == Key::Escape && !handled => {
+                let top = state.entries.iter().rposition(|entry| !entry.closing);
+
+                if let Some(entry) = top.map(|top| &mut state.entries[top]) {
+                    if entry.overlay.dismissible {
+                        entry.closing = true;
+                        cx.set_handled();
+                        cx.animate();
+                    }
+                }
+            }
+            Event::KeyPressed(e) if e.key == Key::Tab && !handled && !e.modifiers.ctrl => {
+                if let Some(entry) = state.blocking().map(|top| &mut state.entries[top]) {
+                    let order = entry.focus_order(cx, data);
+
+                    let next = match e.modifiers.shift {
+                        true => order.prev(),
+                        false => order.next(),
+                    };
+
+                    entry.give_focus(cx, data, next);
+                    cx.set_handled();
+                }
+            }
+            Event::PointerPressed(_) if cx.is_hot() => {
+                if let Some(entry) = state.blocking().map(|top| &mut state.entries[top]) {
+                    if entry.overlay.dismissible {
+                        entry.closing = true;
+                        cx.animate();
+                    }
+                }
+            }
+            Event::WindowResized(_) => {
+                cx.layout();
+            }
+            Event::Animate(dt) => {
+                for entry in &mut state.entries {
+                    if entry.closing {
+                        if let Some(mut on_close) = entry.overlay.on_close.take() {
+                            on_close(cx, data);
+                        }
+                    }
+
+                    if (self.transition).step(&mut entry.t, !entry.closing, *dt) {
+                        cx.animate();
+                        cx.draw();
+                    }
+                }
+
+                let len = state.entries.len();
+                (state.entries).retain(|entry| !entry.closing || entry.t > 0.0);
+
+                if state.entries.len() != len {
+                    cx.draw();
+                }
+            }
+            _ => {}
+        }
+
+        // requests made by the handlers of this event
+        if state.handle_requests(&mut cx.as_build_cx(), data) {
+            cx.layout();
+            cx.animate();
+        }
+
+        self.trap_focus(state, content, cx, data);
+    }
+
+    fn layout(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        let size = self.content.layout(content, cx, data, space);
+        cx.set_baseline(content.parent_baseline());
+
+        let inner = (size - self.padding.size()).max(Size::ZERO);
+
+        for entry in &mut state.entries {
+            let space = match entry.overlay.kind {
+                OverlayKind::Sheet => Space::new(
+                    Size::new(size.width, 0.0),
+                    Size::new(size.width, inner.height),
+                ),
+                _ => Space::new(Size::ZERO, inner),
+            };
+
+            entry.view.layout(&mut entry.state, cx, data, space);
+        }
+
+        size
+    }
+
+    fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.content.draw(content, cx, data);
+
+        let size = cx.size();
+        let transform = cx.transform();
+
+        for (i, entry) in state.entries.iter_mut().enumerate() {
+            let t = self.transition.get(entry.t);
+
+            if t <= 0.0 {
+                continue;
+            }
+
+            let kind = entry.overlay.kind;
+            let offset = self.offset(kind, entry.state.size(), size, t);
+            entry.state.translate(offset);
+
+            cx.overlay(OVERLAY_INDEX.saturating_mul(i as i32 + 1), |cx| {
+                cx.layer(transform, |cx| {
+                    if kind.is_blocking() {
+                        let scrim = Rect::min_size(Point::ZERO, size);
+
+                        if entry.closing {
+                            cx.fill_rect(scrim, self.scrim.fade(t));
+                        } else {
+                            cx.hoverable(|cx| cx.fill_rect(scrim, self.scrim.fade(t)));
+                        }
+                    }
+
+                    entry.view.draw(&mut entry.state, cx, data);
+                });
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use crate::{
+        context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+        event::{Event, Key, KeyPressed, Modifiers},
+        layout::{Size, Space},
+        view::View,
+        views::{on_event, size, testing::ViewTester, vstack},
+        window::Window,
+    };
+
+    use super::{overlay_layer, Overlay, Overlays};
+
+    fn key(key: Key) -> Event {
+        Event::KeyPressed(KeyPressed {
+            key,
+            code: None,
+            text: None,
+            modifiers: Modifiers::default(),
+        })
+    }
+
+    // a focusable view, focused by pressing its key, that adds its key to the data when updated
+    // while focused
+    struct Focusable(char);
+
+    impl View<String> for Focusable {
+        type State = ();
+
+        fn build(&mut self, cx: &mut BuildCx, _: &mut String) -> Self::State {
+            cx.set_focusable(true);
+        }
+
+        fn rebuild(&mut self, _: &mut (), _: &mut RebuildCx, _: &mut String, _: &Self) {}
+
+        fn event(&mut self, _: &mut (), cx: &mut EventCx, data: &mut String, event: &Event) {
+            match event {
+                Event::KeyPressed(e) if e.key == Key::Character(self.0) => {
+                    cx.set_focused(true);
+                }
+                Event::Update if cx.is_focused() => data.push(self.0),
+                _ => {}
+            }
+        }
+
+        fn layout(&mut self, _: &mut (), _: &mut LayoutCx, _: &mut String, _: Space) -> Size {
+            Size::all(10.0)
+        }
+
+        fn draw(&mut self, _: &mut (), _: &mut DrawCx, _: &mut String) {}
+    }
+
+    #[test]
+    fn dialog_blocks_input() {
+        let content = on_event(size(Size::all(200.0), ()), |_, data: &mut u32, event| {
+            if let Event::KeyPressed(_) = event {
+                *data += 1;
+            }
+        });
+
+        let mut view = overlay_layer(content);
+        let mut data = 0;
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+
+        tester.event(&mut view, &mut data, &key(Key::Character('a')));
+        assert_eq!(data, 1);
+
+        let closed = Rc::new(Cell::new(false));
+        let on_close = closed.clone();
+
+        let window = tester.contexts.get::<Window>().unwrap().id();
+        let overlay = Overlay::dialog(|_: &mut u32, _| size(Size::all(50.0), ()))
+            .on_close(move |_, _| on_close.set(true));
+
+        let overlays = tester.contexts.get_or_default::<Overlays>();
+        overlays.open(Some(window), overlay);
+
+        tester.event(&mut view, &mut data, &Event::Update);
+        assert_eq!(tester.state.0.entries.len(), 1);
+        assert!(tester.contexts.get::<Overlays>().unwrap().is_empty());
+
+        tester.event(&mut view, &mut data, &Event::Animate(1.0));
+        assert_eq!(tester.state.0.entries[0].t, 1.0);
+
+        // the content doesn't get keys while the dialog is open
+        tester.event(&mut view, &mut data, &key(Key::Character('a')));
+        assert_eq!(data, 1);
+
+        tester.event(&mut view, &mut data, &key(Key::Escape));
+        assert!(tester.state.0.entries[0].closing);
+
+        tester.event(&mut view, &mut data, &Event::Animate(1.0));
+        assert!(tester.state.0.entries.is_empty());
+        assert!(closed.get());
+
+        tester.event(&mut view, &mut data, &key(Key::Character('a')));
+        assert_eq!(data, 2);
+    }
+
+    #[test]
+    fn escape_handled_inside() {
+        let mut view = overlay_layer(size(Size::all(200.0), ()));
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(&mut view, &mut (), Space::UNBOUNDED);
+
+        // a view inside of the dialog handling `Escape`, like an open popup would
+        let content = |_: &mut (), _| {
+            on_event(size(Size::all(50.0), ()), |cx, _: &mut (), event| {
+                if let Event::KeyPressed(e) = event {
+                    if e.key == Key::Escape {
+                        cx.set_handled();
+                    }
+                }
+            })
+        };
+
+        let window = tester.contexts.get::<Window>().unwrap().id();
+        let overlays = tester.contexts.get_or_default::<Overlays>();
+        overlays.open(Some(window), Overlay::dialog(content));
+
+        tester.event(&mut view, &mut (), &Event::Update);
+        tester.event(&mut view, &mut (), &Event::Animate(1.0));
+
+        tester.event(&mut view, &mut (), &key(Key::Escape));
+        assert!(!tester.state.0.entries[0].closing);
+    }
+
+    #[test]
+    fn dialog_traps_focus() {
+        let mut view = overlay_layer(vstack((Focusable('a'), Focusable('b'))));
+        let mut data = String::new();
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+
+        let mut focused = |tester: &mut ViewTester<_, _>, event: &Event| {
+            tester.event(&mut view, &mut data, event);
+
+            data.clear();
+            tester.event(&mut view, &mut data, &Event::Update);
+            std::mem::take(&mut data)
+        };
+
+        assert_eq!(focused(&mut tester, &key(Key::Character('b'))), "b");
+
+        let window = tester.contexts.get::<Window>().unwrap().id();
+        let overlays = tester.contexts.get_or_default::<Overlays>();
+        overlays.open(
+            Some(window),
+            Overlay::dialog(|_: &mut String, _| vstack((Focusable('x'), Focusable('y')))),
+        );
+
+        // the first view of the dialog takes the focus from the content
+        assert_eq!(focused(&mut tester, &Event::Animate(1.0)), "x");
+
+        // tab cycles through the views of the dialog only
+        assert_eq!(focused(&mut tester, &key(Key::Tab)), "y");
+        assert_eq!(focused(&mut tester, &key(Key::Tab)), "x");
+
+        let shift_tab = Event::KeyPressed(KeyPressed {
+            key: Key::Tab,
+            code: None,
+            text: None,
+            modifiers: Modifiers {
+                shift: true,
+                ..Default::default()
+            },
+        });
+        assert_eq!(focused(&mut tester, &shift_tab), "y");
+
+        // and the focus is given back when it's closed
+        assert_eq!(focused(&mut tester, &key(Key::Escape)), "b");
+    }
+}
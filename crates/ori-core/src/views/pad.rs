@@ -7,7 +7,7 @@ use crate::{
     event::Event,
     layout::{Padding, Size, Space},
     rebuild::Rebuild,
-    view::{Pod, State, View},
+    view::{LayoutDebugger, Pod, State, View},
 };
 
 /// Create a new [`Pad`] view.
@@ -96,13 +96,17 @@ impl<T, V: View<T>> View<T> for Pad<V> {
     ) -> Size {
         let content_space = space.shrink(self.padding.size());
         let content_size = self.content.layout(state, cx, data, content_space);
+        let size = space.fit(content_size + self.padding.size());
 
-        state.translate(self.padding.offset());
+        let offset = self.padding.offset();
+        state.translate(cx.direction().mirror(offset, content_size, size));
+        cx.set_baseline(state.parent_baseline());
 
-        space.fit(content_size + self.padding.size())
+        size
     }
 
     fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        LayoutDebugger::record_padding(cx, self.padding);
         self.content.draw(state, cx, data);
     }
 }
@@ -112,7 +116,7 @@ mod tests {
     use crate::{
         layout::{Rect, Space},
         views::{
-            pad, size,
+            pad, rtl, size,
             testing::{save_layout, test_layout},
         },
     };
@@ -127,4 +131,14 @@ mod tests {
         assert_eq!(layouts["pad"], Rect::from([0.0, 0.0, 19.0, 17.0]));
         assert_eq!(layouts["inner"], Rect::from([6.0, 3.0, 15.0, 12.0]));
     }
+
+    #[test]
+    fn right_to_left() {
+        let inner = save_layout(size(9.0, ()), "inner");
+        let mut view = rtl(pad([3.0, 4.0, 5.0, 6.0], inner));
+
+        let layouts = test_layout(&mut view, &mut (), Space::UNBOUNDED);
+
+        assert_eq!(layouts["inner"], Rect::from([4.0, 3.0, 13.0, 12.0]));
+    }
 }
@@ -0,0 +1,322 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use ori_macro::Build;
+
+use crate::{
+    canvas::{BorderRadius, Color, Curve, Stroke, StrokeCap},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    transition::Transition,
+    view::View,
+};
+
+/// Create a new [`Progress`] bar.
+pub fn progress(value: f32) -> Progress {
+    Progress::new(value)
+}
+
+/// Create a new [`Progress`] ring.
+pub fn progress_ring(value: f32) -> Progress {
+    Progress::new(value).kind(ProgressKind::Ring)
+}
+
+/// The style of a [`Progress`] and a [`Spinner`](super::Spinner).
+#[derive(Clone, Debug)]
+pub struct ProgressStyle {
+    /// The transition of the value.
+    pub transition: Transition,
+
+    /// The length of a bar.
+    pub length: f32,
+
+    /// The thickness of a bar or ring.
+    pub thickness: f32,
+
+    /// The size of a ring.
+    pub size: f32,
+
+    /// The number of turns per second of a spinner.
+    pub speed: f32,
+
+    /// The color of the progress.
+    pub color: Color,
+
+    /// The color of the track.
+    pub background: Color,
+
+    /// The border radius of a bar.
+    pub border_radius: BorderRadius,
+}
+
+impl Style for ProgressStyle {
+    fn styled(style: &Styles) -> Self {
+        let palette = style.palette();
+
+        Self {
+            transition: Transition::ease(0.2),
+            length: 160.0,
+            thickness: 6.0,
+            size: 32.0,
+            speed: 1.0,
+            color: palette.primary,
+            background: palette.surface_high,
+            border_radius: BorderRadius::all(3.0),
+        }
+    }
+}
+
+/// The kind of a [`Progress`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ProgressKind {
+    /// A horizontal bar.
+    #[default]
+    Bar,
+
+    /// A ring, filled clockwise from the top.
+    Ring,
+}
+
+/// A progress indicator, showing a value from `0.0` to `1.0`.
+///
+/// Changes to the value are animated, but only while the progress is drawn. For work of an
+/// unknown length, use a [`Spinner`](super::Spinner).
+///
+/// Can be styled using the [`ProgressStyle`].
+#[derive(Build, Rebuild)]
+pub struct Progress {
+    /// The value of the progress, from `0.0` to `1.0`.
+    #[rebuild(draw)]
+    pub value: f32,
+
+    /// The kind of the progress.
+    #[rebuild(layout)]
+    pub kind: ProgressKind,
+
+    /// The transition of the value.
+    pub transition: Transition,
+
+    /// The length of a bar.
+    #[rebuild(layout)]
+    pub length: f32,
+
+    /// The thickness of a bar or ring.
+    #[rebuild(layout)]
+    pub thickness: f32,
+
+    /// The size of a ring.
+    #[rebuild(layout)]
+    pub size: f32,
+
+    /// The color of the progress.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of the track.
+    #[rebuild(draw)]
+    pub background: Color,
+
+    /// The border radius of a bar.
+    #[rebuild(draw)]
+    pub border_radius: BorderRadius,
+}
+
+impl Progress {
+    /// Create a new [`Progress`].
+    pub fn new(value: f32) -> Self {
+        Self::styled(value, style())
+    }
+
+    /// Create a new [`Progress`] with a style.
+    pub fn styled(value: f32, style: ProgressStyle) -> Self {
+        Self {
+            value,
+            kind: ProgressKind::Bar,
+            transition: style.transition,
+            length: style.length,
+            thickness: style.thickness,
+            size: style.size,
+            color: style.color,
+            background: style.background,
+            border_radius: style.border_radius,
+        }
+    }
+
+    fn target(&self) -> f32 {
+        self.value.clamp(0.0, 1.0)
+    }
+}
+
+#[doc(hidden)]
+pub struct ProgressState {
+    // the shown value when the transition started
+    from: f32,
+    shown: f32,
+    t: f32,
+}
+
+impl<T> View<T> for Progress {
+    type State = ProgressState;
+
+    fn build(&mut self, _cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        ProgressState {
+            from: self.target(),
+            shown: self.target(),
+            t: 1.0,
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        if self.target() != old.target() {
+            state.from = state.shown;
+            state.t = 0.0;
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, _data: &mut T, event: &Event) {
+        if let Event::Animate(dt) = event {
+            self.transition.step(&mut state.t, true, *dt);
+
+            let t = self.transition.get(state.t);
+            state.shown = state.from + (self.target() - state.from) * t;
+
+            cx.draw();
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _state: &mut Self::State,
+        _cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        let size = match self.kind {
+            ProgressKind::Bar => Size::new(self.length, self.thickness),
+            ProgressKind::Ring => Size::all(self.size),
+        };
+
+        space.fit(size)
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        // frames are only requested while drawn, so hidden progress doesn't animate
+        if !self.transition.complete(state.t, true) {
+            cx.animate();
+        }
+
+        match self.kind {
+            ProgressKind::Bar => {
+                cx.quad(
+                    cx.rect(),
+                    self.background,
+                    self.border_radius,
+                    0.0,
+                    Color::TRANSPARENT,
+                );
+
+                if state.shown <= 0.0 {
+                    return;
+                }
+
+                let min_length = self.border_radius.max_element() * 2.0;
+                let length = f32::max(cx.size().width * state.shown, min_length);
+                let size = Size::new(length, cx.size().height);
+
+                // bars fill from the right when right to left
+                let offset = cx.direction().mirror(Vector::ZERO, size, cx.size());
+
+                cx.quad(
+                    Rect::min_size(cx.rect().min + offset, size),
+                    self.color,
+                    self.border_radius,
+                    0.0,
+                    Color::TRANSPARENT,
+                );
+            }
+            ProgressKind::Ring => {
+                let radius = (cx.size().min_element() - self.thickness) / 2.0;
+                let center = cx.rect().center();
+
+                let track = Curve::circle(center, radius);
+                cx.stroke(track, self.thickness, self.background);
+
+                if state.shown <= 0.0 {
+                    return;
+                }
+
+                let curve = arc(center, radius, -FRAC_PI_2, state.shown * TAU);
+                cx.stroke(curve, round(self.thickness), self.color);
+            }
+        }
+    }
+}
+
+// a round capped stroke
+pub(super) fn round(width: f32) -> Stroke {
+    Stroke {
+        width,
+        cap: StrokeCap::Round,
+        ..Default::default()
+    }
+}
+
+// an arc around `center`, clockwise from the angle `start` over the angle `sweep`
+pub(super) fn arc(center: Point, radius: f32, start: f32, sweep: f32) -> Curve {
+    let segments = (sweep.abs() / TAU * 64.0).ceil().max(1.0) as usize;
+
+    let point = |angle: f32| center + Vector::from_angle(angle) * radius;
+
+    let mut curve = Curve::new();
+    curve.move_to(point(start));
+
+    for i in 1..=segments {
+        curve.line_to(point(start + sweep * i as f32 / segments as f32));
+    }
+
+    curve
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{event::Event, layout::Space, view::pod, views::testing::ViewTester};
+
+    use super::progress;
+
+    #[test]
+    fn animates_only_when_drawn() {
+        let mut view = pod(progress(0.0));
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(&mut view, &mut (), Space::UNBOUNDED);
+
+        tester.draw(&mut view, &mut ());
+        assert!(!tester.view_state.needs_animate());
+
+        let old = view;
+        let mut view = pod(progress(1.0));
+        tester.rebuild(&mut view, &mut (), &old);
+
+        tester.draw(&mut view, &mut ());
+        assert!(tester.view_state.needs_animate());
+        tester.view_state.mark_animated();
+
+        tester.event(&mut view, &mut (), &Event::Animate(0.1));
+        tester.view_state.mark_animated();
+
+        // not drawn, so no more frames are requested
+        tester.event(&mut view, &mut (), &Event::Animate(0.1));
+        assert!(!tester.view_state.needs_animate());
+
+        tester.draw(&mut view, &mut ());
+        tester.view_state.mark_animated();
+        tester.event(&mut view, &mut (), &Event::Animate(1.0));
+        tester.draw(&mut view, &mut ());
+
+        assert_eq!(tester.state.content().shown, 1.0);
+        assert!(!tester.view_state.needs_animate());
+    }
+}
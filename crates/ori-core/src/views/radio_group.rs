@@ -0,0 +1,480 @@
+use std::fmt::Display;
+
+use ori_macro::Build;
+
+use crate::{
+    canvas::{Color, Curve, FillRule},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, KeyPressed},
+    layout::{Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    text::{FontFamily, Fonts, TextAttributes, TextBuffer, TextWrap},
+    transition::Transition,
+    view::View,
+    window::Cursor,
+};
+
+use super::TextStyle;
+
+/// Create a new [`RadioGroup`], with `value` selected out of `options`.
+pub fn radio_group<T, O>(value: O, options: impl IntoIterator<Item = O>) -> RadioGroup<T, O> {
+    RadioGroup::new(value, options)
+}
+
+/// The style of a [`RadioGroup`].
+#[derive(Clone, Debug)]
+pub struct RadioGroupStyle {
+    /// The transition of the selection dot.
+    pub transition: Transition,
+
+    /// The size of each radio button.
+    pub size: f32,
+
+    /// The gap between the options.
+    pub gap: f32,
+
+    /// The space between a radio button and its label.
+    pub spacing: f32,
+
+    /// The font size of the labels.
+    pub font_size: f32,
+
+    /// The font family of the labels.
+    pub font_family: FontFamily,
+
+    /// The color of the labels.
+    pub color: Color,
+
+    /// The color of the selection dot.
+    pub dot_color: Color,
+
+    /// The border width of the radio buttons.
+    pub border_width: f32,
+
+    /// The border color of the radio buttons.
+    pub border_color: Color,
+
+    /// The border color of a hovered radio button.
+    pub hover_color: Color,
+
+    /// The color of the focus ring.
+    pub focus_color: Color,
+}
+
+impl Style for RadioGroupStyle {
+    fn styled(style: &Styles) -> Self {
+        let text_style = style.get::<TextStyle>();
+        let palette = style.palette();
+
+        Self {
+            transition: Transition::ease(0.1),
+            size: 20.0,
+            gap: 8.0,
+            spacing: 8.0,
+            font_size: 16.0,
+            font_family: text_style.font_family.clone(),
+            color: palette.contrast,
+            dot_color: palette.primary,
+            border_width: 2.0,
+            border_color: palette.outline,
+            hover_color: palette.outline.lighten(0.2),
+            focus_color: palette.primary.fade(0.5),
+        }
+    }
+}
+
+/// A vertical group of radio buttons, of which only one can be selected.
+///
+/// The options are labeled with their [`Display`] implementation, and the selected option is
+/// compared with [`PartialEq`]. When focused, the arrow keys, `Home` and `End` select another
+/// option.
+///
+/// Can be styled using the [`RadioGroupStyle`].
+#[derive(Build, Rebuild)]
+pub struct RadioGroup<T, O> {
+    /// The selected value.
+    #[build(ignore)]
+    pub value: O,
+
+    /// The options.
+    #[build(ignore)]
+    pub options: Vec<O>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_change: Option<Box<dyn FnMut(&mut EventCx, &mut T, &O)>>,
+
+    /// The transition of the selection dot.
+    #[rebuild(draw)]
+    pub transition: Transition,
+
+    /// The size of each radio button.
+    #[rebuild(layout)]
+    pub size: f32,
+
+    /// The gap between the options.
+    #[rebuild(layout)]
+    pub gap: f32,
+
+    /// The space between a radio button and its label.
+    #[rebuild(layout)]
+    pub spacing: f32,
+
+    /// The font size of the labels.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the labels.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The color of the labels.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of the selection dot.
+    #[rebuild(draw)]
+    pub dot_color: Color,
+
+    /// The border width of the radio buttons.
+    #[rebuild(draw)]
+    pub border_width: f32,
+
+    /// The border color of the radio buttons.
+    #[rebuild(draw)]
+    pub border_color: Color,
+
+    /// The border color of a hovered radio button.
+    #[rebuild(draw)]
+    pub hover_color: Color,
+
+    /// The color of the focus ring.
+    #[rebuild(draw)]
+    pub focus_color: Color,
+}
+
+impl<T, O> RadioGroup<T, O> {
+    /// Create a new [`RadioGroup`].
+    pub fn new(value: O, options: impl IntoIterator<Item = O>) -> Self {
+        Self::styled(value, options, style())
+    }
+
+    /// Create a new [`RadioGroup`] with a style.
+    pub fn styled(value: O, options: impl IntoIterator<Item = O>, style: RadioGroupStyle) -> Self {
+        Self {
+            value,
+            options: options.into_iter().collect(),
+            on_change: None,
+            transition: style.transition,
+            size: style.size,
+            gap: style.gap,
+            spacing: style.spacing,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            color: style.color,
+            dot_color: style.dot_color,
+            border_width: style.border_width,
+            border_color: style.border_color,
+            hover_color: style.hover_color,
+            focus_color: style.focus_color,
+        }
+    }
+
+    /// Set the callback that is called when another option is selected.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_change(mut self, on_change: impl FnMut(&mut EventCx, &mut T, &O) + 'static) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    fn row_height(&self, label: &TextBuffer) -> f32 {
+        f32::max(self.size, label.size().height)
+    }
+}
+
+#[doc(hidden)]
+pub struct RadioGroupState {
+    labels: Vec<String>,
+    buffers: Vec<TextBuffer>,
+    rows: Vec<f32>,
+    hovered: Option<usize>,
+    selected: Option<usize>,
+    t: f32,
+}
+
+impl<T, O: Display + PartialEq> RadioGroup<T, O> {
+    fn labels(&self) -> Vec<String> {
+        self.options.iter().map(ToString::to_string).collect()
+    }
+
+    fn selected(&self) -> Option<usize> {
+        self.options.iter().position(|option| *option == self.value)
+    }
+
+    fn set_buffers(&self, fonts: &mut Fonts, state: &mut RadioGroupState) {
+        state.buffers.clear();
+
+        for label in &state.labels {
+            let mut buffer = TextBuffer::new(fonts, self.font_size, 1.2);
+            buffer.set_wrap(fonts, TextWrap::None);
+            buffer.set_text(
+                fonts,
+                label,
+                TextAttributes {
+                    family: self.font_family.clone(),
+                    ..Default::default()
+                },
+            );
+
+            state.buffers.push(buffer);
+        }
+    }
+
+    fn row_at(&self, state: &RadioGroupState, point: Point) -> Option<usize> {
+        let index = state.rows.iter().rposition(|&y| point.y >= y)?;
+        let bottom = state.rows[index] + self.row_height(&state.buffers[index]);
+        (point.y <= bottom).then_some(index)
+    }
+
+    fn change(&mut self, cx: &mut EventCx, data: &mut T, index: usize) {
+        if self.options[index] == self.value {
+            return;
+        }
+
+        if let Some(ref mut on_change) = self.on_change {
+            on_change(cx, data, &self.options[index]);
+        }
+    }
+
+    fn key_pressed(&mut self, cx: &mut EventCx, data: &mut T, e: &KeyPressed) {
+        if self.options.is_empty() {
+            return;
+        }
+
+        let last = self.options.len() - 1;
+        let selected = self.selected();
+
+        let index = match e.key {
+            Key::Up | Key::Left => selected.map_or(last, |i| i.saturating_sub(1)),
+            Key::Down | Key::Right => selected.map_or(0, |i| usize::min(i + 1, last)),
+            Key::Home => 0,
+            Key::End => last,
+            _ => return,
+        };
+
+        self.change(cx, data, index);
+    }
+}
+
+impl<T, O: Display + PartialEq> View<T> for RadioGroup<T, O> {
+    type State = RadioGroupState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.set_focusable(true);
+
+        let mut state = RadioGroupState {
+            labels: self.labels(),
+            buffers: Vec::new(),
+            rows: Vec::new(),
+            hovered: None,
+            selected: self.selected(),
+            t: 1.0,
+        };
+
+        self.set_buffers(cx.fonts(), &mut state);
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        let labels = self.labels();
+
+        if labels != state.labels
+            || self.font_size != old.font_size
+            || self.font_family != old.font_family
+        {
+            state.labels = labels;
+            self.set_buffers(cx.fonts(), state);
+            cx.layout();
+        }
+
+        let selected = self.selected();
+
+        if selected != state.selected {
+            state.selected = selected;
+            state.t = 0.0;
+            cx.animate();
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        match event {
+            Event::PointerMoved(e) => {
+                let hovered = match cx.is_hot() {
+                    true => self.row_at(state, cx.local(e.position)),
+                    false => None,
+                };
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    cx.draw();
+                }
+
+                cx.set_cursor(hovered.map(|_| Cursor::Pointer));
+            }
+            Event::PointerPressed(e) => {
+                if !cx.is_hot() {
+                    if cx.is_focused() {
+                        cx.set_focused(false);
+                        cx.draw();
+                    }
+
+                    return;
+                }
+
+                if let Some(index) = self.row_at(state, cx.local(e.position)) {
+                    cx.set_focused(true);
+                    cx.draw();
+
+                    self.change(cx, data, index);
+                }
+            }
+            Event::KeyPressed(e) if cx.is_focused() => {
+                self.key_pressed(cx, data, e);
+            }
+            Event::Animate(dt) => {
+                if self.transition.step(&mut state.t, true, *dt) {
+                    cx.animate();
+                }
+
+                cx.draw();
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        _cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        state.rows.clear();
+
+        let mut width: f32 = 0.0;
+        let mut y = 0.0;
+
+        for (i, buffer) in state.buffers.iter().enumerate() {
+            if i > 0 {
+                y += self.gap;
+            }
+
+            state.rows.push(y);
+
+            width = width.max(self.size + self.spacing + buffer.size().width);
+            y += self.row_height(buffer);
+        }
+
+        space.fit(Size::new(width, y))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        let rtl = cx.direction().is_rtl();
+        let width = cx.size().width;
+
+        for (i, buffer) in state.buffers.iter().enumerate() {
+            let row_height = self.row_height(buffer);
+            let y = state.rows[i];
+
+            let x = match rtl {
+                true => width - self.size / 2.0,
+                false => self.size / 2.0,
+            };
+
+            let center = Point::new(x, y + row_height / 2.0);
+            let radius = self.size / 2.0;
+
+            let row = Rect::min_size(Point::new(0.0, y), Size::new(width, row_height));
+            cx.trigger(row);
+
+            let border_color = match state.hovered == Some(i) {
+                true => self.hover_color,
+                false => self.border_color,
+            };
+
+            // fill a ring inside the bounds of the radio button
+            let inner = (radius - self.border_width) * 2.0;
+            let mut ring = Curve::circle(center, radius);
+            ring.push_oval(Rect::center_size(center, Size::all(inner)));
+            cx.fill(ring, FillRule::EvenOdd, border_color);
+
+            if state.selected == Some(i) {
+                let t = self.transition.get(state.t);
+                let dot = (radius - self.border_width * 2.0) * t;
+                cx.fill(
+                    Curve::circle(center, dot),
+                    FillRule::NonZero,
+                    self.dot_color,
+                );
+
+                if cx.is_focused() {
+                    let ring = Curve::circle(center, radius + 2.0);
+                    cx.stroke(ring, 2.0, self.focus_color);
+                }
+            }
+
+            let x = match rtl {
+                true => width - self.size - self.spacing - buffer.size().width,
+                false => self.size + self.spacing,
+            };
+
+            let offset = Vector::new(x, y + (row_height - buffer.size().height) / 2.0);
+            cx.text(buffer, self.color, offset);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Key, KeyPressed, Modifiers},
+        layout::Space,
+        views::testing::ViewTester,
+    };
+
+    use super::radio_group;
+
+    fn key(key: Key) -> Event {
+        Event::KeyPressed(KeyPressed {
+            key,
+            code: None,
+            text: None,
+            modifiers: Modifiers::default(),
+        })
+    }
+
+    #[test]
+    fn arrow_keys() {
+        let mut value = 1;
+
+        let mut view = radio_group(value, [0, 1, 2]).on_change(|_, value, option| *value = *option);
+        let mut tester = ViewTester::new(&mut view, &mut value);
+        tester.layout(&mut view, &mut value, Space::UNBOUNDED);
+        tester.view_state.set_focused(true);
+
+        tester.event(&mut view, &mut value, &key(Key::Down));
+        assert_eq!(value, 2);
+
+        // the view is not rebuilt, so the value is still 1 for the view
+        tester.event(&mut view, &mut value, &key(Key::Up));
+        assert_eq!(value, 0);
+
+        tester.event(&mut view, &mut value, &key(Key::End));
+        assert_eq!(value, 2);
+    }
+}
@@ -0,0 +1,478 @@
+use ori_macro::Build;
+use smol_str::SmolStr;
+
+use crate::{
+    canvas::Color,
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, PointerButton},
+    layout::{Point, Rect, Size, Space, Vector},
+    style::style,
+    text::{
+        FontFamily, FontStretch, FontStyle, FontWeight, Fonts, TextAlign, TextAttributes,
+        TextBuffer, TextWrap,
+    },
+    view::View,
+    window::Cursor,
+};
+
+use super::TextStyle;
+
+/// Create a new [`RichText`].
+pub fn rich_text<T>(spans: impl IntoIterator<Item = Span<T>>) -> RichText<T> {
+    RichText::new(spans)
+}
+
+/// Create a new [`Span`].
+pub fn span<T>(text: impl Into<SmolStr>) -> Span<T> {
+    Span::new(text)
+}
+
+/// A span of text in a [`RichText`].
+///
+/// Attributes that aren't set are inherited from the [`RichText`].
+#[derive(Build)]
+pub struct Span<T> {
+    /// The text.
+    pub text: SmolStr,
+
+    /// The font size of the text.
+    pub font_size: Option<f32>,
+
+    /// The font family of the text.
+    pub font_family: Option<FontFamily>,
+
+    /// The font weight of the text.
+    pub font_weight: Option<FontWeight>,
+
+    /// The font stretch of the text.
+    pub font_stretch: Option<FontStretch>,
+
+    /// The font style of the text.
+    pub font_style: Option<FontStyle>,
+
+    /// The color of the text.
+    pub color: Option<Color>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_click: Option<Box<dyn FnMut(&mut EventCx, &mut T)>>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_hover: Option<Box<dyn FnMut(&mut EventCx, &mut T, bool)>>,
+}
+
+impl<T> Span<T> {
+    /// Create a new [`Span`].
+    pub fn new(text: impl Into<SmolStr>) -> Self {
+        Self {
+            text: text.into(),
+            font_size: None,
+            font_family: None,
+            font_weight: None,
+            font_stretch: None,
+            font_style: None,
+            color: None,
+            on_click: None,
+            on_hover: None,
+        }
+    }
+
+    /// Set the callback that is called when the span is clicked.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_click(mut self, on_click: impl FnMut(&mut EventCx, &mut T) + 'static) -> Self {
+        self.on_click = Some(Box::new(on_click));
+        self
+    }
+
+    /// Set the callback that is called when the pointer enters or leaves the span.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_hover(mut self, on_hover: impl FnMut(&mut EventCx, &mut T, bool) + 'static) -> Self {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+
+    fn is_same(&self, other: &Self) -> bool {
+        self.text == other.text
+            && self.font_size == other.font_size
+            && self.font_family == other.font_family
+            && self.font_weight == other.font_weight
+            && self.font_stretch == other.font_stretch
+            && self.font_style == other.font_style
+            && self.color == other.color
+    }
+}
+
+/// A view that displays text made of [`Span`]s, each with their own attributes.
+///
+/// Unlike a stack of [`Text`](super::Text)s, the spans are laid out and wrapped together as a
+/// single paragraph. Spans with callbacks can be clicked and hovered, which makes them useful
+/// for inline links.
+///
+/// Can be styled using the [`TextStyle`].
+#[derive(Build)]
+pub struct RichText<T> {
+    /// The spans.
+    #[build(ignore)]
+    pub spans: Vec<Span<T>>,
+
+    /// The font size of the text.
+    pub font_size: f32,
+
+    /// The font family of the text.
+    pub font_family: FontFamily,
+
+    /// The font weight of the text.
+    pub font_weight: FontWeight,
+
+    /// The font stretch of the text.
+    pub font_stretch: FontStretch,
+
+    /// The font style of the text.
+    pub font_style: FontStyle,
+
+    /// The color of the text.
+    pub color: Color,
+
+    /// The horizontal alignment of the text.
+    pub align: TextAlign,
+
+    /// The line height of the text.
+    pub line_height: f32,
+
+    /// The text wrap of the text.
+    pub wrap: TextWrap,
+}
+
+impl<T> RichText<T> {
+    /// Create a new rich text.
+    pub fn new(spans: impl IntoIterator<Item = Span<T>>) -> Self {
+        Self::styled(spans, style())
+    }
+
+    /// Create a new rich text with a style.
+    pub fn styled(spans: impl IntoIterator<Item = Span<T>>, style: TextStyle) -> Self {
+        Self {
+            spans: spans.into_iter().collect(),
+            font_size: style.font_size,
+            font_family: style.font_family,
+            font_weight: style.font_weight,
+            font_stretch: style.font_stretch,
+            font_style: style.font_style,
+            color: style.color,
+            align: style.align,
+            line_height: style.line_height,
+            wrap: style.wrap,
+        }
+    }
+
+    /// Add a span.
+    pub fn span(mut self, span: Span<T>) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    fn attributes(&self) -> TextAttributes {
+        TextAttributes {
+            family: self.font_family.clone(),
+            stretch: self.font_stretch,
+            weight: self.font_weight,
+            style: self.font_style,
+        }
+    }
+
+    fn set_text(&self, fonts: &mut Fonts, buffer: &mut TextBuffer) {
+        let attributes: Vec<_> = (self.spans.iter())
+            .map(|span| TextAttributes {
+                family: span.font_family.clone().unwrap_or(self.font_family.clone()),
+                stretch: span.font_stretch.unwrap_or(self.font_stretch),
+                weight: span.font_weight.unwrap_or(self.font_weight),
+                style: span.font_style.unwrap_or(self.font_style),
+            })
+            .collect();
+
+        // the index of each span is stored in the metadata of its glyphs, see `span_at`
+        let spans = self.spans.iter().zip(&attributes).enumerate();
+        let spans = spans.map(|(i, (span, attributes))| {
+            let mut attrs = attributes.to_cosmic_text().metadata(i);
+
+            if let Some(color) = span.color {
+                let [r, g, b, a] = color.to_rgba8();
+                attrs = attrs.color(cosmic_text::Color::rgba(r, g, b, a));
+            }
+
+            if let Some(font_size) = span.font_size {
+                let line_height = font_size * self.line_height;
+                attrs = attrs.metrics(cosmic_text::Metrics::new(font_size, line_height));
+            }
+
+            (span.text.as_str(), attrs)
+        });
+
+        buffer.set_rich_text(fonts, spans, self.attributes());
+        buffer.set_align(self.align);
+    }
+
+    fn is_same_text(&self, old: &Self) -> bool {
+        self.spans.len() == old.spans.len()
+            && self.spans.iter().zip(&old.spans).all(|(a, b)| a.is_same(b))
+            && self.font_family == old.font_family
+            && self.font_weight == old.font_weight
+            && self.font_stretch == old.font_stretch
+            && self.font_style == old.font_style
+    }
+
+    fn offset(&self, state: &RichTextState, size: Size) -> Vector {
+        Rect::min_size(Point::ZERO, size).center() - state.buffer.rect().center()
+    }
+
+    // find the span under `local`
+    fn span_at(&self, state: &RichTextState, size: Size, local: Point) -> Option<usize> {
+        let point = local - self.offset(state, size);
+
+        for run in state.buffer.raw().layout_runs() {
+            if point.y < run.line_top || point.y >= run.line_top + run.line_height {
+                continue;
+            }
+
+            let glyph = run
+                .glyphs
+                .iter()
+                .find(|glyph| point.x >= glyph.x && point.x < glyph.x + glyph.w)?;
+
+            return Some(glyph.metadata).filter(|&i| i < self.spans.len());
+        }
+
+        None
+    }
+
+    fn set_hovered(
+        &mut self,
+        state: &mut RichTextState,
+        cx: &mut EventCx,
+        data: &mut T,
+        hovered: Option<usize>,
+    ) {
+        if state.hovered == hovered {
+            return;
+        }
+
+        let old = std::mem::replace(&mut state.hovered, hovered);
+
+        if let Some(span) = old.and_then(|i| self.spans.get_mut(i)) {
+            if let Some(ref mut on_hover) = span.on_hover {
+                on_hover(cx, data, false);
+            }
+        }
+
+        if let Some(span) = hovered.and_then(|i| self.spans.get_mut(i)) {
+            if let Some(ref mut on_hover) = span.on_hover {
+                on_hover(cx, data, true);
+            }
+        }
+
+        let clickable = hovered.is_some_and(|i| self.spans[i].on_click.is_some());
+        cx.set_cursor(clickable.then_some(Cursor::Pointer));
+    }
+}
+
+#[doc(hidden)]
+pub struct RichTextState {
+    buffer: TextBuffer,
+    hovered: Option<usize>,
+    pressed: Option<usize>,
+}
+
+impl<T> View<T> for RichText<T> {
+    type State = RichTextState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        let mut buffer = TextBuffer::new(cx.fonts(), self.font_size, self.line_height);
+        buffer.set_wrap(cx.fonts(), self.wrap);
+        self.set_text(cx.fonts(), &mut buffer);
+
+        RichTextState {
+            buffer,
+            hovered: None,
+            pressed: None,
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        if self.font_size != old.font_size || self.line_height != old.line_height {
+            (state.buffer).set_metrics(cx.fonts(), self.font_size, self.line_height);
+
+            cx.layout();
+        }
+
+        if self.wrap != old.wrap {
+            state.buffer.set_wrap(cx.fonts(), self.wrap);
+
+            cx.layout();
+        }
+
+        // span font sizes depend on the line height
+        if !self.is_same_text(old) || self.line_height != old.line_height {
+            self.set_text(cx.fonts(), &mut state.buffer);
+
+            cx.layout();
+        } else if self.align != old.align {
+            state.buffer.set_align(self.align);
+
+            cx.draw();
+        }
+
+        if self.spans.len() != old.spans.len() {
+            state.hovered = None;
+            state.pressed = None;
+        }
+
+        if self.color != old.color {
+            cx.draw();
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        match event {
+            Event::PointerMoved(e) => {
+                let hovered = match cx.is_hot() {
+                    true => self.span_at(state, cx.size(), cx.local(e.position)),
+                    false => None,
+                };
+
+                self.set_hovered(state, cx, data, hovered);
+            }
+            Event::PointerLeft(_) => {
+                self.set_hovered(state, cx, data, None);
+            }
+            Event::PointerPressed(e) if e.button == PointerButton::Primary && cx.is_hot() => {
+                let span = self.span_at(state, cx.size(), cx.local(e.position));
+                state.pressed = span.filter(|&i| self.spans[i].on_click.is_some());
+
+                if state.pressed.is_some() {
+                    cx.set_active(true);
+                }
+            }
+            Event::PointerReleased(e) if e.button == PointerButton::Primary => {
+                let Some(pressed) = state.pressed.take() else {
+                    return;
+                };
+
+                cx.set_active(false);
+
+                let span = self.span_at(state, cx.size(), cx.local(e.position));
+
+                if span == Some(pressed) {
+                    if let Some(ref mut on_click) = self.spans[pressed].on_click {
+                        on_click(cx, data);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        if state.buffer.bounds() != space.max {
+            state.buffer.set_bounds(cx.fonts(), space.max);
+        }
+
+        let size = space.fit(state.buffer.size());
+
+        // the text is centered in the view, see `draw` below
+        let offset = size.height / 2.0 - state.buffer.size().height / 2.0;
+        cx.set_baseline(state.buffer.baseline().map(|b| b + offset));
+
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        // spans with callbacks need the view to be hot
+        let interactive =
+            (self.spans.iter()).any(|span| span.on_click.is_some() || span.on_hover.is_some());
+
+        if interactive {
+            cx.hoverable(|cx| cx.fill_rect(cx.rect(), Color::TRANSPARENT));
+        }
+
+        let offset = self.offset(state, cx.size());
+        cx.text(&state.buffer, self.color, offset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{
+            Event, Modifiers, PointerButton, PointerId, PointerMoved, PointerPressed,
+            PointerReleased,
+        },
+        layout::{Point, Space},
+        views::testing::ViewTester,
+    };
+
+    use super::{rich_text, span};
+
+    #[test]
+    fn click_span() {
+        let mut clicked = Vec::new();
+
+        let mut view = rich_text([
+            span("Read the "),
+            span("docs").on_click(|_, clicked: &mut Vec<&str>| clicked.push("docs")),
+        ]);
+
+        let mut tester = ViewTester::new(&mut view, &mut clicked);
+        let size = tester.layout(&mut view, &mut clicked, Space::UNBOUNDED);
+
+        // the link is at the end of the line
+        let link = Point::new(size.width - 4.0, size.height / 2.0);
+        let text = Point::new(4.0, size.height / 2.0);
+
+        tester.view_state.set_hot(true);
+
+        for position in [text, link] {
+            tester.event(&mut view, &mut clicked, &pressed(position));
+            tester.event(&mut view, &mut clicked, &released(position));
+        }
+
+        assert_eq!(clicked, ["docs"]);
+
+        let moved = Event::PointerMoved(PointerMoved {
+            id: PointerId::from_hash(&0),
+            position: link,
+            delta: Default::default(),
+            modifiers: Modifiers::default(),
+        });
+
+        tester.event(&mut view, &mut clicked, &moved);
+        assert_eq!(tester.state.hovered, Some(1));
+
+        fn pressed(position: Point) -> Event {
+            Event::PointerPressed(PointerPressed {
+                id: PointerId::from_hash(&0),
+                position,
+                button: PointerButton::Primary,
+                modifiers: Modifiers::default(),
+            })
+        }
+
+        fn released(position: Point) -> Event {
+            Event::PointerReleased(PointerReleased {
+                id: PointerId::from_hash(&0),
+                position,
+                button: PointerButton::Primary,
+                modifiers: Modifiers::default(),
+                clicked: true,
+            })
+        }
+    }
+}
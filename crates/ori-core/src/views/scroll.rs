@@ -4,7 +4,7 @@ use crate::{
     canvas::{BorderRadius, Color},
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
     event::Event,
-    layout::{Axis, Rect, Size, Space, Vector},
+    layout::{Axis, Direction, Rect, Size, Space, Vector},
     rebuild::Rebuild,
     style::palette,
     transition::Transition,
@@ -71,7 +71,7 @@ impl<V> Scroll<V> {
         }
     }
 
-    fn scrollbar_rect(&self, rect: Rect) -> Rect {
+    fn scrollbar_rect(&self, rect: Rect, direction: Direction) -> Rect {
         let (major, minor) = self.axis.unpack(rect.size());
 
         let length = major - self.inset * 2.0;
@@ -79,12 +79,22 @@ impl<V> Scroll<V> {
         let major_min = self.inset;
         let minor_min = minor - self.width - self.inset;
         let offset = self.axis.pack::<Vector>(major_min, minor_min);
+        let size = self.axis.pack(length, self.width);
 
-        Rect::min_size(rect.top_left() + offset, self.axis.pack(length, self.width))
+        // the scrollbar is at the end of the minor axis, which is the left when right to left
+        let offset = direction.mirror(offset, size, rect.size());
+
+        Rect::min_size(rect.top_left() + offset, size)
     }
 
-    fn scrollbar_knob_rect(&self, rect: Rect, overflow: f32, scroll: f32) -> Rect {
-        let scrollbar_rect = self.scrollbar_rect(rect);
+    fn scrollbar_knob_rect(
+        &self,
+        rect: Rect,
+        direction: Direction,
+        overflow: f32,
+        scroll: f32,
+    ) -> Rect {
+        let scrollbar_rect = self.scrollbar_rect(rect, direction);
 
         let (major_min, minor_min) = self.axis.unpack(scrollbar_rect.min);
         let (major_size, minor_size) = self.axis.unpack(scrollbar_rect.size());
@@ -106,11 +116,53 @@ impl<V> Scroll<V> {
     }
 }
 
+/// The visible region of a [`Scroll`].
+///
+/// This is available as a context to the content of a scroll view while it is being laid out,
+/// see [`BaseCx::get_context`](crate::context::BaseCx::get_context).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollViewport {
+    /// The axis of the scroll view.
+    pub axis: Axis,
+
+    /// The current scroll offset along the axis.
+    pub offset: f32,
+
+    /// The length of the visible region along the axis.
+    pub length: f32,
+
+    observed: bool,
+    scroll_to: Option<f32>,
+}
+
+impl ScrollViewport {
+    fn new(axis: Axis, offset: f32, length: f32) -> Self {
+        Self {
+            axis,
+            offset,
+            length,
+            observed: false,
+            scroll_to: None,
+        }
+    }
+
+    /// Ask the scroll view to lay out the content again whenever the offset changes.
+    pub fn observe(&mut self) {
+        self.observed = true;
+    }
+
+    /// Ask the scroll view to scroll to `offset` after the content has been laid out.
+    pub fn scroll_to(&mut self, offset: f32) {
+        self.scroll_to = Some(offset);
+    }
+}
+
 #[doc(hidden)]
 #[derive(Default)]
 pub struct ScrollState {
     scrollbar_hot: bool,
     scroll: f32,
+    observed: bool,
     t: f32,
 }
 
@@ -148,7 +200,7 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
         if let Event::PointerMoved(e) = event {
             let local = cx.local(e.position);
 
-            let scrollbar_rect = self.scrollbar_rect(cx.rect());
+            let scrollbar_rect = self.scrollbar_rect(cx.rect(), cx.direction());
             state.scrollbar_hot = scrollbar_rect.contains(local);
 
             if cx.is_active() {
@@ -162,6 +214,10 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
 
                 content.translate(self.axis.pack(-state.scroll, 0.0));
 
+                if state.observed {
+                    cx.layout();
+                }
+
                 cx.draw();
             }
         }
@@ -199,6 +255,10 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
 
                 content.translate(self.axis.pack(-state.scroll, 0.0));
 
+                if state.observed {
+                    cx.layout();
+                }
+
                 cx.draw();
             }
         }
@@ -206,7 +266,7 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
 
     fn layout(
         &mut self,
-        (_state, content): &mut Self::State,
+        (state, content): &mut Self::State,
         cx: &mut LayoutCx,
         data: &mut T,
         space: Space,
@@ -219,7 +279,53 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
             self.axis.pack(f32::INFINITY, max_minor),
         );
 
-        let content_size = self.content.layout(content, cx, data, content_space);
+        // the scroll view can be no larger than the window along the axis
+        let length = match self.axis.major(space.max) {
+            length if length.is_finite() => length,
+            _ => self.axis.major(cx.window().size),
+        };
+
+        // when the length depends on the window, so does the layout
+        cx.set_cache_layout(self.axis.major(space.max).is_finite());
+
+        let viewport = ScrollViewport::new(self.axis, state.scroll, length);
+        let (mut content_size, viewport) = layout_content(
+            &mut self.content,
+            content,
+            cx,
+            data,
+            content_space,
+            viewport,
+        );
+
+        state.observed = viewport.observed;
+
+        let size = space.fit(content_size);
+
+        if let Some(offset) = viewport.scroll_to {
+            let overflow = self.overflow(content_size, size);
+            state.scroll = offset.clamp(0.0, overflow);
+            content.translate(self.axis.pack(-state.scroll, 0.0));
+
+            // the content depends on the offset, so it must be laid out again
+            if viewport.observed {
+                let viewport = ScrollViewport::new(self.axis, state.scroll, length);
+                (content_size, _) = layout_content(
+                    &mut self.content,
+                    content,
+                    cx,
+                    data,
+                    content_space,
+                    viewport,
+                );
+            }
+        }
+
+        // content observing the viewport can't be cached, but since the viewport only changes
+        // when the scroll view requests layout, the scroll view itself can be
+        if state.observed {
+            cx.cache_uncached_children();
+        }
 
         let size = space.fit(content_size);
 
@@ -247,7 +353,7 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
         }
 
         cx.quad(
-            self.scrollbar_rect(cx.rect()),
+            self.scrollbar_rect(cx.rect(), cx.direction()),
             self.color.fade(0.7).fade(self.transition.get(state.t)),
             self.border_radius,
             0.0,
@@ -255,7 +361,7 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
         );
 
         cx.quad(
-            self.scrollbar_knob_rect(cx.rect(), overflow, state.scroll),
+            self.scrollbar_knob_rect(cx.rect(), cx.direction(), overflow, state.scroll),
             self.knob_color.fade(0.9).fade(self.transition.get(state.t)),
             self.border_radius,
             0.0,
@@ -263,3 +369,23 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
         );
     }
 }
+
+// lay out the content with the viewport as a context, restoring that of any outer scroll view
+fn layout_content<T, V: View<T>>(
+    view: &mut Pod<V>,
+    content: &mut State<T, V>,
+    cx: &mut LayoutCx,
+    data: &mut T,
+    space: Space,
+    viewport: ScrollViewport,
+) -> (Size, ScrollViewport) {
+    let outer = cx.insert_context(viewport);
+    let size = view.layout(content, cx, data, space);
+
+    let viewport = match outer {
+        Some(outer) => cx.insert_context(outer),
+        None => cx.remove_context(),
+    };
+
+    (size, viewport.expect("ScrollViewport context missing"))
+}
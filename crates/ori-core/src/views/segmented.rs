@@ -0,0 +1,598 @@
+use std::fmt::Display;
+
+use ori_macro::Build;
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key},
+    layout::{Padding, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    text::{FontFamily, FontWeight, Fonts, TextAttributes, TextBuffer, TextWrap},
+    transition::Transition,
+    view::View,
+    window::Cursor,
+};
+
+use super::TextStyle;
+
+/// Create a new [`Segmented`] control, with `value` selected out of `options`.
+pub fn segmented<T, O>(value: O, options: impl IntoIterator<Item = O>) -> Segmented<T, O> {
+    Segmented::new(value, options)
+}
+
+/// The style of a [`Segmented`] control.
+#[derive(Clone, Debug)]
+pub struct SegmentedStyle {
+    /// The transition of the selection indicator.
+    pub transition: Transition,
+
+    /// The padding of each segment.
+    pub padding: Padding,
+
+    /// The font size of the labels.
+    pub font_size: f32,
+
+    /// The font family of the labels.
+    pub font_family: FontFamily,
+
+    /// The font weight of the labels.
+    pub font_weight: FontWeight,
+
+    /// The color of the labels.
+    pub color: Color,
+
+    /// The color of the label of the selected segment.
+    pub selected_color: Color,
+
+    /// The background color.
+    pub background: Color,
+
+    /// The background color of a hovered segment.
+    pub hover: Color,
+
+    /// The color of the selection indicator.
+    pub indicator: Color,
+
+    /// The color of the dividers between segments.
+    pub divider: Color,
+
+    /// The border radius.
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    pub border_color: Color,
+
+    /// The border color when focused.
+    pub focus_color: Color,
+}
+
+impl Style for SegmentedStyle {
+    fn styled(style: &Styles) -> Self {
+        let text_style = style.get::<TextStyle>();
+        let palette = style.palette();
+
+        Self {
+            transition: Transition::ease(0.15),
+            padding: Padding::from([12.0, 6.0]),
+            font_size: 14.0,
+            font_family: text_style.font_family.clone(),
+            font_weight: FontWeight::MEDIUM,
+            color: palette.contrast,
+            selected_color: palette.background,
+            background: palette.surface_high,
+            hover: palette.surface_higher,
+            indicator: palette.primary,
+            divider: palette.outline_low,
+            border_radius: BorderRadius::all(6.0),
+            border_width: BorderWidth::all(1.0),
+            border_color: palette.outline,
+            focus_color: palette.primary,
+        }
+    }
+}
+
+/// A row of connected buttons, of which only one can be selected.
+///
+/// The selection indicator slides between the segments using the [`Transition`]. The options
+/// are labeled with their [`Display`] implementation, and the selected option is compared with
+/// [`PartialEq`]. When focused, the arrow keys, `Home` and `End` select another option.
+///
+/// Can be styled using the [`SegmentedStyle`].
+#[derive(Build, Rebuild)]
+pub struct Segmented<T, O> {
+    /// The selected value.
+    #[build(ignore)]
+    pub value: O,
+
+    /// The options.
+    #[build(ignore)]
+    pub options: Vec<O>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_change: Option<Box<dyn FnMut(&mut EventCx, &mut T, &O)>>,
+
+    /// The transition of the selection indicator.
+    #[rebuild(draw)]
+    pub transition: Transition,
+
+    /// The padding of each segment.
+    #[rebuild(layout)]
+    pub padding: Padding,
+
+    /// The font size of the labels.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the labels.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The font weight of the labels.
+    #[rebuild(layout)]
+    pub font_weight: FontWeight,
+
+    /// The color of the labels.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of the label of the selected segment.
+    #[rebuild(draw)]
+    pub selected_color: Color,
+
+    /// The background color.
+    #[rebuild(draw)]
+    pub background: Color,
+
+    /// The background color of a hovered segment.
+    #[rebuild(draw)]
+    pub hover: Color,
+
+    /// The color of the selection indicator.
+    #[rebuild(draw)]
+    pub indicator: Color,
+
+    /// The color of the dividers between segments.
+    #[rebuild(draw)]
+    pub divider: Color,
+
+    /// The border radius.
+    #[rebuild(draw)]
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    #[rebuild(draw)]
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    #[rebuild(draw)]
+    pub border_color: Color,
+
+    /// The border color when focused.
+    #[rebuild(draw)]
+    pub focus_color: Color,
+}
+
+impl<T, O> Segmented<T, O> {
+    /// Create a new [`Segmented`] control.
+    pub fn new(value: O, options: impl IntoIterator<Item = O>) -> Self {
+        Self::styled(value, options, style())
+    }
+
+    /// Create a new [`Segmented`] control with a style.
+    pub fn styled(value: O, options: impl IntoIterator<Item = O>, style: SegmentedStyle) -> Self {
+        Self {
+            value,
+            options: options.into_iter().collect(),
+            on_change: None,
+            transition: style.transition,
+            padding: style.padding,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            font_weight: style.font_weight,
+            color: style.color,
+            selected_color: style.selected_color,
+            background: style.background,
+            hover: style.hover,
+            indicator: style.indicator,
+            divider: style.divider,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+            focus_color: style.focus_color,
+        }
+    }
+
+    /// Set the callback that is called when another option is selected.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_change(mut self, on_change: impl FnMut(&mut EventCx, &mut T, &O) + 'static) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+}
+
+#[doc(hidden)]
+pub struct SegmentedState {
+    labels: Vec<String>,
+    buffers: Vec<TextBuffer>,
+    hovered: Option<usize>,
+    selected: Option<usize>,
+    // the indicator slides from `from` to `selected`, both in segments
+    from: f32,
+    t: f32,
+}
+
+impl SegmentedState {
+    // the position of the indicator in segments
+    fn indicator(&self, transition: &Transition) -> Option<f32> {
+        let to = self.selected? as f32;
+        Some(self.from + (to - self.from) * transition.get(self.t))
+    }
+}
+
+impl<T, O: Display + PartialEq> Segmented<T, O> {
+    fn labels(&self) -> Vec<String> {
+        self.options.iter().map(ToString::to_string).collect()
+    }
+
+    fn selected(&self) -> Option<usize> {
+        self.options.iter().position(|option| *option == self.value)
+    }
+
+    fn set_buffers(&self, fonts: &mut Fonts, state: &mut SegmentedState) {
+        state.buffers.clear();
+
+        for label in &state.labels {
+            let mut buffer = TextBuffer::new(fonts, self.font_size, 1.2);
+            buffer.set_wrap(fonts, TextWrap::None);
+            buffer.set_text(
+                fonts,
+                label,
+                TextAttributes {
+                    family: self.font_family.clone(),
+                    weight: self.font_weight,
+                    ..Default::default()
+                },
+            );
+
+            state.buffers.push(buffer);
+        }
+    }
+
+    // the segment at `x`, segments are laid out in reverse when right to left
+    fn segment_at(&self, width: f32, x: f32, rtl: bool) -> Option<usize> {
+        if self.options.is_empty() || x < 0.0 || x > width {
+            return None;
+        }
+
+        let segment = width / self.options.len() as f32;
+        let index = ((x / segment) as usize).min(self.options.len() - 1);
+
+        match rtl {
+            true => Some(self.options.len() - 1 - index),
+            false => Some(index),
+        }
+    }
+
+    fn change(&mut self, cx: &mut EventCx, data: &mut T, index: usize) {
+        if self.options[index] == self.value {
+            return;
+        }
+
+        if let Some(ref mut on_change) = self.on_change {
+            on_change(cx, data, &self.options[index]);
+        }
+    }
+}
+
+impl<T, O: Display + PartialEq> View<T> for Segmented<T, O> {
+    type State = SegmentedState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.set_focusable(true);
+
+        let selected = self.selected();
+
+        let mut state = SegmentedState {
+            labels: self.labels(),
+            buffers: Vec::new(),
+            hovered: None,
+            selected,
+            from: selected.unwrap_or_default() as f32,
+            t: 1.0,
+        };
+
+        self.set_buffers(cx.fonts(), &mut state);
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        let labels = self.labels();
+
+        if labels != state.labels
+            || self.font_size != old.font_size
+            || self.font_family != old.font_family
+            || self.font_weight != old.font_weight
+        {
+            state.labels = labels;
+            self.set_buffers(cx.fonts(), state);
+            cx.layout();
+        }
+
+        let selected = self.selected();
+
+        if selected != state.selected {
+            // slide from wherever the indicator currently is
+            state.from = match state.indicator(&self.transition) {
+                Some(position) => position,
+                None => selected.unwrap_or_default() as f32,
+            };
+
+            state.selected = selected;
+            state.t = 0.0;
+            cx.animate();
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        let rtl = cx.direction().is_rtl();
+
+        match event {
+            Event::PointerMoved(e) => {
+                let local = cx.local(e.position);
+
+                let hovered = match cx.is_hot() {
+                    true => self.segment_at(cx.size().width, local.x, rtl),
+                    false => None,
+                };
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    cx.draw();
+                }
+
+                cx.set_cursor(hovered.map(|_| Cursor::Pointer));
+            }
+            Event::PointerPressed(e) => {
+                if !cx.is_hot() {
+                    if cx.is_focused() {
+                        cx.set_focused(false);
+                        cx.draw();
+                    }
+
+                    return;
+                }
+
+                let local = cx.local(e.position);
+
+                if let Some(index) = self.segment_at(cx.size().width, local.x, rtl) {
+                    cx.set_focused(true);
+                    cx.draw();
+
+                    self.change(cx, data, index);
+                }
+            }
+            Event::KeyPressed(e) if cx.is_focused() => {
+                if self.options.is_empty() {
+                    return;
+                }
+
+                let last = self.options.len() - 1;
+                let selected = state.selected;
+
+                let previous = selected.map_or(last, |i| i.saturating_sub(1));
+                let next = selected.map_or(0, |i| usize::min(i + 1, last));
+
+                let index = match e.key {
+                    Key::Left if rtl => next,
+                    Key::Right if rtl => previous,
+                    Key::Left | Key::Up => previous,
+                    Key::Right | Key::Down => next,
+                    Key::Home => 0,
+                    Key::End => last,
+                    _ => return,
+                };
+
+                self.change(cx, data, index);
+            }
+            Event::Animate(dt) => {
+                if self.transition.step(&mut state.t, true, *dt) {
+                    cx.animate();
+                }
+
+                cx.draw();
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        _cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        // all segments are as wide as the widest label
+        let mut segment = Size::ZERO;
+
+        for buffer in &state.buffers {
+            segment = segment.max(buffer.size());
+        }
+
+        let segment = segment + self.padding.size();
+        let count = state.buffers.len() as f32;
+
+        space.fit(Size::new(segment.width * count, segment.height))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        let rtl = cx.direction().is_rtl();
+        let count = state.buffers.len();
+
+        if count == 0 {
+            return;
+        }
+
+        let segment = Size::new(cx.size().width / count as f32, cx.size().height);
+
+        // the position of a segment, which may be fractional while the indicator slides
+        let width = cx.size().width;
+        let x = |position: f32| match rtl {
+            true => width - (position + 1.0) * segment.width,
+            false => position * segment.width,
+        };
+
+        cx.hoverable(|cx| {
+            cx.quad(
+                cx.rect(),
+                self.background,
+                self.border_radius,
+                BorderWidth::all(0.0),
+                Color::TRANSPARENT,
+            );
+        });
+
+        if let Some(hovered) = state.hovered {
+            if state.selected != Some(hovered) {
+                let rect = Rect::min_size(Point::new(x(hovered as f32), 0.0), segment);
+                cx.quad(
+                    rect,
+                    self.hover,
+                    self.border_radius,
+                    0.0,
+                    Color::TRANSPARENT,
+                );
+            }
+        }
+
+        // dividers between segments next to neither the selection nor the hovered segment
+        for i in 1..count {
+            let near =
+                |index: Option<usize>| index.is_some_and(|index| index + 1 == i || index == i);
+
+            if near(state.selected) || near(state.hovered) {
+                continue;
+            }
+
+            let left = x(i as f32 - 1.0).min(x(i as f32));
+            let divider_x = left + segment.width;
+            let inset = segment.height * 0.25;
+
+            let divider = Rect::new(
+                Point::new(divider_x - 0.5, inset),
+                Point::new(divider_x + 0.5, segment.height - inset),
+            );
+
+            cx.fill_rect(divider, self.divider);
+        }
+
+        if let Some(position) = state.indicator(&self.transition) {
+            let rect = Rect::min_size(Point::new(x(position), 0.0), segment);
+            cx.quad(
+                rect,
+                self.indicator,
+                self.border_radius,
+                0.0,
+                Color::TRANSPARENT,
+            );
+        }
+
+        for (i, buffer) in state.buffers.iter().enumerate() {
+            let color = match state.selected == Some(i) {
+                true => self.selected_color,
+                false => self.color,
+            };
+
+            let rect = Rect::min_size(Point::new(x(i as f32), 0.0), segment);
+            let offset = rect.center() - buffer.rect().center();
+            cx.text(
+                buffer,
+                color,
+                Vector::new(offset.x.round(), offset.y.round()),
+            );
+        }
+
+        let border_color = match cx.is_focused() {
+            true => self.focus_color,
+            false => self.border_color,
+        };
+
+        cx.quad(
+            cx.rect(),
+            Color::TRANSPARENT,
+            self.border_radius,
+            self.border_width,
+            border_color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Key, KeyPressed, Modifiers},
+        layout::Space,
+        views::testing::ViewTester,
+    };
+
+    use super::segmented;
+
+    #[test]
+    fn equal_segments() {
+        let mut view = segmented::<(), _>("a", ["a", "bbbbbbbb", "c"]);
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        let size = tester.layout(&mut view, &mut (), Space::UNBOUNDED);
+
+        assert_eq!(
+            view.segment_at(size.width, size.width * 0.5, false),
+            Some(1)
+        );
+        assert_eq!(view.segment_at(size.width, size.width * 0.9, true), Some(0));
+        assert_eq!(
+            view.segment_at(size.width, size.width * 0.1, false),
+            Some(0)
+        );
+        assert_eq!(view.segment_at(size.width, size.width + 1.0, false), None);
+    }
+
+    #[test]
+    fn slide_indicator() {
+        let mut value = 0;
+
+        let mut view = segmented(value, [0, 1, 2]).on_change(|_, value, option| *value = *option);
+        let mut tester = ViewTester::new(&mut view, &mut value);
+        tester.view_state.set_focused(true);
+
+        let right = Event::KeyPressed(KeyPressed {
+            key: Key::Right,
+            code: None,
+            text: None,
+            modifiers: Modifiers::default(),
+        });
+
+        tester.event(&mut view, &mut value, &right);
+        assert_eq!(value, 1);
+
+        let old = view;
+        let mut view = segmented(value, [0, 1, 2]);
+        tester.rebuild(&mut view, &mut value, &old);
+
+        // halfway through the transition the indicator is between the segments
+        tester.event(&mut view, &mut value, &Event::Animate(0.075));
+        let position = tester.state.indicator(&view.transition).unwrap();
+        assert!(position > 0.0 && position < 1.0);
+
+        tester.event(&mut view, &mut value, &Event::Animate(1.0));
+        assert_eq!(tester.state.indicator(&view.transition), Some(1.0));
+    }
+}
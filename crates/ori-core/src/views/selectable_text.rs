@@ -0,0 +1,483 @@
+use std::time::{Duration, Instant};
+
+use cosmic_text::{Action, Buffer, BufferRef, Edit, Editor, Metrics, Selection, Shaping};
+use ori_macro::Build;
+use smol_str::SmolStr;
+
+use crate::{
+    canvas::Color,
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, PointerButton},
+    layout::{Point, Rect, Size, Space, Vector},
+    style::{style, Style, Styles},
+    text::{
+        FontFamily, FontStretch, FontStyle, FontWeight, Fonts, TextAlign, TextAttributes, TextWrap,
+    },
+    view::View,
+    window::Cursor,
+};
+
+use super::TextStyle;
+
+/// Create a new [`SelectableText`].
+pub fn selectable_text(text: impl Into<SmolStr>) -> SelectableText {
+    SelectableText::new(text)
+}
+
+/// The style of a selectable text.
+#[derive(Clone, Debug)]
+pub struct SelectableTextStyle {
+    /// The font size of the text.
+    pub font_size: f32,
+
+    /// The font family of the text.
+    pub font_family: FontFamily,
+
+    /// The font weight of the text.
+    pub font_weight: FontWeight,
+
+    /// The font stretch of the text.
+    pub font_stretch: FontStretch,
+
+    /// The font style of the text.
+    pub font_style: FontStyle,
+
+    /// The color of the text.
+    pub color: Color,
+
+    /// The color of the selection.
+    pub selection_color: Color,
+
+    /// The horizontal alignment of the text.
+    pub align: TextAlign,
+
+    /// The line height of the text.
+    pub line_height: f32,
+
+    /// The text wrap of the text.
+    pub wrap: TextWrap,
+}
+
+impl Style for SelectableTextStyle {
+    fn styled(style: &Styles) -> Self {
+        let text_style = style.get::<TextStyle>();
+        let palette = style.palette();
+
+        Self {
+            font_size: text_style.font_size,
+            font_family: text_style.font_family,
+            font_weight: text_style.font_weight,
+            font_stretch: text_style.font_stretch,
+            font_style: text_style.font_style,
+            color: text_style.color,
+            selection_color: palette.primary.fade(0.3),
+            align: text_style.align,
+            line_height: text_style.line_height,
+            wrap: text_style.wrap,
+        }
+    }
+}
+
+/// A view that displays text that can be selected and copied.
+///
+/// Text is selected by dragging, a word by double clicking and a line by triple clicking.
+/// When focused, `Ctrl+A` selects all the text and `Ctrl+C` copies the selection.
+///
+/// Can be styled using the [`SelectableTextStyle`].
+#[derive(Build)]
+pub struct SelectableText {
+    /// The text.
+    pub text: SmolStr,
+
+    /// The font size of the text.
+    pub font_size: f32,
+
+    /// The font family of the text.
+    pub font_family: FontFamily,
+
+    /// The font weight of the text.
+    pub font_weight: FontWeight,
+
+    /// The font stretch of the text.
+    pub font_stretch: FontStretch,
+
+    /// The font style of the text.
+    pub font_style: FontStyle,
+
+    /// The color of the text.
+    pub color: Color,
+
+    /// The color of the selection.
+    pub selection_color: Color,
+
+    /// The horizontal alignment of the text.
+    pub align: TextAlign,
+
+    /// The line height of the text.
+    pub line_height: f32,
+
+    /// The text wrap of the text.
+    pub wrap: TextWrap,
+}
+
+impl SelectableText {
+    /// Create a new selectable text.
+    pub fn new(text: impl Into<SmolStr>) -> Self {
+        Self::styled(text, style())
+    }
+
+    /// Create a new selectable text with a style.
+    pub fn styled(text: impl Into<SmolStr>, style: SelectableTextStyle) -> Self {
+        Self {
+            text: text.into(),
+            font_size: style.font_size,
+            font_family: style.font_family,
+            font_weight: style.font_weight,
+            font_stretch: style.font_stretch,
+            font_style: style.font_style,
+            color: style.color,
+            selection_color: style.selection_color,
+            align: style.align,
+            line_height: style.line_height,
+            wrap: style.wrap,
+        }
+    }
+
+    fn metrics(&self) -> Metrics {
+        Metrics {
+            font_size: self.font_size,
+            line_height: self.line_height * self.font_size,
+        }
+    }
+
+    fn set_text(&self, fonts: &mut Fonts, state: &mut SelectableTextState) {
+        let attrs = TextAttributes {
+            family: self.font_family.clone(),
+            stretch: self.font_stretch,
+            weight: self.font_weight,
+            style: self.font_style,
+        };
+
+        state.buffer_mut().set_text(
+            &mut fonts.font_system,
+            &self.text,
+            attrs.to_cosmic_text(),
+            Shaping::Advanced,
+        );
+
+        for line in state.buffer_mut().lines.iter_mut() {
+            line.set_align(self.align.to_cosmic_text_directional());
+        }
+
+        state.editor.set_selection(Selection::None);
+        state.editor.set_cursor(cosmic_text::Cursor::default());
+    }
+
+    // the position of a point in the view, relative to the text
+    fn text_point(&self, state: &SelectableTextState, local: Point) -> (i32, i32) {
+        let point = local - state.offset;
+        (point.x as i32, point.y as i32)
+    }
+}
+
+const MULTI_CLICK: Duration = Duration::from_millis(500);
+
+#[doc(hidden)]
+pub struct SelectableTextState {
+    editor: Editor<'static>,
+    offset: Vector,
+    dragging: bool,
+    // the number of clicks in a row, and the time and position of the last one
+    last_click: Option<(u8, Instant, Point)>,
+}
+
+impl SelectableTextState {
+    fn buffer(&self) -> &Buffer {
+        match self.editor.buffer_ref() {
+            BufferRef::Owned(buffer) => buffer,
+            _ => unreachable!(),
+        }
+    }
+
+    fn buffer_mut(&mut self) -> &mut Buffer {
+        match self.editor.buffer_ref_mut() {
+            BufferRef::Owned(buffer) => buffer,
+            _ => unreachable!(),
+        }
+    }
+
+    fn select_all(&mut self) {
+        let lines = &self.buffer().lines;
+        let last = lines.len() - 1;
+        let end = cosmic_text::Cursor::new(last, lines[last].text().len());
+
+        let start = cosmic_text::Cursor::new(0, 0);
+        self.editor.set_selection(Selection::Normal(start));
+        self.editor.set_cursor(end);
+    }
+}
+
+impl<T> View<T> for SelectableText {
+    type State = SelectableTextState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        let font_system = &mut cx.fonts().font_system;
+
+        let mut buffer = Buffer::new(font_system, self.metrics());
+        buffer.set_wrap(font_system, self.wrap.to_cosmic_text());
+
+        let mut state = SelectableTextState {
+            editor: Editor::new(buffer),
+            offset: Vector::ZERO,
+            dragging: false,
+            last_click: None,
+        };
+
+        self.set_text(cx.fonts(), &mut state);
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        if self.font_size != old.font_size || self.line_height != old.line_height {
+            let metrics = self.metrics();
+            (state.buffer_mut()).set_metrics(&mut cx.fonts().font_system, metrics);
+
+            cx.layout();
+        }
+
+        if self.wrap != old.wrap {
+            let wrap = self.wrap.to_cosmic_text();
+            (state.buffer_mut()).set_wrap(&mut cx.fonts().font_system, wrap);
+
+            cx.layout();
+        }
+
+        if self.text != old.text
+            || self.font_family != old.font_family
+            || self.font_weight != old.font_weight
+            || self.font_stretch != old.font_stretch
+            || self.font_style != old.font_style
+            || self.align != old.align
+        {
+            self.set_text(cx.fonts(), state);
+
+            cx.layout();
+        }
+
+        if self.color != old.color || self.selection_color != old.selection_color {
+            cx.draw();
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, _data: &mut T, event: &Event) {
+        if cx.is_hot() {
+            cx.set_cursor(Some(Cursor::Text));
+        } else {
+            cx.set_cursor(None);
+        }
+
+        match event {
+            Event::KeyPressed(e) if cx.is_focused() => {
+                if e.is_key('a') && e.modifiers.ctrl {
+                    state.select_all();
+                    cx.draw();
+                }
+
+                if e.is_key('c') && e.modifiers.ctrl {
+                    if let Some(selection) = state.editor.copy_selection() {
+                        cx.clipboard().set(selection);
+                    }
+                }
+
+                if e.is_key(Key::Escape) {
+                    (state.editor).action(&mut cx.fonts().font_system, Action::Escape);
+                    cx.set_focused(false);
+                    cx.draw();
+                }
+            }
+            Event::PointerPressed(e) if e.button == PointerButton::Primary => {
+                if !cx.is_hot() {
+                    if cx.is_focused() {
+                        (state.editor).action(&mut cx.fonts().font_system, Action::Escape);
+                        cx.set_focused(false);
+                        cx.draw();
+                    }
+
+                    return;
+                }
+
+                cx.set_focused(true);
+                state.dragging = true;
+
+                let local = cx.local(e.position);
+
+                // clicks in quick succession, close to each other, select words and lines
+                let clicks = match state.last_click.take() {
+                    Some((clicks, time, position))
+                        if time.elapsed() < MULTI_CLICK && position.distance(local) < 4.0 =>
+                    {
+                        clicks % 3 + 1
+                    }
+                    _ => 1,
+                };
+
+                state.last_click = Some((clicks, Instant::now(), local));
+
+                let (x, y) = self.text_point(state, local);
+                let action = match clicks {
+                    1 => Action::Click { x, y },
+                    2 => Action::DoubleClick { x, y },
+                    _ => Action::TripleClick { x, y },
+                };
+
+                state.editor.action(&mut cx.fonts().font_system, action);
+                cx.draw();
+            }
+            Event::PointerReleased(_) => {
+                state.dragging = false;
+            }
+            Event::PointerMoved(e) if state.dragging => {
+                let (x, y) = self.text_point(state, cx.local(e.position));
+
+                (state.editor).action(&mut cx.fonts().font_system, Action::Drag { x, y });
+                cx.draw();
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        let font_system = &mut cx.fonts().font_system;
+
+        (state.buffer_mut()).set_size(font_system, Some(space.max.width), Some(space.max.height));
+        (state.editor).shape_as_needed(font_system, false);
+
+        let text_size = Fonts::buffer_size(state.buffer());
+        let size = space.fit(text_size);
+
+        // the text is centered in the view, like `Text`
+        state.offset = (size - text_size).to_vector() / 2.0;
+
+        let baseline = state.buffer().layout_runs().next().map(|run| run.line_y);
+        cx.set_baseline(baseline.map(|baseline| baseline + state.offset.y));
+
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        cx.hoverable(|cx| {
+            cx.fill_rect(cx.rect(), Color::TRANSPARENT);
+
+            let offset = cx.rect().min.to_vector() + state.offset;
+
+            if let Some((start, end)) = state.editor.selection_bounds() {
+                for run in state.buffer().layout_runs() {
+                    if let Some((x, width)) = run.highlight(start, end) {
+                        let min = Point::new(x, run.line_top) + offset;
+                        let size = Size::new(width, run.line_height);
+
+                        cx.fill_rect(Rect::min_size(min, size), self.selection_color);
+                    }
+                }
+            }
+
+            cx.text_raw(state.buffer(), self.color, offset);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{
+        clipboard::{Clipboard, ClipboardBackend},
+        event::{
+            Event, Key, KeyPressed, Modifiers, PointerButton, PointerId, PointerPressed,
+            PointerReleased,
+        },
+        layout::{Point, Space},
+        views::testing::ViewTester,
+    };
+
+    use super::selectable_text;
+
+    struct TestClipboard(Rc<RefCell<String>>);
+
+    impl ClipboardBackend for TestClipboard {
+        fn get_text(&mut self) -> String {
+            self.0.borrow().clone()
+        }
+
+        fn set_text(&mut self, text: &str) {
+            *self.0.borrow_mut() = text.to_string();
+        }
+    }
+
+    #[test]
+    fn select_and_copy() {
+        let mut view = selectable_text("first second third");
+
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        let size = tester.layout(&mut view, &mut (), Space::UNBOUNDED);
+        tester.view_state.set_hot(true);
+
+        let copied = Rc::new(RefCell::new(String::new()));
+        let clipboard = Clipboard::new(Box::new(TestClipboard(copied.clone())));
+        tester.contexts.insert(clipboard);
+
+        let copy = Event::KeyPressed(KeyPressed {
+            key: Key::Character('c'),
+            code: None,
+            text: None,
+            modifiers: Modifiers {
+                ctrl: true,
+                ..Default::default()
+            },
+        });
+
+        // double click the middle of the text, selecting the word `second`
+        let middle = Point::new(size.width / 2.0, size.height / 2.0);
+
+        for _ in 0..2 {
+            tester.event(&mut view, &mut (), &pressed(middle));
+            tester.event(&mut view, &mut (), &released(middle));
+        }
+
+        tester.event(&mut view, &mut (), &copy);
+        assert_eq!(*copied.borrow(), "second");
+
+        // a third click selects the whole line
+        tester.event(&mut view, &mut (), &pressed(middle));
+        tester.event(&mut view, &mut (), &released(middle));
+
+        tester.event(&mut view, &mut (), &copy);
+        assert_eq!(*copied.borrow(), "first second third");
+
+        fn pressed(position: Point) -> Event {
+            Event::PointerPressed(PointerPressed {
+                id: PointerId::from_hash(&0),
+                position,
+                button: PointerButton::Primary,
+                modifiers: Modifiers::default(),
+            })
+        }
+
+        fn released(position: Point) -> Event {
+            Event::PointerReleased(PointerReleased {
+                id: PointerId::from_hash(&0),
+                position,
+                button: PointerButton::Primary,
+                modifiers: Modifiers::default(),
+                clicked: true,
+            })
+        }
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
     canvas::{BorderRadius, BorderWidth, Color},
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
     event::Event,
-    layout::{Axis, Rect, Size, Space},
+    layout::{Axis, Direction, Point, Rect, Size, Space, Vector},
     rebuild::Rebuild,
     style::{style, Style, Styles},
     view::View,
@@ -153,6 +153,20 @@ fn denormalize(value: f32, range: &RangeInclusive<f32>) -> f32 {
     value * (range.end() - range.start()) + range.start()
 }
 
+impl<T> Slider<T> {
+    // get the value at a local pointer position
+    fn pointer_value(&self, direction: Direction, local: Point) -> f32 {
+        let value = self.axis.unpack(local).0 / self.length;
+
+        let value = match self.axis == Axis::Horizontal && direction.is_rtl() {
+            true => 1.0 - value,
+            false => value,
+        };
+
+        denormalize(value, &self.range)
+    }
+}
+
 impl<T> View<T> for Slider<T> {
     type State = ();
 
@@ -168,8 +182,7 @@ impl<T> View<T> for Slider<T> {
                 let local = cx.local(e.position);
 
                 if cx.is_hot() {
-                    let value = self.axis.unpack(local).0 / self.length;
-                    let value = denormalize(value, &self.range);
+                    let value = self.pointer_value(cx.direction(), local);
 
                     if let Some(on_input) = &mut self.on_input {
                         on_input(cx, data, value);
@@ -182,8 +195,7 @@ impl<T> View<T> for Slider<T> {
                 let local = cx.local(e.position);
 
                 if cx.is_active() {
-                    let value = self.axis.unpack(local).0 / self.length;
-                    let value = denormalize(value, &self.range);
+                    let value = self.pointer_value(cx.direction(), local);
 
                     if let Some(on_input) = &mut self.on_input {
                         on_input(cx, data, value);
@@ -227,8 +239,15 @@ impl<T> View<T> for Slider<T> {
             let length = f32::max(length * value, min_length);
             let size = self.axis.pack(length, width);
 
+            // horizontal sliders fill from the right when right to left
+            let offset = cx.direction().mirror(Vector::ZERO, size, cx.size());
+            let offset = match self.axis {
+                Axis::Horizontal => offset,
+                Axis::Vertical => Vector::ZERO,
+            };
+
             cx.quad(
-                Rect::min_size(cx.rect().min, size),
+                Rect::min_size(cx.rect().min + offset, size),
                 self.color,
                 self.border_radius,
                 self.border_width,
@@ -0,0 +1,153 @@
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use ori_macro::Build;
+
+use crate::{
+    canvas::{Color, Curve},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Size, Space},
+    rebuild::Rebuild,
+    style::style,
+    view::View,
+};
+
+use super::{
+    progress::{arc, round},
+    ProgressStyle,
+};
+
+/// Create a new [`Spinner`].
+pub fn spinner() -> Spinner {
+    Spinner::new()
+}
+
+/// A spinning ring, for work of an unknown length.
+///
+/// The spinner only requests animation frames while it's drawn, so it stops when hidden or
+/// scrolled out of view, and continues where it left off when shown again.
+///
+/// Can be styled using the [`ProgressStyle`].
+#[derive(Build, Rebuild)]
+pub struct Spinner {
+    /// The size of the spinner.
+    #[rebuild(layout)]
+    pub size: f32,
+
+    /// The thickness of the ring.
+    #[rebuild(layout)]
+    pub thickness: f32,
+
+    /// The number of turns per second.
+    pub speed: f32,
+
+    /// The color of the spinner.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of the track.
+    #[rebuild(draw)]
+    pub background: Color,
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Spinner {
+    /// Create a new [`Spinner`].
+    pub fn new() -> Self {
+        Self::styled(style())
+    }
+
+    /// Create a new [`Spinner`] with a style.
+    pub fn styled(style: ProgressStyle) -> Self {
+        Self {
+            size: style.size,
+            thickness: style.thickness,
+            speed: style.speed,
+            color: style.color,
+            background: style.background,
+        }
+    }
+}
+
+impl<T> View<T> for Spinner {
+    // the number of turns so far
+    type State = f32;
+
+    fn build(&mut self, _cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        0.0
+    }
+
+    fn rebuild(&mut self, _turns: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+    }
+
+    fn event(&mut self, turns: &mut Self::State, cx: &mut EventCx, _data: &mut T, event: &Event) {
+        if let Event::Animate(dt) = event {
+            *turns = (*turns + dt * self.speed) % 2.0;
+            cx.draw();
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _turns: &mut Self::State,
+        _cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        space.fit(Size::all(self.size))
+    }
+
+    fn draw(&mut self, turns: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        // frames are only requested while drawn, so a hidden spinner stops animating
+        cx.animate();
+
+        let radius = (cx.size().min_element() - self.thickness) / 2.0;
+        let center = cx.rect().center();
+
+        let track = Curve::circle(center, radius);
+        cx.stroke(track, self.thickness, self.background);
+
+        // the arc grows and shrinks once every two turns
+        let grow = (1.0 - f32::cos(*turns * PI)) / 2.0;
+        let sweep = TAU * (0.1 + 0.6 * grow);
+        let start = *turns * TAU - FRAC_PI_2;
+
+        let curve = arc(center, radius, start, sweep);
+        cx.stroke(curve, round(self.thickness), self.color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{event::Event, layout::Space, view::pod, views::testing::ViewTester};
+
+    use super::spinner;
+
+    #[test]
+    fn stops_when_not_drawn() {
+        let mut view = pod(spinner());
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(&mut view, &mut (), Space::UNBOUNDED);
+
+        for _ in 0..2 {
+            tester.draw(&mut view, &mut ());
+            assert!(tester.view_state.needs_animate());
+
+            tester.view_state.mark_animated();
+            tester.event(&mut view, &mut (), &Event::Animate(0.25));
+        }
+
+        assert_eq!(*tester.state.content(), 0.5);
+
+        // the spinner isn't drawn, so it doesn't animate
+        tester.event(&mut view, &mut (), &Event::Animate(0.25));
+        assert!(!tester.view_state.needs_animate());
+        assert_eq!(*tester.state.content(), 0.5);
+    }
+}
@@ -0,0 +1,755 @@
+use std::time::{Duration, Instant};
+
+use ori_macro::Build;
+
+use crate::{
+    canvas::Color,
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, KeyPressed, PointerButton},
+    layout::{Axis, Point, Rect, Size, Space},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    view::{PodSeq, SeqState, View, ViewSeq},
+    window::Cursor,
+};
+
+pub use crate::{hsplit, vsplit};
+
+/// Create a horizontal [`Split`].
+#[macro_export]
+macro_rules! hsplit {
+    ($($child:expr),* $(,)?) => {
+        $crate::views::hsplit(($($child,)*))
+    };
+}
+
+/// Create a vertical [`Split`].
+#[macro_export]
+macro_rules! vsplit {
+    ($($child:expr),* $(,)?) => {
+        $crate::views::vsplit(($($child,)*))
+    };
+}
+
+/// Create a horizontal [`Split`], with panes side by side.
+pub fn hsplit<V>(content: V) -> Split<V> {
+    Split::horizontal(content)
+}
+
+/// Create a vertical [`Split`], with panes on top of each other.
+pub fn vsplit<V>(content: V) -> Split<V> {
+    Split::vertical(content)
+}
+
+/// The style of a split.
+#[derive(Clone, Debug)]
+pub struct SplitStyle {
+    /// The width of the dividers.
+    pub divider_width: f32,
+
+    /// The color of the dividers.
+    pub divider_color: Color,
+
+    /// The color of a hovered, dragged or focused divider.
+    pub highlight_color: Color,
+
+    /// The distance a divider is moved by the arrow keys.
+    pub step: f32,
+}
+
+impl Style for SplitStyle {
+    fn styled(style: &Styles) -> Self {
+        let palette = style.palette();
+
+        Self {
+            divider_width: 5.0,
+            divider_color: palette.outline_low,
+            highlight_color: palette.primary.fade(0.5),
+            step: 16.0,
+        }
+    }
+}
+
+/// A view that divides its space between panes, separated by draggable dividers.
+///
+/// The space is divided according to the ratios of the panes, see [`Split::ratios`]. Dividers
+/// can be dragged with the pointer, or moved with the arrow keys after being clicked, and
+/// double-clicking a divider, or pressing enter, collapses the smaller of the two panes next to
+/// it. Whenever the ratios change [`Split::on_resize`] is called, so they can be stored.
+///
+/// Can be styled using the [`SplitStyle`].
+#[derive(Build, Rebuild)]
+pub struct Split<V> {
+    /// The panes.
+    #[build(ignore)]
+    pub content: PodSeq<V>,
+
+    /// The axis along which the panes are laid out.
+    #[rebuild(layout)]
+    pub axis: Axis,
+
+    /// The ratios of the panes, if empty the space is divided equally.
+    #[build(ignore)]
+    #[rebuild(layout)]
+    pub ratios: Vec<f32>,
+
+    /// The minimum sizes of the panes.
+    #[build(ignore)]
+    #[rebuild(layout)]
+    pub min_sizes: Vec<f32>,
+
+    /// The maximum sizes of the panes.
+    #[build(ignore)]
+    #[rebuild(layout)]
+    pub max_sizes: Vec<f32>,
+
+    /// Whether panes can be collapsed.
+    pub collapsible: bool,
+
+    /// The width of the dividers.
+    #[rebuild(layout)]
+    pub divider_width: f32,
+
+    /// The color of the dividers.
+    #[rebuild(draw)]
+    pub divider_color: Color,
+
+    /// The color of a hovered, dragged or focused divider.
+    #[rebuild(draw)]
+    pub highlight_color: Color,
+
+    /// The distance a divider is moved by the arrow keys.
+    pub step: f32,
+}
+
+impl<V> Split<V> {
+    /// Create a new [`Split`].
+    pub fn new(axis: Axis, content: V) -> Self {
+        Self::styled(axis, content, style())
+    }
+
+    /// Create a new [`Split`] with a style.
+    pub fn styled(axis: Axis, content: V, style: SplitStyle) -> Self {
+        Self {
+            content: PodSeq::new(content),
+            axis,
+            ratios: Vec::new(),
+            min_sizes: Vec::new(),
+            max_sizes: Vec::new(),
+            collapsible: true,
+            divider_width: style.divider_width,
+            divider_color: style.divider_color,
+            highlight_color: style.highlight_color,
+            step: style.step,
+        }
+    }
+
+    /// Create a new horizontal [`Split`].
+    pub fn horizontal(content: V) -> Self {
+        Self::new(Axis::Horizontal, content)
+    }
+
+    /// Create a new vertical [`Split`].
+    pub fn vertical(content: V) -> Self {
+        Self::new(Axis::Vertical, content)
+    }
+
+    /// Set the ratios of the panes.
+    ///
+    /// The ratios don't have to sum to one, they are normalized.
+    pub fn ratios(mut self, ratios: impl Into<Vec<f32>>) -> Self {
+        self.ratios = ratios.into();
+        self
+    }
+
+    /// Set the ratio of the first pane, for splits with two panes.
+    pub fn ratio(self, ratio: f32) -> Self {
+        self.ratios([ratio, 1.0 - ratio])
+    }
+
+    /// Set the minimum size of a pane.
+    pub fn min_size(mut self, pane: usize, size: f32) -> Self {
+        if self.min_sizes.len() <= pane {
+            self.min_sizes.resize(pane + 1, 0.0);
+        }
+
+        self.min_sizes[pane] = size;
+        self
+    }
+
+    /// Set the maximum size of a pane.
+    pub fn max_size(mut self, pane: usize, size: f32) -> Self {
+        if self.max_sizes.len() <= pane {
+            self.max_sizes.resize(pane + 1, f32::INFINITY);
+        }
+
+        self.max_sizes[pane] = size;
+        self
+    }
+
+    /// Set the callback for when the ratios of the panes change.
+    pub fn on_resize<T>(
+        self,
+        on_resize: impl FnMut(&mut EventCx, &mut T, &[f32]) + 'static,
+    ) -> OnResize<T, V> {
+        OnResize {
+            split: self,
+            on_resize: Box::new(on_resize),
+        }
+    }
+}
+
+/// A [`Split`] with a callback for when the ratios of the panes change.
+///
+/// This is created by [`Split::on_resize`].
+pub struct OnResize<T, V> {
+    split: Split<V>,
+    #[allow(clippy::type_complexity)]
+    on_resize: Box<dyn FnMut(&mut EventCx, &mut T, &[f32])>,
+}
+
+// how long a second click may be after the first to count as a double-click
+const DOUBLE_CLICK: Duration = Duration::from_millis(500);
+
+// how far outside a divider it can be grabbed
+const GRAB_MARGIN: f32 = 3.0;
+
+#[doc(hidden)]
+pub struct SplitState {
+    ratios: Vec<f32>,
+    sizes: Vec<f32>,
+    available: f32,
+    collapsed: Vec<Option<f32>>,
+    hovered: Option<usize>,
+    focused: Option<usize>,
+    drag: Option<(usize, f32)>,
+    last_click: Option<(usize, Instant)>,
+}
+
+impl<V> Split<V> {
+    fn normalized_ratios(&self, len: usize) -> Vec<f32> {
+        let sum: f32 = self.ratios.iter().sum();
+
+        match self.ratios.len() == len && sum > 0.0 {
+            true => self
+                .ratios
+                .iter()
+                .map(|ratio| ratio.max(0.0) / sum)
+                .collect(),
+            false => vec![1.0 / len as f32; len],
+        }
+    }
+
+    fn limits(&self, state: &SplitState, pane: usize) -> (f32, f32) {
+        if state.collapsed[pane].is_some() {
+            return (0.0, 0.0);
+        }
+
+        let min = self.min_sizes.get(pane).copied().unwrap_or(0.0);
+        let max = self.max_sizes.get(pane).copied().unwrap_or(f32::INFINITY);
+
+        (min, f32::max(min, max))
+    }
+
+    // fit the sizes of the panes within their limits, while filling the available space
+    fn constrain(&self, state: &mut SplitState) {
+        for pane in 0..state.sizes.len() {
+            let (min, max) = self.limits(state, pane);
+            state.sizes[pane] = state.sizes[pane].clamp(min, max);
+        }
+
+        for _ in 0..state.sizes.len() {
+            let excess = state.available - state.sizes.iter().sum::<f32>();
+
+            if excess.abs() < 0.01 {
+                break;
+            }
+
+            let flexible: Vec<_> = (0..state.sizes.len())
+                .filter(|&pane| {
+                    let (min, max) = self.limits(state, pane);
+
+                    match excess > 0.0 {
+                        true => state.sizes[pane] < max,
+                        false => state.sizes[pane] > min,
+                    }
+                })
+                .collect();
+
+            if flexible.is_empty() {
+                break;
+            }
+
+            let share = excess / flexible.len() as f32;
+
+            for pane in flexible {
+                let (min, max) = self.limits(state, pane);
+                state.sizes[pane] = f32::clamp(state.sizes[pane] + share, min, max);
+            }
+        }
+    }
+
+    // the rect of a divider, in local coordinates
+    fn divider_rect(&self, state: &SplitState, size: Size, divider: usize) -> Rect {
+        let start = state.sizes[..=divider].iter().sum::<f32>();
+        let start = start + divider as f32 * self.divider_width;
+
+        let minor = self.axis.minor(size);
+        let min = self.axis.pack::<Point>(start, 0.0);
+        let size = self.axis.pack::<Size>(self.divider_width, minor);
+
+        Rect::min_size(min, size)
+    }
+
+    fn divider_at(&self, state: &SplitState, size: Size, point: Point) -> Option<usize> {
+        let margin = self.axis.pack::<Size>(GRAB_MARGIN, 0.0);
+
+        (0..state.sizes.len().saturating_sub(1)).find(|&divider| {
+            let rect = self.divider_rect(state, size, divider);
+            Rect::new(rect.min - margin, rect.max + margin).contains(point)
+        })
+    }
+
+    fn update_ratios(&self, state: &mut SplitState) {
+        if state.available <= 0.0 {
+            return;
+        }
+
+        for (ratio, size) in state.ratios.iter_mut().zip(&state.sizes) {
+            *ratio = size / state.available;
+        }
+    }
+
+    // move a divider by `delta`, only changing the size of the two panes next to it
+    fn move_divider(&self, state: &mut SplitState, divider: usize, delta: f32) -> bool {
+        let (before, after) = (divider, divider + 1);
+
+        state.collapsed[before] = None;
+        state.collapsed[after] = None;
+
+        let (min_before, max_before) = self.limits(state, before);
+        let (min_after, max_after) = self.limits(state, after);
+
+        let combined = state.sizes[before] + state.sizes[after];
+        let low = f32::max(min_before, combined - max_after);
+        let high = f32::min(max_before, combined - min_after);
+
+        let size = f32::clamp(state.sizes[before] + delta, low, f32::max(low, high));
+
+        if size == state.sizes[before] {
+            return false;
+        }
+
+        state.sizes[before] = size;
+        state.sizes[after] = combined - size;
+        self.update_ratios(state);
+
+        true
+    }
+
+    // collapse the smaller pane next to a divider, or restore it if already collapsed
+    fn toggle_collapse(&self, state: &mut SplitState, divider: usize) {
+        let (before, after) = (divider, divider + 1);
+
+        let restore = match (state.collapsed[before], state.collapsed[after]) {
+            (Some(ratio), _) => Some((before, after, ratio)),
+            (_, Some(ratio)) => Some((after, before, ratio)),
+            _ => None,
+        };
+
+        if let Some((pane, other, ratio)) = restore {
+            state.collapsed[pane] = None;
+            state.ratios[pane] = ratio;
+            state.ratios[other] = f32::max(state.ratios[other] - ratio, 0.0);
+            return;
+        }
+
+        let (pane, other) = match state.sizes[before] <= state.sizes[after] {
+            true => (before, after),
+            false => (after, before),
+        };
+
+        state.collapsed[pane] = Some(state.ratios[pane]);
+        state.ratios[other] += state.ratios[pane];
+        state.ratios[pane] = 0.0;
+    }
+
+    fn key_delta(&self, e: &KeyPressed) -> Option<f32> {
+        let (back, forward) = match self.axis {
+            Axis::Horizontal => (Key::Left, Key::Right),
+            Axis::Vertical => (Key::Up, Key::Down),
+        };
+
+        let step = match e.modifiers.shift {
+            true => self.step * 4.0,
+            false => self.step,
+        };
+
+        if e.is_key(back) {
+            Some(-step)
+        } else if e.is_key(forward) {
+            Some(step)
+        } else if e.is_key(Key::Home) {
+            Some(f32::NEG_INFINITY)
+        } else if e.is_key(Key::End) {
+            Some(f32::INFINITY)
+        } else {
+            None
+        }
+    }
+
+    fn resize_cursor(&self) -> Cursor {
+        match self.axis {
+            Axis::Horizontal => Cursor::ColResize,
+            Axis::Vertical => Cursor::RowResize,
+        }
+    }
+
+    // handle the events of the dividers, returns whether the ratios changed
+    fn divider_event(&self, state: &mut SplitState, cx: &mut EventCx, event: &Event) -> bool {
+        if cx.focused_changed() && !cx.is_focused() {
+            state.focused = None;
+            cx.draw();
+        }
+
+        match event {
+            Event::PointerMoved(e) => {
+                let local = cx.local(e.position);
+
+                if let Some((divider, grab)) = state.drag {
+                    let rect = self.divider_rect(state, cx.size(), divider);
+                    let delta = self.axis.major(local) - grab - self.axis.major(rect.min);
+
+                    if self.move_divider(state, divider, delta) {
+                        cx.layout();
+                        return true;
+                    }
+
+                    return false;
+                }
+
+                let hovered = match cx.is_hot() || cx.has_hot() {
+                    true => self.divider_at(state, cx.size(), local),
+                    false => None,
+                };
+
+                if state.hovered != hovered {
+                    match hovered {
+                        Some(_) => cx.set_cursor(Some(self.resize_cursor())),
+                        None => cx.set_cursor(None),
+                    }
+
+                    state.hovered = hovered;
+                    cx.draw();
+                }
+
+                false
+            }
+            Event::PointerPressed(e) if e.button == PointerButton::Primary => {
+                let Some(divider) = state.hovered else {
+                    return false;
+                };
+
+                state.focused = Some(divider);
+                cx.set_focused(true);
+                cx.draw();
+
+                let double_click = match state.last_click.take() {
+                    Some((last, time)) => last == divider && time.elapsed() < DOUBLE_CLICK,
+                    None => false,
+                };
+
+                if double_click && self.collapsible {
+                    self.toggle_collapse(state, divider);
+                    cx.layout();
+                    return true;
+                }
+
+                // the distance from the start of the divider to where it was grabbed
+                let rect = self.divider_rect(state, cx.size(), divider);
+                let grab = self.axis.major(cx.local(e.position)) - self.axis.major(rect.min);
+
+                state.drag = Some((divider, grab));
+                state.last_click = Some((divider, Instant::now()));
+
+                cx.set_active(true);
+                false
+            }
+            Event::PointerReleased(e) if e.button == PointerButton::Primary => {
+                if state.drag.take().is_some() {
+                    cx.set_active(false);
+                    cx.draw();
+                }
+
+                false
+            }
+            Event::KeyPressed(e) if cx.is_focused() => {
+                let Some(divider) = state.focused else {
+                    return false;
+                };
+
+                if e.is_key(Key::Escape) {
+                    cx.set_focused(false);
+                    return false;
+                }
+
+                if e.is_key(Key::Enter) && self.collapsible {
+                    self.toggle_collapse(state, divider);
+                    cx.layout();
+                    return true;
+                }
+
+                match self.key_delta(e) {
+                    Some(delta) if self.move_divider(state, divider, delta) => {
+                        cx.layout();
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<T, V: ViewSeq<T>> View<T> for Split<V> {
+    type State = (SplitState, SeqState<T, V>);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let len = self.content.len();
+
+        let state = SplitState {
+            ratios: self.normalized_ratios(len),
+            sizes: vec![0.0; len],
+            available: 0.0,
+            collapsed: vec![None; len],
+            hovered: None,
+            focused: None,
+            drag: None,
+            last_click: None,
+        };
+
+        (state, self.content.build(cx, data))
+    }
+
+    fn rebuild(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        Rebuild::rebuild(self, cx, old);
+
+        let len = self.content.len();
+
+        if len != old.content.len() {
+            state.sizes.resize(len, 0.0);
+            state.collapsed = vec![None; len];
+            state.hovered = None;
+            state.focused = None;
+            state.drag = None;
+        }
+
+        // ratios that were just reported by `on_resize` will come back here, so they are only
+        // applied when they differ from the current ones
+        let ratios = self.normalized_ratios(len);
+        let changed = ratios.len() != state.ratios.len()
+            || (ratios.iter().zip(&state.ratios)).any(|(a, b)| (a - b).abs() > 1e-4);
+
+        if (self.ratios != old.ratios || len != old.content.len()) && changed {
+            state.ratios = ratios;
+            state.collapsed = vec![None; len];
+            cx.layout();
+        }
+
+        (self.content).rebuild(content, &mut cx.as_build_cx(), data, &old.content);
+
+        for i in 0..len {
+            self.content.rebuild_nth(i, content, cx, data, &old.content);
+        }
+    }
+
+    fn event(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) {
+        for i in 0..self.content.len() {
+            self.content.event_nth(i, content, cx, data, event);
+        }
+
+        self.divider_event(state, cx, event);
+    }
+
+    fn layout(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        let (min_major, min_minor) = self.axis.unpack(space.min);
+        let (max_major, max_minor) = self.axis.unpack(space.max);
+
+        let len = self.content.len();
+        let dividers = len.saturating_sub(1) as f32 * self.divider_width;
+
+        // the split fills the space it's given, or as little as possible when unbounded
+        let major = match max_major.is_finite() {
+            true => max_major,
+            false => min_major,
+        };
+
+        state.available = f32::max(major - dividers, 0.0);
+        state.sizes = (state.ratios.iter())
+            .map(|ratio| ratio * state.available)
+            .collect();
+
+        self.constrain(state);
+
+        let mut minor = min_minor;
+        let mut offset = 0.0;
+
+        for i in 0..len {
+            let size = state.sizes[i];
+
+            let space = Space::new(
+                self.axis.pack(size, min_minor),
+                self.axis.pack(size, max_minor),
+            );
+
+            let child_size = self.content.layout_nth(i, content, cx, data, space);
+            minor = f32::max(minor, self.axis.minor(child_size));
+
+            content[i].translate(self.axis.pack(offset, 0.0));
+            offset += size + self.divider_width;
+        }
+
+        let major = state.sizes.iter().sum::<f32>() + dividers;
+        space.fit(self.axis.pack(major, minor))
+    }
+
+    fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        let minor = self.axis.minor(cx.size());
+        let mut offset = 0.0;
+
+        // panes are masked so that collapsed and shrunk panes don't draw over their neighbours
+        for i in 0..self.content.len() {
+            let min = self.axis.pack::<Point>(offset, 0.0);
+            let size = self.axis.pack::<Size>(state.sizes[i], minor);
+
+            cx.mask(Rect::min_size(min, size), |cx| {
+                self.content.draw_nth(i, content, cx, data);
+            });
+
+            offset += state.sizes[i] + self.divider_width;
+        }
+
+        for divider in 0..self.content.len().saturating_sub(1) {
+            let rect = self.divider_rect(state, cx.size(), divider);
+
+            let highlighted = state.hovered == Some(divider)
+                || state.drag.is_some_and(|(dragged, _)| dragged == divider)
+                || (cx.is_focused() && state.focused == Some(divider));
+
+            if highlighted {
+                cx.fill_rect(rect, self.highlight_color);
+            }
+
+            let line = self.axis.pack::<Size>(1.0, self.axis.minor(rect.size()));
+            let line = Rect::center_size(rect.center(), line);
+            cx.fill_rect(line, self.divider_color);
+        }
+    }
+}
+
+impl<T, V: ViewSeq<T>> View<T> for OnResize<T, V> {
+    type State = (SplitState, SeqState<T, V>);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        self.split.build(cx, data)
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        View::rebuild(&mut self.split, state, cx, data, &old.split);
+    }
+
+    fn event(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) {
+        let split = &mut self.split;
+
+        for i in 0..split.content.len() {
+            split.content.event_nth(i, content, cx, data, event);
+        }
+
+        if split.divider_event(state, cx, event) {
+            (self.on_resize)(cx, data, &state.ratios);
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        self.split.layout(state, cx, data, space)
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.split.draw(state, cx, data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        layout::{Rect, Size, Space},
+        views::testing::{save_layout, test_layout},
+    };
+
+    use super::{hsplit, vsplit};
+
+    #[test]
+    fn ratios() {
+        let mut view = hsplit((save_layout((), "a"), save_layout((), "b")))
+            .ratio(0.25)
+            .divider_width(4.0);
+
+        let space = Space::new(Size::ZERO, Size::new(104.0, 50.0));
+        let layouts = test_layout(&mut view, &mut (), space);
+
+        assert_eq!(layouts["a"], Rect::from([0.0, 0.0, 25.0, 0.0]));
+        assert_eq!(layouts["b"], Rect::from([29.0, 0.0, 104.0, 0.0]));
+    }
+
+    #[test]
+    fn min_size() {
+        let content = (
+            save_layout((), "a"),
+            save_layout((), "b"),
+            save_layout((), "c"),
+        );
+
+        let mut view = vsplit(content)
+            .ratios([1.0, 1.0, 1.0])
+            .min_size(0, 40.0)
+            .divider_width(0.0);
+
+        let space = Space::new(Size::ZERO, Size::new(50.0, 90.0));
+        let layouts = test_layout(&mut view, &mut (), space);
+
+        assert_eq!(layouts["a"], Rect::from([0.0, 0.0, 0.0, 40.0]));
+        assert_eq!(layouts["b"], Rect::from([0.0, 40.0, 0.0, 65.0]));
+        assert_eq!(layouts["c"], Rect::from([0.0, 65.0, 0.0, 90.0]));
+    }
+}
@@ -282,19 +282,50 @@ impl<T, V: ViewSeq<T>> View<T> for Stack<V> {
 
         /* position content */
 
+        let baseline = self.align == Align::Baseline && self.axis == Axis::Horizontal;
+
+        // the distances above and below the shared baseline
+        let (ascent, descent) = match baseline {
+            true => baseline_extents(content, &state.minors),
+            false => (0.0, 0.0),
+        };
+
         let major = f32::clamp(state.major() + total_gap, min_major, max_major);
-        let minor = f32::clamp(state.minor(), min_minor, max_minor);
+        let minor = match baseline {
+            true => f32::clamp(ascent + descent, min_minor, max_minor),
+            false => f32::clamp(state.minor(), min_minor, max_minor),
+        };
+
+        let size = self.axis.pack(major, minor);
+        let direction = cx.direction();
 
         for (i, child_major) in (self.justify)
             .layout(&state.majors, major, self.gap)
             .enumerate()
         {
-            let child_align = self.align.align(minor, state.minors[i]);
+            let child_align = match baseline {
+                true => ascent - content[i].baseline().unwrap_or(state.minors[i]),
+                false => self.align.align(minor, state.minors[i]),
+            };
+
             let offset = self.axis.pack(child_major, child_align);
+            let offset = direction.mirror(offset, content[i].size(), size);
             content[i].translate(offset);
         }
 
-        self.axis.pack(major, minor)
+        /* report the baseline */
+
+        let first_baseline = match baseline {
+            true => content
+                .iter()
+                .any(|c| c.baseline().is_some())
+                .then_some(ascent),
+            false => content.iter().find_map(|c| c.parent_baseline()),
+        };
+
+        cx.set_baseline(first_baseline);
+
+        size
     }
 
     fn draw(&mut self, (_, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
@@ -389,3 +420,57 @@ fn layout<T, V: ViewSeq<T>>(
         state.minors[i] = stack.axis.minor(size);
     }
 }
+
+fn baseline_extents<T, V: ViewSeq<T>>(content: &SeqState<T, V>, minors: &[f32]) -> (f32, f32) {
+    let mut ascent = 0.0;
+    let mut descent = 0.0;
+
+    for (i, minor) in minors.iter().copied().enumerate() {
+        let baseline = content[i].baseline().unwrap_or(minor);
+
+        ascent = f32::max(ascent, baseline);
+        descent = f32::max(descent, minor - baseline);
+    }
+
+    (ascent, descent)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        layout::{Align, Rect, Space},
+        views::{
+            hstack, rtl, size,
+            testing::{save_layout, test_layout, with_baseline},
+        },
+    };
+
+    #[test]
+    fn baseline() {
+        let tall = save_layout(with_baseline(8.0, size([10.0, 10.0], ())), "tall");
+        let short = save_layout(with_baseline(2.0, size([10.0, 4.0], ())), "short");
+        let plain = save_layout(size([10.0, 3.0], ()), "plain");
+
+        let mut view = save_layout(hstack((tall, short, plain)).align(Align::Baseline), "stack");
+
+        let layouts = test_layout(&mut view, &mut (), Space::UNBOUNDED);
+
+        assert_eq!(layouts["stack"], Rect::from([0.0, 0.0, 30.0, 10.0]));
+        assert_eq!(layouts["tall"], Rect::from([0.0, 0.0, 10.0, 10.0]));
+        assert_eq!(layouts["short"], Rect::from([10.0, 6.0, 20.0, 10.0]));
+        assert_eq!(layouts["plain"], Rect::from([20.0, 5.0, 30.0, 8.0]));
+    }
+
+    #[test]
+    fn right_to_left() {
+        let first = save_layout(size([10.0, 10.0], ()), "first");
+        let second = save_layout(size([20.0, 5.0], ()), "second");
+
+        let mut view = rtl(hstack((first, second)).align(Align::Start));
+
+        let layouts = test_layout(&mut view, &mut (), Space::UNBOUNDED);
+
+        assert_eq!(layouts["first"], Rect::from([20.0, 0.0, 30.0, 10.0]));
+        assert_eq!(layouts["second"], Rect::from([0.0, 0.0, 20.0, 5.0]));
+    }
+}
@@ -0,0 +1,1038 @@
+use std::ops::Range;
+
+use ori_macro::Build;
+use smol_str::SmolStr;
+
+use crate::{
+    canvas::{Color, Curve, FillRule},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, PointerButton},
+    layout::{Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    text::{FontFamily, FontWeight, Fonts, TextAttributes, TextBuffer, TextWrap},
+    view::{Pod, State, View},
+    window::Cursor,
+};
+
+use super::{vscroll, Scroll, ScrollViewport, TextStyle};
+
+/// Create a new [`Table`].
+///
+/// `cell` is called with the row and column of a cell whenever that row becomes visible.
+pub fn table<T, V>(
+    columns: impl IntoIterator<Item = impl Into<TableColumn>>,
+    rows: usize,
+    cell: impl FnMut(&mut T, usize, usize) -> V + 'static,
+) -> Table<T, V> {
+    Table::new(columns, rows, cell)
+}
+
+/// The order a column of a [`Table`] is sorted in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortOrder {
+    /// The smallest value first.
+    Ascending,
+
+    /// The largest value first.
+    Descending,
+}
+
+impl SortOrder {
+    /// Get the reverse order.
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// A column of a [`Table`].
+#[derive(Clone, Debug, PartialEq, Build)]
+pub struct TableColumn {
+    /// The title of the column.
+    #[build(ignore)]
+    pub title: SmolStr,
+
+    /// The initial width of the column.
+    pub width: f32,
+
+    /// The minimum width of the column, when resized.
+    pub min_width: f32,
+
+    /// Whether the column can be sorted by clicking the header.
+    pub sortable: bool,
+}
+
+impl TableColumn {
+    /// Create a new [`TableColumn`].
+    pub fn new(title: impl Into<SmolStr>) -> Self {
+        Self {
+            title: title.into(),
+            width: 120.0,
+            min_width: 32.0,
+            sortable: true,
+        }
+    }
+}
+
+impl From<&str> for TableColumn {
+    fn from(title: &str) -> Self {
+        Self::new(title)
+    }
+}
+
+impl From<SmolStr> for TableColumn {
+    fn from(title: SmolStr) -> Self {
+        Self::new(title)
+    }
+}
+
+/// The style of a table.
+#[derive(Clone, Debug)]
+pub struct TableStyle {
+    /// The font size of the header.
+    pub font_size: f32,
+
+    /// The font family of the header.
+    pub font_family: FontFamily,
+
+    /// The font weight of the header.
+    pub font_weight: FontWeight,
+
+    /// The height of the header.
+    pub header_height: f32,
+
+    /// The height of each row.
+    pub row_height: f32,
+
+    /// The horizontal padding of each cell.
+    pub cell_padding: f32,
+
+    /// The text color of the header.
+    pub header_color: Color,
+
+    /// The background color of the header.
+    pub header_background: Color,
+
+    /// The background color of a header being dragged.
+    pub header_active: Color,
+
+    /// The background color of the rows.
+    pub background: Color,
+
+    /// The background color of every other row.
+    pub stripe: Color,
+
+    /// The background color of a hovered row.
+    pub hover: Color,
+
+    /// The background color of the selected row.
+    pub selected_color: Color,
+
+    /// The color of the dividers.
+    pub divider: Color,
+
+    /// The color of the sort indicator.
+    pub indicator: Color,
+}
+
+impl Style for TableStyle {
+    fn styled(style: &Styles) -> Self {
+        let text_style = style.get::<TextStyle>();
+        let palette = style.palette();
+
+        Self {
+            font_size: 14.0,
+            font_family: text_style.font_family.clone(),
+            font_weight: FontWeight::SEMI_BOLD,
+            header_height: 32.0,
+            row_height: 28.0,
+            cell_padding: 8.0,
+            header_color: palette.contrast,
+            header_background: palette.surface_high,
+            header_active: palette.surface_higher,
+            background: palette.surface,
+            stripe: palette.surface_low,
+            hover: palette.surface_higher,
+            selected_color: palette.primary.fade(0.4),
+            divider: palette.outline_low,
+            indicator: palette.contrast_low,
+        }
+    }
+}
+
+/// A table with a sticky header, sortable, resizable and reorderable columns and row selection.
+///
+/// Cells are built per `(row, column)`, and only for the rows that are visible, so the table
+/// scales to large datasets. Sorting the data is left to the user, see [`Table::on_sort`], the
+/// table only displays the sort indicator in the header, see [`Table::sort`].
+///
+/// Can be styled using the [`TableStyle`].
+#[derive(Build, Rebuild)]
+pub struct Table<T, V> {
+    /// The columns.
+    #[build(ignore)]
+    pub columns: Vec<TableColumn>,
+
+    #[build(ignore)]
+    body: Pod<Scroll<TableBody<T, V>>>,
+
+    /// The column and order the table is sorted by.
+    #[rebuild(draw)]
+    pub sort: Option<(usize, SortOrder)>,
+
+    /// The selected row.
+    #[rebuild(draw)]
+    pub selected: Option<usize>,
+
+    /// The number of rows built outside the visible region, on either side.
+    #[build(ignore)]
+    #[rebuild(layout)]
+    pub overscan: usize,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_sort: Option<Box<dyn FnMut(&mut EventCx, &mut T, usize, SortOrder)>>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_reorder: Option<Box<dyn FnMut(&mut EventCx, &mut T, &[usize])>>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_resize: Option<Box<dyn FnMut(&mut EventCx, &mut T, usize, f32)>>,
+
+    /// The font size of the header.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the header.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The font weight of the header.
+    #[rebuild(layout)]
+    pub font_weight: FontWeight,
+
+    /// The height of the header.
+    #[rebuild(layout)]
+    pub header_height: f32,
+
+    /// The height of each row.
+    #[rebuild(layout)]
+    pub row_height: f32,
+
+    /// The horizontal padding of each cell.
+    #[rebuild(layout)]
+    pub cell_padding: f32,
+
+    /// The text color of the header.
+    #[rebuild(draw)]
+    pub header_color: Color,
+
+    /// The background color of the header.
+    #[rebuild(draw)]
+    pub header_background: Color,
+
+    /// The background color of a header being dragged.
+    #[rebuild(draw)]
+    pub header_active: Color,
+
+    /// The background color of the rows.
+    #[rebuild(draw)]
+    pub background: Color,
+
+    /// The background color of every other row.
+    #[rebuild(draw)]
+    pub stripe: Color,
+
+    /// The background color of a hovered row.
+    #[rebuild(draw)]
+    pub hover: Color,
+
+    /// The background color of the selected row.
+    #[rebuild(draw)]
+    pub selected_color: Color,
+
+    /// The color of the dividers.
+    #[rebuild(draw)]
+    pub divider: Color,
+
+    /// The color of the sort indicator.
+    #[rebuild(draw)]
+    pub indicator: Color,
+}
+
+impl<T, V> Table<T, V> {
+    /// Create a new [`Table`].
+    pub fn new(
+        columns: impl IntoIterator<Item = impl Into<TableColumn>>,
+        rows: usize,
+        cell: impl FnMut(&mut T, usize, usize) -> V + 'static,
+    ) -> Self {
+        Self::styled(columns, rows, cell, style())
+    }
+
+    /// Create a new [`Table`] with a style.
+    pub fn styled(
+        columns: impl IntoIterator<Item = impl Into<TableColumn>>,
+        rows: usize,
+        cell: impl FnMut(&mut T, usize, usize) -> V + 'static,
+        style: TableStyle,
+    ) -> Self {
+        let columns: Vec<_> = columns.into_iter().map(Into::into).collect();
+
+        let body = TableBody {
+            cell: Box::new(cell),
+            on_select: None,
+            rows,
+            widths: columns.iter().map(|column| column.width).collect(),
+            order: (0..columns.len()).collect(),
+            selected: None,
+            overscan: 4,
+            row_height: style.row_height,
+            cell_padding: style.cell_padding,
+            background: style.background,
+            stripe: style.stripe,
+            hover: style.hover,
+            selected_color: style.selected_color,
+        };
+
+        Self {
+            columns,
+            body: Pod::new(vscroll(body)),
+            sort: None,
+            selected: None,
+            overscan: 4,
+            on_sort: None,
+            on_reorder: None,
+            on_resize: None,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            font_weight: style.font_weight,
+            header_height: style.header_height,
+            row_height: style.row_height,
+            cell_padding: style.cell_padding,
+            header_color: style.header_color,
+            header_background: style.header_background,
+            header_active: style.header_active,
+            background: style.background,
+            stripe: style.stripe,
+            hover: style.hover,
+            selected_color: style.selected_color,
+            divider: style.divider,
+            indicator: style.indicator,
+        }
+    }
+
+    /// Set the number of rows built outside the visible region, on either side.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Set a callback for when a sortable column header is clicked.
+    ///
+    /// The callback is given the column and the order it should be sorted in.
+    pub fn on_sort(
+        mut self,
+        on_sort: impl FnMut(&mut EventCx, &mut T, usize, SortOrder) + 'static,
+    ) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Set a callback for when a row is clicked.
+    pub fn on_select(
+        mut self,
+        on_select: impl FnMut(&mut EventCx, &mut T, usize) + 'static,
+    ) -> Self {
+        self.body.content.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Set a callback for when the columns are reordered.
+    ///
+    /// The callback is given the columns in the order they are displayed.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl FnMut(&mut EventCx, &mut T, &[usize]) + 'static,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Set a callback for when a column has been resized.
+    pub fn on_resize(
+        mut self,
+        on_resize: impl FnMut(&mut EventCx, &mut T, usize, f32) + 'static,
+    ) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    // the body is part of the view, so it has to be given the state of the header every time
+    // it is used
+    fn sync_body(&mut self, state: &TableState) {
+        let body = &mut self.body.content;
+
+        body.widths.clone_from(&state.widths);
+        body.order.clone_from(&state.order);
+        body.selected = self.selected;
+        body.overscan = self.overscan;
+        body.row_height = self.row_height;
+        body.cell_padding = self.cell_padding;
+        body.background = self.background;
+        body.stripe = self.stripe;
+        body.hover = self.hover;
+        body.selected_color = self.selected_color;
+    }
+
+    fn set_titles(&self, fonts: &mut Fonts, titles: &mut Vec<TextBuffer>) {
+        titles.clear();
+
+        for column in &self.columns {
+            let mut buffer = TextBuffer::new(fonts, self.font_size, 1.0);
+            buffer.set_wrap(fonts, TextWrap::None);
+            buffer.set_text(
+                fonts,
+                &column.title,
+                TextAttributes {
+                    family: self.font_family.clone(),
+                    weight: self.font_weight,
+                    ..Default::default()
+                },
+            );
+
+            titles.push(buffer);
+        }
+    }
+
+    // get the column whose right edge is under `point`
+    fn resize_handle(&self, state: &TableState, point: Point) -> Option<usize> {
+        if point.y < 0.0 || point.y > self.header_height {
+            return None;
+        }
+
+        let mut x = 0.0;
+
+        for &column in &state.order {
+            x += state.widths[column];
+
+            if (point.x - x).abs() <= 4.0 {
+                return Some(column);
+            }
+        }
+
+        None
+    }
+
+    // get the display position of the column at `x`
+    fn position_at(&self, state: &TableState, x: f32) -> Option<usize> {
+        let mut right = 0.0;
+
+        for (position, &column) in state.order.iter().enumerate() {
+            right += state.widths[column];
+
+            if x < right {
+                return Some(position);
+            }
+        }
+
+        None
+    }
+}
+
+enum HeaderDrag {
+    Resize {
+        column: usize,
+        start: f32,
+        width: f32,
+    },
+    Move {
+        column: usize,
+        start: f32,
+        moved: bool,
+    },
+}
+
+#[doc(hidden)]
+pub struct TableState {
+    widths: Vec<f32>,
+    order: Vec<usize>,
+    titles: Vec<TextBuffer>,
+    drag: Option<HeaderDrag>,
+}
+
+impl<T, V: View<T>> View<T> for Table<T, V> {
+    type State = (TableState, State<T, Scroll<TableBody<T, V>>>);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let mut state = TableState {
+            widths: self.columns.iter().map(|column| column.width).collect(),
+            order: (0..self.columns.len()).collect(),
+            titles: Vec::new(),
+            drag: None,
+        };
+
+        self.set_titles(cx.fonts(), &mut state.titles);
+        self.sync_body(&state);
+
+        let body = self.body.build(cx, data);
+        (state, body)
+    }
+
+    fn rebuild(
+        &mut self,
+        (state, body): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        Rebuild::rebuild(self, cx, old);
+
+        if self.columns.len() != old.columns.len() {
+            state.widths = self.columns.iter().map(|column| column.width).collect();
+            state.order = (0..self.columns.len()).collect();
+            state.drag = None;
+        } else {
+            for (i, column) in self.columns.iter().enumerate() {
+                if column.width != old.columns[i].width {
+                    state.widths[i] = column.width;
+                    cx.layout();
+                }
+            }
+        }
+
+        if self.columns != old.columns
+            || self.font_size != old.font_size
+            || self.font_family != old.font_family
+            || self.font_weight != old.font_weight
+        {
+            self.set_titles(cx.fonts(), &mut state.titles);
+            cx.layout();
+        }
+
+        self.sync_body(state);
+        self.body.rebuild(body, cx, data, &old.body);
+    }
+
+    fn event(
+        &mut self,
+        (state, body): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) {
+        self.sync_body(state);
+        self.body.event(body, cx, data, event);
+
+        match event {
+            Event::PointerMoved(e) => {
+                let local = cx.local(e.position);
+                let target = self.position_at(state, local.x.max(0.0));
+
+                match state.drag {
+                    Some(HeaderDrag::Resize {
+                        column,
+                        start,
+                        width,
+                    }) => {
+                        let min_width = self.columns[column].min_width;
+                        state.widths[column] = f32::max(width + local.x - start, min_width);
+
+                        cx.layout();
+                    }
+                    Some(HeaderDrag::Move {
+                        column,
+                        start,
+                        ref mut moved,
+                    }) => {
+                        *moved |= (local.x - start).abs() > 4.0;
+
+                        let current = state.order.iter().position(|&c| c == column);
+
+                        if let (true, Some(current), Some(target)) = (*moved, current, target) {
+                            if current != target {
+                                state.order.remove(current);
+                                state.order.insert(target, column);
+
+                                cx.layout();
+                            }
+                        }
+
+                        cx.draw();
+                    }
+                    None => {
+                        let handle = cx.is_hot() && self.resize_handle(state, local).is_some();
+                        cx.set_cursor(handle.then_some(Cursor::ColResize));
+                    }
+                }
+            }
+            Event::PointerPressed(e) if cx.is_hot() && e.button == PointerButton::Primary => {
+                let local = cx.local(e.position);
+
+                // only the header is handled here, the rows are handled by the body
+                if local.y > self.header_height {
+                    return;
+                }
+
+                if let Some(column) = self.resize_handle(state, local) {
+                    state.drag = Some(HeaderDrag::Resize {
+                        column,
+                        start: local.x,
+                        width: state.widths[column],
+                    });
+                } else if let Some(position) = self.position_at(state, local.x) {
+                    state.drag = Some(HeaderDrag::Move {
+                        column: state.order[position],
+                        start: local.x,
+                        moved: false,
+                    });
+                }
+
+                cx.set_active(state.drag.is_some());
+                cx.draw();
+            }
+            Event::PointerReleased(e) if e.button == PointerButton::Primary => {
+                let Some(drag) = state.drag.take() else {
+                    return;
+                };
+
+                cx.set_active(false);
+                cx.draw();
+
+                match drag {
+                    HeaderDrag::Resize { column, .. } => {
+                        if let Some(ref mut on_resize) = self.on_resize {
+                            on_resize(cx, data, column, state.widths[column]);
+                        }
+                    }
+                    HeaderDrag::Move { moved: true, .. } => {
+                        if let Some(ref mut on_reorder) = self.on_reorder {
+                            on_reorder(cx, data, &state.order);
+                        }
+                    }
+                    HeaderDrag::Move { column, .. } => {
+                        if !self.columns[column].sortable {
+                            return;
+                        }
+
+                        let order = match self.sort {
+                            Some((sorted, order)) if sorted == column => order.reverse(),
+                            _ => SortOrder::Ascending,
+                        };
+
+                        if let Some(ref mut on_sort) = self.on_sort {
+                            on_sort(cx, data, column, order);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        (state, body): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        let header = Size::new(0.0, self.header_height);
+        let body_space = Space::new(
+            (space.min - header).max(Size::ZERO),
+            (space.max - header).max(Size::ZERO),
+        );
+
+        self.sync_body(state);
+        let body_size = self.body.layout(body, cx, data, body_space);
+        body.translate(Vector::new(0.0, self.header_height));
+
+        let columns_width: f32 = state.widths.iter().sum();
+        let width = f32::max(body_size.width, columns_width);
+
+        space.fit(Size::new(width, self.header_height + body_size.height))
+    }
+
+    fn draw(&mut self, (state, body): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.sync_body(state);
+        self.body.draw(body, cx, data);
+
+        let header = Rect::min_size(Point::ZERO, Size::new(cx.size().width, self.header_height));
+        cx.fill_rect(header, self.header_background);
+        cx.trigger(header);
+
+        let dragged = match state.drag {
+            Some(HeaderDrag::Move {
+                column,
+                moved: true,
+                ..
+            }) => Some(column),
+            _ => None,
+        };
+
+        let mut x = 0.0;
+
+        for &column in &state.order {
+            let width = state.widths[column];
+            let rect = Rect::min_size(Point::new(x, 0.0), Size::new(width, self.header_height));
+
+            if dragged == Some(column) {
+                cx.fill_rect(rect, self.header_active);
+            }
+
+            let sorted = match self.sort {
+                Some((sorted, order)) if sorted == column => Some(order),
+                _ => None,
+            };
+
+            let title = &state.titles[column];
+            let offset = Vector::new(
+                x + self.cell_padding,
+                (self.header_height - title.size().height) / 2.0,
+            );
+
+            let indicator = if sorted.is_some() { 12.0 } else { 0.0 };
+            let title_rect = Rect::min_size(
+                rect.top_left(),
+                Size::new(width - self.cell_padding - indicator, self.header_height),
+            );
+
+            cx.mask(title_rect, |cx| {
+                cx.text(title, self.header_color, offset);
+            });
+
+            if let Some(order) = sorted {
+                let center = Point::new(
+                    x + width - self.cell_padding - 4.0,
+                    self.header_height / 2.0,
+                );
+
+                cx.fill(
+                    sort_indicator(center, order),
+                    FillRule::NonZero,
+                    self.indicator,
+                );
+            }
+
+            let divider = Rect::min_size(
+                Point::new(x + width - 0.5, 6.0),
+                Size::new(1.0, self.header_height - 12.0),
+            );
+            cx.fill_rect(divider, self.divider);
+
+            x += width;
+        }
+
+        let border = Rect::min_size(
+            Point::new(0.0, self.header_height - 1.0),
+            Size::new(cx.size().width, 1.0),
+        );
+        cx.fill_rect(border, self.divider);
+    }
+}
+
+fn sort_indicator(center: Point, order: SortOrder) -> Curve {
+    let mut curve = Curve::new();
+
+    // the tip points up when ascending
+    let d = 4.0;
+    let tip = match order {
+        SortOrder::Ascending => -d / 2.0,
+        SortOrder::Descending => d / 2.0,
+    };
+
+    curve.move_to(center + Vector::new(-d, -tip));
+    curve.line_to(center + Vector::new(d, -tip));
+    curve.line_to(center + Vector::new(0.0, tip));
+    curve.close();
+
+    curve
+}
+
+/// The rows of a [`Table`].
+#[doc(hidden)]
+pub struct TableBody<T, V> {
+    #[allow(clippy::type_complexity)]
+    cell: Box<dyn FnMut(&mut T, usize, usize) -> V>,
+    #[allow(clippy::type_complexity)]
+    on_select: Option<Box<dyn FnMut(&mut EventCx, &mut T, usize)>>,
+    rows: usize,
+    widths: Vec<f32>,
+    order: Vec<usize>,
+    selected: Option<usize>,
+    overscan: usize,
+    row_height: f32,
+    cell_padding: f32,
+    background: Color,
+    stripe: Color,
+    hover: Color,
+    selected_color: Color,
+}
+
+struct TableRow<T, V: View<T>> {
+    index: usize,
+    cells: Vec<(Pod<V>, State<T, V>)>,
+}
+
+#[doc(hidden)]
+pub struct TableBodyState<T, V: View<T>> {
+    rows: Vec<TableRow<T, V>>,
+    range: Range<usize>,
+    hovered: Option<usize>,
+}
+
+impl<T, V: View<T>> TableBody<T, V> {
+    fn columns(&self) -> usize {
+        self.widths.len()
+    }
+
+    fn row_at(&self, point: Point) -> Option<usize> {
+        let row = f32::floor(point.y / self.row_height);
+
+        match row >= 0.0 && (row as usize) < self.rows {
+            true => Some(row as usize),
+            false => None,
+        }
+    }
+
+    fn build_row(&mut self, cx: &mut BuildCx, data: &mut T, index: usize) -> TableRow<T, V> {
+        let mut cells = Vec::with_capacity(self.columns());
+
+        for column in 0..self.columns() {
+            let mut view = Pod::new((self.cell)(data, index, column));
+            let state = view.build(cx, data);
+            cells.push((view, state));
+        }
+
+        TableRow { index, cells }
+    }
+
+    fn update_range(
+        &mut self,
+        state: &mut TableBodyState<T, V>,
+        cx: &mut BuildCx,
+        data: &mut T,
+        range: Range<usize>,
+    ) {
+        if state.range == range {
+            return;
+        }
+
+        let mut old = std::mem::take(&mut state.rows).into_iter().peekable();
+
+        for index in range.clone() {
+            // the rows are sorted, so we can skip the ones that are no longer visible
+            while old.next_if(|row| row.index < index).is_some() {}
+
+            match old.next_if(|row| row.index == index) {
+                Some(row) => state.rows.push(row),
+                None => {
+                    let row = self.build_row(cx, data, index);
+                    state.rows.push(row);
+                }
+            }
+        }
+
+        state.range = range;
+    }
+}
+
+impl<T, V: View<T>> View<T> for TableBody<T, V> {
+    type State = TableBodyState<T, V>;
+
+    fn build(&mut self, _cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        // the rows are built when the body is laid out, since that is when the visible region
+        // is known
+        TableBodyState {
+            rows: Vec::new(),
+            range: 0..0,
+            hovered: None,
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        if self.rows != old.rows || self.columns() != old.columns() {
+            cx.layout();
+        }
+
+        // rows that no longer exist, or have the wrong number of cells are dropped, and built
+        // again when laid out
+        let columns = self.columns();
+        let rows = self.rows;
+
+        (state.rows).retain(|row| row.index < rows && row.cells.len() == columns);
+        state.range = 0..0;
+
+        for row in &mut state.rows {
+            for (column, (old_view, cell)) in row.cells.iter_mut().enumerate() {
+                let mut view = Pod::new((self.cell)(data, row.index, column));
+                view.rebuild(cell, cx, data, old_view);
+                *old_view = view;
+            }
+        }
+
+        if let Some(row) = state.rows.first() {
+            state.range = row.index..row.index + state.rows.len();
+        }
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        for row in &mut state.rows {
+            for (view, cell) in &mut row.cells {
+                view.event(cell, cx, data, event);
+            }
+        }
+
+        let hovered = cx.is_hot() || cx.has_hot();
+
+        match event {
+            Event::PointerMoved(e) => {
+                let row = self.row_at(cx.local(e.position)).filter(|_| hovered);
+
+                if state.hovered != row {
+                    state.hovered = row;
+                    cx.draw();
+                }
+            }
+            Event::PointerLeft(_) if state.hovered.is_some() => {
+                state.hovered = None;
+                cx.draw();
+            }
+            Event::PointerPressed(e) if hovered && e.button == PointerButton::Primary => {
+                let Some(row) = self.row_at(cx.local(e.position)) else {
+                    return;
+                };
+
+                if let Some(ref mut on_select) = self.on_select {
+                    on_select(cx, data, row);
+                    cx.draw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        // the visible region depends on the scroll view, so the layout can't be cached
+        cx.set_cache_layout(false);
+
+        // when not inside a scroll view, the rows are visible up to the window
+        let (offset, length) = match cx.get_context_mut::<ScrollViewport>() {
+            Some(viewport) => {
+                viewport.observe();
+                (viewport.offset, viewport.length)
+            }
+            None => (0.0, cx.window().size.height),
+        };
+
+        let first = f32::floor(offset / self.row_height) as usize;
+        let last = f32::ceil((offset + length) / self.row_height) as usize;
+
+        let first = usize::min(first.saturating_sub(self.overscan), self.rows);
+        let last = usize::min(last + self.overscan, self.rows);
+
+        self.update_range(state, &mut cx.as_build_cx(), data, first..last);
+
+        for row in &mut state.rows {
+            let y = row.index as f32 * self.row_height;
+            let mut x = 0.0;
+
+            for &column in &self.order {
+                let width = self.widths[column];
+                let (view, cell) = &mut row.cells[column];
+
+                let max_width = f32::max(width - self.cell_padding * 2.0, 0.0);
+                let cell_space = Space::new(Size::ZERO, Size::new(max_width, self.row_height));
+                let size = view.layout(cell, cx, data, cell_space);
+
+                let offset = (self.row_height - size.height) / 2.0;
+                cell.translate(Vector::new(x + self.cell_padding, y + offset));
+
+                x += width;
+            }
+        }
+
+        let width: f32 = self.widths.iter().sum();
+        space.fit(Size::new(width, self.rows as f32 * self.row_height))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        for row in &mut state.rows {
+            let rect = Rect::min_size(
+                Point::new(0.0, row.index as f32 * self.row_height),
+                Size::new(cx.size().width, self.row_height),
+            );
+
+            let background = if self.selected == Some(row.index) {
+                self.selected_color
+            } else if state.hovered == Some(row.index) {
+                self.hover
+            } else if row.index % 2 == 1 {
+                self.stripe
+            } else {
+                self.background
+            };
+
+            cx.fill_rect(rect, background);
+            cx.trigger(rect);
+
+            for (view, cell) in &mut row.cells {
+                view.draw(cell, cx, data);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        layout::{Rect, Size, Space},
+        views::{
+            size,
+            testing::{save_layout, test_layout},
+        },
+    };
+
+    use super::{table, TableColumn};
+
+    #[test]
+    fn layout() {
+        let columns = [
+            TableColumn::new("a").width(50.0),
+            TableColumn::new("b").width(30.0),
+        ];
+
+        let table = table(columns, 10_000, |built: &mut usize, row, column| {
+            *built += 1;
+            save_layout(size([10.0, 10.0], ()), format!("{},{}", row, column))
+        });
+
+        let mut view = table
+            .header_height(20.0)
+            .row_height(20.0)
+            .cell_padding(5.0)
+            .overscan(0);
+
+        let space = Space::new(Size::ZERO, Size::new(200.0, 120.0));
+
+        let mut built = 0;
+        let layouts = test_layout(&mut view, &mut built, space);
+
+        // 100 pixels of rows are visible below the header
+        assert_eq!(built, 5 * 2);
+        assert_eq!(layouts["0,0"], Rect::from([5.0, 25.0, 15.0, 35.0]));
+        assert_eq!(layouts["2,1"], Rect::from([55.0, 65.0, 65.0, 75.0]));
+    }
+}
@@ -0,0 +1,1105 @@
+use ori_macro::Build;
+use smol_str::SmolStr;
+
+use crate::{
+    canvas::{BorderRadius, Color, Curve, Pattern},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, PointerButton},
+    image::Image,
+    layout::{Affine, Padding, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    text::{FontFamily, Fonts, TextAttributes, TextBuffer, TextWrap},
+    transition::Transition,
+    view::{Pod, State, View},
+    window::Cursor,
+};
+
+use super::TextStyle;
+
+/// Create a new [`Tabs`] view, with the tab at index `active` selected.
+///
+/// `page` is called with the index of the active tab, only the page of the active tab is built.
+pub fn tabs<T, V>(
+    tabs: impl IntoIterator<Item = impl Into<Tab>>,
+    active: usize,
+    page: impl FnMut(&mut T, usize) -> V + 'static,
+) -> Tabs<T, V> {
+    Tabs::new(tabs, active, page)
+}
+
+/// A tab of a [`Tabs`] view.
+#[derive(Clone, Debug, PartialEq, Build)]
+pub struct Tab {
+    /// The title of the tab.
+    #[build(ignore)]
+    pub title: SmolStr,
+
+    /// The icon of the tab.
+    #[build(ignore)]
+    pub icon: Option<Image>,
+
+    /// Whether the tab has a close button.
+    pub closable: bool,
+}
+
+impl Tab {
+    /// Create a new [`Tab`].
+    pub fn new(title: impl Into<SmolStr>) -> Self {
+        Self {
+            title: title.into(),
+            icon: None,
+            closable: false,
+        }
+    }
+
+    /// Set the icon of the tab.
+    pub fn icon(mut self, icon: impl Into<Image>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+}
+
+impl From<&str> for Tab {
+    fn from(title: &str) -> Self {
+        Self::new(title)
+    }
+}
+
+impl From<SmolStr> for Tab {
+    fn from(title: SmolStr) -> Self {
+        Self::new(title)
+    }
+}
+
+impl From<String> for Tab {
+    fn from(title: String) -> Self {
+        Self::new(title)
+    }
+}
+
+/// The style of a [`Tabs`] view.
+#[derive(Clone, Debug)]
+pub struct TabsStyle {
+    /// The transition of the indicator.
+    pub transition: Transition,
+
+    /// The padding of each tab.
+    pub padding: Padding,
+
+    /// The space between the icon, the title and the close button of a tab.
+    pub spacing: f32,
+
+    /// The size of the icons.
+    pub icon_size: f32,
+
+    /// The size of the close buttons.
+    pub close_size: f32,
+
+    /// The font size of the titles.
+    pub font_size: f32,
+
+    /// The font family of the titles.
+    pub font_family: FontFamily,
+
+    /// The color of the titles.
+    pub color: Color,
+
+    /// The color of the title of the active tab.
+    pub active_color: Color,
+
+    /// The background color of the tab bar.
+    pub background: Color,
+
+    /// The background color of a hovered tab.
+    pub hover: Color,
+
+    /// The background color of the active tab.
+    pub active_background: Color,
+
+    /// The background color of a hovered close button.
+    pub close_hover: Color,
+
+    /// The color of the indicator.
+    pub indicator: Color,
+
+    /// The height of the indicator.
+    pub indicator_height: f32,
+
+    /// The color of the line below the tab bar.
+    pub divider: Color,
+}
+
+impl Style for TabsStyle {
+    fn styled(style: &Styles) -> Self {
+        let text_style = style.get::<TextStyle>();
+        let palette = style.palette();
+
+        Self {
+            transition: Transition::ease(0.15),
+            padding: Padding::from([12.0, 8.0]),
+            spacing: 6.0,
+            icon_size: 16.0,
+            close_size: 16.0,
+            font_size: 14.0,
+            font_family: text_style.font_family.clone(),
+            color: palette.contrast_low,
+            active_color: palette.contrast,
+            background: palette.surface,
+            hover: palette.surface_high,
+            active_background: palette.background,
+            close_hover: palette.surface_higher,
+            indicator: palette.primary,
+            indicator_height: 2.0,
+            divider: palette.outline_low,
+        }
+    }
+}
+
+/// A tab bar with a page for the active tab.
+///
+/// Only the page of the active tab is built, unless [`Tabs::keep_alive`] is set, in which case
+/// the state of every page that has been visited is kept. Tabs can be closed with their close
+/// button or the middle mouse button, and reordered by dragging them. When the tabs don't fit
+/// the tab bar can be scrolled. `Ctrl+Tab`, `Ctrl+Shift+Tab`, `Ctrl+PageUp` and `Ctrl+PageDown`
+/// switch tabs while the pointer or focus is inside the view.
+///
+/// The tabs are controlled, [`Tabs::on_select`], [`Tabs::on_close`] and [`Tabs::on_reorder`]
+/// are expected to apply the change to the data and rebuild, the kept pages are moved along
+/// with their tabs.
+///
+/// Can be styled using the [`TabsStyle`].
+#[derive(Build, Rebuild)]
+pub struct Tabs<T, V> {
+    /// The tabs.
+    #[build(ignore)]
+    pub tabs: Vec<Tab>,
+
+    /// The index of the active tab.
+    #[build(ignore)]
+    pub active: usize,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    page: Box<dyn FnMut(&mut T, usize) -> V>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_select: Option<Box<dyn FnMut(&mut EventCx, &mut T, usize)>>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_close: Option<Box<dyn FnMut(&mut EventCx, &mut T, usize)>>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_reorder: Option<Box<dyn FnMut(&mut EventCx, &mut T, usize, usize)>>,
+
+    /// Whether to keep the state of inactive pages.
+    pub keep_alive: bool,
+
+    /// The transition of the indicator.
+    #[rebuild(draw)]
+    pub transition: Transition,
+
+    /// The padding of each tab.
+    #[rebuild(layout)]
+    pub padding: Padding,
+
+    /// The space between the icon, the title and the close button of a tab.
+    #[rebuild(layout)]
+    pub spacing: f32,
+
+    /// The size of the icons.
+    #[rebuild(layout)]
+    pub icon_size: f32,
+
+    /// The size of the close buttons.
+    #[rebuild(layout)]
+    pub close_size: f32,
+
+    /// The font size of the titles.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the titles.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The color of the titles.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of the title of the active tab.
+    #[rebuild(draw)]
+    pub active_color: Color,
+
+    /// The background color of the tab bar.
+    #[rebuild(draw)]
+    pub background: Color,
+
+    /// The background color of a hovered tab.
+    #[rebuild(draw)]
+    pub hover: Color,
+
+    /// The background color of the active tab.
+    #[rebuild(draw)]
+    pub active_background: Color,
+
+    /// The background color of a hovered close button.
+    #[rebuild(draw)]
+    pub close_hover: Color,
+
+    /// The color of the indicator.
+    #[rebuild(draw)]
+    pub indicator: Color,
+
+    /// The height of the indicator.
+    #[rebuild(draw)]
+    pub indicator_height: f32,
+
+    /// The color of the line below the tab bar.
+    #[rebuild(draw)]
+    pub divider: Color,
+}
+
+impl<T, V> Tabs<T, V> {
+    /// Create a new [`Tabs`] view.
+    pub fn new(
+        tabs: impl IntoIterator<Item = impl Into<Tab>>,
+        active: usize,
+        page: impl FnMut(&mut T, usize) -> V + 'static,
+    ) -> Self {
+        Self::styled(tabs, active, page, style())
+    }
+
+    /// Create a new [`Tabs`] view with a style.
+    pub fn styled(
+        tabs: impl IntoIterator<Item = impl Into<Tab>>,
+        active: usize,
+        page: impl FnMut(&mut T, usize) -> V + 'static,
+        style: TabsStyle,
+    ) -> Self {
+        Self {
+            tabs: tabs.into_iter().map(Into::into).collect(),
+            active,
+            page: Box::new(page),
+            on_select: None,
+            on_close: None,
+            on_reorder: None,
+            keep_alive: false,
+            transition: style.transition,
+            padding: style.padding,
+            spacing: style.spacing,
+            icon_size: style.icon_size,
+            close_size: style.close_size,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            color: style.color,
+            active_color: style.active_color,
+            background: style.background,
+            hover: style.hover,
+            active_background: style.active_background,
+            close_hover: style.close_hover,
+            indicator: style.indicator,
+            indicator_height: style.indicator_height,
+            divider: style.divider,
+        }
+    }
+
+    /// Set a callback for when a tab is selected.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_select(
+        mut self,
+        on_select: impl FnMut(&mut EventCx, &mut T, usize) + 'static,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Set a callback for when a tab is closed, the callback is expected to remove the tab.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_close(mut self, on_close: impl FnMut(&mut EventCx, &mut T, usize) + 'static) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Set a callback for when a tab is dragged from one index to another, the callback is
+    /// expected to move the tab. Tabs can only be reordered when this is set.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl FnMut(&mut EventCx, &mut T, usize, usize) + 'static,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+}
+
+struct TabDrag {
+    index: usize,
+    // the pointer position and where the tab was grabbed, in tab bar coordinates
+    x: f32,
+    grab: f32,
+    start: f32,
+    moved: bool,
+}
+
+#[doc(hidden)]
+pub struct TabsState<T, V: View<T>> {
+    tabs: Vec<Tab>,
+    titles: Vec<TextBuffer>,
+    widths: Vec<f32>,
+    #[allow(clippy::type_complexity)]
+    pages: Vec<Option<(Pod<V>, State<T, V>)>>,
+    active: Option<usize>,
+    bar_height: f32,
+    scroll: f32,
+    reveal: bool,
+    hovered: Option<(usize, bool)>,
+    drag: Option<TabDrag>,
+    // the indicator slides from `from` to the active tab
+    from: Option<(f32, f32)>,
+    t: f32,
+}
+
+fn move_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < index && index <= to {
+        index - 1
+    } else if to <= index && index < from {
+        index + 1
+    } else {
+        index
+    }
+}
+
+fn move_item<I>(items: &mut Vec<I>, from: usize, to: usize) {
+    if from < items.len() && to < items.len() {
+        let item = items.remove(from);
+        items.insert(to, item);
+    }
+}
+
+impl<T, V: View<T>> TabsState<T, V> {
+    // the tabs in the order they are shown, which differs from the tabs while dragging
+    fn order(&self) -> Vec<usize> {
+        let mut order: Vec<_> = (0..self.widths.len()).collect();
+
+        if let Some(ref drag) = self.drag {
+            if drag.moved {
+                let to = self.drop_index(drag);
+                move_item(&mut order, drag.index, to);
+            }
+        }
+
+        order
+    }
+
+    // the position of each tab in the tab bar, without scrolling
+    fn offsets(&self) -> Vec<f32> {
+        let mut offsets = vec![0.0; self.widths.len()];
+        let mut x = 0.0;
+
+        for index in self.order() {
+            offsets[index] = x;
+            x += self.widths[index];
+        }
+
+        offsets
+    }
+
+    fn total_width(&self) -> f32 {
+        self.widths.iter().sum()
+    }
+
+    // the position of the dragged tab
+    fn drag_offset(&self, drag: &TabDrag) -> f32 {
+        let width = self.widths[drag.index];
+        f32::clamp(drag.x - drag.grab, 0.0, self.total_width() - width)
+    }
+
+    // the index the dragged tab would be dropped at
+    fn drop_index(&self, drag: &TabDrag) -> usize {
+        let center = self.drag_offset(drag) + self.widths[drag.index] / 2.0;
+
+        let mut x = 0.0;
+        let mut index = 0;
+
+        for (i, &width) in self.widths.iter().enumerate() {
+            if i == drag.index {
+                continue;
+            }
+
+            if x + width / 2.0 < center {
+                index += 1;
+            }
+
+            x += width;
+        }
+
+        index
+    }
+
+    // the position and width of the indicator
+    fn indicator(&self, transition: &Transition) -> Option<(f32, f32)> {
+        let active = self.active?;
+        let width = *self.widths.get(active)?;
+
+        let x = match self.drag {
+            Some(ref drag) if drag.index == active => self.drag_offset(drag),
+            _ => self.offsets()[active],
+        };
+
+        let Some((from_x, from_width)) = self.from else {
+            return Some((x, width));
+        };
+
+        let t = transition.get(self.t);
+        Some((
+            from_x + (x - from_x) * t,
+            from_width + (width - from_width) * t,
+        ))
+    }
+
+    // the tab at `point`, and whether the point is over its close button
+    fn tab_at(
+        &self,
+        tabs: &Tabs<T, V>,
+        point: Point,
+        width: f32,
+        rtl: bool,
+    ) -> Option<(usize, bool)> {
+        if point.y < 0.0 || point.y > self.bar_height || point.x < 0.0 || point.x > width {
+            return None;
+        }
+
+        let x = match rtl {
+            true => width - point.x,
+            false => point.x,
+        } + self.scroll;
+
+        let offsets = self.offsets();
+        let index =
+            (0..self.widths.len()).find(|&i| x >= offsets[i] && x < offsets[i] + self.widths[i])?;
+
+        let end = offsets[index] + self.widths[index] - tabs.padding.right;
+        let closable = self.tabs.get(index).is_some_and(|tab| tab.closable);
+        let close = closable && x >= end - tabs.close_size && x <= end;
+
+        Some((index, close))
+    }
+
+    // the visual position of a span in the tab bar
+    fn visual_x(&self, x: f32, width: f32, bar_width: f32, rtl: bool) -> f32 {
+        match rtl {
+            true => bar_width - (x - self.scroll) - width,
+            false => x - self.scroll,
+        }
+    }
+
+    fn remove(&mut self, index: usize) {
+        self.tabs.remove(index);
+        self.titles.remove(index);
+        self.widths.remove(index);
+        self.pages.remove(index);
+
+        self.active = match self.active {
+            Some(active) if active == index => None,
+            Some(active) if active > index => Some(active - 1),
+            active => active,
+        };
+
+        self.hovered = None;
+        self.from = None;
+    }
+
+    fn reorder(&mut self, from: usize, to: usize) {
+        move_item(&mut self.tabs, from, to);
+        move_item(&mut self.titles, from, to);
+        move_item(&mut self.widths, from, to);
+        move_item(&mut self.pages, from, to);
+
+        self.active = self.active.map(|active| move_index(active, from, to));
+        self.hovered = None;
+        self.from = None;
+    }
+}
+
+impl<T, V: View<T>> Tabs<T, V> {
+    fn active(&self) -> Option<usize> {
+        (self.active < self.tabs.len()).then_some(self.active)
+    }
+
+    fn set_titles(&self, fonts: &mut Fonts, state: &mut TabsState<T, V>) {
+        state.titles.clear();
+
+        for tab in &self.tabs {
+            let mut buffer = TextBuffer::new(fonts, self.font_size, 1.2);
+            buffer.set_wrap(fonts, TextWrap::None);
+            buffer.set_text(
+                fonts,
+                &tab.title,
+                TextAttributes {
+                    family: self.font_family.clone(),
+                    ..Default::default()
+                },
+            );
+
+            state.titles.push(buffer);
+        }
+
+        state.tabs = self.tabs.clone();
+    }
+
+    fn tab_width(&self, tab: &Tab, title: &TextBuffer) -> f32 {
+        let mut width = self.padding.left + title.size().width + self.padding.right;
+
+        if tab.icon.is_some() {
+            width += self.icon_size + self.spacing;
+        }
+
+        if tab.closable {
+            width += self.spacing + self.close_size;
+        }
+
+        width
+    }
+
+    // build or rebuild the page of the active tab, and drop the other pages unless kept alive
+    fn update_pages(&mut self, state: &mut TabsState<T, V>, cx: &mut RebuildCx, data: &mut T) {
+        state.pages.resize_with(self.tabs.len(), || None);
+
+        let active = self.active();
+
+        if !self.keep_alive {
+            for (i, page) in state.pages.iter_mut().enumerate() {
+                if Some(i) != active {
+                    *page = None;
+                }
+            }
+        }
+
+        let Some(active) = active else {
+            return;
+        };
+
+        let mut view = Pod::new((self.page)(data, active));
+
+        match state.pages[active] {
+            Some((ref mut old, ref mut page)) => {
+                view.rebuild(page, cx, data, old);
+                *old = view;
+            }
+            None => {
+                let page = view.build(&mut cx.as_build_cx(), data);
+                state.pages[active] = Some((view, page));
+                cx.layout();
+            }
+        }
+    }
+
+    fn select(&mut self, cx: &mut EventCx, data: &mut T, index: usize) {
+        if index == self.active {
+            return;
+        }
+
+        if let Some(ref mut on_select) = self.on_select {
+            on_select(cx, data, index);
+        }
+    }
+
+    fn close(&mut self, state: &mut TabsState<T, V>, cx: &mut EventCx, data: &mut T, index: usize) {
+        let Some(ref mut on_close) = self.on_close else {
+            return;
+        };
+
+        on_close(cx, data, index);
+
+        state.remove(index);
+        cx.layout();
+    }
+}
+
+impl<T, V: View<T>> View<T> for Tabs<T, V> {
+    type State = TabsState<T, V>;
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let mut state = TabsState {
+            tabs: Vec::new(),
+            titles: Vec::new(),
+            widths: Vec::new(),
+            pages: Vec::new(),
+            active: self.active(),
+            bar_height: 0.0,
+            scroll: 0.0,
+            reveal: true,
+            hovered: None,
+            drag: None,
+            from: None,
+            t: 1.0,
+        };
+
+        self.set_titles(cx.fonts(), &mut state);
+
+        state.pages.resize_with(self.tabs.len(), || None);
+
+        if let Some(active) = state.active {
+            let mut view = Pod::new((self.page)(data, active));
+            let page = view.build(cx, data);
+            state.pages[active] = Some((view, page));
+        }
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        if self.tabs != state.tabs
+            || self.font_size != old.font_size
+            || self.font_family != old.font_family
+        {
+            self.set_titles(cx.fonts(), state);
+            state.drag = None;
+            cx.layout();
+        }
+
+        let active = self.active();
+
+        if active != state.active {
+            // slide from wherever the indicator currently is
+            state.from = state.indicator(&self.transition);
+            state.active = active;
+            state.reveal = true;
+            state.t = 0.0;
+
+            cx.animate();
+            cx.layout();
+        }
+
+        self.update_pages(state, cx, data);
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        if let Some(Some((view, page))) = state.active.map(|i| &mut state.pages[i]) {
+            view.event(page, cx, data, event);
+        }
+
+        let rtl = cx.direction().is_rtl();
+        let width = cx.size().width;
+
+        match event {
+            Event::PointerMoved(e) => {
+                let local = cx.local(e.position);
+
+                if let Some(ref mut drag) = state.drag {
+                    let x = match rtl {
+                        true => width - local.x,
+                        false => local.x,
+                    } + state.scroll;
+
+                    drag.x = x;
+                    drag.moved |= (x - drag.start).abs() > 4.0;
+
+                    cx.draw();
+                    return;
+                }
+
+                let hovered = match cx.is_hot() {
+                    true => state.tab_at(self, local, width, rtl),
+                    false => None,
+                };
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    cx.draw();
+                }
+
+                cx.set_cursor(hovered.map(|_| Cursor::Pointer));
+            }
+            Event::PointerPressed(e) if cx.is_hot() => {
+                let local = cx.local(e.position);
+
+                let Some((index, close)) = state.tab_at(self, local, width, rtl) else {
+                    return;
+                };
+
+                match e.button {
+                    PointerButton::Primary if close => {
+                        self.close(state, cx, data, index);
+                    }
+                    PointerButton::Tertiary if state.tabs[index].closable => {
+                        self.close(state, cx, data, index);
+                    }
+                    PointerButton::Primary => {
+                        self.select(cx, data, index);
+
+                        if self.on_reorder.is_none() {
+                            return;
+                        }
+
+                        let x = match rtl {
+                            true => width - local.x,
+                            false => local.x,
+                        } + state.scroll;
+
+                        state.drag = Some(TabDrag {
+                            index,
+                            x,
+                            grab: x - state.offsets()[index],
+                            start: x,
+                            moved: false,
+                        });
+
+                        cx.set_active(true);
+                    }
+                    _ => {}
+                }
+            }
+            Event::PointerReleased(e) if e.button == PointerButton::Primary => {
+                let Some(drag) = state.drag.take() else {
+                    return;
+                };
+
+                cx.set_active(false);
+                cx.draw();
+
+                let to = state.drop_index(&drag);
+
+                if !drag.moved || to == drag.index {
+                    return;
+                }
+
+                if let Some(ref mut on_reorder) = self.on_reorder {
+                    on_reorder(cx, data, drag.index, to);
+                }
+
+                state.reorder(drag.index, to);
+                cx.layout();
+            }
+            Event::PointerScrolled(e) if cx.is_hot() => {
+                let overflow = (state.total_width() - width).max(0.0);
+                let delta = e.delta.y + e.delta.x;
+
+                state.scroll = (state.scroll - delta * 10.0).clamp(0.0, overflow);
+                cx.draw();
+            }
+            Event::KeyPressed(e) if e.modifiers.ctrl => {
+                let within = cx.is_hot() || cx.has_hot() || cx.is_focused() || cx.has_focused();
+
+                if !within || self.tabs.is_empty() {
+                    return;
+                }
+
+                let count = self.tabs.len();
+                let active = self.active().unwrap_or_default();
+
+                let next = (active + 1) % count;
+                let previous = (active + count - 1) % count;
+
+                let index = match e.key {
+                    Key::Tab if e.modifiers.shift => previous,
+                    Key::Tab | Key::PageDown => next,
+                    Key::PageUp => previous,
+                    _ => return,
+                };
+
+                self.select(cx, data, index);
+            }
+            Event::Animate(dt) => {
+                if self.transition.step(&mut state.t, true, *dt) {
+                    cx.animate();
+                }
+
+                cx.draw();
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        state.widths = (state.tabs.iter().zip(&state.titles))
+            .map(|(tab, title)| self.tab_width(tab, title))
+            .collect();
+
+        let content = f32::max(self.font_size * 1.2, self.icon_size.max(self.close_size));
+        state.bar_height = content + self.padding.size().height;
+
+        let page_space = space - Size::new(0.0, state.bar_height);
+        let mut page_size = Size::ZERO;
+
+        if let Some(Some((view, page))) = state.active.map(|i| &mut state.pages[i]) {
+            page_size = view.layout(page, cx, data, page_space);
+            page.translate(Vector::new(0.0, state.bar_height));
+        }
+
+        let total = state.total_width();
+        let bar_width = total.min(space.max.width);
+
+        let size = space.fit(Size::new(
+            f32::max(bar_width, page_size.width),
+            state.bar_height + page_size.height,
+        ));
+
+        // keep the active tab in view after it changes
+        if let (true, Some(active)) = (state.reveal, state.active) {
+            let offset = state.offsets()[active];
+            let width = state.widths[active];
+
+            state.scroll = state.scroll.max(offset + width - size.width).min(offset);
+            state.reveal = false;
+        }
+
+        state.scroll = state.scroll.clamp(0.0, (total - size.width).max(0.0));
+
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        if let Some(Some((view, page))) = state.active.map(|i| &mut state.pages[i]) {
+            view.draw(page, cx, data);
+        }
+
+        let rtl = cx.direction().is_rtl();
+        let width = cx.size().width;
+        let bar = Rect::min_size(Point::ZERO, Size::new(width, state.bar_height));
+
+        cx.hoverable(|cx| {
+            cx.fill_rect(bar, self.background);
+        });
+
+        let divider = Rect::new(
+            Point::new(0.0, state.bar_height - 1.0),
+            Point::new(width, state.bar_height),
+        );
+        cx.fill_rect(divider, self.divider);
+
+        let offsets = state.offsets();
+        let dragged = state.drag.as_ref().filter(|drag| drag.moved);
+
+        cx.mask(bar, |cx| {
+            for (index, &offset) in offsets.iter().enumerate() {
+                if dragged.is_some_and(|drag| drag.index == index) {
+                    continue;
+                }
+
+                self.draw_tab(state, cx, index, offset, false, rtl);
+            }
+
+            if let Some((x, w)) = state.indicator(&self.transition) {
+                let x = state.visual_x(x, w, width, rtl);
+                let rect = Rect::new(
+                    Point::new(x, state.bar_height - self.indicator_height),
+                    Point::new(x + w, state.bar_height),
+                );
+
+                cx.fill_rect(rect, self.indicator);
+            }
+
+            if let Some(drag) = dragged {
+                let x = state.drag_offset(drag);
+                self.draw_tab(state, cx, drag.index, x, true, rtl);
+            }
+        });
+    }
+}
+
+impl<T, V: View<T>> Tabs<T, V> {
+    fn draw_tab(
+        &self,
+        state: &TabsState<T, V>,
+        cx: &mut DrawCx,
+        index: usize,
+        x: f32,
+        dragged: bool,
+        rtl: bool,
+    ) {
+        let bar_width = cx.size().width;
+        let width = state.widths[index];
+        let height = state.bar_height;
+
+        let rect = |x: f32, w: f32, h: f32| {
+            let x = state.visual_x(x, w, bar_width, rtl);
+            Rect::min_size(Point::new(x, (height - h) / 2.0), Size::new(w, h))
+        };
+
+        let active = state.active == Some(index);
+        let hovered = state.hovered.map(|(i, _)| i) == Some(index);
+
+        let background = match active || dragged {
+            true => Some(self.active_background),
+            false => hovered.then_some(self.hover),
+        };
+
+        if let Some(background) = background {
+            cx.fill_rect(rect(x, width, height), background);
+        }
+
+        let tab = &state.tabs[index];
+        let mut x = x + self.padding.left;
+
+        if let Some(ref icon) = tab.icon {
+            let icon_rect = rect(x, self.icon_size, self.icon_size);
+
+            cx.fill_rect(
+                icon_rect,
+                Pattern {
+                    image: icon.clone(),
+                    transform: Affine::translate(icon_rect.top_left() - Point::ZERO)
+                        * Affine::scale(Vector::from(icon_rect.size() / icon.size())),
+                    opacity: 1.0,
+                },
+            );
+
+            x += self.icon_size + self.spacing;
+        }
+
+        let color = match active {
+            true => self.active_color,
+            false => self.color,
+        };
+
+        let title = &state.titles[index];
+        let title_rect = rect(x, title.size().width, height);
+        let offset = title_rect.center() - title.rect().center();
+        cx.text(
+            title,
+            color,
+            Vector::new(offset.x.round(), offset.y.round()),
+        );
+
+        if !tab.closable {
+            return;
+        }
+
+        let close_x = x + title.size().width + self.spacing;
+        let close_rect = rect(close_x, self.close_size, self.close_size);
+
+        if state.hovered == Some((index, true)) {
+            cx.quad(
+                close_rect,
+                self.close_hover,
+                BorderRadius::all(self.close_size / 2.0),
+                0.0,
+                Color::TRANSPARENT,
+            );
+        }
+
+        let cross = close_rect.shrink(self.close_size * 0.3);
+
+        let mut curve = Curve::new();
+        curve.move_to(cross.top_left());
+        curve.line_to(cross.bottom_right());
+        curve.move_to(cross.top_right());
+        curve.line_to(cross.bottom_left());
+
+        cx.stroke(curve, 1.5, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Key, KeyPressed, Modifiers},
+        layout::{Size, Space},
+        views::{testing::ViewTester, text_input, Tab, TextInput},
+    };
+
+    use super::{tabs, Tabs};
+
+    struct Data {
+        active: usize,
+        built: Vec<usize>,
+    }
+
+    fn view(data: &Data, keep_alive: bool) -> Tabs<Data, TextInput<Data>> {
+        let titles = [Tab::new("a"), Tab::new("b"), Tab::new("c").closable(true)];
+
+        tabs(titles, data.active, |data: &mut Data, index| {
+            data.built.push(index);
+            text_input()
+        })
+        .keep_alive(keep_alive)
+        .on_select(|_, data, index| data.active = index)
+    }
+
+    #[test]
+    fn build_active_page() {
+        let mut data = Data {
+            active: 1,
+            built: Vec::new(),
+        };
+
+        let mut tabs = view(&data, false);
+        let mut tester = ViewTester::new(&mut tabs, &mut data);
+        assert_eq!(data.built, vec![1]);
+
+        data.active = 2;
+        let old = tabs;
+        let mut tabs = view(&data, false);
+        tester.rebuild(&mut tabs, &mut data, &old);
+
+        let pages: Vec<_> = tester.state.pages.iter().map(Option::is_some).collect();
+        assert_eq!(pages, vec![false, false, true]);
+
+        data.active = 1;
+        let old = tabs;
+        let mut tabs = view(&data, true);
+        tester.rebuild(&mut tabs, &mut data, &old);
+
+        data.active = 0;
+        let old = tabs;
+        let mut tabs = view(&data, true);
+        tester.rebuild(&mut tabs, &mut data, &old);
+
+        let pages: Vec<_> = tester.state.pages.iter().map(Option::is_some).collect();
+        assert_eq!(pages, vec![true, true, true]);
+    }
+
+    #[test]
+    fn switch_with_keys() {
+        let mut data = Data {
+            active: 0,
+            built: Vec::new(),
+        };
+
+        let mut tabs = view(&data, false);
+        let mut tester = ViewTester::new(&mut tabs, &mut data);
+        tester.layout(
+            &mut tabs,
+            &mut data,
+            Space::new(Size::ZERO, Size::all(400.0)),
+        );
+        tester.view_state.set_focused(true);
+
+        let key = |key, shift| {
+            Event::KeyPressed(KeyPressed {
+                key,
+                code: None,
+                text: None,
+                modifiers: Modifiers {
+                    ctrl: true,
+                    shift,
+                    ..Default::default()
+                },
+            })
+        };
+
+        tester.event(&mut tabs, &mut data, &key(Key::Tab, true));
+        assert_eq!(data.active, 2);
+
+        tester.event(&mut tabs, &mut data, &key(Key::PageDown, false));
+        assert_eq!(data.active, 1);
+    }
+}
@@ -236,7 +236,13 @@ impl<T> View<T> for Text {
             state.buffer.set_bounds(cx.fonts(), space.max);
         }
 
-        space.fit(state.buffer.size())
+        let size = space.fit(state.buffer.size());
+
+        // the text is centered in the view, see `draw` below
+        let offset = size.height / 2.0 - state.buffer.size().height / 2.0;
+        cx.set_baseline(state.buffer.baseline().map(|b| b + offset));
+
+        size
     }
 
     fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
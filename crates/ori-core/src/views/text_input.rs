@@ -1,13 +1,13 @@
 use cosmic_text::{
-    Action, Attrs, AttrsList, Buffer, BufferLine, BufferRef, Edit, Editor, LineEnding, Metrics,
-    Motion, Shaping,
+    Action, Attrs, AttrsList, Buffer, BufferLine, BufferRef, Change, Edit, Editor, LayoutRun,
+    LineEnding, Metrics, Motion, Selection, Shaping,
 };
 use ori_macro::{example, Build};
 
 use crate::{
-    canvas::Color,
+    canvas::{BorderRadius, BorderWidth, Color, Curve, FillRule},
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
-    event::{Event, Key, KeyPressed},
+    event::{Event, ImePreedit, Key, KeyPressed},
     layout::{Point, Rect, Size, Space, Vector},
     style::{style, Palette, Style, Styles},
     text::{
@@ -20,6 +20,10 @@ use crate::{
 
 use super::TextStyle;
 
+pub use self::mask::*;
+
+mod mask;
+
 /// Create a new [`TextInput`].
 pub fn text_input<T>() -> TextInput<T> {
     TextInput::new()
@@ -57,6 +61,21 @@ pub struct TextInputStyle {
 
     /// The text wrap of the text.
     pub wrap: TextWrap,
+
+    /// The color of the border and message when the input is invalid.
+    pub invalid_color: Color,
+
+    /// The border radius when the input is invalid.
+    pub invalid_border_radius: BorderRadius,
+
+    /// The border width when the input is invalid.
+    pub invalid_border_width: BorderWidth,
+
+    /// The font size of the validation message.
+    pub message_font_size: f32,
+
+    /// The color of the password reveal toggle.
+    pub toggle_color: Color,
 }
 
 impl Style for TextInputStyle {
@@ -75,10 +94,21 @@ impl Style for TextInputStyle {
             align: text_style.align,
             line_height: text_style.line_height,
             wrap: text_style.wrap,
+            invalid_color: palette.danger,
+            invalid_border_radius: BorderRadius::all(4.0),
+            invalid_border_width: BorderWidth::all(1.0),
+            message_font_size: text_style.font_size * 0.85,
+            toggle_color: palette.contrast_low,
         }
     }
 }
 
+// the character shown in place of each character of a password
+const BULLET: char = '•';
+
+// the space between the input and the validation message
+const MESSAGE_GAP: f32 = 4.0;
+
 /// A text input.
 ///
 /// Can be styled using the [`TextInputStyle`].
@@ -99,6 +129,11 @@ pub struct TextInput<T> {
     #[allow(clippy::type_complexity)]
     pub on_submit: Option<Box<dyn FnMut(&mut EventCx, &mut T, String)>>,
 
+    /// A callback that validates the text, returning a message when it's invalid.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub validator: Option<Box<dyn FnMut(&T, &str) -> Result<(), String>>>,
+
     /// Placeholder text to display when the input is empty.
     pub placeholder: String,
 
@@ -107,6 +142,20 @@ pub struct TextInput<T> {
     /// When disabled (the default), the input will only accept a single line of text.
     pub multiline: bool,
 
+    /// Whether the input is a password.
+    ///
+    /// The text is shown as bullets, and can't be copied.
+    pub password: bool,
+
+    /// Whether to show a toggle that reveals the password.
+    pub reveal_toggle: bool,
+
+    /// A mask that constrains and formats the text as it's typed.
+    pub mask: Option<InputMask>,
+
+    /// The maximum number of characters in the text.
+    pub max_length: Option<usize>,
+
     /// The font size of the text.
     pub font_size: f32,
 
@@ -136,6 +185,21 @@ pub struct TextInput<T> {
 
     /// The text wrap of the text.
     pub wrap: TextWrap,
+
+    /// The color of the border and message when the input is invalid.
+    pub invalid_color: Color,
+
+    /// The border radius when the input is invalid.
+    pub invalid_border_radius: BorderRadius,
+
+    /// The border width when the input is invalid.
+    pub invalid_border_width: BorderWidth,
+
+    /// The font size of the validation message.
+    pub message_font_size: f32,
+
+    /// The color of the password reveal toggle.
+    pub toggle_color: Color,
 }
 
 impl<T> Default for TextInput<T> {
@@ -156,8 +220,13 @@ impl<T> TextInput<T> {
             text: None,
             on_input: None,
             on_submit: None,
+            validator: None,
             placeholder: String::from("..."),
             multiline: false,
+            password: false,
+            reveal_toggle: false,
+            mask: None,
+            max_length: None,
             font_size: style.font_size,
             font_family: style.font_family,
             font_weight: style.font_weight,
@@ -168,6 +237,11 @@ impl<T> TextInput<T> {
             align: style.align,
             line_height: style.line_height,
             wrap: style.wrap,
+            invalid_color: style.invalid_color,
+            invalid_border_radius: style.invalid_border_radius,
+            invalid_border_width: style.invalid_border_width,
+            message_font_size: style.message_font_size,
+            toggle_color: style.toggle_color,
         }
     }
 
@@ -197,6 +271,159 @@ impl<T> TextInput<T> {
         self
     }
 
+    /// Set the callback that validates the text.
+    ///
+    /// When it returns an error, the input is marked as invalid with the message, and can't be
+    /// submitted. The text is validated once it has been edited or submitted.
+    pub fn validator(
+        mut self,
+        validator: impl FnMut(&T, &str) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    fn attributes(&self) -> TextAttributes {
+        TextAttributes {
+            family: self.font_family.clone(),
+            stretch: self.font_stretch,
+            weight: self.font_weight,
+            style: self.font_style,
+        }
+    }
+
+    // whether the text is shown as bullets
+    fn is_masked(&self, state: &TextInputState) -> bool {
+        self.password && !state.revealed
+    }
+
+    // the size of the password reveal toggle, zero when there is none
+    fn toggle_size(&self) -> f32 {
+        match self.password && self.reveal_toggle {
+            true => self.font_size * self.line_height,
+            false => 0.0,
+        }
+    }
+
+    // edit the text, then apply the mask and the maximum length
+    fn constrain(&self, editor: &mut Editor<'static>, edit: impl FnOnce(&mut Editor<'static>)) {
+        let before = text_length(editor);
+
+        edit(editor);
+
+        if let Some(ref mask) = self.mask {
+            let cursor = editor.cursor();
+
+            let (text, index, end) = editor.with_buffer(|buffer| {
+                let mut text = String::new();
+                let mut index = 0;
+
+                for (i, line) in buffer.lines.iter().enumerate() {
+                    if i == cursor.line {
+                        let before = &line.text()[..cursor.index];
+                        index = text.chars().count() + before.chars().count();
+                    }
+
+                    text.push_str(line.text());
+                }
+
+                let last = buffer.lines.len() - 1;
+                let end = cosmic_text::Cursor::new(last, buffer.lines[last].text().len());
+
+                (text, index, end)
+            });
+
+            let (formatted, index) = mask.format_with_cursor(&text, index);
+
+            if formatted != text || end.line > 0 {
+                editor.delete_range(cosmic_text::Cursor::default(), end);
+                editor.insert_at(cosmic_text::Cursor::default(), &formatted, None);
+            }
+
+            let index = formatted.char_indices().nth(index);
+            let index = index.map_or(formatted.len(), |(i, _)| i);
+
+            editor.set_selection(Selection::None);
+            editor.set_cursor(cosmic_text::Cursor::new(0, index));
+        }
+
+        // only the characters that were just inserted are removed, so text that is already too
+        // long can be shortened, but not lengthened
+        if let Some(max_length) = self.max_length {
+            let excess = text_length(editor).saturating_sub(usize::max(max_length, before));
+
+            if excess > 0 {
+                let cursor = editor.cursor();
+                let start = editor.with_buffer(|buffer| {
+                    let mut excess = excess;
+                    let mut line = cursor.line;
+                    let mut index = cursor.index;
+
+                    // the inserted text ends at the cursor, but can span several lines
+                    loop {
+                        let before = &buffer.lines[line].text()[..index];
+                        let count = before.chars().count();
+
+                        if excess <= count || line == 0 {
+                            let start = before.char_indices().nth(count.saturating_sub(excess));
+                            let index = start.map_or(before.len(), |(i, _)| i);
+                            break cosmic_text::Cursor::new(line, index);
+                        }
+
+                        excess -= count + 1;
+                        line -= 1;
+                        index = buffer.lines[line].text().len();
+                    }
+                });
+
+                editor.delete_range(start, cursor);
+                editor.set_cursor(start);
+            }
+        }
+    }
+
+    // run the validator, returns whether the message changed
+    fn validate(&mut self, fonts: &mut Fonts, state: &mut TextInputState, data: &T) -> bool {
+        let error = match self.validator {
+            Some(ref mut validator) => validator(data, &state.text()).err(),
+            None => None,
+        };
+
+        if error == state.error {
+            return false;
+        }
+
+        if let Some(ref message) = error {
+            state.message.set_text(fonts, message, self.attributes());
+        }
+
+        state.error = error;
+        true
+    }
+
+    // validate the text and call the input callback, after the user edited the text
+    fn changed(&mut self, cx: &mut EventCx, state: &mut TextInputState, data: &mut T) {
+        state.validated = true;
+
+        if self.validate(cx.fonts(), state, data) {
+            cx.layout();
+        }
+
+        if let Some(ref mut on_input) = self.on_input {
+            on_input(cx, data, state.text());
+        }
+    }
+
+    // replace the bullets shown in place of the text
+    fn set_masked(&self, fonts: &mut Fonts, state: &mut TextInputState) {
+        let text = state.text();
+        let bullets: String = (text.chars())
+            .map(|c| if c == '\n' { c } else { BULLET })
+            .collect();
+
+        state.masked.set_text(fonts, &bullets, self.attributes());
+    }
+
     fn set_attributes(&self, fonts: &mut Fonts, state: &mut TextInputState) {
         let attrs = TextAttributes {
             family: self.font_family.clone(),
@@ -239,6 +466,27 @@ impl<T> TextInput<T> {
         (state.placeholder).set_text(fonts, &self.placeholder, placeholder_attrs);
     }
 
+    // replace the text of the input, moving the cursor to the end
+    pub(crate) fn set_text(&self, fonts: &mut Fonts, state: &mut TextInputState, text: &str) {
+        let attrs = TextAttributes {
+            family: self.font_family.clone(),
+            stretch: self.font_stretch,
+            weight: self.font_weight,
+            style: self.font_style,
+        };
+
+        state.preedit = None;
+        state.buffer_mut().set_text(
+            &mut fonts.font_system,
+            text,
+            attrs.to_cosmic_text(),
+            Shaping::Advanced,
+        );
+
+        (state.editor).action(&mut fonts.font_system, Action::Motion(Motion::BufferEnd));
+        state.history.clear();
+    }
+
     fn set_attrs_list(&self, buffer: &mut Buffer) {
         let attrs = TextAttributes {
             family: self.font_family.clone(),
@@ -255,10 +503,61 @@ impl<T> TextInput<T> {
     }
 }
 
+// the maximum number of edits that can be undone
+const HISTORY_LIMIT: usize = 100;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Snapshot {
+    cursor: cosmic_text::Cursor,
+    selection: Selection,
+}
+
+#[derive(Debug)]
+struct HistoryEntry {
+    change: Change,
+    before: Snapshot,
+    after: Snapshot,
+}
+
+#[derive(Debug, Default)]
+struct History {
+    undo: Vec<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+    // whether the last edit was typing, that the next typed text can be grouped with
+    typing: bool,
+}
+
+impl History {
+    fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.typing = false;
+    }
+}
+
+// text being composed by the input method, it's inserted into the buffer so the text around it
+// is laid out correctly, but it isn't part of the text until it's committed
+#[derive(Clone, Copy, Debug)]
+struct Preedit {
+    line: usize,
+    start: usize,
+    end: usize,
+    cursor: Option<(usize, usize)>,
+}
+
 #[doc(hidden)]
 pub struct TextInputState {
     editor: Editor<'static>,
     placeholder: TextBuffer,
+    masked: TextBuffer,
+    message: TextBuffer,
+    history: History,
+    preedit: Option<Preedit>,
+    error: Option<String>,
+    validated: bool,
+    revealed: bool,
+    text_height: f32,
+    ime: bool,
     dragging: bool,
     blink: f32,
 }
@@ -278,7 +577,7 @@ impl TextInputState {
         }
     }
 
-    fn text(&self) -> String {
+    pub(crate) fn text(&self) -> String {
         let mut text = String::new();
 
         for (i, line) in self.buffer().lines.iter().enumerate() {
@@ -286,13 +585,230 @@ impl TextInputState {
                 text.push('\n');
             }
 
-            text.push_str(line.text());
+            match self.preedit {
+                Some(preedit) if preedit.line == i => {
+                    text.push_str(&line.text()[..preedit.start]);
+                    text.push_str(&line.text()[preedit.end..]);
+                }
+                _ => text.push_str(line.text()),
+            }
         }
 
         text
     }
 
+    // whether there is nothing to show, the text being composed counts as content
+    fn is_empty(&self) -> bool {
+        self.preedit.is_none() && self.text().is_empty()
+    }
+
+    // replace the preedit text, the cursor is placed as requested by the input method
+    fn set_preedit(&mut self, preedit: &ImePreedit) {
+        let start = self.remove_preedit();
+
+        if preedit.is_empty() {
+            return;
+        }
+
+        // the preedit must stay on a single line
+        let text = preedit.text.replace(['\n', '\r'], " ");
+        let end = self.editor.insert_at(start, &text, None);
+
+        let cursor = preedit.cursor.filter(|(start, end)| {
+            start <= end && text.is_char_boundary(*start) && text.is_char_boundary(*end)
+        });
+
+        self.preedit = Some(Preedit {
+            line: start.line,
+            start: start.index,
+            end: end.index,
+            cursor,
+        });
+
+        let index = match cursor {
+            Some((_, end)) => start.index + end,
+            None => end.index,
+        };
+
+        self.editor.set_selection(Selection::None);
+        (self.editor).set_cursor(cosmic_text::Cursor::new(start.line, index));
+    }
+
+    // remove the preedit text, returning where it was
+    fn remove_preedit(&mut self) -> cosmic_text::Cursor {
+        let Some(preedit) = self.preedit.take() else {
+            return self.editor.cursor();
+        };
+
+        let start = cosmic_text::Cursor::new(preedit.line, preedit.start);
+        let end = cosmic_text::Cursor::new(preedit.line, preedit.end);
+
+        self.editor.delete_range(start, end);
+        self.editor.set_cursor(start);
+
+        start
+    }
+
+    // the cursor in the bullets shown in place of a password
+    fn mask_cursor(&self, cursor: cosmic_text::Cursor) -> cosmic_text::Cursor {
+        let text = self.buffer().lines[cursor.line].text();
+        let chars = text[..cursor.index].chars().count();
+
+        cosmic_text::Cursor::new(cursor.line, chars * BULLET.len_utf8())
+    }
+
+    // the cursor in the text, at the position of a cursor in the bullets
+    fn unmask_cursor(&self, cursor: cosmic_text::Cursor) -> cosmic_text::Cursor {
+        let lines = &self.buffer().lines;
+        let line = usize::min(cursor.line, lines.len() - 1);
+        let text = lines[line].text();
+
+        let index = text.char_indices().nth(cursor.index / BULLET.len_utf8());
+        cosmic_text::Cursor::new(line, index.map_or(text.len(), |(i, _)| i))
+    }
+
+    // find the cursor at a point in the bullets shown in place of a password
+    fn hit_masked(&self, point: Point) -> Option<cosmic_text::Cursor> {
+        let cursor = self.masked.raw().hit(point.x, point.y)?;
+        Some(self.unmask_cursor(cursor))
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            cursor: self.editor.cursor(),
+            selection: self.editor.selection(),
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.editor.set_cursor(snapshot.cursor);
+        self.editor.set_selection(snapshot.selection);
+    }
+
+    // perform an edit and record it in the history, returns whether the text changed
+    //
+    // consecutive typing is grouped into a single edit, until the cursor is moved or a word
+    // is finished with whitespace
+    fn edit(&mut self, typing: bool, edit: impl FnOnce(&mut Editor<'static>)) -> bool {
+        let before = self.snapshot();
+        let text = self.text();
+
+        self.editor.start_change();
+        edit(&mut self.editor);
+
+        let change = match self.editor.finish_change() {
+            Some(change) if !change.items.is_empty() => change,
+            _ => return false,
+        };
+
+        // the edit may have been undone by the mask or the maximum length
+        if self.text() == text {
+            return false;
+        }
+
+        let after = self.snapshot();
+        let history = &mut self.history;
+
+        history.redo.clear();
+
+        let groups = |last: &HistoryEntry| {
+            let word = last.change.items.last();
+            let word = word.is_some_and(|item| !item.text.ends_with(char::is_whitespace));
+
+            typing
+                && history.typing
+                && word
+                && before.selection == Selection::None
+                && last.after.cursor == before.cursor
+        };
+
+        match history.undo.last_mut() {
+            Some(last) if groups(last) => {
+                last.change.items.extend(change.items);
+                last.after = after;
+            }
+            _ => {
+                if history.undo.len() == HISTORY_LIMIT {
+                    history.undo.remove(0);
+                }
+
+                (history.undo).push(HistoryEntry {
+                    change,
+                    before,
+                    after,
+                });
+            }
+        }
+
+        history.typing = typing;
+        true
+    }
+
+    fn undo(&mut self) -> bool {
+        let entry = match self.history.undo.pop() {
+            Some(entry) => entry,
+            None => return false,
+        };
+
+        let mut change = entry.change.clone();
+        change.reverse();
+
+        self.editor.apply_change(&change);
+        self.restore(entry.before);
+
+        self.history.redo.push(entry);
+        self.history.typing = false;
+        true
+    }
+
+    fn redo(&mut self) -> bool {
+        let entry = match self.history.redo.pop() {
+            Some(entry) => entry,
+            None => return false,
+        };
+
+        self.editor.apply_change(&entry.change);
+        self.restore(entry.after);
+
+        self.history.undo.push(entry);
+        self.history.typing = false;
+        true
+    }
+
+    // keep the cursor in the text, after it was replaced
+    fn clamp_cursor(&mut self) {
+        let cursor = self.editor.cursor();
+
+        let lines = &self.buffer().lines;
+        let line = usize::min(cursor.line, lines.len() - 1);
+        let text = lines[line].text();
+
+        let mut index = usize::min(cursor.index, text.len());
+        while !text.is_char_boundary(index) {
+            index -= 1;
+        }
+
+        self.editor.set_selection(Selection::None);
+        (self.editor).set_cursor(cosmic_text::Cursor::new(line, index));
+    }
+
+    // stop composing and disable the input method, after the input lost focus
+    fn end_ime(&mut self, cx: &mut EventCx) {
+        if !self.ime {
+            return;
+        }
+
+        if self.preedit.is_some() {
+            self.remove_preedit();
+            cx.layout();
+        }
+
+        cx.window_mut().ime_area = None;
+        self.ime = false;
+    }
+
     fn clear_text(&mut self) {
+        self.preedit = None;
         self.buffer_mut().lines = vec![BufferLine::new(
             "",
             LineEnding::None,
@@ -335,6 +851,8 @@ impl<T> View<T> for TextInput<T> {
     type State = TextInputState;
 
     fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.set_focusable(true);
+
         let editor = Editor::new(Buffer::new(
             &mut cx.fonts().font_system,
             Metrics {
@@ -344,10 +862,24 @@ impl<T> View<T> for TextInput<T> {
         ));
 
         let placeholder = TextBuffer::new(cx.fonts(), self.font_size, self.line_height);
+        let mut masked = TextBuffer::new(cx.fonts(), self.font_size, self.line_height);
+        let message = TextBuffer::new(cx.fonts(), self.message_font_size, self.line_height);
+
+        masked.set_wrap(cx.fonts(), self.wrap);
+        masked.set_align(self.align);
 
         let mut state = TextInputState {
             editor,
             placeholder,
+            masked,
+            message,
+            history: History::default(),
+            preedit: None,
+            error: None,
+            validated: false,
+            revealed: false,
+            text_height: 0.0,
+            ime: false,
             dragging: false,
             blink: 0.0,
         };
@@ -357,7 +889,7 @@ impl<T> View<T> for TextInput<T> {
         state
     }
 
-    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
         if self.font_size != old.font_size || self.line_height != old.line_height {
             state.buffer_mut().set_metrics(
                 &mut cx.fonts().font_system,
@@ -368,6 +900,13 @@ impl<T> View<T> for TextInput<T> {
             );
 
             (state.placeholder).set_metrics(cx.fonts(), self.font_size, self.line_height);
+            (state.masked).set_metrics(cx.fonts(), self.font_size, self.line_height);
+
+            cx.layout();
+        }
+
+        if self.message_font_size != old.message_font_size || self.line_height != old.line_height {
+            (state.message).set_metrics(cx.fonts(), self.message_font_size, self.line_height);
 
             cx.layout();
         }
@@ -375,16 +914,18 @@ impl<T> View<T> for TextInput<T> {
         if self.wrap != old.wrap {
             (state.buffer_mut()).set_wrap(&mut cx.fonts().font_system, self.wrap.to_cosmic_text());
             state.placeholder.set_wrap(cx.fonts(), self.wrap);
+            state.masked.set_wrap(cx.fonts(), self.wrap);
 
             cx.layout();
         }
 
         if self.align != old.align {
             for line in state.buffer_mut().lines.iter_mut() {
-                line.set_align(Some(self.align.to_cosmic_text()));
+                line.set_align(self.align.to_cosmic_text_directional());
             }
 
             state.placeholder.set_align(self.align);
+            state.masked.set_align(self.align);
 
             cx.layout();
         }
@@ -407,6 +948,8 @@ impl<T> View<T> for TextInput<T> {
                     text.push('\n');
                 }
 
+                // the composition is lost with the text it was inserted into
+                state.preedit = None;
                 state.buffer_mut().set_text(
                     &mut cx.fonts().font_system,
                     &text,
@@ -414,6 +957,10 @@ impl<T> View<T> for TextInput<T> {
                     Shaping::Advanced,
                 );
 
+                // the history refers to the old text, and can't be applied to the new one
+                state.history.clear();
+                state.clamp_cursor();
+
                 cx.layout();
             }
         } else if attrs_changed {
@@ -436,6 +983,17 @@ impl<T> View<T> for TextInput<T> {
 
             cx.layout();
         }
+
+        if self.password != old.password || self.reveal_toggle != old.reveal_toggle {
+            state.revealed &= self.reveal_toggle;
+
+            cx.layout();
+        }
+
+        // the validator may depend on the data, so it's run again once the text was validated
+        if state.validated && self.validate(cx.fonts(), state, data) {
+            cx.layout();
+        }
     }
 
     fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
@@ -445,81 +1003,156 @@ impl<T> View<T> for TextInput<T> {
             cx.set_cursor(None);
         }
 
+        if !cx.is_focused() {
+            state.end_ime(cx);
+        }
+
         match event {
             Event::KeyPressed(e) => {
                 if !cx.is_focused() {
                     return;
                 }
 
+                // keys that reach us while composing, cancel the composition
+                if state.preedit.is_some() {
+                    state.remove_preedit();
+                    cx.layout();
+                }
+
                 let mut changed = false;
                 let mut submit = false;
 
                 if !e.modifiers.ctrl && !e.modifiers.alt && !e.modifiers.meta {
                     if let Some(ref text) = e.text {
-                        for c in text.chars() {
-                            (state.editor).action(&mut cx.fonts().font_system, Action::Insert(c));
-                        }
+                        let font_system = &mut cx.fonts().font_system;
+                        changed |= state.edit(true, |editor| {
+                            self.constrain(editor, |editor| {
+                                for c in text.chars() {
+                                    editor.action(font_system, Action::Insert(c));
+                                }
+                            });
+                        });
 
                         self.set_attrs_list(state.buffer_mut());
 
                         cx.layout();
                         state.blink = 0.0;
-                        changed = true;
                     }
                 }
 
                 if let Some(action) = delete_key(e) {
-                    state.editor.action(&mut cx.fonts().font_system, action);
+                    let font_system = &mut cx.fonts().font_system;
+                    changed |= state.edit(false, |editor| {
+                        self.constrain(editor, |editor| {
+                            // literals of the mask can't be deleted, so delete the character after
+                            if let (Action::Delete, Some(ref mask)) = (action, &self.mask) {
+                                while editor.selection_bounds().is_none() {
+                                    let cursor = editor.cursor();
+                                    let line = editor.with_buffer(|buffer| {
+                                        buffer.lines[cursor.line].text().to_string()
+                                    });
+
+                                    let index = line[..cursor.index].chars().count();
+                                    if cursor.index == line.len() || !mask.is_literal(index) {
+                                        break;
+                                    }
+
+                                    editor.action(font_system, Action::Motion(Motion::Right));
+                                }
+                            }
+
+                            editor.action(font_system, action);
+                        });
+                    });
+
                     cx.layout();
                     state.blink = 0.0;
-                    changed = true;
                 }
 
                 if e.is_key(Key::Escape) {
                     (state.editor).action(&mut cx.fonts().font_system, Action::Escape);
                     cx.set_focused(false);
+                    state.end_ime(cx);
                     cx.draw();
                 }
 
                 if e.is_key(Key::Enter) && self.multiline {
-                    (state.editor).action(&mut cx.fonts().font_system, Action::Enter);
+                    let font_system = &mut cx.fonts().font_system;
+                    changed |= state.edit(false, |editor| {
+                        self.constrain(editor, |editor| {
+                            editor.action(font_system, Action::Enter);
+                        });
+                    });
+
                     cx.layout();
                     state.blink = 0.0;
-                    changed = true;
                 }
 
                 if e.is_key(Key::Enter) && !self.multiline {
                     cx.set_focused(false);
+                    state.end_ime(cx);
                     submit = true;
                 }
 
                 if let Some(motion) = move_key(e) {
+                    // words would give away the password
+                    let motion = match motion {
+                        Motion::LeftWord if self.is_masked(state) => Motion::Home,
+                        Motion::RightWord if self.is_masked(state) => Motion::End,
+                        motion => motion,
+                    };
+
                     (state.editor).action(&mut cx.fonts().font_system, Action::Motion(motion));
                     cx.draw();
                     state.blink = 0.0;
+                    state.history.typing = false;
                 }
 
-                if e.is_key('c') && e.modifiers.ctrl {
+                if e.is_key('c') && e.modifiers.ctrl && !self.password {
                     if let Some(selection) = state.editor.copy_selection() {
                         cx.clipboard().set(selection);
                     }
                 }
 
-                if e.is_key('x') && e.modifiers.ctrl {
+                if e.is_key('x') && e.modifiers.ctrl && !self.password {
                     if let Some(selection) = state.editor.copy_selection() {
                         cx.clipboard().set(selection);
                         cx.layout();
                     }
 
-                    state.editor.delete_selection();
-                    changed = true;
+                    changed |= state.edit(false, |editor| {
+                        self.constrain(editor, |editor| {
+                            editor.delete_selection();
+                        });
+                    });
                 }
 
                 if e.is_key('v') && e.modifiers.ctrl {
                     let text = cx.clipboard().get();
-                    state.editor.insert_string(&text, None);
+                    changed |= state.edit(false, |editor| {
+                        self.constrain(editor, |editor| {
+                            editor.insert_string(&text, None);
+                        });
+                    });
 
                     cx.layout();
+                }
+
+                let redo = (e.is_key('z') && e.modifiers.shift) || e.is_key('y');
+
+                if e.is_key('z') && e.modifiers.ctrl && !e.modifiers.shift && state.undo() {
+                    self.set_attrs_list(state.buffer_mut());
+
+                    cx.layout();
+                    state.blink = 0.0;
+                    changed = true;
+                }
+
+                if redo && e.modifiers.ctrl && state.redo() {
+                    self.set_attrs_list(state.buffer_mut());
+
+                    cx.layout();
+                    state.blink = 0.0;
                     changed = true;
                 }
 
@@ -527,44 +1160,128 @@ impl<T> View<T> for TextInput<T> {
                     return;
                 }
 
-                let text = state.text();
-
                 if changed {
-                    if let Some(ref mut on_change) = self.on_input {
-                        on_change(cx, data, text.clone());
-                    }
+                    self.changed(cx, state, data);
                 }
 
                 if submit {
+                    state.validated = true;
+
+                    if self.validate(cx.fonts(), state, data) {
+                        cx.layout();
+                    }
+
+                    // invalid text can't be submitted
+                    if state.error.is_some() {
+                        return;
+                    }
+
                     if let Some(ref mut on_submit) = self.on_submit {
-                        on_submit(cx, data, text);
+                        on_submit(cx, data, state.text());
 
                         if self.text.is_none() {
                             state.clear_text();
+                            state.history.clear();
                         }
 
                         state.editor.set_cursor(cosmic_text::Cursor::default());
                     }
                 }
             }
+            Event::ImePreedit(e) => {
+                if !cx.is_focused() || self.password {
+                    return;
+                }
+
+                // the composition replaces the selection
+                let mut changed = false;
+                if state.preedit.is_none() && state.editor.selection_bounds().is_some() {
+                    changed = state.edit(false, |editor| {
+                        editor.delete_selection();
+                    });
+                }
+
+                state.set_preedit(e);
+                self.set_attrs_list(state.buffer_mut());
+
+                cx.layout();
+                state.blink = 0.0;
+
+                if changed {
+                    self.changed(cx, state, data);
+                }
+            }
+            Event::ImeCommit(e) => {
+                if !cx.is_focused() {
+                    return;
+                }
+
+                state.remove_preedit();
+
+                let text = match self.multiline {
+                    true => e.text.clone(),
+                    false => e.text.replace(['\n', '\r'], " "),
+                };
+
+                let changed = state.edit(true, |editor| {
+                    self.constrain(editor, |editor| {
+                        editor.insert_string(&text, None);
+                    });
+                });
+                self.set_attrs_list(state.buffer_mut());
+
+                cx.layout();
+                state.blink = 0.0;
+
+                if changed {
+                    self.changed(cx, state, data);
+                }
+            }
             Event::PointerPressed(e) => {
                 if !cx.is_hot() {
                     if cx.is_focused() {
                         (state.editor).action(&mut cx.fonts().font_system, Action::Escape);
                         cx.set_focused(false);
+                        state.end_ime(cx);
                         cx.draw();
                     }
 
                     return;
                 }
 
+                if state.preedit.is_some() {
+                    state.remove_preedit();
+                    cx.layout();
+                }
+
+                let local = cx.local(e.position);
+                let toggle = self.toggle_size();
+
+                if toggle > 0.0
+                    && local.x >= cx.size().width - toggle
+                    && local.y <= state.text_height
+                {
+                    state.revealed = !state.revealed;
+                    cx.layout();
+                    return;
+                }
+
                 cx.set_focused(true);
                 cx.animate();
 
                 state.blink = 0.0;
                 state.dragging = true;
+                state.history.typing = false;
+
+                if self.is_masked(state) {
+                    if let Some(cursor) = state.hit_masked(local) {
+                        state.editor.set_selection(Selection::None);
+                        state.editor.set_cursor(cursor);
+                    }
+
+                    return;
+                }
 
-                let local = cx.local(e.position);
                 state.editor.action(
                     &mut cx.fonts().font_system,
                     Action::Click {
@@ -579,7 +1296,17 @@ impl<T> View<T> for TextInput<T> {
             Event::PointerMoved(e) => {
                 let local = cx.local(e.position);
 
-                if state.dragging {
+                if state.dragging && self.is_masked(state) {
+                    if let Some(cursor) = state.hit_masked(local) {
+                        if state.editor.selection() == Selection::None {
+                            let anchor = state.editor.cursor();
+                            state.editor.set_selection(Selection::Normal(anchor));
+                        }
+
+                        state.editor.set_cursor(cursor);
+                        cx.draw();
+                    }
+                } else if state.dragging {
                     state.editor.action(
                         &mut cx.fonts().font_system,
                         Action::Drag {
@@ -610,24 +1337,60 @@ impl<T> View<T> for TextInput<T> {
         _data: &mut T,
         space: Space,
     ) -> Size {
+        // the reveal toggle takes space at the end of the text
+        let toggle = self.toggle_size();
+        let bounds = Size::new(space.max.width - toggle, space.max.height);
+
         state.buffer_mut().set_size(
             &mut cx.fonts().font_system,
-            Some(space.max.width),
-            Some(space.max.height),
+            Some(bounds.width),
+            Some(bounds.height),
         );
-        state.placeholder.set_bounds(cx.fonts(), space.max);
+        state.placeholder.set_bounds(cx.fonts(), bounds);
 
         // FIXME: this is bad
         (state.editor).shape_as_needed(&mut cx.fonts().font_system, true);
 
+        let masked = self.is_masked(state);
+
+        if masked {
+            self.set_masked(cx.fonts(), state);
+            state.masked.set_bounds(cx.fonts(), bounds);
+        }
+
         // if the text is empty, we need to layout the placeholder
-        let mut size = if !state.text().is_empty() {
+        let mut size = if state.is_empty() {
+            state.placeholder.size()
+        } else if masked {
+            state.masked.size()
+        } else {
             Fonts::buffer_size(state.buffer())
+        };
+
+        let baseline = if state.is_empty() {
+            state.placeholder.baseline()
+        } else if masked {
+            state.masked.baseline()
         } else {
-            state.placeholder.size()
+            state.buffer().layout_runs().next().map(|run| run.line_y)
         };
 
+        cx.set_baseline(baseline);
+
         size.height = f32::max(size.height, self.font_size);
+        size.width += toggle;
+        state.text_height = size.height;
+
+        // the validation message is shown below the text
+        if state.error.is_some() {
+            let bounds = Size::new(space.max.width, f32::INFINITY);
+            state.message.set_bounds(cx.fonts(), bounds);
+
+            let message = state.message.size();
+            size.width = f32::max(size.width, message.width);
+            size.height += MESSAGE_GAP + message.height;
+        }
+
         space.fit(size)
     }
 
@@ -638,16 +1401,33 @@ impl<T> View<T> for TextInput<T> {
             // FIXME: this is bad
             (state.editor).shape_as_needed(&mut cx.fonts().font_system, true);
 
-            let cursor = state.editor.cursor();
+            let masked = self.is_masked(state);
+
+            // a password is drawn as bullets, so the cursor is moved to match them
+            let (buffer, cursor, selection) = match masked {
+                true => (
+                    state.masked.raw(),
+                    state.mask_cursor(state.editor.cursor()),
+                    (state.editor.selection_bounds())
+                        .map(|(start, end)| (state.mask_cursor(start), state.mask_cursor(end))),
+                ),
+                false => (
+                    state.buffer(),
+                    state.editor.cursor(),
+                    state.editor.selection_bounds(),
+                ),
+            };
 
             /* draw the highlights and the cursor */
+            let mut caret = None;
+
             // FIXME: this is bad
-            for (i, run) in state.buffer().layout_runs().enumerate() {
+            for run in buffer.layout_runs() {
                 if !cx.is_focused() {
                     break;
                 }
 
-                if let Some((start, end)) = state.editor.selection_bounds() {
+                if let Some((start, end)) = selection {
                     if let Some((start, width)) = run.highlight(start, end) {
                         let min =
                             Point::new(cx.rect().min.x + start, cx.rect().min.y + run.line_top);
@@ -659,35 +1439,392 @@ impl<T> View<T> for TextInput<T> {
                     }
                 }
 
-                if i == cursor.line {
-                    let size = Size::new(1.0, self.font_size * self.line_height);
+                /* underline the text being composed */
+                if let Some(preedit) = state.preedit.filter(|p| p.line == run.line_i && !masked) {
+                    let start = cosmic_text::Cursor::new(preedit.line, preedit.start);
+                    let end = cosmic_text::Cursor::new(preedit.line, preedit.end);
 
-                    let min = match run.glyphs.get(cursor.index) {
-                        Some(glyph) => {
-                            let physical = glyph.physical((cx.rect().min.x, cx.rect().min.y), 1.0);
-                            Point::new(physical.x as f32, run.line_top + physical.y as f32)
-                        }
-                        None if cursor.index == 0 => {
-                            Point::new(cx.rect().min.x, cx.rect().min.y + run.line_top)
-                        }
-                        None => {
-                            Point::new(cx.rect().min.x + run.line_w, cx.rect().min.y + run.line_top)
+                    if let Some((x, width)) = run.highlight(start, end) {
+                        let min = cx.rect().min + Vector::new(x, run.line_y + 2.0);
+                        let underline = Rect::min_size(min, Size::new(width, 1.0));
+
+                        cx.fill_rect(underline.round(), self.color);
+                    }
+
+                    // the part that is being converted is underlined thicker
+                    if let Some((from, to)) = preedit.cursor.filter(|(from, to)| from < to) {
+                        let start = cosmic_text::Cursor::new(preedit.line, preedit.start + from);
+                        let end = cosmic_text::Cursor::new(preedit.line, preedit.start + to);
+
+                        if let Some((x, width)) = run.highlight(start, end) {
+                            let min = cx.rect().min + Vector::new(x, run.line_y + 2.0);
+                            let underline = Rect::min_size(min, Size::new(width, 2.0));
+
+                            cx.fill_rect(underline.round(), self.color);
                         }
-                    };
+                    }
+                }
 
-                    let cursor = Rect::min_size(min.round(), size);
+                // a wrapped line has several runs, the cursor is in the first one that has it
+                if run.line_i == cursor.line && !matches!(caret, Some((_, true))) {
+                    let (x, found) = caret_x(&run, cursor.index);
+                    let min = Point::new(cx.rect().min.x + x, cx.rect().min.y + run.line_top);
 
-                    let blink = state.blink.cos() * 0.5 + 0.5;
-                    cx.fill_rect(cursor, self.color.fade(blink));
+                    caret = Some((min, found));
                 }
             }
 
+            if let Some((min, _)) = caret {
+                let size = Size::new(1.0, self.font_size * self.line_height);
+                let cursor = Rect::min_size(min.round(), size);
+
+                let blink = state.blink.cos() * 0.5 + 0.5;
+                cx.fill_rect(cursor, self.color.fade(blink));
+            }
+
+            /* tell the input method where the cursor is, a password isn't composed */
+            if cx.is_focused() && !self.password {
+                let (x, y) = state.editor.cursor_position().unwrap_or_default();
+                let min = cx.rect().min + Vector::new(x as f32, y as f32);
+                let size = Size::new(1.0, self.font_size * self.line_height);
+
+                let area = Rect::min_size(min, size).transform(cx.transform());
+                cx.window_mut().ime_area = Some(area);
+                state.ime = true;
+            }
+
             /* draw the text */
-            if !state.text().is_empty() {
-                cx.text_raw(state.buffer(), self.color, Vector::ZERO)
+            if state.is_empty() {
+                cx.text(&state.placeholder, self.placeholder_color, Vector::ZERO);
+            } else if masked {
+                cx.text(&state.masked, self.color, Vector::ZERO);
             } else {
-                cx.text(&state.placeholder, self.placeholder_color, Vector::ZERO)
-            };
+                cx.text_raw(state.buffer(), self.color, Vector::ZERO);
+            }
+
+            /* draw the reveal toggle */
+            let toggle = self.toggle_size();
+
+            if toggle > 0.0 {
+                let min = Point::new(cx.rect().max.x - toggle, cx.rect().min.y);
+                let rect = Rect::min_size(min, Size::all(toggle));
+
+                draw_eye(cx, rect, state.revealed, self.toggle_color);
+            }
+
+            /* mark the input as invalid */
+            if state.error.is_some() {
+                let size = Size::new(cx.size().width, state.text_height);
+                let rect = Rect::min_size(cx.rect().min, size).inflate(MESSAGE_GAP);
+
+                cx.quad(
+                    rect,
+                    Color::TRANSPARENT,
+                    self.invalid_border_radius,
+                    self.invalid_border_width,
+                    self.invalid_color,
+                );
+
+                let offset = Vector::new(0.0, state.text_height + MESSAGE_GAP);
+                cx.text(&state.message, self.invalid_color, offset);
+            }
         });
     }
 }
+
+// the horizontal position of the cursor at `index` in a run, and whether the run contains it
+fn caret_x(run: &LayoutRun, index: usize) -> (f32, bool) {
+    match run.glyphs.iter().find(|glyph| glyph.end > index) {
+        Some(glyph) => (glyph.x, true),
+        None => {
+            let last = run.glyphs.last();
+            (last.map_or(0.0, |glyph| glyph.x + glyph.w), false)
+        }
+    }
+}
+
+// draw an eye, crossed out when the password is revealed
+fn draw_eye(cx: &mut DrawCx, rect: Rect, revealed: bool, color: Color) {
+    let center = rect.center();
+    let width = rect.width() / 3.0;
+    let height = width * 0.6;
+
+    let mut curve = Curve::new();
+    curve.move_to(center - Vector::new(width, 0.0));
+    curve.quad_to(
+        center - Vector::new(0.0, height * 2.0),
+        center + Vector::new(width, 0.0),
+    );
+    curve.quad_to(
+        center + Vector::new(0.0, height * 2.0),
+        center - Vector::new(width, 0.0),
+    );
+    curve.close();
+
+    cx.stroke(curve, 1.5, color);
+    cx.fill(
+        Curve::circle(center, height * 0.5),
+        FillRule::NonZero,
+        color,
+    );
+
+    if revealed {
+        let mut curve = Curve::new();
+        curve.move_to(center + Vector::new(-width, height * 1.5));
+        curve.line_to(center + Vector::new(width, -height * 1.5));
+
+        cx.stroke(curve, 1.5, color);
+    }
+}
+
+// the number of characters in the editor, counting line breaks
+fn text_length(editor: &Editor<'static>) -> usize {
+    editor.with_buffer(|buffer| {
+        let lines = buffer.lines.iter();
+        lines
+            .map(|line| line.text().chars().count() + 1)
+            .sum::<usize>()
+            - 1
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmic_text::{Cursor, Edit};
+
+    use crate::{
+        event::{Event, ImeCommit, ImePreedit, Key, KeyPressed, Modifiers},
+        layout::{Point, Space},
+        views::testing::ViewTester,
+        window::Window,
+    };
+
+    use super::{text_input, InputMask, TextInput};
+
+    fn key(key: Key, text: Option<&str>, modifiers: Modifiers) -> Event {
+        Event::KeyPressed(KeyPressed {
+            key,
+            code: None,
+            text: text.map(String::from),
+            modifiers,
+        })
+    }
+
+    fn ctrl(c: char, shift: bool) -> Event {
+        let modifiers = Modifiers {
+            ctrl: true,
+            shift,
+            ..Default::default()
+        };
+
+        key(Key::Character(c), None, modifiers)
+    }
+
+    fn input(text: &str) -> TextInput<String> {
+        text_input()
+            .text(text)
+            .on_input(|_, data: &mut String, text| *data = text)
+    }
+
+    #[test]
+    fn undo_and_redo() {
+        let mut data = String::new();
+        let mut view = input("");
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        tester.view_state.set_focused(true);
+
+        for c in "hello world".chars() {
+            let text = c.to_string();
+            let event = key(Key::Character(c), Some(&text), Modifiers::default());
+            tester.event(&mut view, &mut data, &event);
+        }
+
+        let backspace = key(Key::Backspace, None, Modifiers::default());
+        tester.event(&mut view, &mut data, &backspace);
+        assert_eq!(data, "hello worl");
+
+        // the deletion is undone on its own, and typing is undone a word at a time
+        tester.event(&mut view, &mut data, &ctrl('z', false));
+        assert_eq!(data, "hello world");
+        assert_eq!(tester.state.editor.cursor().index, 11);
+
+        tester.event(&mut view, &mut data, &ctrl('z', false));
+        assert_eq!(data, "hello ");
+
+        tester.event(&mut view, &mut data, &ctrl('z', false));
+        assert_eq!(data, "");
+
+        tester.event(&mut view, &mut data, &ctrl('z', true));
+        assert_eq!(data, "hello ");
+        assert_eq!(tester.state.editor.cursor().index, 6);
+    }
+
+    #[test]
+    fn replace_text_clears_history() {
+        let mut data = String::new();
+        let mut view = input("");
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        tester.view_state.set_focused(true);
+
+        let event = key(Key::Character('a'), Some("a"), Modifiers::default());
+        tester.event(&mut view, &mut data, &event);
+        assert_eq!(data, "a");
+
+        let old = view;
+        let mut view = input("replaced");
+        tester.rebuild(&mut view, &mut data, &old);
+
+        tester.event(&mut view, &mut data, &ctrl('z', false));
+        assert_eq!(data, "a");
+        assert_eq!(tester.state.text(), "replaced");
+    }
+
+    #[test]
+    fn compose_with_input_method() {
+        let mut data = String::new();
+        let mut view = input("");
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        tester.view_state.set_focused(true);
+
+        let event = key(Key::Character('a'), Some("a"), Modifiers::default());
+        tester.event(&mut view, &mut data, &event);
+
+        let preedit = Event::ImePreedit(ImePreedit {
+            text: String::from("かな"),
+            cursor: Some((3, 3)),
+        });
+        tester.event(&mut view, &mut data, &preedit);
+
+        // the preedit is shown, but isn't part of the text
+        assert_eq!(data, "a");
+        assert_eq!(tester.state.text(), "a");
+        assert_eq!(tester.state.buffer().lines[0].text(), "aかな");
+        assert_eq!(tester.state.editor.cursor().index, 4);
+
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        tester.draw(&mut view, &mut data);
+        assert!(tester.contexts.get::<Window>().unwrap().ime_area.is_some());
+
+        let commit = Event::ImeCommit(ImeCommit {
+            text: String::from("仮名"),
+        });
+        tester.event(&mut view, &mut data, &commit);
+
+        assert_eq!(data, "a仮名");
+        assert_eq!(tester.state.buffer().lines[0].text(), "a仮名");
+
+        tester.view_state.set_focused(false);
+        tester.event(&mut view, &mut data, &Event::Update);
+        assert!(tester.contexts.get::<Window>().unwrap().ime_area.is_none());
+    }
+
+    #[test]
+    fn password_and_max_length() {
+        let mut data = String::new();
+        let mut view = input("").password(true).max_length(4);
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        tester.view_state.set_focused(true);
+
+        for c in "secret".chars() {
+            let text = c.to_string();
+            let event = key(Key::Character(c), Some(&text), Modifiers::default());
+            tester.event(&mut view, &mut data, &event);
+        }
+
+        // the text is cut at the maximum length, and shown as bullets
+        assert_eq!(data, "secr");
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        assert_eq!(tester.state.masked.raw().lines[0].text(), "••••");
+
+        // clicking puts the cursor between the bullets
+        let index = tester.state.hit_masked(Point::new(1.0, 1.0)).unwrap().index;
+        assert_eq!(index, 0);
+    }
+
+    #[test]
+    fn mask_and_validator() {
+        let mut data = String::new();
+        let mut view = input("")
+            .mask(InputMask::PHONE)
+            .validator(|_, text| match InputMask::PHONE.is_complete(text) {
+                true => Ok(()),
+                false => Err(String::from("Incomplete phone number")),
+            });
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        tester.view_state.set_focused(true);
+
+        for c in "555x1234".chars() {
+            let text = c.to_string();
+            let event = key(Key::Character(c), Some(&text), Modifiers::default());
+            tester.event(&mut view, &mut data, &event);
+        }
+
+        assert_eq!(data, "(555) 123-4");
+        assert!(tester.state.error.is_some());
+
+        for c in "567".chars() {
+            let text = c.to_string();
+            let event = key(Key::Character(c), Some(&text), Modifiers::default());
+            tester.event(&mut view, &mut data, &event);
+        }
+
+        assert_eq!(data, "(555) 123-4567");
+        assert!(tester.state.error.is_none());
+    }
+
+    #[test]
+    fn max_length_of_long_text() {
+        let mut data = String::new();
+        let mut view = input("abcdef").max_length(4);
+
+        // the bound text is set when rebuilt
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.rebuild(&mut view, &mut data, &input(""));
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        tester.view_state.set_focused(true);
+        tester.state.editor.set_cursor(Cursor::new(0, 1));
+
+        // text that is already too long isn't cut, but nothing can be inserted
+        let event = key(Key::Character('x'), Some("x"), Modifiers::default());
+        tester.event(&mut view, &mut data, &event);
+        assert_eq!(tester.state.text(), "abcdef");
+        assert_eq!(tester.state.editor.cursor().index, 1);
+
+        // but it can be shortened
+        let backspace = key(Key::Backspace, None, Modifiers::default());
+        tester.event(&mut view, &mut data, &backspace);
+        assert_eq!(data, "bcdef");
+
+        tester.event(&mut view, &mut data, &event);
+        assert_eq!(tester.state.text(), "bcdef");
+    }
+
+    #[test]
+    fn max_length_of_pasted_lines() {
+        let mut data = String::new();
+        let mut view = input("ab").multiline(true).max_length(6);
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.rebuild(&mut view, &mut data, &input(""));
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        tester.view_state.set_focused(true);
+        tester.state.editor.set_cursor(Cursor::new(0, 2));
+
+        let commit = Event::ImeCommit(ImeCommit {
+            text: String::from("cd\nefgh\nijk"),
+        });
+        tester.event(&mut view, &mut data, &commit);
+
+        assert_eq!(data, "abcd\ne");
+    }
+}
@@ -0,0 +1,171 @@
+use std::borrow::Cow;
+
+/// A pattern that constrains and formats the text of a [`TextInput`](super::TextInput).
+///
+/// In the pattern `9` is a digit, `a` is a letter, `h` is a hexadecimal digit and `*` is any
+/// character. Everything else is a literal, which is inserted as the text is typed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InputMask {
+    pattern: Cow<'static, str>,
+}
+
+impl InputMask {
+    /// A phone number, `(555) 123-4567`.
+    pub const PHONE: Self = Self::from_static("(999) 999-9999");
+
+    /// A date, `2024-12-31`.
+    pub const DATE: Self = Self::from_static("9999-99-99");
+
+    /// A time, `23:59`.
+    pub const TIME: Self = Self::from_static("99:99");
+
+    /// A hex color, `#ff8800`.
+    pub const HEX_COLOR: Self = Self::from_static("#hhhhhh");
+
+    /// Create a new [`InputMask`] from a `pattern`.
+    pub fn new(pattern: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            pattern: pattern.into(),
+        }
+    }
+
+    const fn from_static(pattern: &'static str) -> Self {
+        Self {
+            pattern: Cow::Borrowed(pattern),
+        }
+    }
+
+    /// Get the pattern of the mask.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Get the length of a complete text in characters.
+    pub fn max_length(&self) -> usize {
+        self.pattern.chars().count()
+    }
+
+    /// Check if `text` fills the whole pattern.
+    pub fn is_complete(&self, text: &str) -> bool {
+        let formatted = self.format(text);
+        formatted == text && formatted.chars().count() == self.max_length()
+    }
+
+    /// Format `text`, dropping the characters that don't fit the pattern.
+    pub fn format(&self, text: &str) -> String {
+        self.format_with_cursor(text, 0).0
+    }
+
+    /// Check if the character at `index` is a literal.
+    pub(crate) fn is_literal(&self, index: usize) -> bool {
+        let slot = self.pattern.chars().nth(index);
+        slot.is_some_and(|slot| !is_placeholder(slot))
+    }
+
+    /// Format `text`, and move the `cursor`, counted in characters, along with it.
+    pub(crate) fn format_with_cursor(&self, text: &str, cursor: usize) -> (String, usize) {
+        let pattern: Vec<char> = self.pattern.chars().collect();
+
+        let placeholders: Vec<usize> = (pattern.iter().enumerate())
+            .filter(|(_, slot)| is_placeholder(**slot))
+            .map(|(i, _)| i)
+            .collect();
+
+        // collect the characters that fill the placeholders
+        let mut filled = Vec::new();
+        let mut cursor_filled = None;
+
+        for (i, c) in text.chars().enumerate() {
+            if i == cursor {
+                cursor_filled = Some(filled.len());
+            }
+
+            let Some(&index) = placeholders.get(filled.len()) else {
+                break;
+            };
+
+            // literals typed by the user are skipped, they're inserted anyway
+            let previous = filled
+                .len()
+                .checked_sub(1)
+                .map_or(0, |i| placeholders[i] + 1);
+            if pattern[previous..index].contains(&c) {
+                continue;
+            }
+
+            if accepts(pattern[index], c) {
+                filled.push(c);
+            }
+        }
+
+        // literals are only inserted when the placeholder after them is filled
+        let mut formatted = String::new();
+        let mut literals = String::new();
+        let mut next = 0;
+
+        for &slot in &pattern {
+            if !is_placeholder(slot) {
+                literals.push(slot);
+                continue;
+            }
+
+            let Some(&c) = filled.get(next) else {
+                break;
+            };
+
+            formatted.push_str(&literals);
+            formatted.push(c);
+            literals.clear();
+            next += 1;
+        }
+
+        let cursor = match cursor_filled.unwrap_or(filled.len()) {
+            0 => 0,
+            n => placeholders[n - 1] + 1,
+        };
+
+        (formatted, cursor)
+    }
+}
+
+fn is_placeholder(slot: char) -> bool {
+    matches!(slot, '9' | 'a' | 'h' | '*')
+}
+
+fn accepts(slot: char, c: char) -> bool {
+    match slot {
+        '9' => c.is_ascii_digit(),
+        'a' => c.is_alphabetic(),
+        'h' => c.is_ascii_hexdigit(),
+        '*' => !c.is_control(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputMask;
+
+    #[test]
+    fn format_phone_number() {
+        let mask = InputMask::PHONE;
+
+        assert_eq!(mask.format("555"), "(555");
+        assert_eq!(mask.format("5551234567"), "(555) 123-4567");
+        assert_eq!(mask.format("(555) 12a3"), "(555) 123");
+        assert_eq!(mask.format("555123456789"), "(555) 123-4567");
+
+        // the cursor follows the digit it was after
+        assert_eq!(
+            mask.format_with_cursor("(555) 123", 3),
+            (String::from("(555) 123"), 3)
+        );
+        assert_eq!(
+            mask.format_with_cursor("5551", 4),
+            (String::from("(555) 1"), 7)
+        );
+
+        assert!(mask.is_complete("(555) 123-4567"));
+        assert!(!mask.is_complete("(555) 123"));
+    }
+}
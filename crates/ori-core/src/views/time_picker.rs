@@ -0,0 +1,817 @@
+use ori_macro::Build;
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, KeyPressed, PointerButton},
+    layout::{Padding, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    text::{FontFamily, Fonts, TextAttributes, TextBuffer, TextWrap},
+    view::View,
+    window::Cursor,
+};
+
+use super::{dropdown::draw_arrow, DateLocale, DropdownStyle, Time};
+
+/// Create a new [`TimePicker`].
+pub fn time_picker<T>() -> TimePicker<T> {
+    TimePicker::new()
+}
+
+/// The style of a time picker.
+#[derive(Clone, Debug)]
+pub struct TimePickerStyle {
+    /// The locale of the times.
+    pub locale: DateLocale,
+
+    /// The font size of the text.
+    pub font_size: f32,
+
+    /// The font family of the text.
+    pub font_family: FontFamily,
+
+    /// The color of the text.
+    pub color: Color,
+
+    /// The color of the placeholder text, and of times that can't be chosen.
+    pub placeholder_color: Color,
+
+    /// The color of the arrow.
+    pub icon_color: Color,
+
+    /// The padding of the picker and of each item in the lists.
+    pub padding: Padding,
+
+    /// The background color.
+    pub background: Color,
+
+    /// The background color when hovered.
+    pub hover: Color,
+
+    /// The border radius.
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    pub border_color: Color,
+
+    /// The border color when focused.
+    pub focus_color: Color,
+
+    /// The background color of the lists.
+    pub list_background: Color,
+
+    /// The background color of the highlighted items in the lists.
+    pub highlight: Color,
+
+    /// The maximum height of the lists.
+    pub max_height: f32,
+}
+
+impl Style for TimePickerStyle {
+    fn styled(style: &Styles) -> Self {
+        let dropdown = style.get::<DropdownStyle>();
+
+        Self {
+            locale: DateLocale::system(),
+            font_size: dropdown.font_size,
+            font_family: dropdown.font_family,
+            color: dropdown.color,
+            placeholder_color: dropdown.placeholder_color,
+            icon_color: dropdown.icon_color,
+            padding: dropdown.padding,
+            background: dropdown.background,
+            hover: dropdown.hover,
+            border_radius: dropdown.border_radius,
+            border_width: dropdown.border_width,
+            border_color: dropdown.border_color,
+            focus_color: dropdown.focus_color,
+            list_background: dropdown.list_background,
+            highlight: dropdown.highlight,
+            max_height: dropdown.max_height,
+        }
+    }
+}
+
+/// A view that shows a time, and lets the user choose another from lists of hours and minutes
+/// in a popover.
+///
+/// When focused, the arrow keys step the time by [`TimePicker::minute_step`] minutes, and
+/// `Enter` or `Space` open the lists. While open, the up and down arrow keys move through a
+/// list, the left and right arrow keys switch between them, `Enter` chooses the highlighted
+/// item and `Escape` closes the lists.
+///
+/// Times before [`TimePicker::min`] or after [`TimePicker::max`] can't be chosen.
+///
+/// Can be styled using the [`TimePickerStyle`].
+#[derive(Build, Rebuild)]
+pub struct TimePicker<T> {
+    /// The selected time.
+    #[rebuild(layout)]
+    pub time: Option<Time>,
+
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_change: Option<Box<dyn FnMut(&mut EventCx, &mut T, Time)>>,
+
+    /// The earliest time that can be chosen.
+    #[rebuild(draw)]
+    pub min: Option<Time>,
+
+    /// The latest time that can be chosen.
+    #[rebuild(draw)]
+    pub max: Option<Time>,
+
+    /// The number of minutes between the items in the list of minutes.
+    #[rebuild(layout)]
+    pub minute_step: u8,
+
+    /// The text displayed when no time is selected.
+    #[rebuild(layout)]
+    pub placeholder: String,
+
+    /// The locale of the times.
+    #[rebuild(layout)]
+    pub locale: DateLocale,
+
+    /// The font size of the text.
+    #[rebuild(layout)]
+    pub font_size: f32,
+
+    /// The font family of the text.
+    #[rebuild(layout)]
+    pub font_family: FontFamily,
+
+    /// The color of the text.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The color of the placeholder text, and of times that can't be chosen.
+    #[rebuild(draw)]
+    pub placeholder_color: Color,
+
+    /// The color of the arrow.
+    #[rebuild(draw)]
+    pub icon_color: Color,
+
+    /// The padding of the picker and of each item in the lists.
+    #[rebuild(layout)]
+    pub padding: Padding,
+
+    /// The background color.
+    #[rebuild(draw)]
+    pub background: Color,
+
+    /// The background color when hovered.
+    #[rebuild(draw)]
+    pub hover: Color,
+
+    /// The border radius.
+    #[rebuild(draw)]
+    pub border_radius: BorderRadius,
+
+    /// The border width.
+    #[rebuild(draw)]
+    pub border_width: BorderWidth,
+
+    /// The border color.
+    #[rebuild(draw)]
+    pub border_color: Color,
+
+    /// The border color when focused.
+    #[rebuild(draw)]
+    pub focus_color: Color,
+
+    /// The background color of the lists.
+    #[rebuild(draw)]
+    pub list_background: Color,
+
+    /// The background color of the highlighted items in the lists.
+    #[rebuild(draw)]
+    pub highlight: Color,
+
+    /// The maximum height of the lists.
+    #[rebuild(draw)]
+    pub max_height: f32,
+}
+
+impl<T> Default for TimePicker<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TimePicker<T> {
+    /// Create a new [`TimePicker`].
+    pub fn new() -> Self {
+        Self::styled(style())
+    }
+
+    /// Create a new [`TimePicker`] with a style.
+    pub fn styled(style: TimePickerStyle) -> Self {
+        Self {
+            time: None,
+            on_change: None,
+            min: None,
+            max: None,
+            minute_step: 5,
+            placeholder: String::from("Select time..."),
+            locale: style.locale,
+            font_size: style.font_size,
+            font_family: style.font_family,
+            color: style.color,
+            placeholder_color: style.placeholder_color,
+            icon_color: style.icon_color,
+            padding: style.padding,
+            background: style.background,
+            hover: style.hover,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+            focus_color: style.focus_color,
+            list_background: style.list_background,
+            highlight: style.highlight,
+            max_height: style.max_height,
+        }
+    }
+
+    /// Set the callback that is called when the time changes.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_change(
+        mut self,
+        on_change: impl FnMut(&mut EventCx, &mut T, Time) + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    fn step(&self) -> u8 {
+        self.minute_step.clamp(1, 60)
+    }
+
+    fn minute_count(&self) -> usize {
+        60usize.div_ceil(self.step() as usize)
+    }
+
+    fn attributes(&self) -> TextAttributes {
+        TextAttributes {
+            family: self.font_family.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn buffer(&self, fonts: &mut Fonts, text: &str) -> TextBuffer {
+        let mut buffer = TextBuffer::new(fonts, self.font_size, LINE_HEIGHT);
+        buffer.set_wrap(fonts, TextWrap::None);
+        buffer.set_text(fonts, text, self.attributes());
+        buffer
+    }
+
+    fn set_label(&self, fonts: &mut Fonts, state: &mut TimePickerState) {
+        let label = match self.time {
+            Some(time) => self.locale.format_time(time),
+            None => self.placeholder.clone(),
+        };
+
+        state.label.set_metrics(fonts, self.font_size, LINE_HEIGHT);
+        state.label.set_text(fonts, &label, self.attributes());
+    }
+
+    fn set_items(&self, fonts: &mut Fonts, state: &mut TimePickerState) {
+        state.columns[0] = (0..24)
+            .map(|hour| Time::new(hour, 0).unwrap())
+            .map(|time| self.buffer(fonts, &self.locale.format_hour(time)))
+            .collect();
+
+        state.columns[1] = (0..self.minute_count())
+            .map(|i| self.buffer(fonts, &format!("{:02}", i * self.step() as usize)))
+            .collect();
+    }
+
+    fn is_enabled(&self, time: Time) -> bool {
+        time.clamp_to(self.min, self.max) == time
+    }
+
+    // whether the item at `index` in `column` can be chosen
+    fn item_enabled(&self, state: &TimePickerState, column: usize, index: usize) -> bool {
+        match column {
+            0 => {
+                let first = Time::new(index as u8, 0).unwrap();
+                let last = Time::new(index as u8, 59).unwrap();
+
+                self.max.is_none_or(|max| first <= max) && self.min.is_none_or(|min| last >= min)
+            }
+            _ => self.is_enabled(state.time_at(self.step(), index)),
+        }
+    }
+
+    fn row_height(&self) -> f32 {
+        self.font_size * LINE_HEIGHT + self.padding.size().height
+    }
+
+    fn list_height(&self) -> f32 {
+        f32::min(24.0 * self.row_height(), self.max_height)
+    }
+
+    fn column_width(&self, state: &TimePickerState, column: usize) -> f32 {
+        let width = (state.columns[column].iter()).fold(0.0, |w: f32, b| w.max(b.size().width));
+        width + self.padding.size().width
+    }
+
+    // the rect of a column, local to the picker of size `size`
+    fn column_rect(&self, state: &TimePickerState, size: Size, column: usize, rtl: bool) -> Rect {
+        let hours = self.column_width(state, 0);
+        let minutes = self.column_width(state, 1);
+        let height = self.list_height();
+
+        let y = match state.above {
+            true => -height - GAP,
+            false => size.height + GAP,
+        };
+
+        // the hours are on the left, or the right when right to left
+        let x = match (column == 0, rtl) {
+            (true, false) => 0.0,
+            (false, false) => hours,
+            (true, true) => size.width - hours,
+            (false, true) => size.width - hours - minutes,
+        };
+
+        let width = if column == 0 { hours } else { minutes };
+        Rect::min_size(Point::new(x, y), Size::new(width, height))
+    }
+
+    fn item_at(
+        &self,
+        state: &TimePickerState,
+        size: Size,
+        local: Point,
+        rtl: bool,
+    ) -> Option<(usize, usize)> {
+        for column in 0..2 {
+            let rect = self.column_rect(state, size, column, rtl);
+
+            if rect.contains(local) {
+                let y = local.y - rect.min.y + state.scroll[column];
+                let index = (y / self.row_height()) as usize;
+
+                return (index < state.columns[column].len()).then_some((column, index));
+            }
+        }
+
+        None
+    }
+
+    fn scroll_to(&self, state: &mut TimePickerState, column: usize) {
+        let top = state.highlighted[column] as f32 * self.row_height();
+        let bottom = top + self.row_height();
+        let height = self.list_height();
+
+        if top < state.scroll[column] {
+            state.scroll[column] = top;
+        } else if bottom > state.scroll[column] + height {
+            state.scroll[column] = bottom - height;
+        }
+    }
+
+    fn scroll_by(&self, state: &mut TimePickerState, column: usize, delta: f32) {
+        let len = state.columns[column].len() as f32;
+        let overflow = len * self.row_height() - self.list_height();
+        state.scroll[column] = (state.scroll[column] + delta).clamp(0.0, overflow.max(0.0));
+    }
+
+    fn open(&self, state: &mut TimePickerState) {
+        let time = self.time.or(self.min).unwrap_or(Time::MIDNIGHT);
+
+        state.open = true;
+        state.column = 0;
+        state.hovered = None;
+        state.highlighted = [time.hour() as usize, (time.minute() / self.step()) as usize];
+        state.scroll = [0.0; 2];
+
+        self.scroll_to(state, 0);
+        self.scroll_to(state, 1);
+    }
+
+    fn change(&mut self, state: &mut TimePickerState, cx: &mut EventCx, data: &mut T, time: Time) {
+        self.time = Some(time);
+        self.set_label(cx.fonts(), state);
+        cx.layout();
+
+        if let Some(ref mut on_change) = self.on_change {
+            on_change(cx, data, time);
+        }
+    }
+
+    // choose the item at `index` in `column`, choosing a minute closes the lists
+    fn choose(
+        &mut self,
+        state: &mut TimePickerState,
+        cx: &mut EventCx,
+        data: &mut T,
+        column: usize,
+        index: usize,
+    ) {
+        if !self.item_enabled(state, column, index) {
+            return;
+        }
+
+        state.highlighted[column] = index;
+
+        if column == 1 {
+            let time = state.time_at(self.step(), index);
+            state.open = false;
+            self.change(state, cx, data, time);
+            return;
+        }
+
+        let minute = self.time.map_or(0, Time::minute);
+        let time = Time::new(index as u8, minute).unwrap();
+        let time = time.clamp_to(self.min, self.max);
+
+        state.column = 1;
+        state.highlighted[1] = (time.minute() / self.step()) as usize;
+        self.scroll_to(state, 1);
+        self.change(state, cx, data, time);
+    }
+
+    fn key_pressed(
+        &mut self,
+        state: &mut TimePickerState,
+        cx: &mut EventCx,
+        data: &mut T,
+        e: &KeyPressed,
+    ) {
+        if !state.open {
+            let step = match e.key {
+                Key::Up => self.step() as i64,
+                Key::Down => -(self.step() as i64),
+                Key::Enter => {
+                    self.open(state);
+                    cx.draw();
+                    return;
+                }
+                _ if e.is_key(' ') => {
+                    self.open(state);
+                    cx.draw();
+                    return;
+                }
+                _ => return,
+            };
+
+            let time = match self.time {
+                Some(time) => time.add_minutes(step),
+                None => self.min.unwrap_or(Time::MIDNIGHT),
+            };
+
+            let time = time.clamp_to(self.min, self.max);
+            self.change(state, cx, data, time);
+            return;
+        }
+
+        let column = state.column;
+        let len = state.columns[column].len();
+        let (back, forward) = match cx.direction().is_rtl() {
+            true => (Key::Right, Key::Left),
+            false => (Key::Left, Key::Right),
+        };
+
+        match e.key {
+            Key::Up => state.highlighted[column] = state.highlighted[column].saturating_sub(1),
+            Key::Down => {
+                state.highlighted[column] = usize::min(state.highlighted[column] + 1, len - 1)
+            }
+            Key::Home => state.highlighted[column] = 0,
+            Key::End => state.highlighted[column] = len - 1,
+            key if key == back => state.column = 0,
+            key if key == forward => state.column = 1,
+            Key::Enter => {
+                let index = state.highlighted[column];
+                self.choose(state, cx, data, column, index);
+            }
+            Key::Escape => {
+                state.open = false;
+                cx.set_handled();
+            }
+            _ => return,
+        }
+
+        self.scroll_to(state, state.column);
+        cx.draw();
+    }
+}
+
+#[doc(hidden)]
+pub struct TimePickerState {
+    label: TextBuffer,
+    // the hours and the minutes
+    columns: [Vec<TextBuffer>; 2],
+    open: bool,
+    above: bool,
+    // the column used by the keyboard
+    column: usize,
+    highlighted: [usize; 2],
+    scroll: [f32; 2],
+    hovered: Option<(usize, usize)>,
+}
+
+impl TimePickerState {
+    // the time at the minute `index`, in the highlighted hour
+    fn time_at(&self, step: u8, index: usize) -> Time {
+        let minute = (index * step as usize).min(59) as u8;
+        Time::new(self.highlighted[0] as u8, minute).unwrap()
+    }
+}
+
+impl<T> View<T> for TimePicker<T> {
+    type State = TimePickerState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.set_focusable(true);
+
+        let mut state = TimePickerState {
+            label: self.buffer(cx.fonts(), ""),
+            columns: [Vec::new(), Vec::new()],
+            open: false,
+            above: false,
+            column: 0,
+            highlighted: [0; 2],
+            scroll: [0.0; 2],
+            hovered: None,
+        };
+
+        self.set_label(cx.fonts(), &mut state);
+        self.set_items(cx.fonts(), &mut state);
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        if self.locale != old.locale
+            || self.minute_step != old.minute_step
+            || self.font_size != old.font_size
+            || self.font_family != old.font_family
+        {
+            self.set_items(cx.fonts(), state);
+            state.highlighted[1] = state.highlighted[1].min(self.minute_count() - 1);
+        }
+
+        self.set_label(cx.fonts(), state);
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        let rtl = cx.direction().is_rtl();
+
+        match event {
+            Event::PointerPressed(e) if e.button == PointerButton::Primary => {
+                let local = cx.local(e.position);
+
+                if state.open {
+                    match self.item_at(state, cx.size(), local, rtl) {
+                        Some((column, index)) => self.choose(state, cx, data, column, index),
+                        None => {
+                            state.open = false;
+                            cx.set_focused(cx.rect().contains(local));
+                        }
+                    }
+
+                    cx.draw();
+                } else if cx.is_hot() {
+                    self.open(state);
+                    cx.set_focused(true);
+                    cx.draw();
+                } else if cx.is_focused() {
+                    cx.set_focused(false);
+                    cx.draw();
+                }
+            }
+            Event::PointerMoved(e) => {
+                let local = cx.local(e.position);
+
+                let hovered = match state.open {
+                    true => self.item_at(state, cx.size(), local, rtl),
+                    false => None,
+                };
+
+                if state.hovered != hovered || cx.hot_changed() {
+                    state.hovered = hovered;
+                    cx.draw();
+                }
+
+                cx.set_cursor(cx.is_hot().then_some(Cursor::Pointer));
+            }
+            Event::PointerScrolled(e) if state.open => {
+                let local = cx.local(e.position);
+
+                if let Some((column, _)) = self.item_at(state, cx.size(), local, rtl) {
+                    self.scroll_by(state, column, -e.delta.y * self.row_height());
+                    cx.draw();
+                }
+            }
+            Event::KeyPressed(e) if cx.is_focused() => {
+                self.key_pressed(state, cx, data, e);
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        let height = self.font_size * LINE_HEIGHT;
+        let size = Size::new(state.label.size().width + height, height) + self.padding.size();
+        let size = space.fit(size);
+
+        let baseline = state.label.baseline().map(|b| b + self.padding.top);
+        cx.set_baseline(baseline);
+
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        let background = match cx.is_hot() && !state.open {
+            true => self.hover,
+            false => self.background,
+        };
+
+        let border_color = match cx.is_focused() {
+            true => self.focus_color,
+            false => self.border_color,
+        };
+
+        cx.hoverable(|cx| {
+            cx.quad(
+                cx.rect(),
+                background,
+                self.border_radius,
+                self.border_width,
+                border_color,
+            );
+        });
+
+        let color = match self.time {
+            Some(_) => self.color,
+            None => self.placeholder_color,
+        };
+
+        let rtl = cx.direction().is_rtl();
+        let arrow = self.font_size * LINE_HEIGHT;
+        let (label_x, arrow_x) = match rtl {
+            true => (
+                cx.size().width - self.padding.right - state.label.size().width,
+                self.padding.left,
+            ),
+            false => (
+                self.padding.left,
+                cx.size().width - self.padding.right - arrow,
+            ),
+        };
+
+        cx.text(&state.label, color, Vector::new(label_x, self.padding.top));
+
+        let arrow = Rect::min_size(Point::new(arrow_x, self.padding.top), Size::all(arrow));
+        draw_arrow(cx, arrow, state.open, self.icon_color);
+
+        if !state.open {
+            return;
+        }
+
+        // open above the picker when the lists don't fit below it, but do above
+        let transform = cx.transform();
+        let window = cx.window().size;
+        let height = self.list_height() + GAP;
+        let top = (transform * Point::ZERO).y;
+        let bottom = (transform * Point::new(0.0, cx.size().height)).y;
+        state.above = bottom + height > window.height && top - height >= 0.0;
+
+        let size = cx.size();
+        let row_height = self.row_height();
+
+        cx.overlay(1, |cx| {
+            cx.layer(transform, |cx| {
+                let hours = self.column_rect(state, size, 0, rtl);
+                let minutes = self.column_rect(state, size, 1, rtl);
+
+                cx.hoverable(|cx| {
+                    cx.quad(
+                        hours.union(minutes),
+                        self.list_background,
+                        self.border_radius,
+                        1.0,
+                        self.border_color,
+                    );
+                });
+
+                for (column, rect) in [(0, hours), (1, minutes)] {
+                    cx.mask(rect, |cx| {
+                        let first = (state.scroll[column] / row_height) as usize;
+                        let count = (rect.height() / row_height).ceil() as usize + 1;
+                        let len = state.columns[column].len();
+
+                        for index in first..usize::min(first + count, len) {
+                            let y = rect.min.y + index as f32 * row_height - state.scroll[column];
+                            let row = Rect::min_size(
+                                Point::new(rect.min.x, y),
+                                Size::new(rect.width(), row_height),
+                            );
+
+                            let enabled = self.item_enabled(state, column, index);
+
+                            if state.highlighted[column] == index {
+                                let highlight = match state.column == column {
+                                    true => self.highlight,
+                                    false => self.highlight.fade(0.5),
+                                };
+
+                                cx.fill_rect(row, highlight);
+                            } else if enabled && state.hovered == Some((column, index)) {
+                                cx.fill_rect(row, self.hover);
+                            }
+
+                            let color = match enabled {
+                                true => self.color,
+                                false => self.placeholder_color,
+                            };
+
+                            let buffer = &state.columns[column][index];
+                            let x = rect.min.x + (rect.width() - buffer.size().width) / 2.0;
+                            cx.text(buffer, color, Vector::new(x, y + self.padding.top));
+                        }
+                    });
+                }
+            });
+        });
+    }
+}
+
+const LINE_HEIGHT: f32 = 1.2;
+
+// the gap between the picker and the lists
+const GAP: f32 = 4.0;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Key, KeyPressed, Modifiers},
+        layout::Space,
+        views::{testing::ViewTester, Time},
+    };
+
+    use super::time_picker;
+
+    fn key(key: Key) -> Event {
+        Event::KeyPressed(KeyPressed {
+            key,
+            code: None,
+            text: None,
+            modifiers: Modifiers::default(),
+        })
+    }
+
+    #[test]
+    fn choose_hour_and_minute() {
+        let mut time = None;
+
+        let mut view = time_picker()
+            .time(Time::new(9, 30).unwrap())
+            .minute_step(15)
+            .max(Time::new(17, 0).unwrap())
+            .on_change(|_, time: &mut Option<Time>, new| *time = Some(new));
+
+        let mut tester = ViewTester::new(&mut view, &mut time);
+        tester.layout(&mut view, &mut time, Space::UNBOUNDED);
+        tester.view_state.set_focused(true);
+
+        tester.event(&mut view, &mut time, &key(Key::Enter));
+        assert_eq!(tester.state.highlighted, [9, 2]);
+
+        // 17:30 is after the latest time, so it's clamped
+        for _ in 0..8 {
+            tester.event(&mut view, &mut time, &key(Key::Down));
+        }
+
+        tester.event(&mut view, &mut time, &key(Key::Enter));
+        assert_eq!(time, Time::new(17, 0));
+        assert_eq!(tester.state.column, 1);
+
+        // the later minutes can't be chosen
+        tester.event(&mut view, &mut time, &key(Key::Down));
+        tester.event(&mut view, &mut time, &key(Key::Enter));
+        assert!(tester.state.open);
+
+        tester.event(&mut view, &mut time, &key(Key::Up));
+        tester.event(&mut view, &mut time, &key(Key::Enter));
+        assert!(!tester.state.open);
+        assert_eq!(time, Time::new(17, 0));
+    }
+}
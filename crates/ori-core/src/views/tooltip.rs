@@ -331,14 +331,14 @@ impl<T, V: View<T>> View<T> for Tooltip<V> {
 
     fn layout(
         &mut self,
-        (state, content): &mut Self::State,
+        (_, content): &mut Self::State,
         cx: &mut LayoutCx,
         data: &mut T,
         space: Space,
     ) -> Size {
-        let window_size = cx.window().size - self.padding.size();
-        state.buffer.set_bounds(cx.fonts(), window_size);
-        self.content.layout(content, cx, data, space)
+        let size = self.content.layout(content, cx, data, space);
+        cx.set_baseline(content.parent_baseline());
+        size
     }
 
     fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
@@ -354,6 +354,13 @@ impl<T, V: View<T>> View<T> for Tooltip<V> {
             return;
         }
 
+        // the tooltip is bounded by the window, which isn't known to the layout
+        let window_size = cx.window().size - self.padding.size();
+
+        if state.buffer.bounds() != window_size {
+            state.buffer.set_bounds(cx.fonts(), window_size);
+        }
+
         // we need to try to move the tooltip so it fits on the screen
         let window_rect = Rect::min_size(Point::ZERO, cx.window().size);
 
@@ -0,0 +1,966 @@
+use std::{
+    collections::{HashMap, HashSet},
+    f32::consts::PI,
+    hash::{DefaultHasher, Hash, Hasher},
+    ops::Range,
+};
+
+use ori_macro::Build;
+
+use crate::{
+    canvas::{Color, FillRule},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key, Modifiers, PointerButton},
+    layout::{Affine, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{style, Style, Styles},
+    transition::Transition,
+    view::{Pod, State, View},
+};
+
+use super::{collapsing::icon, ScrollViewport};
+
+/// The number of rows built outside the visible region, on either side.
+const OVERSCAN: usize = 4;
+
+/// Create a new [`Tree`].
+///
+/// `children` is called with the path of a node to get its number of children, and `builder`
+/// is called with the path of a node whenever its row becomes visible.
+pub fn tree<T, V>(
+    roots: usize,
+    children: impl FnMut(&mut T, &[usize]) -> usize + 'static,
+    builder: impl FnMut(&mut T, &[usize]) -> V + 'static,
+) -> Tree<T, V> {
+    Tree::new(roots, children, builder)
+}
+
+/// The style of a tree.
+#[derive(Clone, Debug)]
+pub struct TreeStyle {
+    /// The transition of the nodes when expanded and collapsed.
+    pub transition: Transition,
+
+    /// The height of each row.
+    pub row_height: f32,
+
+    /// The indentation of each level.
+    pub indent: f32,
+
+    /// The size of the expand arrows.
+    pub icon_size: f32,
+
+    /// The color of the expand arrows.
+    pub icon_color: Color,
+
+    /// The color of the indentation guides.
+    pub guide_color: Color,
+
+    /// The background color of the hovered row.
+    pub hover_color: Color,
+
+    /// The background color of the selected rows.
+    pub selected_color: Color,
+}
+
+impl Style for TreeStyle {
+    fn styled(style: &Styles) -> Self {
+        let palette = style.palette();
+
+        Self {
+            transition: Transition::ease(0.1),
+            row_height: 24.0,
+            indent: 16.0,
+            icon_size: 16.0,
+            icon_color: palette.contrast_low,
+            guide_color: palette.outline_low,
+            hover_color: palette.surface_high,
+            selected_color: palette.primary.fade(0.4),
+        }
+    }
+}
+
+/// A tree of nodes, each displayed as a row that can be expanded to show its children.
+///
+/// Nodes are identified by their path, the indices of the node and its ancestors. Children
+/// are only counted when their parent is expanded, and like the [`VirtualList`] only the rows
+/// in the visible region are built, so this is meant to be the content of a vertical
+/// [`Scroll`]. Whether a node is expanded and selected follows its key, see [`Tree::key`].
+///
+/// The tree is navigated with the arrow keys while focused, `Left` and `Right` collapse and
+/// expand nodes. When [`Tree::multiple`] is set, `Shift` and `Ctrl` extend the selection.
+///
+/// Can be styled using the [`TreeStyle`].
+///
+/// [`VirtualList`]: super::VirtualList
+/// [`Scroll`]: super::Scroll
+#[derive(Build, Rebuild)]
+pub struct Tree<T, V> {
+    /// The number of root nodes.
+    #[build(ignore)]
+    pub roots: usize,
+
+    /// The number of children of a node.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub children: Box<dyn FnMut(&mut T, &[usize]) -> usize>,
+
+    /// The builder of the rows.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub builder: Box<dyn FnMut(&mut T, &[usize]) -> V>,
+
+    /// Whether a node can be expanded, see [`Tree::expandable`].
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub expandable: Option<Box<dyn FnMut(&mut T, &[usize]) -> bool>>,
+
+    /// The key of the nodes.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub key: Box<dyn Fn(&T, &[usize]) -> u64>,
+
+    /// The callback for when the selection changes.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub on_select: Option<Box<dyn FnMut(&mut EventCx, &mut T, &[Vec<usize>])>>,
+
+    /// The callback for when a node is expanded or collapsed.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub on_expand: Option<Box<dyn FnMut(&mut EventCx, &mut T, &[usize], bool)>>,
+
+    /// The revision of the nodes.
+    ///
+    /// The nodes are counted again when this or the number of roots changes, and when nodes are
+    /// expanded or collapsed, so this should be changed when nodes are added or removed.
+    pub revision: u64,
+
+    /// Whether multiple nodes can be selected.
+    pub multiple: bool,
+
+    /// The transition of the nodes when expanded and collapsed.
+    pub transition: Transition,
+
+    /// The height of each row.
+    #[rebuild(layout)]
+    pub row_height: f32,
+
+    /// The indentation of each level.
+    #[rebuild(layout)]
+    pub indent: f32,
+
+    /// The size of the expand arrows.
+    #[rebuild(layout)]
+    pub icon_size: f32,
+
+    /// The color of the expand arrows.
+    #[rebuild(draw)]
+    pub icon_color: Color,
+
+    /// The color of the indentation guides.
+    #[rebuild(draw)]
+    pub guide_color: Color,
+
+    /// The background color of the hovered row.
+    #[rebuild(draw)]
+    pub hover_color: Color,
+
+    /// The background color of the selected rows.
+    #[rebuild(draw)]
+    pub selected_color: Color,
+}
+
+impl<T, V> Tree<T, V> {
+    /// Create a new [`Tree`].
+    pub fn new(
+        roots: usize,
+        children: impl FnMut(&mut T, &[usize]) -> usize + 'static,
+        builder: impl FnMut(&mut T, &[usize]) -> V + 'static,
+    ) -> Self {
+        Self::styled(roots, children, builder, style())
+    }
+
+    /// Create a new [`Tree`] with a style.
+    pub fn styled(
+        roots: usize,
+        children: impl FnMut(&mut T, &[usize]) -> usize + 'static,
+        builder: impl FnMut(&mut T, &[usize]) -> V + 'static,
+        style: TreeStyle,
+    ) -> Self {
+        Self {
+            roots,
+            children: Box::new(children),
+            builder: Box::new(builder),
+            expandable: None,
+            key: Box::new(|_, path| {
+                let mut hasher = DefaultHasher::new();
+                path.hash(&mut hasher);
+                hasher.finish()
+            }),
+            on_select: None,
+            on_expand: None,
+            revision: 0,
+            multiple: false,
+            transition: style.transition,
+            row_height: style.row_height,
+            indent: style.indent,
+            icon_size: style.icon_size,
+            icon_color: style.icon_color,
+            guide_color: style.guide_color,
+            hover_color: style.hover_color,
+            selected_color: style.selected_color,
+        }
+    }
+
+    /// Set whether a node can be expanded.
+    ///
+    /// By default a node can be expanded when it has children, set this when counting the
+    /// children is expensive, like when reading a directory, so it's only done when expanded.
+    pub fn expandable(
+        mut self,
+        expandable: impl FnMut(&mut T, &[usize]) -> bool + 'static,
+    ) -> Self {
+        self.expandable = Some(Box::new(expandable));
+        self
+    }
+
+    /// Set the key of the nodes, by default the path of a node is used.
+    ///
+    /// Whether a node is expanded and selected follows the key, so this should be set when
+    /// nodes can be inserted or removed anywhere but the end of their parent.
+    pub fn key<K: Hash>(mut self, key: impl Fn(&T, &[usize]) -> K + 'static) -> Self {
+        self.key = Box::new(move |data, path| {
+            let mut hasher = DefaultHasher::new();
+            key(data, path).hash(&mut hasher);
+            hasher.finish()
+        });
+
+        self
+    }
+
+    /// Set the callback for when the selection changes, called with the selected paths.
+    ///
+    /// Note that this doesn't trigger a rebuild automatically.
+    pub fn on_select(
+        mut self,
+        on_select: impl FnMut(&mut EventCx, &mut T, &[Vec<usize>]) + 'static,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Set the callback for when a node is expanded or collapsed.
+    ///
+    /// This is where children can be loaded lazily. Note that this doesn't trigger a rebuild
+    /// automatically.
+    pub fn on_expand(
+        mut self,
+        on_expand: impl FnMut(&mut EventCx, &mut T, &[usize], bool) + 'static,
+    ) -> Self {
+        self.on_expand = Some(Box::new(on_expand));
+        self
+    }
+}
+
+struct TreeRow {
+    path: Vec<usize>,
+    key: u64,
+    expandable: bool,
+    // how much of the row is shown, while an ancestor is expanding or collapsing
+    scale: f32,
+    // whether an ancestor is collapsed, and the row is on its way out
+    hidden: bool,
+}
+
+impl TreeRow {
+    fn depth(&self) -> usize {
+        self.path.len() - 1
+    }
+}
+
+struct TreeItem<T, V: View<T>> {
+    row: usize,
+    key: u64,
+    view: Pod<V>,
+    state: State<T, V>,
+}
+
+#[doc(hidden)]
+pub struct TreeState<T, V: View<T>> {
+    rows: Vec<TreeRow>,
+    offsets: Vec<f32>,
+    items: Vec<TreeItem<T, V>>,
+    range: Range<usize>,
+    expanded: HashSet<u64>,
+    t: HashMap<u64, f32>,
+    selected: HashSet<u64>,
+    anchor: Option<u64>,
+    cursor: Option<u64>,
+    hovered: Option<usize>,
+}
+
+impl<T, V: View<T>> TreeState<T, V> {
+    fn take_items(&mut self) -> HashMap<u64, TreeItem<T, V>> {
+        self.items.drain(..).map(|item| (item.key, item)).collect()
+    }
+
+    fn row_of(&self, key: Option<u64>) -> Option<usize> {
+        let key = key?;
+        self.rows.iter().position(|row| row.key == key)
+    }
+
+    fn row_at(&self, y: f32) -> Option<usize> {
+        let index = self.offsets[1..].partition_point(|&bottom| bottom <= y);
+        (index < self.rows.len() && !self.rows[index].hidden).then_some(index)
+    }
+
+    fn parent_of(&self, index: usize) -> Option<usize> {
+        let parent = &self.rows[index].path[..self.rows[index].depth()];
+        (self.rows[..index].iter()).rposition(|row| row.path == parent)
+    }
+
+    fn selected_paths(&self) -> Vec<Vec<usize>> {
+        (self.rows.iter())
+            .filter(|row| self.selected.contains(&row.key))
+            .map(|row| row.path.clone())
+            .collect()
+    }
+}
+
+impl<T, V: View<T>> Tree<T, V> {
+    // flatten the expanded nodes into rows
+    fn flatten(&mut self, state: &mut TreeState<T, V>, data: &mut T) {
+        state.rows.clear();
+
+        let mut path = Vec::new();
+        self.flatten_children(state, data, &mut path, self.roots, 1.0, false);
+
+        state.offsets.clear();
+        state.offsets.push(0.0);
+
+        let mut offset = 0.0;
+        for row in &state.rows {
+            offset += self.row_height * row.scale;
+            state.offsets.push(offset);
+        }
+    }
+
+    fn flatten_children(
+        &mut self,
+        state: &mut TreeState<T, V>,
+        data: &mut T,
+        path: &mut Vec<usize>,
+        count: usize,
+        scale: f32,
+        hidden: bool,
+    ) {
+        for index in 0..count {
+            path.push(index);
+
+            let key = (self.key)(data, path);
+            let expandable = match self.expandable {
+                Some(ref mut expandable) => expandable(data, path),
+                None => (self.children)(data, path) > 0,
+            };
+
+            state.rows.push(TreeRow {
+                path: path.clone(),
+                key,
+                expandable,
+                scale,
+                hidden,
+            });
+
+            let expanded = state.expanded.contains(&key);
+            let t = state.t.get(&key).copied().unwrap_or(0.0);
+
+            if expandable && (expanded || t > 0.0) {
+                let count = (self.children)(data, path);
+                let scale = scale * self.transition.get(t);
+                self.flatten_children(state, data, path, count, scale, hidden || !expanded);
+            }
+
+            path.pop();
+        }
+    }
+
+    fn set_expanded(
+        &mut self,
+        state: &mut TreeState<T, V>,
+        cx: &mut EventCx,
+        data: &mut T,
+        index: usize,
+        expanded: bool,
+    ) {
+        let row = &state.rows[index];
+
+        if !row.expandable || state.expanded.contains(&row.key) == expanded {
+            return;
+        }
+
+        match expanded {
+            true => state.expanded.insert(row.key),
+            false => state.expanded.remove(&row.key),
+        };
+
+        let path = row.path.clone();
+
+        if let Some(ref mut on_expand) = self.on_expand {
+            on_expand(cx, data, &path, expanded);
+        }
+
+        self.flatten(state, data);
+        cx.animate();
+        cx.layout();
+    }
+
+    fn select(
+        &mut self,
+        state: &mut TreeState<T, V>,
+        cx: &mut EventCx,
+        data: &mut T,
+        index: usize,
+        modifiers: Modifiers,
+    ) {
+        let key = state.rows[index].key;
+
+        match state.row_of(state.anchor) {
+            Some(anchor) if self.multiple && modifiers.shift => {
+                let range = usize::min(anchor, index)..usize::max(anchor, index) + 1;
+
+                state.selected = (state.rows[range].iter())
+                    .filter(|row| !row.hidden)
+                    .map(|row| row.key)
+                    .collect();
+            }
+            _ if self.multiple && modifiers.ctrl => {
+                if !state.selected.remove(&key) {
+                    state.selected.insert(key);
+                }
+
+                state.anchor = Some(key);
+            }
+            _ => {
+                state.selected.clear();
+                state.selected.insert(key);
+                state.anchor = Some(key);
+            }
+        }
+
+        state.cursor = Some(key);
+        cx.draw();
+
+        if let Some(ref mut on_select) = self.on_select {
+            let paths = state.selected_paths();
+            on_select(cx, data, &paths);
+        }
+    }
+
+    fn handle_key(
+        &mut self,
+        state: &mut TreeState<T, V>,
+        cx: &mut EventCx,
+        data: &mut T,
+        key: Key,
+        modifiers: Modifiers,
+    ) {
+        let visible: Vec<usize> = (0..state.rows.len())
+            .filter(|&index| !state.rows[index].hidden)
+            .collect();
+
+        let Some(&last) = visible.last() else {
+            return;
+        };
+
+        let cursor = state.row_of(state.cursor);
+        let position = cursor.and_then(|cursor| visible.iter().position(|&i| i == cursor));
+
+        let next = match (key, cursor, position) {
+            (Key::Down, _, Some(position)) => visible.get(position + 1).copied(),
+            (Key::Up, _, Some(position)) => position.checked_sub(1).map(|p| visible[p]),
+            (Key::Down | Key::Up | Key::Home, _, _) => Some(visible[0]),
+            (Key::End, _, _) => Some(last),
+            (Key::Right, Some(cursor), _) => {
+                let row = &state.rows[cursor];
+
+                if row.expandable && !state.expanded.contains(&row.key) {
+                    self.set_expanded(state, cx, data, cursor, true);
+                    return;
+                }
+
+                // move to the first child
+                (state.rows.get(cursor + 1))
+                    .filter(|child| child.path.starts_with(&state.rows[cursor].path))
+                    .map(|_| cursor + 1)
+            }
+            (Key::Left, Some(cursor), _) => {
+                let row = &state.rows[cursor];
+
+                if state.expanded.contains(&row.key) {
+                    self.set_expanded(state, cx, data, cursor, false);
+                    return;
+                }
+
+                state.parent_of(cursor)
+            }
+            _ => return,
+        };
+
+        if let Some(next) = next {
+            self.select(state, cx, data, next, modifiers);
+            self.scroll_to(state, cx, next);
+        }
+    }
+
+    fn scroll_to(&mut self, state: &TreeState<T, V>, cx: &mut EventCx, index: usize) {
+        let top = state.offsets[index];
+        let bottom = state.offsets[index + 1];
+
+        if let Some(viewport) = cx.get_context_mut::<ScrollViewport>() {
+            if top < viewport.offset {
+                viewport.scroll_to(top);
+            } else if bottom > viewport.offset + viewport.length {
+                viewport.scroll_to(bottom - viewport.length);
+            }
+        }
+
+        cx.layout();
+    }
+
+    // build the rows in `range`, keeping the ones that are already built, returns whether any
+    // rows were built or dropped
+    fn update_range(
+        &mut self,
+        state: &mut TreeState<T, V>,
+        cx: &mut BuildCx,
+        data: &mut T,
+        range: Range<usize>,
+    ) -> bool {
+        // the rows move around when nodes are expanded and collapsed
+        let moved = (state.items.iter())
+            .any(|item| state.rows.get(item.row).map(|row| row.key) != Some(item.key));
+
+        if state.range == range && !moved {
+            return false;
+        }
+
+        let mut old = state.take_items();
+        let mut changed = false;
+
+        for row in range.clone() {
+            let key = state.rows[row].key;
+
+            if let Some(mut item) = old.remove(&key) {
+                item.row = row;
+                state.items.push(item);
+                continue;
+            }
+
+            let mut view = Pod::new((self.builder)(data, &state.rows[row].path));
+            let item_state = view.build(cx, data);
+
+            state.items.push(TreeItem {
+                row,
+                key,
+                view,
+                state: item_state,
+            });
+
+            changed = true;
+        }
+
+        state.range = range;
+        changed || !old.is_empty()
+    }
+
+    fn content_offset(&self, depth: usize) -> f32 {
+        self.indent * depth as f32 + self.icon_size
+    }
+}
+
+impl<T, V: View<T>> View<T> for Tree<T, V> {
+    type State = TreeState<T, V>;
+
+    fn build(&mut self, _cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let mut state = TreeState {
+            rows: Vec::new(),
+            offsets: Vec::new(),
+            items: Vec::new(),
+            range: 0..0,
+            expanded: HashSet::new(),
+            t: HashMap::new(),
+            selected: HashSet::new(),
+            anchor: None,
+            cursor: None,
+            hovered: None,
+        };
+
+        // the rows are built when the tree is laid out, since that is when the visible region
+        // is known
+        self.flatten(&mut state, data);
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        // expanding and collapsing nodes flattens them as well
+        if self.roots != old.roots || self.revision != old.revision {
+            self.flatten(state, data);
+            cx.layout();
+        }
+
+        let len = state.rows.len();
+        let range = usize::min(state.range.start, len)..usize::min(state.range.end, len);
+        let mut old_items = state.take_items();
+
+        for row in range.clone() {
+            let key = state.rows[row].key;
+            let mut view = Pod::new((self.builder)(data, &state.rows[row].path));
+
+            match old_items.remove(&key) {
+                Some(mut item) => {
+                    view.rebuild(&mut item.state, cx, data, &item.view);
+
+                    item.row = row;
+                    item.view = view;
+                    state.items.push(item);
+                }
+                None => {
+                    let item_state = view.build(&mut cx.as_build_cx(), data);
+
+                    state.items.push(TreeItem {
+                        row,
+                        key,
+                        view,
+                        state: item_state,
+                    });
+
+                    cx.layout();
+                }
+            }
+        }
+
+        if !old_items.is_empty() {
+            cx.layout();
+        }
+
+        state.range = range;
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        for item in &mut state.items {
+            item.view.event(&mut item.state, cx, data, event);
+        }
+
+        match event {
+            Event::PointerMoved(e) => {
+                let local = cx.local(e.position);
+
+                let hovered = match cx.is_hot() || cx.has_hot() {
+                    true => state.row_at(local.y),
+                    false => None,
+                };
+
+                if state.hovered != hovered {
+                    state.hovered = hovered;
+                    cx.draw();
+                }
+            }
+            Event::PointerLeft(_) if state.hovered.is_some() => {
+                state.hovered = None;
+                cx.draw();
+            }
+            Event::PointerPressed(e) if cx.is_hot() || cx.has_hot() => {
+                if e.button != PointerButton::Primary {
+                    return;
+                }
+
+                cx.set_focused(true);
+
+                let local = cx.local(e.position);
+                let Some(index) = state.row_at(local.y) else {
+                    return;
+                };
+
+                let row = &state.rows[index];
+                let arrow = self.content_offset(row.depth());
+
+                if row.expandable && local.x >= arrow - self.icon_size && local.x < arrow {
+                    let expanded = state.expanded.contains(&row.key);
+                    self.set_expanded(state, cx, data, index, !expanded);
+                } else {
+                    self.select(state, cx, data, index, e.modifiers);
+                }
+            }
+            Event::PointerPressed(_) if cx.is_focused() => {
+                cx.set_focused(false);
+                cx.draw();
+            }
+            Event::KeyPressed(e) if cx.is_focused() => {
+                self.handle_key(state, cx, data, e.key, e.modifiers);
+            }
+            Event::Animate(dt) => {
+                let mut animating = false;
+
+                for row in &state.rows {
+                    if !row.expandable {
+                        continue;
+                    }
+
+                    let expanded = state.expanded.contains(&row.key);
+                    let t = state.t.entry(row.key).or_insert(0.0);
+
+                    animating |= self.transition.step(t, expanded, *dt);
+                }
+
+                state.t.retain(|_, t| *t > 0.0);
+
+                if animating {
+                    self.flatten(state, data);
+                    cx.animate();
+                    cx.layout();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        // the visible region depends on the scroll view, so the layout can't be cached
+        cx.set_cache_layout(false);
+
+        let (offset, length) = match cx.get_context_mut::<ScrollViewport>() {
+            Some(viewport) => {
+                viewport.observe();
+                (viewport.offset, viewport.length)
+            }
+            None if space.max.height.is_finite() => (0.0, space.max.height),
+            None => (0.0, cx.window().size.height),
+        };
+
+        let len = state.rows.len();
+        let first = state.offsets[1..].partition_point(|&bottom| bottom <= offset);
+        let last = state.offsets[..len].partition_point(|&top| top < offset + length);
+
+        let first = first.saturating_sub(OVERSCAN);
+        let last = usize::min(last + OVERSCAN, len);
+        let range = first..usize::max(first, last);
+
+        self.update_range(state, &mut cx.as_build_cx(), data, range);
+
+        // rows fill the width of the tree if possible
+        let min_width = match space.max.width.is_finite() {
+            true => space.max.width,
+            false => space.min.width,
+        };
+
+        let mut width = min_width;
+
+        for item in &mut state.items {
+            let row = &state.rows[item.row];
+            let indent = self.content_offset(row.depth());
+
+            let space = Space::new(
+                Size::new(f32::max(min_width - indent, 0.0), self.row_height),
+                Size::new(space.max.width - indent, self.row_height),
+            );
+
+            let size = item.view.layout(&mut item.state, cx, data, space);
+            width = f32::max(width, size.width + indent);
+
+            // rows of expanding nodes slide out from beneath their parent
+            let slide = self.row_height * (1.0 - row.scale);
+            let offset = Vector::new(indent, state.offsets[item.row] - slide);
+            item.state.translate(offset);
+        }
+
+        space.fit(Size::new(width, state.offsets[len]))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        cx.trigger(cx.rect());
+
+        let width = cx.size().width;
+
+        for item in &mut state.items {
+            let row = &state.rows[item.row];
+            let top = state.offsets[item.row];
+            let height = state.offsets[item.row + 1] - top;
+
+            if height <= 0.0 {
+                continue;
+            }
+
+            let rect = Rect::min_size(Point::new(0.0, top), Size::new(width, height));
+
+            if state.selected.contains(&row.key) {
+                cx.fill_rect(rect, self.selected_color);
+            } else if state.hovered == Some(item.row) {
+                cx.fill_rect(rect, self.hover_color);
+            }
+
+            // the indentation guides of the ancestors
+            for depth in 0..row.depth() {
+                let x = self.content_offset(depth) - self.icon_size / 2.0;
+                let guide = Rect::min_size(Point::new(x.round(), top), Size::new(1.0, height));
+                cx.fill_rect(guide, self.guide_color);
+            }
+
+            cx.mask(rect, |cx| {
+                if row.expandable {
+                    let t = state.t.get(&row.key).copied().unwrap_or(0.0);
+                    let t = self.transition.get(t);
+
+                    let x = self.content_offset(row.depth()) - self.icon_size / 2.0;
+                    let y = top + height - self.row_height / 2.0;
+
+                    let transform = Affine::translate(Vector::new(x, y))
+                        * Affine::scale(Vector::all(self.icon_size))
+                        * Affine::rotate(PI / 2.0 * t);
+
+                    cx.layer(transform, |cx| {
+                        cx.fill(icon(), FillRule::EvenOdd, self.icon_color);
+                    });
+                }
+
+                item.view.draw(&mut item.state, cx, data);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Key, KeyPressed, Modifiers},
+        layout::{Size, Space},
+        views::{
+            testing::{save_layout, SavedLayouts, ViewTester},
+            vscroll,
+        },
+    };
+
+    use super::{tree, OVERSCAN};
+
+    fn press(key: Key) -> Event {
+        Event::KeyPressed(KeyPressed {
+            key,
+            code: None,
+            text: None,
+            modifiers: Modifiers::default(),
+        })
+    }
+
+    #[test]
+    fn lazy_children() {
+        // every node has 3 children, forever
+        let mut counted = Vec::new();
+
+        let mut view = tree(
+            2,
+            |counted: &mut Vec<Vec<usize>>, path| {
+                counted.push(path.to_vec());
+                3
+            },
+            |_, path| save_layout((), format!("{:?}", path)),
+        )
+        .expandable(|_, path| path.len() < 3);
+
+        let mut tester = ViewTester::new(&mut view, &mut counted);
+        tester.layout(&mut view, &mut counted, Space::UNBOUNDED);
+
+        // only the expanded nodes are counted
+        assert!(counted.is_empty());
+        assert_eq!(tester.state.rows.len(), 2);
+
+        tester.view_state.set_focused(true);
+        tester.event(&mut view, &mut counted, &press(Key::Down));
+        tester.event(&mut view, &mut counted, &press(Key::Right));
+
+        assert_eq!(counted, vec![vec![0]]);
+        assert_eq!(tester.state.rows.len(), 5);
+
+        // move to the first child, and then the next
+        tester.event(&mut view, &mut counted, &Event::Animate(1.0));
+        tester.event(&mut view, &mut counted, &press(Key::Right));
+        tester.event(&mut view, &mut counted, &press(Key::Down));
+
+        let paths = tester.state.selected_paths();
+        assert_eq!(paths, vec![vec![0, 1]]);
+
+        // collapse the parent from the child
+        tester.event(&mut view, &mut counted, &press(Key::Left));
+        tester.event(&mut view, &mut counted, &press(Key::Left));
+        tester.event(&mut view, &mut counted, &Event::Animate(1.0));
+
+        assert_eq!(tester.state.rows.len(), 2);
+        assert_eq!(tester.state.selected_paths(), vec![vec![0]]);
+    }
+
+    #[test]
+    fn rebuild_counts_on_revision() {
+        let counting = |revision: u64| {
+            let children = |counted: &mut usize, _: &[usize]| {
+                *counted += 1;
+                2
+            };
+
+            tree(2, children, |_, _| ())
+                .expandable(|_, path| path.len() < 2)
+                .revision(revision)
+        };
+
+        let mut counted = 0;
+        let mut view = counting(0);
+
+        let mut tester = ViewTester::new(&mut view, &mut counted);
+        tester.layout(&mut view, &mut counted, Space::UNBOUNDED);
+
+        tester.view_state.set_focused(true);
+        tester.event(&mut view, &mut counted, &press(Key::Down));
+        tester.event(&mut view, &mut counted, &press(Key::Right));
+        assert_eq!(counted, 1);
+
+        // the nodes aren't counted again when rebuilt, unless the revision changes
+        let old = view;
+        let mut view = counting(0);
+        tester.rebuild(&mut view, &mut counted, &old);
+        assert_eq!(counted, 1);
+
+        let old = view;
+        let mut view = counting(1);
+        tester.rebuild(&mut view, &mut counted, &old);
+        assert_eq!(counted, 2);
+        assert_eq!(tester.state.rows.len(), 4);
+    }
+
+    #[test]
+    fn virtualized_rows() {
+        let mut view = vscroll(
+            tree(
+                1000,
+                |_, _| 0,
+                |_: &mut (), path| save_layout((), format!("{:?}", path)),
+            )
+            .row_height(10.0),
+        );
+
+        let space = Space::new(Size::ZERO, Size::new(100.0, 50.0));
+
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(&mut view, &mut (), space);
+        tester.event(&mut view, &mut (), &Event::Update);
+
+        let layouts = tester.contexts.get::<SavedLayouts>().unwrap();
+        assert_eq!(layouts.len(), 5 + OVERSCAN);
+    }
+}
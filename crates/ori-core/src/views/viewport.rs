@@ -0,0 +1,359 @@
+use ori_macro::{example, Build};
+
+use crate::{
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Code, Event, Key, PointerButton},
+    layout::{Affine, Point, Size, Space, Vector},
+    rebuild::Rebuild,
+    view::{Pod, State, View},
+    window::Cursor,
+};
+
+/// Create a new [`Viewport`].
+pub fn viewport<V>(content: V) -> Viewport<V> {
+    Viewport::new(content)
+}
+
+/// A command that fits the content of every [`Viewport`] into view.
+///
+/// Send it with [`BaseCx::cmd`](crate::context::BaseCx::cmd).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FitToContent;
+
+/// A view that zooms and pans its content, like an infinite canvas.
+///
+/// The content is laid out without constraints, and transformed like a [`Transform`]. Scrolling
+/// and pinching zoom around the pointer, and dragging with the middle button, or with the
+/// primary button while space is held, pans the content. Pointer events reaching the content
+/// are transformed along with it, and content outside the viewport isn't drawn.
+///
+/// [`Transform`]: super::Transform
+#[example(name = "viewport", width = 400, height = 300)]
+#[derive(Build, Rebuild)]
+pub struct Viewport<V> {
+    /// The content.
+    #[build(ignore)]
+    pub content: Pod<V>,
+
+    /// The minimum zoom.
+    pub min_zoom: f32,
+
+    /// The maximum zoom.
+    pub max_zoom: f32,
+
+    /// How much a single step of the scroll wheel zooms.
+    pub zoom_step: f32,
+
+    /// Whether to fit the content into view when it's first laid out.
+    pub fit: bool,
+
+    /// The space around the content when it's fit into view.
+    pub fit_padding: f32,
+}
+
+impl<V> Viewport<V> {
+    /// Create a new [`Viewport`].
+    pub fn new(content: V) -> Self {
+        Self {
+            content: Pod::new(content),
+            min_zoom: 0.1,
+            max_zoom: 10.0,
+            zoom_step: 0.1,
+            fit: false,
+            fit_padding: 16.0,
+        }
+    }
+
+    // zoom by `factor` keeping `point` in place, returns whether the zoom changed
+    fn zoom_around(&self, state: &mut ViewportState, point: Point, factor: f32) -> bool {
+        let zoom = f32::clamp(state.zoom * factor, self.min_zoom, self.max_zoom);
+
+        if zoom == state.zoom || !zoom.is_finite() {
+            return false;
+        }
+
+        // the point in the content stays under the pointer
+        let content = (point.to_vector() - state.pan) / state.zoom;
+        state.pan = point.to_vector() - content * zoom;
+        state.zoom = zoom;
+
+        true
+    }
+
+    // zoom and pan so that the content fills the viewport
+    fn fit_content(&self, state: &mut ViewportState, content: Size, size: Size) {
+        let available = size - self.fit_padding * 2.0;
+
+        if content.width <= 0.0 || content.height <= 0.0 {
+            return;
+        }
+
+        let zoom = f32::min(
+            available.width / content.width,
+            available.height / content.height,
+        );
+
+        state.zoom = zoom.clamp(self.min_zoom, self.max_zoom);
+        state.pan = (size.to_vector() - content.to_vector() * state.zoom) / 2.0;
+    }
+
+    // handle the gestures of the viewport, returns whether the event was consumed
+    fn viewport_event(
+        &self,
+        state: &mut ViewportState,
+        content: Size,
+        cx: &mut EventCx,
+        event: &Event,
+    ) -> bool {
+        let hot = cx.is_hot() || cx.has_hot();
+
+        match event {
+            // space is left alone while the content has focus, it may be typing
+            Event::KeyPressed(e) if is_space(&e.key, e.code) && !cx.has_focused() => {
+                if hot && !state.space {
+                    state.space = true;
+                    cx.set_cursor(Some(Cursor::Grab));
+                }
+
+                state.space
+            }
+            Event::KeyReleased(e) if is_space(&e.key, e.code) && state.space => {
+                state.space = false;
+
+                if state.panning.is_none() {
+                    cx.set_cursor(None);
+                }
+
+                true
+            }
+            Event::PointerPressed(e) if hot => {
+                let pans = match e.button {
+                    PointerButton::Tertiary => true,
+                    PointerButton::Primary => state.space,
+                    _ => false,
+                };
+
+                if !pans {
+                    return false;
+                }
+
+                state.panning = Some((e.button, cx.local(e.position)));
+                cx.set_active(true);
+                cx.set_cursor(Some(Cursor::Grabbing));
+
+                true
+            }
+            Event::PointerMoved(e) => {
+                let Some((button, last)) = state.panning else {
+                    return false;
+                };
+
+                let local = cx.local(e.position);
+                state.pan += local - last;
+                state.panning = Some((button, local));
+
+                true
+            }
+            Event::PointerReleased(e) => {
+                if !state.panning.is_some_and(|(button, _)| button == e.button) {
+                    return false;
+                }
+
+                state.panning = None;
+                cx.set_active(false);
+
+                match state.space {
+                    true => cx.set_cursor(Some(Cursor::Grab)),
+                    false => cx.set_cursor(None),
+                }
+
+                true
+            }
+            Event::PointerScrolled(e) if hot => {
+                let factor = f32::powf(1.0 + self.zoom_step, e.delta.y);
+                self.zoom_around(state, cx.local(e.position), factor);
+
+                true
+            }
+            Event::PointerPinched(e) if hot => {
+                self.zoom_around(state, cx.local(e.position), e.scale);
+
+                true
+            }
+            _ if event.is_cmd::<FitToContent>() => {
+                self.fit_content(state, content, cx.size());
+
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
+fn is_space(key: &Key, code: Option<Code>) -> bool {
+    *key == Key::Character(' ') || code == Some(Code::Space)
+}
+
+#[doc(hidden)]
+pub struct ViewportState {
+    zoom: f32,
+    pan: Vector,
+    fitted: bool,
+    space: bool,
+    panning: Option<(PointerButton, Point)>,
+}
+
+impl ViewportState {
+    fn transform(&self) -> Affine {
+        Affine::translate(self.pan) * Affine::scale(Vector::all(self.zoom))
+    }
+}
+
+impl<T, V: View<T>> View<T> for Viewport<V> {
+    type State = (ViewportState, State<T, V>);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let state = ViewportState {
+            zoom: 1.0,
+            pan: Vector::ZERO,
+            fitted: false,
+            space: false,
+            panning: None,
+        };
+
+        (state, self.content.build(cx, data))
+    }
+
+    fn rebuild(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        Rebuild::rebuild(self, cx, old);
+
+        if self.min_zoom != old.min_zoom || self.max_zoom != old.max_zoom {
+            state.zoom = f32::clamp(state.zoom, self.min_zoom, self.max_zoom);
+            cx.layout();
+        }
+
+        self.content.rebuild(content, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) {
+        let transform = state.transform();
+        let consumed = self.viewport_event(state, content.size(), cx, event);
+
+        if state.transform() != transform {
+            content.set_transform(state.transform());
+            cx.draw();
+        }
+
+        // gestures of the viewport don't reach the content
+        if !consumed {
+            self.content.event(content, cx, data, event);
+        }
+    }
+
+    fn layout(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        let content_size = self.content.layout(content, cx, data, Space::UNBOUNDED);
+
+        // the viewport fills the space it's given, or fits the content when unbounded
+        let size = Size::new(
+            match space.max.width.is_finite() {
+                true => space.max.width,
+                false => content_size.width,
+            },
+            match space.max.height.is_finite() {
+                true => space.max.height,
+                false => content_size.height,
+            },
+        );
+
+        let size = space.fit(size);
+
+        if self.fit && !state.fitted {
+            self.fit_content(state, content_size, size);
+            state.fitted = true;
+        }
+
+        content.set_transform(state.transform());
+
+        size
+    }
+
+    fn draw(&mut self, (_state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        cx.trigger(cx.rect());
+
+        // the mask limits the visible area, which culls the content outside the viewport
+        cx.mask(cx.rect(), |cx| {
+            self.content.draw(content, cx, data);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Modifiers, PointerId, PointerScrolled},
+        layout::{Point, Rect, Size, Space, Vector},
+        views::{
+            size,
+            testing::{save_layout, SavedLayouts, ViewTester},
+        },
+    };
+
+    use super::viewport;
+
+    #[test]
+    fn fit_content() {
+        let mut view = viewport(save_layout(size([100.0, 50.0], ()), "a"))
+            .fit(true)
+            .fit_padding(0.0);
+
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(&mut view, &mut (), Space::new(Size::ZERO, Size::all(200.0)));
+        tester.event(&mut view, &mut (), &Event::Update);
+
+        let layouts = tester.contexts.get::<SavedLayouts>().unwrap();
+        assert_eq!(layouts["a"], Rect::from([0.0, 50.0, 200.0, 150.0]));
+    }
+
+    #[test]
+    fn zoom_around_pointer() {
+        let mut view = viewport(save_layout(size([100.0, 100.0], ()), "a"));
+
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(&mut view, &mut (), Space::new(Size::ZERO, Size::all(200.0)));
+        tester.view_state.set_hot(true);
+
+        // zoom in twice around the middle of the content
+        let scroll = Event::PointerScrolled(PointerScrolled {
+            id: PointerId::from_u64(0),
+            position: Point::new(50.0, 50.0),
+            delta: Vector::new(0.0, 1.0),
+            modifiers: Modifiers::default(),
+        });
+
+        view.zoom_step = 1.0;
+        tester.event(&mut view, &mut (), &scroll);
+        tester.event(&mut view, &mut (), &Event::Update);
+
+        // the point under the pointer stays in place, so children see it at the same position
+        let layouts = tester.contexts.get::<SavedLayouts>().unwrap();
+        assert_eq!(layouts["a"], Rect::from([-50.0, -50.0, 150.0, 150.0]));
+    }
+}
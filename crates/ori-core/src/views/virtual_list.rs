@@ -0,0 +1,633 @@
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    ops::Range,
+};
+
+use ori_macro::Build;
+
+use crate::{
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Size, Space, Vector},
+    rebuild::Rebuild,
+    view::{Pod, State, View},
+};
+
+use super::ScrollViewport;
+
+/// Create a new [`VirtualList`].
+///
+/// `builder` is called with the index of an item whenever that item becomes visible.
+pub fn virtual_list<T, V>(
+    len: usize,
+    builder: impl FnMut(&mut T, usize) -> V + 'static,
+) -> VirtualList<T, V> {
+    VirtualList::new(len, builder)
+}
+
+/// A vertical list that only builds the items that are visible.
+///
+/// This is meant to be the content of a vertical [`Scroll`](super::Scroll), where it will only
+/// build, lay out and draw the items in the visible region, plus `overscan` items on either side.
+/// Items are either of a fixed height, see [`VirtualList::item_height`], or measured when they
+/// are first laid out, in which case `estimated_height` is used for items not yet measured.
+///
+/// The state of an item is kept for as long as it stays in the visible range, and follows the key
+/// of the item, see [`VirtualList::key`].
+#[derive(Build, Rebuild)]
+pub struct VirtualList<T, V> {
+    /// The builder of the items.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub builder: Box<dyn FnMut(&mut T, usize) -> V>,
+
+    /// The key of the items.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub key: Box<dyn Fn(&T, usize) -> u64>,
+
+    /// The number of items.
+    #[rebuild(layout)]
+    pub len: usize,
+
+    /// The fixed height of every item, if `None` the items are measured.
+    #[rebuild(layout)]
+    pub item_height: Option<f32>,
+
+    /// The height used for items that haven't been measured yet.
+    #[rebuild(layout)]
+    pub estimated_height: f32,
+
+    /// The revision of the keys of the items.
+    ///
+    /// Measured heights are matched with the keys when the number of items changes, this should
+    /// be changed when items are moved without changing the number of items.
+    #[rebuild(layout)]
+    pub revision: u64,
+
+    /// The number of items built outside the visible region, on either side.
+    #[build(ignore)]
+    #[rebuild(layout)]
+    pub overscan: usize,
+
+    /// The index to scroll to, whenever this changes.
+    pub scroll_to_index: Option<usize>,
+}
+
+impl<T, V> VirtualList<T, V> {
+    /// Create a new [`VirtualList`].
+    pub fn new(len: usize, builder: impl FnMut(&mut T, usize) -> V + 'static) -> Self {
+        Self {
+            builder: Box::new(builder),
+            key: Box::new(|_, index| index as u64),
+            len,
+            item_height: None,
+            estimated_height: 24.0,
+            revision: 0,
+            overscan: 4,
+            scroll_to_index: None,
+        }
+    }
+
+    /// Set the number of items built outside the visible region, on either side.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Set the key of the items, by default the index of an item is used.
+    ///
+    /// Per-item state and measured heights follow the key, so this should be set when items can be
+    /// inserted or removed anywhere but the end of the list, see also [`VirtualList::revision`].
+    pub fn key<K: Hash>(mut self, key: impl Fn(&T, usize) -> K + 'static) -> Self {
+        self.key = Box::new(move |data, index| {
+            let mut hasher = DefaultHasher::new();
+            key(data, index).hash(&mut hasher);
+            hasher.finish()
+        });
+
+        self
+    }
+
+    // measured heights follow the keys of the items, so inserting or removing items keeps them
+    // on the right rows
+    fn rekey(&self, state: &mut VirtualListState<T, V>, data: &T)
+    where
+        V: View<T>,
+    {
+        if let Some(height) = self.item_height {
+            state.heights = Heights::new(vec![f32::NAN; self.len], height);
+            state.sizes.clear();
+            return;
+        }
+
+        let mut sizes = HashMap::new();
+
+        let measured = (0..self.len).map(|index| {
+            let key = (self.key)(data, index);
+
+            match state.sizes.remove(&key) {
+                Some(height) => {
+                    sizes.insert(key, height);
+                    height
+                }
+                None => f32::NAN,
+            }
+        });
+
+        state.heights = Heights::new(measured.collect(), self.estimated_height);
+        state.sizes = sizes;
+    }
+
+    fn visible_range(&self, state: &VirtualListState<T, V>, start: f32, end: f32) -> Range<usize>
+    where
+        V: View<T>,
+    {
+        let heights = &state.heights;
+
+        // the first item ending after `start`, and the last item starting before `end`
+        let first = heights.count(start, true);
+        let last = heights.count(end, false);
+        let last = match last < self.len && heights.offset(last) < end {
+            true => last + 1,
+            false => last,
+        };
+
+        let first = first.saturating_sub(self.overscan);
+        let last = usize::min(last + self.overscan, self.len);
+
+        first..usize::max(first, last)
+    }
+}
+
+// the heights of the items, summed in a fenwick tree so that offsets can be found and updated in
+// logarithmic time, even in very long lists
+struct Heights {
+    // the measured height of each item, NaN when it hasn't been measured
+    measured: Vec<f32>,
+    estimated: f32,
+    tree: Vec<f32>,
+}
+
+impl Heights {
+    fn new(measured: Vec<f32>, estimated: f32) -> Self {
+        let mut tree: Vec<f32> = (measured.iter())
+            .map(|&height| match height.is_nan() {
+                true => estimated,
+                false => height,
+            })
+            .collect();
+
+        // each node is added to its parent, which builds the tree in linear time
+        for node in 1..=tree.len() {
+            let parent = node + lowest_bit(node);
+
+            if parent <= tree.len() {
+                tree[parent - 1] += tree[node - 1];
+            }
+        }
+
+        Self {
+            measured,
+            estimated,
+            tree,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.measured.len()
+    }
+
+    fn get(&self, index: usize) -> f32 {
+        match self.measured[index] {
+            height if height.is_nan() => self.estimated,
+            height => height,
+        }
+    }
+
+    fn set(&mut self, index: usize, height: f32) {
+        let delta = height - self.get(index);
+        self.measured[index] = height;
+
+        if delta == 0.0 {
+            return;
+        }
+
+        let mut node = index + 1;
+        while node <= self.len() {
+            self.tree[node - 1] += delta;
+            node += lowest_bit(node);
+        }
+    }
+
+    // the offset of the top of the item at `index`
+    fn offset(&self, index: usize) -> f32 {
+        let mut offset = 0.0;
+        let mut node = index;
+
+        while node > 0 {
+            offset += self.tree[node - 1];
+            node -= lowest_bit(node);
+        }
+
+        offset
+    }
+
+    fn total(&self) -> f32 {
+        self.offset(self.len())
+    }
+
+    // the number of leading items ending before `offset`, or at it when `inclusive`
+    fn count(&self, offset: f32, inclusive: bool) -> usize {
+        if self.len() == 0 {
+            return 0;
+        }
+
+        let mut count = 0;
+        let mut bottom = 0.0;
+        let mut step = 1 << (usize::BITS - 1 - self.len().leading_zeros());
+
+        while step > 0 {
+            let next = count + step;
+
+            if next <= self.len() {
+                let next_bottom = bottom + self.tree[next - 1];
+
+                if next_bottom < offset || inclusive && next_bottom == offset {
+                    count = next;
+                    bottom = next_bottom;
+                }
+            }
+
+            step >>= 1;
+        }
+
+        count
+    }
+}
+
+fn lowest_bit(node: usize) -> usize {
+    node & node.wrapping_neg()
+}
+
+struct Item<T, V: View<T>> {
+    index: usize,
+    key: u64,
+    view: Pod<V>,
+    state: State<T, V>,
+}
+
+#[doc(hidden)]
+pub struct VirtualListState<T, V: View<T>> {
+    items: Vec<Item<T, V>>,
+    heights: Heights,
+    sizes: HashMap<u64, f32>,
+    range: Range<usize>,
+    scroll_to: Option<usize>,
+}
+
+impl<T, V: View<T>> VirtualListState<T, V> {
+    fn take_items(&mut self) -> HashMap<u64, Item<T, V>> {
+        self.items.drain(..).map(|item| (item.key, item)).collect()
+    }
+}
+
+impl<T, V: View<T>> VirtualList<T, V> {
+    // build the items in `range`, keeping the ones that are already built, returns whether any
+    // items were built or dropped
+    fn update_range(
+        &mut self,
+        state: &mut VirtualListState<T, V>,
+        cx: &mut BuildCx,
+        data: &mut T,
+        range: Range<usize>,
+    ) -> bool {
+        if state.range == range {
+            return false;
+        }
+
+        let mut old = state.take_items();
+        let mut changed = false;
+
+        for index in range.clone() {
+            let key = (self.key)(data, index);
+
+            if let Some(mut item) = old.remove(&key) {
+                item.index = index;
+                state.items.push(item);
+                continue;
+            }
+
+            let mut view = Pod::new((self.builder)(data, index));
+            let item_state = view.build(cx, data);
+
+            state.items.push(Item {
+                index,
+                key,
+                view,
+                state: item_state,
+            });
+
+            changed = true;
+        }
+
+        state.range = range;
+        changed || !old.is_empty()
+    }
+}
+
+impl<T, V: View<T>> View<T> for VirtualList<T, V> {
+    type State = VirtualListState<T, V>;
+
+    fn build(&mut self, _cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        // the items are built when the list is laid out, since that is when the visible region
+        // is known
+        VirtualListState {
+            items: Vec::new(),
+            heights: Heights::new(
+                vec![f32::NAN; self.len],
+                self.item_height.unwrap_or(self.estimated_height),
+            ),
+            sizes: HashMap::new(),
+            range: 0..0,
+            scroll_to: self.scroll_to_index,
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        if self.scroll_to_index != old.scroll_to_index {
+            state.scroll_to = self.scroll_to_index;
+            cx.layout();
+        }
+
+        if self.len != old.len
+            || self.item_height != old.item_height
+            || self.estimated_height != old.estimated_height
+            || self.revision != old.revision
+        {
+            self.rekey(state, data);
+        }
+
+        let range = usize::min(state.range.start, self.len)..usize::min(state.range.end, self.len);
+        let mut old_items = state.take_items();
+
+        for index in range.clone() {
+            let key = (self.key)(data, index);
+            let mut view = Pod::new((self.builder)(data, index));
+
+            match old_items.remove(&key) {
+                Some(mut item) => {
+                    view.rebuild(&mut item.state, cx, data, &item.view);
+
+                    item.index = index;
+                    item.view = view;
+                    state.items.push(item);
+                }
+                None => {
+                    let item_state = view.build(&mut cx.as_build_cx(), data);
+
+                    state.items.push(Item {
+                        index,
+                        key,
+                        view,
+                        state: item_state,
+                    });
+
+                    cx.layout();
+                }
+            }
+        }
+
+        if !old_items.is_empty() {
+            cx.layout();
+        }
+
+        state.range = range;
+    }
+
+    fn event(&mut self, state: &mut Self::State, cx: &mut EventCx, data: &mut T, event: &Event) {
+        for item in &mut state.items {
+            item.view.event(&mut item.state, cx, data, event);
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        // the visible region depends on the scroll view, so the layout can't be cached
+        cx.set_cache_layout(false);
+
+        // when not inside a scroll view, the list is visible up to the max height or the window
+        let (offset, length) = match cx.get_context_mut::<ScrollViewport>() {
+            Some(viewport) => {
+                viewport.observe();
+                (viewport.offset, viewport.length)
+            }
+            None if space.max.height.is_finite() => (0.0, space.max.height),
+            None => (0.0, cx.window().size.height),
+        };
+
+        // items fill the width of the list if possible
+        let min_width = match space.max.width.is_finite() {
+            true => space.max.width,
+            false => space.min.width,
+        };
+
+        let mut width = min_width;
+
+        // measuring new items can change which items are visible, so we do a few passes
+        for _ in 0..4 {
+            let range = self.visible_range(state, offset, offset + length);
+            let changed = self.update_range(state, &mut cx.as_build_cx(), data, range);
+
+            width = min_width;
+
+            for item in &mut state.items {
+                let space = match self.item_height {
+                    Some(height) => Space::new(
+                        Size::new(min_width, height),
+                        Size::new(space.max.width, height),
+                    ),
+                    None => Space::new(
+                        Size::new(min_width, 0.0),
+                        Size::new(space.max.width, f32::INFINITY),
+                    ),
+                };
+
+                let size = item.view.layout(&mut item.state, cx, data, space);
+                state.heights.set(item.index, size.height);
+
+                if self.item_height.is_none() {
+                    state.sizes.insert(item.key, size.height);
+                }
+
+                width = f32::max(width, size.width);
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        for item in &mut state.items {
+            let offset = state.heights.offset(item.index);
+            item.state.translate(Vector::new(0.0, offset));
+        }
+
+        if let Some(index) = state.scroll_to.take() {
+            let offset = state.heights.offset(usize::min(index, self.len));
+
+            if let Some(viewport) = cx.get_context_mut::<ScrollViewport>() {
+                viewport.scroll_to(offset);
+            }
+        }
+
+        space.fit(Size::new(width, state.heights.total()))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        for item in &mut state.items {
+            item.view.draw(&mut item.state, cx, data);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use crate::{
+        layout::{Rect, Size, Space},
+        views::{
+            size,
+            testing::{save_layout, test_layout, ViewTester},
+            vscroll,
+        },
+    };
+
+    use super::{virtual_list, Heights};
+
+    #[test]
+    fn builds_visible_range() {
+        let list = virtual_list(1000, |built: &mut Vec<usize>, index| {
+            built.push(index);
+            save_layout((), format!("item{}", index))
+        });
+
+        let mut view = vscroll(list.item_height(10.0).overscan(2));
+        let space = Space::new(Size::ZERO, Size::new(100.0, 50.0));
+
+        let mut built = Vec::new();
+        let layouts = test_layout(&mut view, &mut built, space);
+
+        assert_eq!(built, (0..7).collect::<Vec<_>>());
+        assert_eq!(layouts["item3"], Rect::from([0.0, 30.0, 100.0, 40.0]));
+    }
+
+    #[test]
+    fn scroll_to_index() {
+        let list = virtual_list(1000, |_: &mut (), index| {
+            save_layout((), format!("item{}", index))
+        });
+
+        let mut view = vscroll(list.item_height(10.0).overscan(0).scroll_to_index(500));
+        let space = Space::new(Size::ZERO, Size::new(100.0, 50.0));
+
+        let layouts = test_layout(&mut view, &mut (), space);
+
+        assert_eq!(layouts.len(), 5);
+        assert_eq!(layouts["item500"], Rect::from([0.0, 0.0, 100.0, 10.0]));
+    }
+
+    #[test]
+    fn heights_offsets() {
+        let mut heights = Heights::new(vec![f32::NAN; 100_000], 10.0);
+
+        heights.set(2, 30.0);
+        assert_eq!(heights.offset(3), 50.0);
+        assert_eq!(heights.total(), 1_000_020.0);
+
+        // the items ending at or before an offset
+        assert_eq!(heights.count(50.0, true), 3);
+        assert_eq!(heights.count(50.0, false), 2);
+        assert_eq!(heights.count(f32::INFINITY, false), 100_000);
+    }
+
+    #[test]
+    fn heights_follow_keys() {
+        let list = || {
+            let list = virtual_list(0, |ids: &mut Vec<u32>, index| match ids[index] {
+                0 => size([10.0, 30.0], ()),
+                _ => size([10.0, 10.0], ()),
+            });
+
+            list.key(|ids: &Vec<u32>, index| ids[index])
+        };
+
+        let mut ids: Vec<u32> = (0..10).collect();
+        let mut view = list().len(ids.len());
+
+        let mut tester = ViewTester::new(&mut view, &mut ids);
+        tester.layout(
+            &mut view,
+            &mut ids,
+            Space::new(Size::ZERO, Size::all(100.0)),
+        );
+        assert_eq!(tester.state.heights.get(0), 30.0);
+
+        // an item inserted at the top moves the measured heights down with their items
+        ids.insert(0, 10);
+        let old = view;
+        let mut view = list().len(ids.len());
+        tester.rebuild(&mut view, &mut ids, &old);
+
+        assert!(tester.state.heights.measured[0].is_nan());
+        assert_eq!(tester.state.heights.get(1), 30.0);
+
+        // moving an item without changing the length needs a new revision
+        ids.swap(0, 1);
+        let old = view;
+        let mut view = list().len(ids.len()).revision(1u64);
+        tester.rebuild(&mut view, &mut ids, &old);
+
+        assert_eq!(tester.state.heights.get(0), 30.0);
+        assert!(tester.state.heights.measured[1].is_nan());
+    }
+
+    #[test]
+    fn rebuild_keeps_keys() {
+        let calls = Rc::new(Cell::new(0));
+
+        let list = || {
+            let calls = calls.clone();
+
+            virtual_list(1000, |_: &mut (), _| ()).key(move |_, index| {
+                calls.set(calls.get() + 1);
+                index
+            })
+        };
+
+        let mut view = vscroll(list().item_height(10.0).overscan(0));
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(
+            &mut view,
+            &mut (),
+            Space::new(Size::ZERO, Size::new(100.0, 50.0)),
+        );
+
+        // rebuilding with the same length only keys the visible items
+        calls.set(0);
+        let old = view;
+        let mut view = vscroll(list().item_height(10.0).overscan(0));
+        tester.rebuild(&mut view, &mut (), &old);
+
+        assert_eq!(calls.get(), 5);
+    }
+}
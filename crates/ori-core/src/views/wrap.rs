@@ -10,6 +10,8 @@ use crate::{
     view::{AnyView, PodSeq, SeqState, View, ViewSeq},
 };
 
+use super::Flex;
+
 pub use crate::{hwrap, vwrap};
 
 /// Create a horizontal [`Wrap`].
@@ -60,7 +62,8 @@ pub fn vwrap_any<'a, V>() -> Wrap<Vec<Box<dyn AnyView<V> + 'a>>> {
 
 /// A view that lays out it's content in a line wrapping if it doesn't fit.
 ///
-/// Note that unlike [`Stack`](super::Stack) this view does not care about flex.
+/// Content is broken into runs by its natural size, after which [`Flexible`](super::Flexible)
+/// content in each run is grown to fill the remaining space, or shrunk if the run overflows.
 #[example(name = "wrap", width = 400, height = 600)]
 #[derive(Build, Rebuild)]
 pub struct Wrap<V> {
@@ -266,43 +269,71 @@ impl<T, V: ViewSeq<T>> View<T> for Wrap<V> {
 
         let (major_gap, minor_gap) = self.axis.unpack((self.row_gap, self.column_gap));
 
-        let mut major = 0.0;
+        /* break the content into runs */
 
         state.runs.clear();
         state.run_minors.clear();
 
         let mut run_start = 0;
         let mut run_major = 0.0;
-        let mut run_minor = 0.0;
 
         for i in 0..self.content.len() {
-            let (child_major, child_minor) = self.axis.unpack(content[i].size());
-            let gap = if run_major > 0.0 { major_gap } else { 0.0 };
+            let child_major = state.majors[i];
+            let gap = if i > run_start { major_gap } else { 0.0 };
 
-            if run_major + child_major + gap <= max_major {
+            if i == run_start || run_major + child_major + gap <= max_major {
                 run_major += gap + child_major;
-                run_minor = f32::max(run_minor, child_minor);
                 continue;
             }
 
             state.runs.push(run_start..i);
-            state.run_minors.push(run_minor);
-            major = f32::max(major, run_major);
 
             run_start = i;
             run_major = child_major;
-            run_minor = child_minor;
         }
 
         state.runs.push(run_start..self.content.len());
-        state.run_minors.push(run_minor);
-        major = f32::max(major, run_major);
+
+        /* flex and measure the runs */
+
+        let baseline = self.align == Align::Baseline && self.axis == Axis::Horizontal;
+
+        let mut major = 0.0;
+        let mut ascents = Vec::with_capacity(state.runs.len());
+
+        for i in 0..state.runs.len() {
+            let run = state.runs[i].clone();
+            flex_run(self, cx, content, state, data, run.clone(), max_major);
+
+            let total_gap = major_gap * (run.len() as f32 - 1.0).max(0.0);
+            let run_major = state.majors[run.clone()].iter().sum::<f32>() + total_gap;
+            major = f32::max(major, run_major);
+
+            if baseline {
+                let (ascent, descent) = baseline_extents(self.axis, content, run);
+                state.run_minors.push(ascent + descent);
+                ascents.push(ascent);
+            } else {
+                let run_minor = run
+                    .map(|i| self.axis.minor(content[i].size()))
+                    .fold(0.0, f32::max);
+
+                state.run_minors.push(run_minor);
+            }
+        }
+
+        /* position the content */
 
         let total_minor_gap = minor_gap * (state.runs.len() as f32 - 1.0);
 
         let major = f32::clamp(major, min_major, max_major);
         let minor = f32::clamp(state.minor() + total_minor_gap, min_minor, max_minor);
 
+        let size = self.axis.pack(major, minor);
+        let direction = cx.direction();
+
+        let mut first_baseline = None;
+
         for (i, run_position) in (self.justify_cross)
             .layout(&state.run_minors, minor, minor_gap)
             .enumerate()
@@ -315,13 +346,25 @@ impl<T, V: ViewSeq<T>> View<T> for Wrap<V> {
                 .zip(run)
             {
                 let child_minor = self.axis.minor(content[j].size());
-                let child_align = self.align.align(run_minor, child_minor);
+
+                let child_align = match baseline {
+                    true => ascents[i] - content[j].baseline().unwrap_or(child_minor),
+                    false => self.align.align(run_minor, child_minor),
+                };
+
                 let offset = self.axis.pack(child_position, run_position + child_align);
+                let offset = direction.mirror(offset, content[j].size(), size);
                 content[j].translate(offset);
+
+                if first_baseline.is_none() {
+                    first_baseline = content[j].parent_baseline();
+                }
             }
         }
 
-        self.axis.pack(major, minor)
+        cx.set_baseline(first_baseline);
+
+        size
     }
 
     fn draw(&mut self, (_, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
@@ -330,3 +373,128 @@ impl<T, V: ViewSeq<T>> View<T> for Wrap<V> {
         }
     }
 }
+
+/// Grow or shrink the flexible content of a run, so that it fits `max_major` exactly.
+fn flex_run<T, V: ViewSeq<T>>(
+    wrap: &mut Wrap<V>,
+    cx: &mut LayoutCx,
+    content: &mut SeqState<T, V>,
+    state: &mut WrapState,
+    data: &mut T,
+    run: Range<usize>,
+    max_major: f32,
+) {
+    if !max_major.is_finite() {
+        return;
+    }
+
+    let major_gap = wrap.axis.major((wrap.row_gap, wrap.column_gap));
+    let total_gap = major_gap * (run.len() as f32 - 1.0).max(0.0);
+    let run_major = state.majors[run.clone()].iter().sum::<f32>() + total_gap;
+
+    let mut grow_sum = 0.0;
+    let mut shrink_sum = 0.0;
+
+    for i in run.clone() {
+        if let Some(flex) = content[i].get_property::<Flex>() {
+            grow_sum += flex.amount;
+            shrink_sum += flex.amount * state.majors[i];
+        }
+    }
+
+    let remaining = max_major - run_major;
+
+    // grow the content when there is space left, and shrink it when the run overflows,
+    // shrinking is weighted by the size of the content so small views don't collapse first
+    let grow = remaining > 0.0 && grow_sum > 0.0;
+    let shrink = remaining < 0.0 && shrink_sum > 0.0;
+
+    if !grow && !shrink {
+        return;
+    }
+
+    for i in run {
+        let Some(flex) = content[i].get_property::<Flex>().copied() else {
+            continue;
+        };
+
+        let natural = state.majors[i];
+
+        let target = match grow {
+            true => natural + remaining * flex.amount / grow_sum,
+            false => f32::max(
+                natural + remaining * flex.amount * natural / shrink_sum,
+                0.0,
+            ),
+        };
+
+        let min_major = if flex.is_tight { 0.0 } else { target };
+
+        let space = Space::new(
+            wrap.axis.pack(min_major, 0.0),
+            wrap.axis.pack(target, f32::INFINITY),
+        );
+
+        let size = wrap.content.layout_nth(i, content, cx, data, space);
+        state.majors[i] = wrap.axis.major(size);
+    }
+}
+
+/// Get the largest distances above and below the baseline in a run.
+fn baseline_extents<T, V: ViewSeq<T>>(
+    axis: Axis,
+    content: &SeqState<T, V>,
+    run: Range<usize>,
+) -> (f32, f32) {
+    let mut ascent = 0.0;
+    let mut descent = 0.0;
+
+    for i in run {
+        let minor = axis.minor(content[i].size());
+        let baseline = content[i].baseline().unwrap_or(minor);
+
+        ascent = f32::max(ascent, baseline);
+        descent = f32::max(descent, minor - baseline);
+    }
+
+    (ascent, descent)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        layout::{Rect, Size, Space},
+        views::{
+            flex, hwrap, min_size,
+            testing::{save_layout, test_layout},
+        },
+    };
+
+    #[test]
+    fn flex_grow() {
+        let grow = save_layout(flex(min_size([20.0, 10.0], ())), "grow");
+        let fixed = save_layout(min_size([30.0, 10.0], ()), "fixed");
+        let next = save_layout(min_size([60.0, 10.0], ()), "next");
+
+        let mut view = hwrap((grow, fixed, next));
+        let space = Space::new(Size::ZERO, Size::new(100.0, f32::INFINITY));
+
+        let layouts = test_layout(&mut view, &mut (), space);
+
+        assert_eq!(layouts["grow"], Rect::from([0.0, 0.0, 70.0, 10.0]));
+        assert_eq!(layouts["fixed"], Rect::from([70.0, 0.0, 100.0, 10.0]));
+        assert_eq!(layouts["next"], Rect::from([0.0, 10.0, 60.0, 20.0]));
+    }
+
+    #[test]
+    fn flex_shrink() {
+        let shrink = save_layout(flex(min_size([150.0, 10.0], ())), "shrink");
+
+        let mut view = hwrap((shrink,));
+        let space = Space::new(Size::ZERO, Size::new(100.0, f32::INFINITY));
+
+        let layouts = test_layout(&mut view, &mut (), space);
+
+        assert_eq!(layouts["shrink"], Rect::from([0.0, 0.0, 100.0, 10.0]));
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     canvas::Color,
     event::{PointerButton, PointerId},
     image::Image,
-    layout::{Point, Size, Vector},
+    layout::{Point, Rect, Size, Vector},
     view::ViewId,
 };
 
@@ -95,6 +95,11 @@ pub struct Window {
 
     /// The color of the window.
     pub color: Option<Color>,
+
+    /// The area of the text cursor, used to position the input method candidate window.
+    ///
+    /// This is in logical coordinates relative to the window, `None` disables the input method.
+    pub ime_area: Option<Rect>,
 }
 
 impl Default for Window {
@@ -119,6 +124,7 @@ impl Window {
             maximized: false,
             visible: true,
             color: None,
+            ime_area: None,
         }
     }
 
@@ -302,6 +308,7 @@ impl Window {
             WindowUpdate::Maximized(self.maximized),
             WindowUpdate::Visible(self.visible),
             WindowUpdate::Color(self.color),
+            WindowUpdate::ImeArea(self.ime_area),
         ]
     }
 
@@ -317,6 +324,7 @@ impl Window {
             maximized: self.maximized,
             visible: self.visible,
             color: self.color,
+            ime_area: self.ime_area,
         }
     }
 }
@@ -353,6 +361,9 @@ pub enum WindowUpdate {
 
     /// Set the cursor of the window.
     Cursor(Cursor),
+
+    /// Set the input method area of the window.
+    ImeArea(Option<Rect>),
 }
 
 /// The state of a window.
@@ -384,6 +395,9 @@ pub struct WindowSnapshot {
 
     /// The color of the window.
     pub color: Option<Color>,
+
+    /// The input method area of the window.
+    pub ime_area: Option<Rect>,
 }
 
 impl WindowSnapshot {
@@ -427,6 +441,10 @@ impl WindowSnapshot {
             updates.push(WindowUpdate::Color(window.color));
         }
 
+        if self.ime_area != window.ime_area {
+            updates.push(WindowUpdate::ImeArea(window.ime_area));
+        }
+
         updates
     }
 }
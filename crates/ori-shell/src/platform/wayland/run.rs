@@ -5,7 +5,7 @@ use ori_core::{
     clipboard::{Clipboard, ClipboardBackend},
     command::CommandWaker,
     event::{Code, Key, PointerButton, PointerId},
-    layout::{Point, Vector},
+    layout::{Point, Rect, Vector},
     window::{Cursor, Window, WindowId, WindowUpdate},
 };
 use ori_glow::GlowRenderer;
@@ -62,6 +62,16 @@ use wayland_csd_frame::{
     DecorationsFrame, FrameAction, FrameClick, ResizeEdge, WindowState as CsdWindowState,
 };
 use wayland_egl::WlEglSurface;
+use wayland_protocols::wp::{
+    pointer_gestures::zv1::client::{
+        zwp_pointer_gesture_pinch_v1::{Event as PinchEvent, ZwpPointerGesturePinchV1},
+        zwp_pointer_gestures_v1::ZwpPointerGesturesV1,
+    },
+    text_input::zv3::client::{
+        zwp_text_input_manager_v3::ZwpTextInputManagerV3,
+        zwp_text_input_v3::{ContentHint, ContentPurpose, Event as TextInputEvent, ZwpTextInputV3},
+    },
+};
 use xkeysym::Keysym;
 
 use crate::platform::linux::{
@@ -105,6 +115,12 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), WaylandError> {
     let seat = SeatState::new(&globals, &qhandle);
     let shm = Shm::bind(&globals, &qhandle).unwrap();
 
+    // input methods are optional, not every compositor supports them
+    let text_input_manager = globals.bind(&qhandle, 1..=1, ()).ok();
+
+    // and so are pointer gestures
+    let pointer_gestures = globals.bind(&qhandle, 1..=1, ()).ok();
+
     let output = OutputState::new(&globals, &qhandle);
     let registry = RegistryState::new(&globals);
 
@@ -131,12 +147,15 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), WaylandError> {
         xdg_shell,
         seat,
         shm,
+        text_input_manager,
+        pointer_gestures,
 
         output,
         registry,
 
         pointers: Vec::new(),
         keyboards: Vec::new(),
+        text_inputs: Vec::new(),
 
         events: Vec::new(),
         windows: Vec::new(),
@@ -312,6 +331,15 @@ fn handle_app_request<T>(
                     window.cursor_icon = cursor_icon(cursor);
                     window.set_cursor_icon = true;
                 }
+                WindowUpdate::ImeArea(area) => {
+                    window.ime_area = area;
+
+                    for text_input in &mut state.text_inputs {
+                        if text_input.window == Some(window.id) {
+                            text_input.update(area);
+                        }
+                    }
+                }
             }
         }
 
@@ -410,6 +438,7 @@ fn open_window<T>(
         resizable: window.resizable,
         decorated: window.decorated,
         last_configure: None,
+        ime_area: window.ime_area,
 
         pointers: Vec::new(),
         keyboards: Vec::new(),
@@ -586,6 +615,15 @@ fn handle_event<T>(
             app.pointer_scrolled(data, id, pointer_id, delta);
         }
 
+        Event::PointerPinch {
+            id,
+            object_id,
+            scale,
+        } => {
+            let pointer_id = PointerId::from_hash(&object_id);
+            app.pointer_pinched(data, id, pointer_id, scale);
+        }
+
         Event::Keyboard {
             id,
             key,
@@ -599,6 +637,14 @@ fn handle_event<T>(
         Event::Modifiers { modifiers } => {
             app.modifiers_changed(modifiers);
         }
+
+        Event::ImePreedit { id, text, cursor } => {
+            app.ime_preedit(data, id, text, cursor);
+        }
+
+        Event::ImeCommit { id, text } => {
+            app.ime_commit(data, id, text);
+        }
     }
 
     Ok(())
@@ -618,12 +664,15 @@ struct State {
     xdg_shell: XdgShell,
     seat: SeatState,
     shm: Shm,
+    text_input_manager: Option<ZwpTextInputManagerV3>,
+    pointer_gestures: Option<ZwpPointerGesturesV1>,
 
     output: OutputState,
     registry: RegistryState,
 
     pointers: Vec<PointerState>,
     keyboards: Vec<KeyboardState>,
+    text_inputs: Vec<TextInputState>,
 
     events: Vec<Event>,
     windows: Vec<WindowState>,
@@ -639,6 +688,11 @@ struct PointerState {
     seat: WlSeat,
     pointer: ThemedPointer,
     last_button_serial: u32,
+
+    // the window being pinched, and the scale of the pinch so far
+    pinch: Option<ZwpPointerGesturePinchV1>,
+    pinch_window: Option<WindowId>,
+    pinch_scale: f64,
 }
 
 #[allow(unused)]
@@ -653,6 +707,52 @@ struct KeyboardState {
     repeat_token: Option<RegistrationToken>,
 }
 
+struct TextInputState {
+    text_input: ZwpTextInputV3,
+
+    // the window the text input entered
+    window: Option<WindowId>,
+    enabled: bool,
+
+    // double buffered state, applied on `done`
+    preedit: Option<(String, Option<(usize, usize)>)>,
+    commit: Option<String>,
+}
+
+impl TextInputState {
+    // enable or disable the input method, depending on whether the window has a text cursor
+    fn update(&mut self, area: Option<Rect>) {
+        match area {
+            Some(area) => {
+                if !self.enabled {
+                    let (hint, purpose) = (ContentHint::None, ContentPurpose::Normal);
+
+                    self.text_input.enable();
+                    self.text_input.set_content_type(hint, purpose);
+                    self.enabled = true;
+                }
+
+                self.text_input.set_cursor_rectangle(
+                    area.min.x as i32,
+                    area.min.y as i32,
+                    area.width().ceil() as i32,
+                    area.height().ceil() as i32,
+                );
+            }
+            None => {
+                if !self.enabled {
+                    return;
+                }
+
+                self.text_input.disable();
+                self.enabled = false;
+            }
+        }
+
+        self.text_input.commit();
+    }
+}
+
 enum Event {
     Resized {
         id: WindowId,
@@ -693,6 +793,12 @@ enum Event {
         delta: Vector,
     },
 
+    PointerPinch {
+        id: WindowId,
+        object_id: ObjectId,
+        scale: f32,
+    },
+
     Keyboard {
         id: WindowId,
         key: Key,
@@ -704,6 +810,17 @@ enum Event {
     Modifiers {
         modifiers: ori_core::event::Modifiers,
     },
+
+    ImePreedit {
+        id: WindowId,
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
+
+    ImeCommit {
+        id: WindowId,
+        text: String,
+    },
 }
 
 #[allow(unused)]
@@ -722,6 +839,7 @@ struct WindowState {
     resizable: bool,
     decorated: bool,
     last_configure: Option<WindowConfigure>,
+    ime_area: Option<Rect>,
 
     pointers: Vec<ObjectId>,
     keyboards: Vec<ObjectId>,
@@ -1014,10 +1132,17 @@ impl SeatHandler for State {
             );
 
             if let Ok(pointer) = pointer {
+                let pinch = (self.pointer_gestures.as_ref())
+                    .map(|gestures| gestures.get_pinch_gesture(pointer.pointer(), qh, ()));
+
                 let state = PointerState {
                     seat: seat.clone(),
                     pointer,
                     last_button_serial: 0,
+
+                    pinch,
+                    pinch_window: None,
+                    pinch_scale: 1.0,
                 };
 
                 self.pointers.push(state);
@@ -1028,6 +1153,18 @@ impl SeatHandler for State {
             let keyboard = seat.get_keyboard(qh, ());
             let xkb_keyboard = XkbKeyboard::new(&self.xkb_context).unwrap();
 
+            if let Some(ref manager) = self.text_input_manager {
+                let text_input = manager.get_text_input(&seat, qh, ());
+
+                self.text_inputs.push(TextInputState {
+                    text_input,
+                    window: None,
+                    enabled: false,
+                    preedit: None,
+                    commit: None,
+                });
+            }
+
             let state = KeyboardState {
                 seat,
                 keyboard,
@@ -1051,6 +1188,10 @@ impl SeatHandler for State {
     ) {
         if capability == Capability::Pointer {
             for pointer in self.pointers.drain(..) {
+                if let Some(pinch) = pointer.pinch {
+                    pinch.destroy();
+                }
+
                 pointer.pointer.pointer().release();
             }
         }
@@ -1059,6 +1200,10 @@ impl SeatHandler for State {
             for keyboard in self.keyboards.drain(..) {
                 keyboard.keyboard.release();
             }
+
+            for text_input in self.text_inputs.drain(..) {
+                text_input.text_input.destroy();
+            }
         }
     }
 
@@ -1404,6 +1549,133 @@ impl Dispatch<WlKeyboard, ()> for State {
     }
 }
 
+impl Dispatch<ZwpTextInputManagerV3, ()> for State {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZwpTextInputManagerV3,
+        _event: <ZwpTextInputManagerV3 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwpTextInputV3, ()> for State {
+    fn event(
+        state: &mut Self,
+        proxy: &ZwpTextInputV3,
+        event: <ZwpTextInputV3 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let Some(text_input) = (state.text_inputs.iter_mut()).find(|t| t.text_input == *proxy)
+        else {
+            return;
+        };
+
+        match event {
+            TextInputEvent::Enter { surface } => {
+                if let Some(window) = window_by_surface(&mut state.windows, &surface) {
+                    text_input.window = Some(window.id);
+                    text_input.update(window.ime_area);
+                }
+            }
+            TextInputEvent::Leave { .. } => {
+                text_input.update(None);
+                text_input.window = None;
+            }
+            TextInputEvent::PreeditString {
+                text,
+                cursor_begin,
+                cursor_end,
+            } => {
+                // a negative cursor means it should be hidden
+                let cursor = match cursor_begin >= 0 && cursor_end >= 0 {
+                    true => Some((cursor_begin as usize, cursor_end as usize)),
+                    false => None,
+                };
+
+                text_input.preedit = text.map(|text| (text, cursor));
+            }
+            TextInputEvent::CommitString { text } => {
+                text_input.commit = text;
+            }
+            TextInputEvent::Done { .. } => {
+                let Some(id) = text_input.window else {
+                    return;
+                };
+
+                // the commit is applied before the new preedit, which replaces the old one
+                if let Some(text) = text_input.commit.take() {
+                    state.events.push(Event::ImeCommit { id, text });
+                }
+
+                let (text, cursor) = text_input.preedit.take().unwrap_or_default();
+                state.events.push(Event::ImePreedit { id, text, cursor });
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Dispatch<ZwpPointerGesturesV1, ()> for State {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZwpPointerGesturesV1,
+        _event: <ZwpPointerGesturesV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwpPointerGesturePinchV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        proxy: &ZwpPointerGesturePinchV1,
+        event: <ZwpPointerGesturePinchV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let Some(pointer) = (state.pointers.iter_mut()).find(|p| p.pinch.as_ref() == Some(proxy))
+        else {
+            return;
+        };
+
+        match event {
+            PinchEvent::Begin { surface, .. } => {
+                let window = window_by_surface(&mut state.windows, &surface);
+                pointer.pinch_window = window.map(|window| window.id);
+                pointer.pinch_scale = 1.0;
+            }
+            PinchEvent::Update { scale, .. } => {
+                let Some(id) = pointer.pinch_window else {
+                    return;
+                };
+
+                // the scale is relative to the start of the pinch, but the event wants the change
+                if scale > 0.0 && pointer.pinch_scale > 0.0 {
+                    state.events.push(Event::PointerPinch {
+                        id,
+                        object_id: pointer.pointer.pointer().id(),
+                        scale: (scale / pointer.pinch_scale) as f32,
+                    });
+
+                    pointer.pinch_scale = scale;
+                }
+            }
+            PinchEvent::End { .. } => {
+                pointer.pinch_window = None;
+            }
+            _ => {}
+        }
+    }
+}
+
 impl ProvidesRegistryState for State {
     fn registry(&mut self) -> &mut RegistryState {
         &mut self.registry
@@ -0,0 +1,432 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Arc,
+};
+
+use ori_core::{layout::Rect, locale::Locale};
+use tracing::warn;
+use x11rb::{
+    protocol::{xproto::KeyPressEvent, Event as XEvent},
+    xcb_ffi::XCBConnection,
+};
+use xim::{
+    x11rb::X11rbClient, AHashMap, AttributeName, CaretDirection, CaretStyle, Client, ClientError,
+    ClientHandler, Feedback, ForwardEventFlag, InputStyle, Point, PreeditDrawStatus,
+};
+
+type XimClient = X11rbClient<Arc<XCBConnection>>;
+
+pub enum ImeEvent {
+    Preedit {
+        window: u32,
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
+    Commit {
+        window: u32,
+        text: String,
+    },
+    // a key event the input method didn't handle
+    Key(KeyPressEvent),
+}
+
+/// An input method connection, using the XIM protocol.
+pub struct X11Ime {
+    client: XimClient,
+    handler: ImeHandler,
+}
+
+impl X11Ime {
+    /// Connect to the input method named by `XMODIFIERS`, if there is one.
+    pub fn new(conn: &Arc<XCBConnection>, screen: usize) -> Option<Self> {
+        let client = match X11rbClient::init(conn.clone(), screen, None) {
+            Ok(client) => client,
+            Err(err) => {
+                warn!("Failed to connect to the input method: {}", err);
+                return None;
+            }
+        };
+
+        Some(Self {
+            client,
+            handler: ImeHandler::default(),
+        })
+    }
+
+    /// Create an input context for a window.
+    pub fn add_window(&mut self, window: u32) {
+        match self.handler.input_method {
+            Some(input_method) => {
+                let result = self
+                    .handler
+                    .create_ic(&mut self.client, input_method, window);
+                self.log(result);
+            }
+            None => self.handler.pending.push(window),
+        }
+    }
+
+    /// Destroy the input context of a window.
+    pub fn remove_window(&mut self, window: u32) {
+        self.handler.pending.retain(|&w| w != window);
+        self.handler.focused.remove(&window);
+
+        let input_method = self.handler.input_method;
+        let input_context = self.handler.contexts.remove(&window);
+
+        if let (Some(input_method), Some(input_context)) = (input_method, input_context) {
+            self.handler.preedits.remove(&input_context);
+
+            let result = self.client.destroy_ic(input_method, input_context);
+            self.log(result);
+        }
+    }
+
+    /// Focus or unfocus the input context of a window, and move the candidate window to `area`.
+    ///
+    /// `area` is in physical pixels relative to the window.
+    pub fn set_area(&mut self, window: u32, area: Option<Rect>) {
+        let Some(input_method) = self.handler.input_method else {
+            return;
+        };
+
+        let Some(&input_context) = self.handler.contexts.get(&window) else {
+            return;
+        };
+
+        let result = match area {
+            Some(area) => {
+                let spot = Point {
+                    x: area.min.x as i16,
+                    y: area.max.y as i16,
+                };
+
+                let attributes = (self.client.build_ic_attributes())
+                    .nested_list(AttributeName::PreeditAttributes, |builder| {
+                        builder.push(AttributeName::SpotLocation, spot);
+                    })
+                    .build();
+
+                let focus = match self.handler.focused.insert(window) {
+                    true => self.client.set_focus(input_method, input_context),
+                    false => Ok(()),
+                };
+
+                focus.and_then(|_| {
+                    (self.client).set_ic_values(input_method, input_context, attributes)
+                })
+            }
+            None => match self.handler.focused.remove(&window) {
+                true => self.client.unset_focus(input_method, input_context),
+                false => Ok(()),
+            },
+        };
+
+        self.log(result);
+    }
+
+    /// Handle the XIM messages in `event`, returns `true` if the event was consumed.
+    pub fn filter_event(&mut self, event: &XEvent) -> bool {
+        match self.client.filter_event(event, &mut self.handler) {
+            Ok(consumed) => consumed,
+            Err(err) => {
+                warn!("Input method error: {}", err);
+                false
+            }
+        }
+    }
+
+    /// Send a key event to the input method of the window, returns `true` if it was sent.
+    ///
+    /// Keys the input method doesn't use are sent back, and returned by [`X11Ime::take_events`].
+    pub fn forward_key(&mut self, event: &KeyPressEvent) -> bool {
+        let Some(input_method) = self.handler.input_method else {
+            return false;
+        };
+
+        if !self.handler.focused.contains(&event.event) {
+            return false;
+        }
+
+        let Some(&input_context) = self.handler.contexts.get(&event.event) else {
+            return false;
+        };
+
+        let flag = ForwardEventFlag::empty();
+
+        match (self.client).forward_event(input_method, input_context, flag, event) {
+            Ok(()) => true,
+            Err(err) => {
+                warn!("Input method error: {}", err);
+                false
+            }
+        }
+    }
+
+    /// Take the events received from the input method.
+    pub fn take_events(&mut self) -> Vec<ImeEvent> {
+        std::mem::take(&mut self.handler.events)
+    }
+
+    fn log(&self, result: Result<(), ClientError>) {
+        if let Err(err) = result {
+            warn!("Input method error: {}", err);
+        }
+    }
+}
+
+#[derive(Default)]
+struct ImeHandler {
+    input_method: Option<u16>,
+
+    // windows waiting for the input method to open
+    pending: Vec<u32>,
+
+    // windows with a create_ic request in flight, the replies come in order
+    creating: VecDeque<u32>,
+    contexts: HashMap<u32, u16>,
+    focused: HashSet<u32>,
+
+    // the preedit text of each input context, edited in place by preedit_draw
+    preedits: HashMap<u16, Vec<char>>,
+    events: Vec<ImeEvent>,
+}
+
+impl ImeHandler {
+    fn create_ic(
+        &mut self,
+        client: &mut XimClient,
+        input_method: u16,
+        window: u32,
+    ) -> Result<(), ClientError> {
+        let attributes = (client.build_ic_attributes())
+            .push(
+                AttributeName::InputStyle,
+                InputStyle::PREEDIT_CALLBACKS | InputStyle::STATUS_NOTHING,
+            )
+            .push(AttributeName::ClientWindow, window)
+            .push(AttributeName::FocusWindow, window)
+            .build();
+
+        self.creating.push_back(window);
+        client.create_ic(input_method, attributes)
+    }
+
+    fn window(&self, input_context: u16) -> Option<u32> {
+        let mut contexts = self.contexts.iter();
+        contexts.find_map(|(&window, &ic)| (ic == input_context).then_some(window))
+    }
+}
+
+impl ClientHandler<XimClient> for ImeHandler {
+    fn handle_connect(&mut self, client: &mut XimClient) -> Result<(), ClientError> {
+        let locale = Locale::system("LC_CTYPE");
+        let locale = locale.map_or_else(|| String::from("en_US"), |locale| locale.to_string());
+
+        client.open(&locale)
+    }
+
+    fn handle_disconnect(&mut self) {
+        self.input_method = None;
+        self.creating.clear();
+        self.preedits.clear();
+
+        // the windows get new input contexts if the input method comes back
+        self.pending
+            .extend(self.contexts.drain().map(|(window, _)| window));
+        self.focused.clear();
+    }
+
+    fn handle_open(
+        &mut self,
+        client: &mut XimClient,
+        input_method_id: u16,
+    ) -> Result<(), ClientError> {
+        self.input_method = Some(input_method_id);
+        client.get_im_values(input_method_id, &[AttributeName::QueryInputStyle])
+    }
+
+    fn handle_close(
+        &mut self,
+        _client: &mut XimClient,
+        _input_method_id: u16,
+    ) -> Result<(), ClientError> {
+        self.handle_disconnect();
+        Ok(())
+    }
+
+    fn handle_query_extension(
+        &mut self,
+        _client: &mut XimClient,
+        _extensions: &[xim::Extension],
+    ) -> Result<(), ClientError> {
+        Ok(())
+    }
+
+    fn handle_get_im_values(
+        &mut self,
+        client: &mut XimClient,
+        input_method_id: u16,
+        _attributes: AHashMap<AttributeName, Vec<u8>>,
+    ) -> Result<(), ClientError> {
+        for window in std::mem::take(&mut self.pending) {
+            self.create_ic(client, input_method_id, window)?;
+        }
+
+        Ok(())
+    }
+
+    fn handle_set_ic_values(
+        &mut self,
+        _client: &mut XimClient,
+        _input_method_id: u16,
+        _input_context_id: u16,
+    ) -> Result<(), ClientError> {
+        Ok(())
+    }
+
+    fn handle_create_ic(
+        &mut self,
+        _client: &mut XimClient,
+        _input_method_id: u16,
+        input_context_id: u16,
+    ) -> Result<(), ClientError> {
+        if let Some(window) = self.creating.pop_front() {
+            self.contexts.insert(window, input_context_id);
+        }
+
+        Ok(())
+    }
+
+    fn handle_destroy_ic(
+        &mut self,
+        _client: &mut XimClient,
+        _input_method_id: u16,
+        input_context_id: u16,
+    ) -> Result<(), ClientError> {
+        self.contexts.retain(|_, &mut ic| ic != input_context_id);
+        self.preedits.remove(&input_context_id);
+
+        Ok(())
+    }
+
+    fn handle_commit(
+        &mut self,
+        _client: &mut XimClient,
+        _input_method_id: u16,
+        input_context_id: u16,
+        text: &str,
+    ) -> Result<(), ClientError> {
+        if let Some(window) = self.window(input_context_id) {
+            let text = text.to_string();
+            self.events.push(ImeEvent::Commit { window, text });
+        }
+
+        Ok(())
+    }
+
+    fn handle_forward_event(
+        &mut self,
+        _client: &mut XimClient,
+        _input_method_id: u16,
+        _input_context_id: u16,
+        _flag: ForwardEventFlag,
+        xev: KeyPressEvent,
+    ) -> Result<(), ClientError> {
+        self.events.push(ImeEvent::Key(xev));
+        Ok(())
+    }
+
+    fn handle_set_event_mask(
+        &mut self,
+        _client: &mut XimClient,
+        _input_method_id: u16,
+        _input_context_id: u16,
+        _forward_event_mask: u32,
+        _synchronous_event_mask: u32,
+    ) -> Result<(), ClientError> {
+        Ok(())
+    }
+
+    fn handle_preedit_start(
+        &mut self,
+        _client: &mut XimClient,
+        _input_method_id: u16,
+        input_context_id: u16,
+    ) -> Result<(), ClientError> {
+        self.preedits.insert(input_context_id, Vec::new());
+        Ok(())
+    }
+
+    fn handle_preedit_draw(
+        &mut self,
+        _client: &mut XimClient,
+        _input_method_id: u16,
+        input_context_id: u16,
+        caret: i32,
+        chg_first: i32,
+        chg_len: i32,
+        status: PreeditDrawStatus,
+        preedit_string: &str,
+        _feedbacks: Vec<Feedback>,
+    ) -> Result<(), ClientError> {
+        let Some(window) = self.window(input_context_id) else {
+            return Ok(());
+        };
+
+        // replace `chg_len` characters at `chg_first` with the new string
+        let preedit = self.preedits.entry(input_context_id).or_default();
+        let start = usize::min(chg_first.max(0) as usize, preedit.len());
+        let end = usize::min(start + chg_len.max(0) as usize, preedit.len());
+
+        let chars = match status.contains(PreeditDrawStatus::NO_STRING) {
+            true => Vec::new(),
+            false => preedit_string.chars().collect(),
+        };
+
+        preedit.splice(start..end, chars);
+
+        // the caret is counted in characters, but the event uses bytes
+        let caret = usize::min(caret.max(0) as usize, preedit.len());
+        let caret = preedit[..caret].iter().map(|c| c.len_utf8()).sum();
+
+        self.events.push(ImeEvent::Preedit {
+            window,
+            text: preedit.iter().collect(),
+            cursor: Some((caret, caret)),
+        });
+
+        Ok(())
+    }
+
+    fn handle_preedit_caret(
+        &mut self,
+        _client: &mut XimClient,
+        _input_method_id: u16,
+        _input_context_id: u16,
+        _position: &mut i32,
+        _direction: CaretDirection,
+        _style: CaretStyle,
+    ) -> Result<(), ClientError> {
+        Ok(())
+    }
+
+    fn handle_preedit_done(
+        &mut self,
+        _client: &mut XimClient,
+        _input_method_id: u16,
+        input_context_id: u16,
+    ) -> Result<(), ClientError> {
+        self.preedits.remove(&input_context_id);
+
+        if let Some(window) = self.window(input_context_id) {
+            self.events.push(ImeEvent::Preedit {
+                window,
+                text: String::new(),
+                cursor: None,
+            });
+        }
+
+        Ok(())
+    }
+}
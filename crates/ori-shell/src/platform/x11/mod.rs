@@ -2,6 +2,7 @@
 
 mod clipboard;
 mod error;
+mod ime;
 mod run;
 
 pub use error::X11Error;
@@ -15,7 +15,7 @@ use ori_core::{
     command::CommandWaker,
     event::{Code, Modifiers, PointerButton, PointerId},
     image::Image,
-    layout::{Point, Vector},
+    layout::{Point, Rect, Vector},
     window::{Cursor, Window, WindowId, WindowUpdate},
 };
 use ori_glow::GlowRenderer;
@@ -36,8 +36,8 @@ use x11rb::{
         xproto::{
             AtomEnum, ChangeWindowAttributesAux, ClientMessageData, ClientMessageEvent,
             ColormapAlloc, ConfigureWindowAux, ConnectionExt as _, CreateWindowAux,
-            Cursor as XCursor, EventMask, ModMask, PropMode, VisualClass, Visualid, WindowClass,
-            CLIENT_MESSAGE_EVENT,
+            Cursor as XCursor, EventMask, KeyPressEvent, ModMask, PropMode, VisualClass, Visualid,
+            WindowClass, CLIENT_MESSAGE_EVENT, KEY_PRESS_EVENT,
         },
         Event as XEvent,
     },
@@ -53,7 +53,11 @@ use crate::platform::linux::{
     LIB_GL,
 };
 
-use super::{clipboard::X11ClipboardServer, X11Error};
+use super::{
+    clipboard::X11ClipboardServer,
+    ime::{ImeEvent, X11Ime},
+    X11Error,
+};
 
 atom_manager! {
     pub Atoms: AtomsCookie {
@@ -405,6 +409,8 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), X11Error> {
     let xkb_context = unsafe { XkbContext::from_xcb(xcb_conn).unwrap() };
     let core_keyboard = unsafe { XkbKeyboard::new_xcb(&xkb_context, xcb_conn).unwrap() };
 
+    let ime = X11Ime::new(&conn, screen_num);
+
     let mut app = app.build(waker);
     app.add_context(Clipboard::new(Box::new(clipboard)));
 
@@ -425,6 +431,7 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), X11Error> {
         egl_context,
         xkb_context,
         core_keyboard,
+        ime,
     };
 
     state.app.init(data);
@@ -483,6 +490,7 @@ struct X11App<T> {
     egl_context: EglContext,
     xkb_context: XkbContext,
     core_keyboard: XkbKeyboard,
+    ime: Option<X11Ime>,
 }
 
 impl<T> X11App<T> {
@@ -637,6 +645,10 @@ impl<T> X11App<T> {
 
         self.conn.flush()?;
 
+        if let Some(ref mut ime) = self.ime {
+            ime.add_window(win_id);
+        }
+
         self.windows.push(x11_window);
         self.app.add_window(data, ui, window);
 
@@ -647,6 +659,10 @@ impl<T> X11App<T> {
         if let Some(index) = self.windows.iter().position(|w| w.ori_id == id) {
             let window = self.windows.remove(index);
 
+            if let Some(ref mut ime) = self.ime {
+                ime.remove_window(window.x11_id);
+            }
+
             self.conn.destroy_window(window.x11_id)?;
             self.app.remove_window(id);
         }
@@ -806,6 +822,15 @@ impl<T> X11App<T> {
                         let x_window = window.x11_id;
                         self.set_cursor(x_window, cursor)?;
                     }
+                    WindowUpdate::ImeArea(area) => {
+                        if let Some(ref mut ime) = self.ime {
+                            // the spot location is in physical pixels
+                            let scale = window.scale_factor;
+                            let area = area.map(|a| Rect::new(a.min * scale, a.max * scale));
+
+                            ime.set_area(window.x11_id, area);
+                        }
+                    }
                 }
             }
             AppRequest::Quit => self.running = false,
@@ -815,6 +840,12 @@ impl<T> X11App<T> {
     }
 
     fn handle_event(&mut self, data: &mut T, event: XEvent) -> Result<(), X11Error> {
+        if let Some(ref mut ime) = self.ime {
+            if ime.filter_event(&event) {
+                return self.handle_ime_events(data);
+            }
+        }
+
         match event {
             XEvent::Expose(event) => {
                 if let Some(index) = self.get_window_x11(event.window) {
@@ -931,46 +962,74 @@ impl<T> X11App<T> {
 
                 self.app.modifiers_changed(modifiers);
             }
-            XEvent::KeyPress(event) => {
-                if let Some(index) = self.get_window_x11(event.event) {
-                    let keymap = self.core_keyboard.keymap().unwrap();
-                    let state = self.core_keyboard.state().unwrap();
-
-                    let layout = state.layout();
-                    let code = Code::from_linux_scancode(event.detail - 8);
-                    let keysym_raw = keymap.first_keysym(layout, event.detail as _).unwrap();
-                    let keysym = state.get_one_sym(event.detail as _);
-
-                    let key = self.core_keyboard.keysym_to_key(keysym_raw);
-                    let text = self.core_keyboard.keysym_to_utf8(keysym);
-
-                    let id = self.windows[index].ori_id;
-                    (self.app).keyboard_key(data, id, key, code, text, true);
+            // while an input method is active, keys go through it first
+            XEvent::KeyPress(event) | XEvent::KeyRelease(event) => {
+                if let Some(ref mut ime) = self.ime {
+                    if ime.forward_key(&event) {
+                        return Ok(());
+                    }
                 }
+
+                self.key_event(data, event);
             }
-            XEvent::KeyRelease(event) => {
-                if let Some(index) = self.get_window_x11(event.event) {
-                    let keymap = self.core_keyboard.keymap().unwrap();
-                    let state = self.core_keyboard.state().unwrap();
+            _ => {}
+        }
 
-                    let layout = state.layout();
-                    let code = Code::from_linux_scancode(event.detail - 8);
-                    let keysym_raw = keymap.first_keysym(layout, event.detail as _).unwrap();
-                    let keysym = state.get_one_sym(event.detail as _);
+        Ok(())
+    }
 
-                    let key = self.core_keyboard.keysym_to_key(keysym_raw);
-                    let text = self.core_keyboard.keysym_to_utf8(keysym);
+    fn handle_ime_events(&mut self, data: &mut T) -> Result<(), X11Error> {
+        let events = match self.ime {
+            Some(ref mut ime) => ime.take_events(),
+            None => return Ok(()),
+        };
 
-                    let id = self.windows[index].ori_id;
-                    (self.app).keyboard_key(data, id, key, code, text, false);
+        for event in events {
+            match event {
+                ImeEvent::Preedit {
+                    window,
+                    text,
+                    cursor,
+                } => {
+                    if let Some(index) = self.get_window_x11(window) {
+                        let id = self.windows[index].ori_id;
+                        self.app.ime_preedit(data, id, text, cursor);
+                    }
                 }
+                ImeEvent::Commit { window, text } => {
+                    if let Some(index) = self.get_window_x11(window) {
+                        let id = self.windows[index].ori_id;
+                        self.app.ime_commit(data, id, text);
+                    }
+                }
+                ImeEvent::Key(event) => self.key_event(data, event),
             }
-            _ => {}
         }
 
         Ok(())
     }
 
+    fn key_event(&mut self, data: &mut T, event: KeyPressEvent) {
+        if let Some(index) = self.get_window_x11(event.event) {
+            let keymap = self.core_keyboard.keymap().unwrap();
+            let state = self.core_keyboard.state().unwrap();
+
+            let layout = state.layout();
+            let code = Code::from_linux_scancode(event.detail - 8);
+            let keysym_raw = keymap.first_keysym(layout, event.detail as _).unwrap();
+            let keysym = state.get_one_sym(event.detail as _);
+
+            let key = self.core_keyboard.keysym_to_key(keysym_raw);
+            let text = self.core_keyboard.keysym_to_utf8(keysym);
+
+            // press and release events share a type, the response type tells them apart
+            let pressed = event.response_type & 0x7f == KEY_PRESS_EVENT;
+
+            let id = self.windows[index].ori_id;
+            (self.app).keyboard_key(data, id, key, code, text, pressed);
+        }
+    }
+
     fn pointer_button(&mut self, data: &mut T, id: WindowId, code: u8, pressed: bool) {
         let pointer_id = PointerId::from_hash(&0);
 
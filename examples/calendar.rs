@@ -0,0 +1,65 @@
+use ori::prelude::*;
+
+struct Data {
+    stay: DateSelection,
+    arrival: Option<Date>,
+    time: Option<Time>,
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Self {
+            stay: DateSelection::None,
+            arrival: None,
+            time: Some(Time::new(12, 0).unwrap()),
+        }
+    }
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let today = Date::today();
+
+    // weekends are fully booked
+    let stay = calendar()
+        .selection(data.stay)
+        .range(true)
+        .min(today)
+        .disabled(|_, date: Date| matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday))
+        .on_select(|cx, data: &mut Data, selection| {
+            data.stay = selection;
+            cx.rebuild();
+        });
+
+    let arrival = date_picker()
+        .selection(data.arrival)
+        .min(today)
+        .max(today.add_months(3))
+        .on_select(|cx, data: &mut Data, selection| {
+            data.arrival = selection.start();
+            cx.rebuild();
+        });
+
+    let time = time_picker()
+        .time(data.time)
+        .minute_step(15)
+        .min(Time::new(8, 0).unwrap())
+        .max(Time::new(20, 0).unwrap())
+        .on_change(|cx, data: &mut Data, time| {
+            data.time = Some(time);
+            cx.rebuild();
+        });
+
+    let summary = match (data.stay.start(), data.stay.end()) {
+        (Some(start), Some(end)) => format!("Staying from {} to {}", start, end),
+        _ => String::from("Choose the days of your stay"),
+    };
+
+    center(vstack![stay, text(summary), hstack![arrival, time].gap(8.0)].gap(16.0))
+}
+
+fn main() {
+    let window = Window::new().title("Calendar (examples/calendar.rs)");
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut Data::default()).unwrap();
+}
@@ -0,0 +1,80 @@
+use ori::prelude::*;
+
+const MONTHS: [&str; 6] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun"];
+
+struct Data {
+    seed: u32,
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Self { seed: 1 }
+    }
+}
+
+impl Data {
+    // a small random number generator, so every shuffle gives new values
+    fn values(&self, offset: u32, count: usize) -> Vec<f32> {
+        let mut state = self.seed.wrapping_mul(747796405).wrapping_add(offset);
+
+        (0..count)
+            .map(|_| {
+                state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+                (state >> 16) as f32 / 65536.0 * 100.0
+            })
+            .collect()
+    }
+}
+
+fn sales(data: &mut Data) -> impl View<Data> {
+    let chart = bar_chart([
+        Series::values("North", data.values(1, MONTHS.len())),
+        Series::values("South", data.values(2, MONTHS.len())),
+    ]);
+
+    chart.categories(MONTHS)
+}
+
+fn revenue(data: &mut Data) -> impl View<Data> {
+    area_chart([Series::values("Revenue", data.values(3, MONTHS.len()))]).categories(MONTHS)
+}
+
+// a hundred thousand points, decimated to the width of the chart
+fn signal(data: &mut Data) -> impl View<Data> {
+    let phase = data.seed as f32;
+
+    let points = (0..100_000).map(|i| {
+        let x = i as f32 / 1000.0;
+        let y = f32::sin(x + phase) + f32::sin(x * 13.0) * 0.2;
+        Point::new(x, y)
+    });
+
+    line_chart([Series::new("Signal", points)])
+}
+
+fn shares(data: &mut Data) -> impl View<Data> {
+    let values = data.values(4, 4);
+    let names = ["Desktop", "Mobile", "Tablet", "Other"];
+
+    donut_chart(names.into_iter().zip(values))
+}
+
+fn app(data: &mut Data) -> impl View<Data> {
+    let shuffle = on_click(button(text("Shuffle")).fancy(4.0), |cx, data: &mut Data| {
+        data.seed += 1;
+        cx.rebuild();
+    });
+
+    let top = hstack![flex(sales(data)), flex(revenue(data))].gap(16.0);
+    let bottom = hstack![flex(signal(data)), flex(shares(data))].gap(16.0);
+
+    pad(16.0, vstack![shuffle, flex(top), flex(bottom)].gap(16.0))
+}
+
+fn main() {
+    let window = Window::new().title("Chart (examples/chart.rs)");
+
+    let app = App::build().window(window, app);
+
+    ori::run(app, &mut Data::default()).unwrap();
+}
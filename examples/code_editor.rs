@@ -0,0 +1,86 @@
+use ori::prelude::*;
+
+const RUST: &str = r#"use std::collections::HashMap;
+
+/// Count the words in a text.
+fn count_words(text: &str) -> HashMap<&str, usize> {
+    let mut counts = HashMap::new();
+
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+fn main() {
+    let counts = count_words("the quick brown fox jumps over the lazy dog");
+    println!("{:?}", counts.get("the"));
+}
+"#;
+
+const JSON: &str = r#"{
+    "name": "ori",
+    "version": "0.1.0",
+    "features": ["calendar", "markdown"],
+    "stable": false,
+    "downloads": 1024,
+    "license": null
+}
+"#;
+
+struct Data {
+    language: &'static str,
+    rust: String,
+    json: String,
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let language =
+        segmented(data.language, ["Rust", "JSON"]).on_change(|cx, data: &mut Data, language| {
+            data.language = *language;
+            cx.rebuild();
+        });
+
+    let (editor, text) = match data.language {
+        "Rust" => (
+            code_editor()
+                .text(&data.rust)
+                .language(Language::Rust)
+                .on_input(|cx, data: &mut Data, text| {
+                    data.rust = text;
+                    cx.rebuild();
+                }),
+            &data.rust,
+        ),
+        _ => (
+            code_editor()
+                .text(&data.json)
+                .language(Language::Json)
+                .tab_width(2u16)
+                .on_input(|cx, data: &mut Data, text| {
+                    data.json = text;
+                    cx.rebuild();
+                }),
+            &data.json,
+        ),
+    };
+
+    let lines = text!("{} lines", text.lines().count());
+
+    let content = vstack![language, size([600.0, 400.0], editor), lines].gap(16.0);
+    center(content)
+}
+
+fn main() {
+    let window = Window::new().title("Code Editor (examples/code_editor.rs)");
+
+    let mut data = Data {
+        language: "Rust",
+        rust: RUST.repeat(500),
+        json: String::from(JSON),
+    };
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut data).unwrap();
+}
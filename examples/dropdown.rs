@@ -0,0 +1,50 @@
+use ori::prelude::*;
+
+const FRUITS: &[&str] = &[
+    "Apple",
+    "Apricot",
+    "Banana",
+    "Blueberry",
+    "Cherry",
+    "Grape",
+    "Lemon",
+    "Mango",
+    "Orange",
+    "Peach",
+    "Pear",
+    "Plum",
+];
+
+#[derive(Default)]
+struct Data {
+    fruit: Option<&'static str>,
+    search: String,
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let dropdown = dropdown(FRUITS.iter().copied())
+        .selected(data.fruit)
+        .on_select(|cx, data: &mut Data, fruit| {
+            data.fruit = Some(*fruit);
+            cx.rebuild();
+        });
+
+    let combobox = combobox(FRUITS.iter().copied())
+        .text(&data.search)
+        .placeholder("Search fruits...")
+        .on_input(|cx, data: &mut Data, text| {
+            data.search = text;
+            cx.rebuild();
+        });
+
+    let selected = text!("Selected: {}", data.fruit.unwrap_or("nothing"));
+
+    center(vstack![selected, dropdown, combobox].gap(16.0))
+}
+
+fn main() {
+    let window = Window::new().title("Dropdown (examples/dropdown.rs)");
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut Data::default()).unwrap();
+}
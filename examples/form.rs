@@ -0,0 +1,80 @@
+use ori::prelude::*;
+
+#[derive(Default)]
+struct Data {
+    email: String,
+    password: String,
+    phone: String,
+    submitted: bool,
+}
+
+fn field(label: &str, input: TextInput<Data>) -> impl View<Data> {
+    let input = container(pad(8.0, min_width(220.0, input)))
+        .background(palette().surface)
+        .border_radius(6.0);
+
+    vstack![text(label), input].align(Align::Start).gap(4.0)
+}
+
+fn email(data: &mut Data) -> impl View<Data> {
+    let input = text_input()
+        .text(&data.email)
+        .placeholder("name@example.com")
+        .on_input(|_, data: &mut Data, text| data.email = text)
+        .validator(|_, text| match text.contains('@') {
+            true => Ok(()),
+            false => Err(String::from("Enter a valid email address")),
+        });
+
+    field("Email", input)
+}
+
+fn password(data: &mut Data) -> impl View<Data> {
+    let input = text_input()
+        .text(&data.password)
+        .password(true)
+        .reveal_toggle(true)
+        .max_length(64)
+        .on_input(|_, data: &mut Data, text| data.password = text)
+        .validator(|_, text| match text.chars().count() >= 8 {
+            true => Ok(()),
+            false => Err(String::from("At least 8 characters")),
+        });
+
+    field("Password", input)
+}
+
+fn phone(data: &mut Data) -> impl View<Data> {
+    let input = text_input()
+        .text(&data.phone)
+        .placeholder("(555) 123-4567")
+        .mask(InputMask::PHONE)
+        .on_input(|_, data: &mut Data, text| data.phone = text)
+        .on_submit(|cx, data: &mut Data, _| {
+            data.submitted = true;
+            cx.rebuild();
+        });
+
+    field("Phone", input)
+}
+
+fn app(data: &mut Data) -> impl View<Data> {
+    let status = match data.submitted {
+        true => text(format!("Submitted {}", data.phone)),
+        false => text("Press enter to submit"),
+    };
+
+    center(
+        vstack![email(data), password(data), phone(data), status]
+            .align(Align::Start)
+            .gap(12.0),
+    )
+}
+
+fn main() {
+    let window = Window::new().title("Form (examples/form.rs)");
+
+    let app = App::build().window(window, app);
+
+    ori::run(app, &mut Data::default()).unwrap();
+}
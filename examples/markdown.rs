@@ -0,0 +1,58 @@
+use ori::prelude::*;
+
+const NOTES: &str = r#"
+# Release notes
+
+Version **0.2** brings a handful of *new views*, see the [changelog](https://example.com/changelog)
+for everything else.
+
+## Highlights
+
+- Rich text with styled spans
+- A `markdown` view, which rendered this page
+  1. headings, lists and quotes
+  2. code blocks and tables
+- Faster text layout
+
+> Markdown is parsed once, and only parsed again when the text or the style changes.
+
+```rust
+markdown(NOTES).on_link(|_, _, link| info!("clicked {}", link))
+```
+
+| View       | Feature    |
+|:-----------|-----------:|
+| `calendar` | `calendar` |
+| `markdown` | `markdown` |
+
+---
+
+Thanks to everyone who contributed!
+"#;
+
+#[derive(Default)]
+struct Data {
+    link: Option<String>,
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let notes = markdown(NOTES).on_link(|cx, data: &mut Data, link| {
+        data.link = Some(link.to_string());
+        cx.rebuild();
+    });
+
+    let link = match data.link {
+        Some(ref link) => text!("Clicked {}", link),
+        None => text!("Click a link"),
+    };
+
+    let content = vstack![notes, link].align(Align::Start).gap(24.0);
+    vscroll(pad(32.0, content))
+}
+
+fn main() {
+    let window = Window::new().title("Markdown (examples/markdown.rs)");
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut Data::default()).unwrap();
+}
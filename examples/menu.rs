@@ -0,0 +1,104 @@
+use ori::prelude::*;
+
+#[derive(Clone, Debug)]
+enum Action {
+    New,
+    Clear,
+    Upper,
+    Lower,
+    ToggleWrap,
+    Quit,
+}
+
+#[derive(Default)]
+struct Data {
+    text: String,
+    wrap: bool,
+    log: Vec<String>,
+}
+
+fn menus(data: &Data) -> Vec<Menu> {
+    let file = Menu::new("&File")
+        .item(MenuItem::new("&New").accelerator("Ctrl+N").command(Action::New))
+        .item(MenuItem::new("&Open...").enabled(false))
+        .separator()
+        .item(MenuItem::new("&Quit").accelerator("Ctrl+Q").command(Action::Quit));
+
+    let edit = Menu::new("&Edit")
+        .item(MenuItem::new("&Clear").command(Action::Clear))
+        .submenu(
+            Menu::new("C&onvert")
+                .item(MenuItem::new("&Uppercase").command(Action::Upper))
+                .item(MenuItem::new("&Lowercase").command(Action::Lower)),
+        );
+
+    let view = Menu::new("&View").item(
+        MenuItem::new("&Word Wrap")
+            .accelerator("Alt+Z")
+            .checked(data.wrap)
+            .command(Action::ToggleWrap),
+    );
+
+    vec![file, edit, view]
+}
+
+fn context(data: &Data) -> Menu {
+    Menu::default()
+        .item(MenuItem::new("&Clear").command(Action::Clear))
+        .separator()
+        .item(MenuItem::new("&Uppercase").command(Action::Upper))
+        .item(MenuItem::new("&Lowercase").command(Action::Lower))
+        .separator()
+        .item(
+            MenuItem::new("&Word Wrap")
+                .checked(data.wrap)
+                .command(Action::ToggleWrap),
+        )
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let input = text_input()
+        .text(&data.text)
+        .placeholder("Right-click for a context menu")
+        .multiline(data.wrap)
+        .on_input(|_, data: &mut Data, text| data.text = text);
+
+    let input = context_menu(context(data), pad(16.0, input));
+
+    let log: Vec<_> = (data.log.iter().rev().take(8))
+        .map(|line| text(line.as_str()))
+        .collect();
+    let log = vstack(log).align(Align::Start);
+
+    vstack![menu_bar(menus(data)), input, pad(16.0, log)].align(Align::Stretch)
+}
+
+struct AppDelegate;
+
+impl Delegate<Data> for AppDelegate {
+    fn event(&mut self, cx: &mut DelegateCx<Data>, data: &mut Data, event: &Event) -> bool {
+        let Some(action) = event.cmd::<Action>() else {
+            return false;
+        };
+
+        match action {
+            Action::New | Action::Clear => data.text.clear(),
+            Action::Upper => data.text = data.text.to_uppercase(),
+            Action::Lower => data.text = data.text.to_lowercase(),
+            Action::ToggleWrap => data.wrap = !data.wrap,
+            Action::Quit => cx.quit(),
+        }
+
+        data.log.push(format!("{:?}", action));
+        cx.rebuild();
+
+        true
+    }
+}
+
+fn main() {
+    let window = Window::new().title("Menu (examples/menu.rs)");
+
+    let app = App::build().window(window, ui).delegate(AppDelegate);
+    ori::run(app, &mut Data::default()).unwrap();
+}
@@ -0,0 +1,43 @@
+use ori::prelude::*;
+
+struct Data {
+    quantity: u32,
+    price: f64,
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let quantity = number_input(data.quantity)
+        .label("Qty")
+        .range(1, 99)
+        .on_change(|cx, data: &mut Data, quantity| {
+            data.quantity = quantity;
+            cx.rebuild();
+        });
+
+    let price = number_input(data.price)
+        .label("Price")
+        .range(0.0, 1000.0)
+        .step(0.25)
+        .format(NumberFormat::system().precision(2))
+        .on_change(|cx, data: &mut Data, price| {
+            data.price = price;
+            cx.rebuild();
+        });
+
+    let total = data.quantity as f64 * data.price;
+    let total = NumberFormat::system().precision(2).format(total);
+
+    center(vstack![quantity, price, text(format!("Total: {}", total))].gap(8.0))
+}
+
+fn main() {
+    let window = Window::new().title("Number Input (examples/number_input.rs)");
+
+    let mut data = Data {
+        quantity: 1,
+        price: 9.5,
+    };
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut data).unwrap();
+}
@@ -0,0 +1,99 @@
+use ori::prelude::*;
+
+#[derive(Default)]
+struct Data {
+    name: String,
+    saved: Vec<String>,
+    notice: Option<OverlayId>,
+}
+
+fn card<V>(content: V) -> impl View<Data>
+where
+    V: View<Data>,
+{
+    container(pad(16.0, content))
+        .background(palette().surface)
+        .border_radius(8.0)
+}
+
+fn confirm(_data: &mut Data, id: OverlayId) -> impl View<Data> {
+    let cancel = on_click(button(text("Cancel")), move |cx, _: &mut Data| {
+        cx.close_overlay(id);
+    });
+
+    let discard = on_click(
+        button(text("Discard")).fancy(4.0),
+        move |cx, data: &mut Data| {
+            data.name.clear();
+            cx.close_overlay(id);
+            cx.rebuild();
+        },
+    );
+
+    card(vstack![
+        text("Discard the name?"),
+        hstack![cancel, discard].gap(8.0)
+    ])
+}
+
+fn save_sheet(data: &mut Data, id: OverlayId) -> impl View<Data> {
+    let saved: Vec<_> = (data.saved.iter().rev())
+        .map(|name| text(name.as_str()))
+        .collect();
+
+    let close = on_click(button(text("Close")), move |cx, _: &mut Data| {
+        cx.close_overlay(id);
+    });
+
+    card(vstack![text("Saved names"), vstack(saved), close].align(Align::Start))
+}
+
+fn notice(_data: &mut Data, _id: OverlayId) -> impl View<Data> {
+    card(text("Saved!"))
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let input = text_input()
+        .text(&data.name)
+        .placeholder("Name")
+        .on_input(|_, data: &mut Data, text| data.name = text);
+
+    let discard = on_click(button(text("Discard")), |cx, _: &mut Data| {
+        cx.open_overlay(Overlay::dialog(confirm).dismissible(false));
+    });
+
+    let save = on_click(button(text("Save")).fancy(4.0), |cx, data: &mut Data| {
+        data.saved.push(data.name.clone());
+
+        if let Some(id) = data.notice.take() {
+            cx.close_overlay(id);
+        }
+
+        // a panel doesn't block input, the user can keep typing while it's shown
+        let panel = Overlay::panel(Alignment::TOP_RIGHT, notice);
+        let id = panel.id();
+
+        let panel = panel.on_close(move |_, data: &mut Data| {
+            if data.notice == Some(id) {
+                data.notice = None;
+            }
+        });
+
+        data.notice = Some(cx.open_overlay(panel));
+    });
+
+    let history = on_click(button(text("History")), |cx, _: &mut Data| {
+        cx.open_overlay(Overlay::sheet(save_sheet));
+    });
+
+    let buttons = hstack![discard, history, save].gap(8.0);
+    // overlays are only shown inside of an overlay layer
+    overlay_layer(center(vstack![min_width(200.0, input), buttons].gap(16.0)))
+}
+
+fn main() {
+    let window = Window::new().title("Overlay (examples/overlay.rs)");
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut Data::default()).unwrap();
+}
@@ -0,0 +1,43 @@
+use ori::prelude::*;
+
+#[derive(Default)]
+struct Data {
+    progress: f32,
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let step = on_click(button(text("Step")), |cx, data: &mut Data| {
+        data.progress = (data.progress + 0.1).min(1.0);
+        cx.rebuild();
+    });
+
+    let reset = on_click(button(text("Reset")), |cx, data: &mut Data| {
+        data.progress = 0.0;
+        cx.rebuild();
+    });
+
+    let percent = text!("{:.0}%", data.progress * 100.0);
+
+    let determinate = vstack![
+        progress(data.progress),
+        hstack![progress_ring(data.progress), percent].gap(8.0),
+        hstack![reset, step].gap(8.0),
+    ]
+    .gap(16.0);
+
+    // the spinner stops animating while it's scrolled out of view
+    let scrolled = vscroll(vstack![
+        text("Scroll down"),
+        height(400.0, center(spinner())),
+        text("Scroll up"),
+    ]);
+
+    center(vstack![determinate, height(200.0, scrolled)].gap(32.0))
+}
+
+fn main() {
+    let window = Window::new().title("Progress (examples/progress.rs)");
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut Data::default()).unwrap();
+}
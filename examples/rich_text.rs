@@ -0,0 +1,40 @@
+use ori::prelude::*;
+
+#[derive(Default)]
+struct Data {
+    clicks: u32,
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let link = span("this link")
+        .color(palette().primary)
+        .on_click(|cx, data: &mut Data| {
+            data.clicks += 1;
+            cx.rebuild();
+        });
+
+    let paragraph = rich_text([
+        span("Rich text is laid out as a "),
+        span("single").font_weight(FontWeight::BOLD),
+        span(" paragraph, so "),
+        span("styled words").font_style(FontStyle::Italic),
+        span(" wrap together with the rest. Spans can use a "),
+        span("monospace").font_family(FontFamily::Monospace),
+        span(" font, be "),
+        span("larger").font_size(24.0),
+        span(" or be clicked, like "),
+        link,
+        span("."),
+    ]);
+
+    let clicks = text!("The link was clicked {} times", data.clicks);
+
+    center(width(300.0, vstack![paragraph, clicks].gap(16.0)))
+}
+
+fn main() {
+    let window = Window::new().title("Rich Text (examples/rich_text.rs)");
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut Data::default()).unwrap();
+}
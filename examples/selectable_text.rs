@@ -0,0 +1,19 @@
+use ori::prelude::*;
+
+fn ui() -> impl View {
+    let error = selectable_text("error[E0308]: mismatched types, expected `u32`, found `&str`")
+        .font_family(FontFamily::Monospace)
+        .color(palette().danger);
+
+    let id = selectable_text("Request id: 0f9b6c2e-51a4-4c57-a3d4-8e1f0a7b9d13");
+
+    let hint = text("Drag, double click or triple click to select, Ctrl+C to copy")
+        .color(palette().contrast_low);
+
+    center(width(400.0, vstack![error, id, hint].gap(16.0)))
+}
+
+fn main() {
+    let window = Window::new().title("Selectable Text (examples/selectable_text.rs)");
+    ori::run_simple(window, ui).unwrap();
+}
@@ -0,0 +1,52 @@
+use ori::prelude::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum Size {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl std::fmt::Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+#[derive(Default)]
+struct Data {
+    size: Size,
+    view: &'static str,
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let sizes = [Size::Small, Size::Medium, Size::Large];
+
+    let radio = radio_group(data.size, sizes).on_change(|cx, data: &mut Data, size| {
+        data.size = *size;
+        cx.rebuild();
+    });
+
+    let view = if data.view.is_empty() {
+        "List"
+    } else {
+        data.view
+    };
+    let segmented =
+        segmented(view, ["List", "Grid", "Columns"]).on_change(|cx, data: &mut Data, view| {
+            data.view = *view;
+            cx.rebuild();
+        });
+
+    let summary = text!("{} items as {}", data.size, view);
+
+    center(vstack![segmented, radio, summary].gap(16.0))
+}
+
+fn main() {
+    let window = Window::new().title("Selection (examples/selection.rs)");
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut Data::default()).unwrap();
+}
@@ -0,0 +1,46 @@
+use ori::prelude::*;
+
+struct Data {
+    sidebar: Vec<f32>,
+    editor: Vec<f32>,
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Self {
+            sidebar: vec![0.25, 0.75],
+            editor: vec![0.7, 0.3],
+        }
+    }
+}
+
+fn panel(title: &str, color: Color) -> impl View<Data> {
+    container(center(text(title))).background(color)
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let editor = vsplit((
+        panel("Editor", palette().surface),
+        panel("Terminal", palette().surface_low),
+    ))
+    .ratios(data.editor.clone())
+    .min_size(0, 100.0)
+    .on_resize(|_, data: &mut Data, ratios| {
+        data.editor = ratios.to_vec();
+    });
+
+    hsplit((panel("Files", palette().surface_high), editor))
+        .ratios(data.sidebar.clone())
+        .min_size(0, 120.0)
+        .max_size(0, 400.0)
+        .on_resize(|_, data: &mut Data, ratios| {
+            data.sidebar = ratios.to_vec();
+        })
+}
+
+fn main() {
+    let window = Window::new().title("Split (examples/split.rs)");
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut Data::default()).unwrap();
+}
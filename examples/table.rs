@@ -0,0 +1,94 @@
+use ori::prelude::*;
+
+struct Person {
+    name: String,
+    age: u32,
+    city: &'static str,
+}
+
+struct Data {
+    people: Vec<Person>,
+    sort: Option<(usize, SortOrder)>,
+    selected: Option<usize>,
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        const NAMES: &[&str] = &["Alice", "Bob", "Carol", "Dave", "Eve", "Frank", "Grace"];
+        const CITIES: &[&str] = &["Oslo", "Lima", "Pune", "Kyoto", "Accra", "Quito"];
+
+        let people = (0..10_000)
+            .map(|i| Person {
+                name: format!("{} {}", NAMES[i % NAMES.len()], i),
+                age: (i * 37 % 80) as u32 + 18,
+                city: CITIES[i * 7 % CITIES.len()],
+            })
+            .collect();
+
+        Self {
+            people,
+            sort: None,
+            selected: None,
+        }
+    }
+}
+
+impl Data {
+    fn sort(&mut self, column: usize, order: SortOrder) {
+        self.people.sort_by(|a, b| {
+            let ordering = match column {
+                0 => a.name.cmp(&b.name),
+                1 => a.age.cmp(&b.age),
+                _ => a.city.cmp(b.city),
+            };
+
+            match order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+
+        self.sort = Some((column, order));
+        self.selected = None;
+    }
+}
+
+fn cell(data: &mut Data, row: usize, column: usize) -> impl View<Data> {
+    let person = &data.people[row];
+
+    match column {
+        0 => text(&person.name),
+        1 => text!("{}", person.age),
+        _ => text(person.city),
+    }
+    .font_size(14.0)
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let columns = [
+        TableColumn::new("Name").width(200.0),
+        TableColumn::new("Age").width(80.0),
+        TableColumn::new("City").width(160.0),
+    ];
+
+    let table = table(columns, data.people.len(), cell)
+        .sort(data.sort)
+        .selected(data.selected)
+        .on_sort(|cx, data: &mut Data, column, order| {
+            data.sort(column, order);
+            cx.rebuild();
+        })
+        .on_select(|cx, data: &mut Data, row| {
+            data.selected = Some(row);
+            cx.rebuild();
+        });
+
+    pad(16.0, table)
+}
+
+fn main() {
+    let window = Window::new().title("Table (examples/table.rs)");
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut Data::default()).unwrap();
+}
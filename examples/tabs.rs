@@ -0,0 +1,104 @@
+use ori::prelude::*;
+
+struct Document {
+    name: String,
+    text: String,
+}
+
+struct Data {
+    documents: Vec<Document>,
+    active: usize,
+    next: usize,
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Self {
+            documents: vec![Document {
+                name: String::from("Untitled 1"),
+                text: String::new(),
+            }],
+            active: 0,
+            next: 2,
+        }
+    }
+}
+
+impl Data {
+    fn new_document(&mut self) {
+        self.documents.push(Document {
+            name: format!("Untitled {}", self.next),
+            text: String::new(),
+        });
+
+        self.active = self.documents.len() - 1;
+        self.next += 1;
+    }
+
+    fn close_document(&mut self, index: usize) {
+        self.documents.remove(index);
+
+        if self.active > index || self.active >= self.documents.len() {
+            self.active = self.active.saturating_sub(1);
+        }
+    }
+
+    fn move_document(&mut self, from: usize, to: usize) {
+        let document = self.documents.remove(from);
+        self.documents.insert(to, document);
+
+        if self.active == from {
+            self.active = to;
+        } else if from < self.active && self.active <= to {
+            self.active -= 1;
+        } else if to <= self.active && self.active < from {
+            self.active += 1;
+        }
+    }
+}
+
+fn editor(data: &mut Data, index: usize) -> impl View<Data> {
+    let input = text_input()
+        .text(&data.documents[index].text)
+        .placeholder("Start typing...")
+        .multiline(true)
+        .on_input(move |_, data: &mut Data, text| data.documents[index].text = text);
+
+    pad(16.0, input)
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let titles = data
+        .documents
+        .iter()
+        .map(|document| Tab::new(document.name.as_str()).closable(true));
+
+    let tabs = tabs(titles, data.active, editor)
+        .keep_alive(true)
+        .on_select(|cx, data: &mut Data, index| {
+            data.active = index;
+            cx.rebuild();
+        })
+        .on_close(|cx, data: &mut Data, index| {
+            data.close_document(index);
+            cx.rebuild();
+        })
+        .on_reorder(|cx, data: &mut Data, from, to| {
+            data.move_document(from, to);
+            cx.rebuild();
+        });
+
+    let new_document = on_click(button(text("New document")), |cx, data: &mut Data| {
+        data.new_document();
+        cx.rebuild();
+    });
+
+    vstack![pad(8.0, new_document), flex(tabs)].align(Align::Stretch)
+}
+
+fn main() {
+    let window = Window::new().title("Tabs (examples/tabs.rs)");
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut Data::default()).unwrap();
+}
@@ -0,0 +1,112 @@
+use std::path::PathBuf;
+
+use ori::prelude::*;
+
+struct Entry {
+    path: PathBuf,
+    is_dir: bool,
+    // loaded when the directory is first expanded
+    children: Option<Vec<Entry>>,
+}
+
+impl Entry {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            is_dir: path.is_dir(),
+            path,
+            children: None,
+        }
+    }
+
+    fn name(&self) -> String {
+        let name = self.path.file_name().unwrap_or(self.path.as_os_str());
+        name.to_string_lossy().into_owned()
+    }
+
+    fn load(&mut self) -> &mut Vec<Entry> {
+        self.children.get_or_insert_with(|| {
+            let mut children: Vec<_> = std::fs::read_dir(&self.path)
+                .into_iter()
+                .flatten()
+                .flatten()
+                .map(|entry| Entry::new(entry.path()))
+                .collect();
+
+            children.sort_by_key(|entry| (!entry.is_dir, entry.name()));
+            children
+        })
+    }
+}
+
+struct Data {
+    root: Entry,
+    selected: Vec<String>,
+}
+
+impl Data {
+    fn entry(&mut self, path: &[usize]) -> &mut Entry {
+        let mut entry = &mut self.root;
+
+        for &index in path {
+            entry = &mut entry.load()[index];
+        }
+
+        entry
+    }
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let roots = data.root.load().len();
+
+    let files = tree(
+        roots,
+        |data: &mut Data, path| match data.entry(path).is_dir {
+            true => data.entry(path).load().len(),
+            false => 0,
+        },
+        |data, path| {
+            let entry = data.entry(path);
+
+            let name = match entry.is_dir {
+                true => format!("{}/", entry.name()),
+                false => entry.name(),
+            };
+
+            left(text(name))
+        },
+    )
+    .expandable(|data, path| data.entry(path).is_dir)
+    .key(|data, path| {
+        // the parents are always loaded, since their children are only keyed when expanded
+        let mut entry = &data.root;
+
+        for &index in path {
+            entry = &entry.children.as_ref().unwrap()[index];
+        }
+
+        entry.path.clone()
+    })
+    .multiple(true)
+    .on_select(|cx, data, paths| {
+        let selected = (paths.iter()).map(|path| data.entry(path).name()).collect();
+
+        data.selected = selected;
+        cx.rebuild();
+    });
+
+    let selected = text(format!("Selected: {}", data.selected.join(", ")));
+
+    vstack![flex(vscroll(files)), pad(8.0, selected)].align(Align::Stretch)
+}
+
+fn main() {
+    let window = Window::new().title("Tree (examples/tree.rs)");
+
+    let mut data = Data {
+        root: Entry::new(PathBuf::from(".")),
+        selected: Vec::new(),
+    };
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut data).unwrap();
+}
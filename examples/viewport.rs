@@ -0,0 +1,56 @@
+use ori::prelude::*;
+
+#[derive(Default)]
+struct Data {
+    selected: Option<&'static str>,
+}
+
+fn node(name: &'static str, x: f32, y: f32) -> impl View<Data> {
+    let button = on_click(button(text(name)).fancy(4.0), move |cx, data: &mut Data| {
+        data.selected = Some(name);
+        cx.rebuild();
+    });
+
+    translate((x, y), button)
+}
+
+fn canvas() -> impl View<Data> {
+    let background = container(size([1000.0, 600.0], ()))
+        .background(palette().surface_low)
+        .border_radius(8.0);
+
+    let nodes = zstack![
+        background,
+        node("Start", 80.0, 120.0),
+        node("Load", 360.0, 60.0),
+        node("Transform", 360.0, 360.0),
+        node("Save", 760.0, 220.0),
+    ];
+
+    viewport(nodes).fit(true)
+}
+
+fn fit_button() -> impl View<Data> {
+    on_click(button(text("Fit")).fancy(4.0), |cx, _| {
+        cx.cmd(FitToContent);
+    })
+}
+
+fn app(data: &mut Data) -> impl View<Data> {
+    let status = match data.selected {
+        Some(name) => text(format!("Selected {}", name)),
+        None => text("Scroll to zoom, drag with the middle button or space to pan"),
+    };
+
+    let toolbar = pad(8.0, hstack![fit_button(), status].gap(8.0));
+
+    vstack![toolbar, flex(canvas())]
+}
+
+fn main() {
+    let window = Window::new().title("Viewport (examples/viewport.rs)");
+
+    let app = App::build().window(window, app);
+
+    ori::run(app, &mut Data::default()).unwrap();
+}
@@ -0,0 +1,66 @@
+use ori::prelude::*;
+
+struct Data {
+    lines: Vec<String>,
+    jump_to: Option<usize>,
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        let lines = (0..100_000)
+            .map(|i| format!("[{:>6}] log line number {}", i, i))
+            .collect();
+
+        Self {
+            lines,
+            jump_to: None,
+        }
+    }
+}
+
+fn line(data: &mut Data, index: usize) -> impl View<Data> {
+    let color = match index % 2 {
+        0 => palette().surface,
+        _ => palette().surface_low,
+    };
+
+    let line = text(&data.lines[index])
+        .font_size(14.0)
+        .font_family(FontFamily::Monospace);
+
+    container(pad([8.0, 2.0], line)).background(color)
+}
+
+fn jump_button(label: &'static str, index: usize) -> impl View<Data> {
+    on_click(
+        button(text(label)).fancy(4.0),
+        move |cx, data: &mut Data| {
+            data.jump_to = Some(index);
+            cx.rebuild();
+        },
+    )
+}
+
+fn ui(data: &mut Data) -> impl View<Data> {
+    let list = virtual_list(data.lines.len(), line)
+        .item_height(24.0)
+        .scroll_to_index(data.jump_to);
+
+    let buttons = hstack![
+        jump_button("Top", 0),
+        jump_button("Middle", 50_000),
+        jump_button("Bottom", 99_999),
+    ]
+    .gap(8.0);
+
+    let content = vstack![buttons, expand(vscroll(list))].gap(8.0);
+
+    pad(8.0, content)
+}
+
+fn main() {
+    let window = Window::new().title("Virtual list (examples/virtual_list.rs)");
+
+    let app = App::build().window(window, ui);
+    ori::run(app, &mut Data::default()).unwrap();
+}